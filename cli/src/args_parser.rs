@@ -2,6 +2,8 @@ use clap::Arg;
 use clap::SubCommand;
 use clap::{App, AppSettings};
 
+use android_localization_core::csv_dialect;
+
 use crate::constants;
 
 mod doc {
@@ -28,10 +30,153 @@ together in the same file. An example file would be -
 string_name, default_locale         , spanish, french
 string_1   , string_1 default locale,
 string_3   , string_3 default locale,
+
+Plurals are written out as one row per quantity with the quantity in
+square brackets after the plural's name. Eg., plural_1[one], plural_1[other].
+Each locale gets the quantities its language needs as per the CLDR plural
+rules (Eg., `many` for French) with the default text of `other` for the ones
+the default plural doesn't have. Quantities that are still missing from a
+foreign plural keep being written out. `localized` doesn't write plurals
+out until their `other` quantity is localized
+String arrays are written out as one row per item with the item's index in
+square brackets after the array's name. Eg., array_1[0], array_1[1]
+
+Values are written out as markup, the same way they appear in the strings
+file. Inline elements like <b> or <xliff:g> must be kept intact in the
+localized texts
+
+If any string has an XML comment right before it or a `tools:maxLength`
+attribute, `description` & `max_length` columns are written out right after
+`default_locale` to give translators some context. `localized` skips them
+
+A CSV file is written out per group of locales requiring the same strings
+to be localized & named after them (Eg., to_localize_french_spanish.csv).
+Locales, strings & files are always written out in the same order so that
+the output can be committed & diffed
+
+With `--grouping per-locale`, a CSV file is written out per locale instead.
+With `--grouping single`, a single CSV file is written out with a column
+per locale & a row per string that any of the locales need localized (the
+cells of the locales that don't need it are left empty too). XLSX workbooks
+get a sheet per CSV file that would have been written out
+
+Output files that already exist aren't touched unless `--overwrite` or
+`--merge` is passed in. With `--merge`, the texts already filled in a CSV
+file are kept (as long as the default value of the string hasn't changed)
+& only the strings that still need to be localized are written out
+
+With `--state-file`, the strings whose default value has changed since they
+were localized (as recorded by `localized` in the same state file) are
+written out too. Plurals are checked per quantity & string arrays are
+written out as a whole if any of their items has changed. CSV files then
+get a `status` column (`new` or `changed`) & a column per locale with the
+old localized texts (Eg., `french (old)`)
+
+With `--suggest`, translations of the same or similar default texts (off
+by an edit or two) that are already in the strings files are written out in
+a column per locale (Eg., `french (suggestion)`) for translators to reuse.
+`localized` skips them. With `--fill-exact-matches`, strings whose default
+text has already been localized (Eg., `Cancel` under `cancel_button`) are
+written straight into the foreign strings files with the same translation
+instead of being written out. Plurals & string arrays are never filled in
+
+With `--translation-endpoint`, the texts to be localized are machine
+translated & written out in a column per locale (Eg., `french (draft)`) for
+translators to review. `localized` skips them. The texts of every locale are
+posted to the endpoint as `{"source_language": "en", "target_language":
+"fr", "texts": [...]}` (the BCP 47 tag of the locale like pt-BR for
+pt-rBR, not its mapped name) & a
+`{"translations": [...]}` with a translation per text is expected back.
+Texts are sent as plain text (escapes & entities are resolved) with format
+specifiers & markup swapped for placeholders (Eg., `⟦0⟧`) that must come
+back as is. Only works with CSV files
+
+With `--stats`, nothing is written out. Instead, the number of strings,
+words & characters (format specifiers & markup aren't counted) to be
+localized are printed out for every locale, along with the repeated segments
+(texts that are the same as that of another string). With `--rate` (Eg.,
+`french=0.12` per word), the cost of every locale is printed out too. Words
+in repeated segments aren't charged for. Use `--stats-format json` to get
+the same as a JSON object
+
+CSV files are comma separated UTF-8 files by default. Spreadsheet apps
+often expect something else (like `;` as the delimiter in some locales or
+UTF-16 instead of UTF-8). Use `--csv-delimiter`, `--csv-encoding`,
+`--csv-bom` & `--csv-quote` to write out files they open right away
+
+With `--csv-formula-safe`, default values starting with `=`, `+`, `-` or `@`
+are written out with a leading `'` so that spreadsheet apps don't run them
+as formulas. Pass the same flag to `localized` to strip it off again
+
+With `--format xliff`, a single XLIFF 1.2 file is written out instead with
+a `file` element per foreign locale & source file. Locales are written out
+as BCP 47 language tags (Eg., pt-BR for pt-rBR). Markup & format
+specifiers are written out as `ph` elements, comments as `note`s & every
+unit has a `needs-translation` target
+
+With `--format po`, a POT template (to_localize.pot) with every string &
+a PO file per foreign locale (to_localize_<locale>.po) are written out.
+The `Language` header of a PO file is the BCP 47 language tag of its
+locale. Every entry carries the string's name in `msgctxt`, its default
+value in `msgid` & the XML comment right before it as a translator comment
+
+With `--format xlsx`, a single workbook (to_localize.xlsx) is written out
+instead with a sheet per group of locales, named after the locales in it
+(cut down to the 31 characters Excel allows). Sheets are laid out the same
+way as the CSV files. Values are always text (so that spreadsheet apps
+don't turn them into numbers or formulae) & only the columns of the
+foreign locales can be edited
+
+With `--format ios`, an en.lproj dir (with every string & its value) & a
+<locale>.lproj dir per foreign locale (with empty values) are written out,
+each with a Localizable.strings file & a Localizable.stringsdict file for
+the plurals. Keys are the string names, `%1$s` becomes `%1$@` & Android
+escapes are turned into plain text
+
+With `--format json`, a single i18next style JSON file (to_localize.json)
+is written out instead with an object per foreign locale. Every string is
+an object with its default value as `source` & an empty `target` to be
+filled in. Strings of a module are nested in an object for the module
+
+With `--format properties`, a Java resource bundle is written out instead
+with a base file (to_localize.properties) with every string & its value &
+a file per foreign locale (to_localize_<locale>.properties) with empty
+values. Keys are the string names (prefixed with the module) & values are
+plain text. Anything other than ASCII is written as a unicode escape
+
+With `--format arb`, a Flutter ARB file with every string & its value
+(to_localize.arb) & one per foreign locale (to_localize_<locale>.arb) with
+empty messages are written out. Format specifiers become placeholders
+(`%1$s` becomes `{arg1}`) declared in the `@key` metadata along with the
+XML comment of the string as its description
 "#;
 
         pub mod args {
             pub static OUTPUT_DIR: &str = "Specifies output dir to write CSV files to";
+            pub static CSV_DELIMITER: &str =
+                "Delimiter to write CSV files with (a single char or `tab`)";
+            pub static FORMAT: &str = "Format to write the texts to be localized in";
+            pub static GROUPING: &str =
+                "How to split locales across CSV files (or the sheets of an XLSX workbook)";
+            pub static OVERWRITE: &str = "Set this to overwrite output files that already exist";
+            pub static MERGE: &str =
+                "Set this to keep the texts already filled in CSV files that already exist";
+            pub static CSV_BOM: &str =
+                "Set this to start UTF-8 CSV files with a BOM (Excel needs it to detect UTF-8)";
+            pub static CSV_ENCODING: &str =
+                "Encoding to write CSV files in (UTF-16 files always start with a BOM)";
+            pub static CSV_QUOTE: &str =
+                "Whether to quote every CSV field or only the ones that need it";
+            pub static SUGGEST: &str =
+                "Set this to write out existing translations of similar texts as suggestions";
+            pub static FILL_EXACT_MATCHES: &str =
+                "Set this to fill in strings whose default text has already been localized";
+            pub static TRANSLATION_ENDPOINT: &str =
+                "URL of a translation service to write out machine translations as drafts";
+            pub static STATS: &str =
+                "Set this to print out word counts of the texts to be localized instead";
+            pub static STATS_FORMAT: &str = "Format to print out the word counts in";
+            pub static RATE: &str = "Locale (french) to rate per word; Eg., french=0.12";
             pub mod mapping {
                 pub static SHORT: &str = "Locale ID (fr) to CSV file name (french); Eg., fr=french";
                 pub static LONG: &str = r#"
@@ -44,6 +189,10 @@ case for these mappings is that they can be used as a filter. For eg., if an
 Android project happens to have 3 locales - de, es & fr and if only mappings
 for 2 of them are defined, the written CSV file would only carry those headers
 
+Locale IDs are written the same way they appear in the values dir names. Eg.,
+fr, pt-rBR (for values-pt-rBR) or b+sr+Latn (for values-b+sr+Latn). Values
+dirs with other qualifiers (values-night, values-fr-land etc.) are skipped
+
 Note: When no mappings are given, all foreign locales will be included in
 the CSV file with their appropriate locale IDs as headers
             "#;
@@ -63,13 +212,70 @@ string_name, default_locale         , spanish        , french
 string_1   , string_1 default locale, spanish value 1, french value 1
 string_3   , string_3 default locale, spanish value 2, french value 2
 
+CSV files can be UTF-8 or UTF-16 (either of which can start with a BOM;
+UTF-16 files have to). Pass in `--csv-delimiter` if the file isn't comma
+separated (Eg., `--csv-delimiter ";"` or `--csv-delimiter tab`). Pass in
+`--csv-formula-safe` if the file was written out with it. A leading `'` is
+stripped off of values starting with `'=`, `'+`, `'-` or `'@`
+
+Columns written out by `localize` for translators (`description`,
+`max_length`, `status` & the ones with the old localized texts) are skipped
+
+XLIFF 1.2 files (with the .xlf or .xliff extension) are read too. Only
+the targets that are `translated`, `signed-off` or `final` (or don't have
+a state at all) are read. Units with `translate="no"` are skipped & so are
+the alternatives in `alt-trans` elements. Markup & format specifiers are
+expected to be in `ph` elements. The `target-language` of a `file` can be
+the language tag, ID or mapped name of its locale
+
+PO files (with the .po extension) are read too. The locale is picked off
+of the `Language` header, which can be the language code (Eg., pt_BR or
+pt-BR), ID or mapped name of the locale. Files for any other language are
+an error. Fuzzy entries are skipped
+
+XLSX workbooks (with the .xlsx extension) are read too. Every sheet is
+expected to be laid out the same way as a CSV file
+
+iOS strings files (with the .strings or .stringsdict extension) are read
+too, along with their sibling with the other extension. The file has to
+be in a <locale>.lproj dir (named after either the locale ID or its mapped
+name) & the defaults are read from the Base.lproj (or en.lproj) dir next
+to it. This helps seed Android translations from existing iOS ones
+
+JSON files (with the .json extension) are read too. Every string is an
+object with a `source` & a `target` (nested in objects for its module or
+with the module in its key). Strings with an empty `target` are skipped
+
+Java .properties files & Flutter ARB files (with the .properties or .arb
+extension) are read too. The file has to be named after its locale (Eg.,
+messages_fr.properties or app_fr.arb) & the defaults are read from the
+base file of its bundle (messages.properties or app.arb). The locale of
+an ARB file is picked off of `@@locale` if it is there. ARB placeholders
+are turned back into format specifiers
+
 When populating the `strings.xml` file, translated texts will be written
 only if that particular string's value has stayed the same in the default
-locale
+locale. A string array is written only if all of its items are localized
+
+Everything else in the foreign strings files (comments, attributes, other
+resources etc.) is left as is. Only the changed strings are rewritten in
+place & the new ones are added at the end
+
+A changed string is rewritten in whichever file of the foreign values dir
+it already is in. A new string goes into the foreign file with the same
+name as the default file it is from. Eg., a string from
+values/strings_settings.xml goes into values-fr/strings_settings.xml
+
+With `--state-file`, the default value every string (& every plural
+quantity & string array item) was localized against is recorded (as a
+hash) in the state file. Pass in the same file to
+`localize` to find the strings whose default value has changed since
 "#;
 
         pub mod args {
-            pub static INPUT_FILE: &str = "Specifies input CSV file to read localized texts from";
+            pub static CSV_DELIMITER: &str = "Delimiter of the CSV file (a single char or `tab`)";
+            pub static INPUT_FILE: &str =
+                "Specifies input CSV (or XLIFF, PO, XLSX, iOS strings, JSON, properties or ARB) file to read localized texts from";
             pub mod mapping {
                 pub static SHORT: &str = "CSV file name (french) to locale ID (fr); Eg., french=fr";
                 pub static LONG: &str = r#"
@@ -83,6 +289,9 @@ localized texts are available for 3 locales - de, es & fr and if only mappings
 for 2 of them are defined, the `strings.xml` files of only those locales would
 be updated
 
+Locale IDs are written the same way they appear in the values dir names. Eg.,
+fr, pt-rBR (for values-pt-rBR) or b+sr+Latn (for values-b+sr+Latn)
+
 Note: When no mappings are given, all foreign locales will be updated with
 the consideration that the headers are the locale IDs
             "#;
@@ -93,11 +302,26 @@ the consideration that the headers are the locale IDs
     pub mod validate {
         pub static SHORT: &str = "Runs some common validations on XML string files";
         pub static LONG: &str = r#"
-The following validations are run on the `strings.xml` files
+The following validations are run on the strings files
     - Checking for unlocalized strings
     - Unescaped apostrophe (`'` without a preceeding `\`)
     - Format string mismatch with default locale (this could be either the
       number of format strings or the type of data they refer to)
+    - Glossary terms not translated as required (only with `--glossary`)
+
+Unlocalized strings are reported against the foreign file with the same
+name as the default file they are from. Foreign plurals must have every
+quantity their language needs as per the CLDR plural rules
+
+A glossary is a CSV file with a `term` column, an optional `do_not_translate`
+column (`true` or `false`) & a column per locale ID (Eg., fr or pt-rBR) with
+the required translation of the term. Foreign texts whose default text has
+the term in it (as a whole word) must have the required translation (or the
+term itself, if it isn't to be translated) in them. Case is ignored. Eg.,
+
+    term,do_not_translate,fr,es
+    Wallet,,Portefeuille,Cartera
+    Acme,true,,
 
 Note: There are known corner cases whether these validations would be failing
 incorrectly. As of now, this validation is not aware of the allowed grammar
@@ -107,16 +331,80 @@ of the `strings.xml` files. This uses some naive regex to validate
         pub mod args {
             pub static SKIP_UNLOCALIZED: &str =
                 "Set this to not fail validation in case there are unlocalized default strings";
+            pub static GLOSSARY: &str =
+                "Specifies a CSV file with terms that must be translated consistently";
         }
     }
 
+    pub mod pseudolocalize {
+        pub static SHORT: &str =
+            "Writes out the en-XA & ar-XB pseudolocales of the default XML string files";
+        pub static LONG: &str = r#"
+Writes out the strings files of the pseudolocales that Android ships with
+to test an app's UI without real translations
+    - values-en-rXA: letters are accented, texts are ~30% longer (padded
+      with words) & bracketed. Eg., `Hello` => `[Ĥéļļö one]`. This helps
+      catch hardcoded & truncated texts
+    - values-ar-rXB: words are wrapped in right to left marks so that texts
+      are shown right to left. This helps catch layouts that don't mirror
+
+Every default file with localizable strings, plurals or string arrays gets
+a file with the same name in the values dir of each pseudolocale. Format
+specifiers (`%1$s`), markup, the content of `xliff:g` elements, escapes,
+entities & CDATA sections are left as is
+
+These files are generated & are written over on every run. They aren't
+considered foreign locales by `localize`, `localized` & `validate`
+
+Pseudolocales have to be turned on with `pseudoLocalesEnabled true` in the
+build type of the module's `build.gradle` for them to be shown on devices
+        "#;
+    }
+
     pub mod common {
+        pub static CSV_FORMULA_SAFE: &str =
+            "Set this to escape CSV values that spreadsheet apps would run as formulas";
+        pub static STATE_FILE: &str =
+            "JSON file with the default values the strings were localized against";
         pub static RES_DIR_SHORT: &str = "Points to the `res` dir of an Android module";
         pub static RES_DIR_LONG: &str = r#"
 This utility expects the Android module to follow the standard structure.
 Eg., if there is a default locale & 2 foreign locales (french & spanish),
-the `strings.xml` files are expected to be found in their respective
-values folders => values, values-fr & values-es
+the strings files are expected to be found in their respective values
+folders => values, values-fr & values-es
+
+Every `*.xml` file in a values folder is read (strings can be split across
+strings.xml, strings_settings.xml etc.). Strings in files whose names start
+with `donottranslate` are considered non-translatable
+"#;
+
+        pub static OVERLAY_RES_DIR_SHORT: &str =
+            "Points to the `res` dir of a flavor or a build type that overrides `res-dir`";
+        pub static OVERLAY_RES_DIR_LONG: &str = r#"
+Android merges the `res` dirs of a variant's source sets. Eg., for the
+`freeDebug` variant, src/main/res, src/free/res & src/debug/res. Pass in the
+main res dir as `res-dir` & the rest as overlays (in the increasing order of
+precedence; AGP gives a build type precedence over a flavor). A string in an
+overlay overrides the string with the same name in the dirs before it
+
+All the strings files are read together as one. Translations of an
+overridden string from dirs before the one that overrides it are ignored.
+Strings are written out along with the name of the source set they are from
+(in a `module` column). New translations go into the source set of the
+default string unless there is an existing translation elsewhere
+"#;
+
+        pub static PROJECT_DIR_SHORT: &str =
+            "Points to the root dir of a Gradle project (instead of a `res` dir)";
+        pub static PROJECT_DIR_LONG: &str = r#"
+Every `src/*/res` dir under the project dir is worked on (hidden & `build`
+dirs are skipped). Eg., app/src/main/res, app/src/debug/res,
+features/settings/src/main/res etc.
+
+The CSV files get an extra leading `module` column with the path of the
+res dir (relative to the project dir) each string is from. This column
+is used to write the localized texts back into the right res dir. A res
+dir is only localized into the locales it already has a values dir for
 "#;
     }
 }
@@ -132,13 +420,20 @@ pub fn build() -> App<'static, 'static> {
         .subcommand(build_localize_sub_command())
         .subcommand(build_localized_sub_command())
         .subcommand(build_validate_sub_command())
+        .subcommand(build_pseudolocalize_sub_command())
 }
 
 fn build_localize_sub_command() -> App<'static, 'static> {
     SubCommand::with_name(constants::commands::LOCALIZE)
         .about(doc::localize::SHORT)
         .long_about(doc::localize::LONG)
-        .arg(build_res_dir_arg())
+        .arg(
+            build_res_dir_arg()
+                .required_unless(constants::args::PROJECT_DIR)
+                .conflicts_with(constants::args::PROJECT_DIR),
+        )
+        .arg(build_overlay_res_dir_arg().conflicts_with(constants::args::PROJECT_DIR))
+        .arg(build_project_dir_arg())
         .arg(build_mapping_arg(
             doc::localize::args::mapping::SHORT,
             doc::localize::args::mapping::LONG.trim_start(),
@@ -148,15 +443,133 @@ fn build_localize_sub_command() -> App<'static, 'static> {
                 .help(doc::localize::args::OUTPUT_DIR)
                 .long(constants::args::LOCALIZE_OUTPUT_DIR)
                 .takes_value(true)
-                .required(true),
+                .required_unless(constants::args::LOCALIZE_STATS),
+        )
+        .arg(
+            Arg::with_name(constants::args::LOCALIZE_FORMAT)
+                .help(doc::localize::args::FORMAT)
+                .long(constants::args::LOCALIZE_FORMAT)
+                .takes_value(true)
+                .possible_values(&[
+                    "csv",
+                    "xliff",
+                    "po",
+                    "xlsx",
+                    "ios",
+                    "json",
+                    "properties",
+                    "arb",
+                ])
+                .default_value("csv"),
+        )
+        .arg(
+            Arg::with_name(constants::args::LOCALIZE_GROUPING)
+                .help(doc::localize::args::GROUPING)
+                .long(constants::args::LOCALIZE_GROUPING)
+                .takes_value(true)
+                .possible_values(&["by-strings", "per-locale", "single"])
+                .default_value("by-strings"),
+        )
+        .arg(
+            Arg::with_name(constants::args::LOCALIZE_OVERWRITE)
+                .help(doc::localize::args::OVERWRITE)
+                .long(constants::args::LOCALIZE_OVERWRITE)
+                .takes_value(false)
+                .conflicts_with(constants::args::LOCALIZE_MERGE),
+        )
+        .arg(
+            Arg::with_name(constants::args::LOCALIZE_MERGE)
+                .help(doc::localize::args::MERGE)
+                .long(constants::args::LOCALIZE_MERGE)
+                .takes_value(false),
         )
+        .arg(build_csv_delimiter_arg(doc::localize::args::CSV_DELIMITER))
+        .arg(build_csv_formula_safe_arg())
+        .arg(
+            Arg::with_name(constants::args::LOCALIZE_CSV_ENCODING)
+                .help(doc::localize::args::CSV_ENCODING)
+                .long(constants::args::LOCALIZE_CSV_ENCODING)
+                .takes_value(true)
+                .possible_values(&["utf-8", "utf-16"])
+                .default_value("utf-8"),
+        )
+        .arg(
+            Arg::with_name(constants::args::LOCALIZE_CSV_BOM)
+                .help(doc::localize::args::CSV_BOM)
+                .long(constants::args::LOCALIZE_CSV_BOM)
+                .takes_value(false)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name(constants::args::LOCALIZE_CSV_QUOTE)
+                .help(doc::localize::args::CSV_QUOTE)
+                .long(constants::args::LOCALIZE_CSV_QUOTE)
+                .takes_value(true)
+                .possible_values(&["necessary", "always"])
+                .default_value("necessary"),
+        )
+        .arg(
+            Arg::with_name(constants::args::LOCALIZE_SUGGEST)
+                .help(doc::localize::args::SUGGEST)
+                .long(constants::args::LOCALIZE_SUGGEST)
+                .takes_value(false)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name(constants::args::LOCALIZE_FILL_EXACT_MATCHES)
+                .help(doc::localize::args::FILL_EXACT_MATCHES)
+                .long(constants::args::LOCALIZE_FILL_EXACT_MATCHES)
+                .takes_value(false)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name(constants::args::LOCALIZE_TRANSLATION_ENDPOINT)
+                .help(doc::localize::args::TRANSLATION_ENDPOINT)
+                .long(constants::args::LOCALIZE_TRANSLATION_ENDPOINT)
+                .takes_value(true)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name(constants::args::LOCALIZE_STATS)
+                .help(doc::localize::args::STATS)
+                .long(constants::args::LOCALIZE_STATS)
+                .takes_value(false)
+                .conflicts_with_all(&[
+                    constants::args::LOCALIZE_OUTPUT_DIR,
+                    constants::args::LOCALIZE_TRANSLATION_ENDPOINT,
+                ]),
+        )
+        .arg(
+            Arg::with_name(constants::args::LOCALIZE_STATS_FORMAT)
+                .help(doc::localize::args::STATS_FORMAT)
+                .long(constants::args::LOCALIZE_STATS_FORMAT)
+                .takes_value(true)
+                .possible_values(&["text", "json"])
+                .default_value("text"),
+        )
+        .arg(
+            Arg::with_name(constants::args::LOCALIZE_RATE)
+                .help(doc::localize::args::RATE)
+                .long(constants::args::LOCALIZE_RATE)
+                .takes_value(true)
+                .validator(rate_validator)
+                .multiple(true)
+                .requires(constants::args::LOCALIZE_STATS),
+        )
+        .arg(build_state_file_arg())
 }
 
 fn build_localized_sub_command() -> App<'static, 'static> {
     SubCommand::with_name(constants::commands::LOCALIZED)
         .about(doc::localized::SHORT)
         .long_about(doc::localized::LONG)
-        .arg(build_res_dir_arg())
+        .arg(
+            build_res_dir_arg()
+                .required_unless(constants::args::PROJECT_DIR)
+                .conflicts_with(constants::args::PROJECT_DIR),
+        )
+        .arg(build_overlay_res_dir_arg().conflicts_with(constants::args::PROJECT_DIR))
+        .arg(build_project_dir_arg())
         .arg(build_mapping_arg(
             doc::localized::args::mapping::SHORT,
             doc::localized::args::mapping::LONG.trim_start(),
@@ -168,6 +581,9 @@ fn build_localized_sub_command() -> App<'static, 'static> {
                 .takes_value(true)
                 .required(true),
         )
+        .arg(build_csv_delimiter_arg(doc::localized::args::CSV_DELIMITER))
+        .arg(build_csv_formula_safe_arg())
+        .arg(build_state_file_arg())
 }
 
 fn build_validate_sub_command() -> App<'static, 'static> {
@@ -175,6 +591,7 @@ fn build_validate_sub_command() -> App<'static, 'static> {
         .about(doc::validate::SHORT)
         .long_about(doc::validate::LONG)
         .arg(build_res_dir_arg())
+        .arg(build_overlay_res_dir_arg())
         .arg(
             Arg::with_name(constants::args::SKIP_UNLOCALIZED)
                 .help(doc::validate::args::SKIP_UNLOCALIZED)
@@ -182,6 +599,20 @@ fn build_validate_sub_command() -> App<'static, 'static> {
                 .takes_value(false)
                 .required(false),
         )
+        .arg(
+            Arg::with_name(constants::args::GLOSSARY)
+                .help(doc::validate::args::GLOSSARY)
+                .long(constants::args::GLOSSARY)
+                .takes_value(true)
+                .required(false),
+        )
+}
+
+fn build_pseudolocalize_sub_command() -> App<'static, 'static> {
+    SubCommand::with_name(constants::commands::PSEUDOLOCALIZE)
+        .about(doc::pseudolocalize::SHORT)
+        .long_about(doc::pseudolocalize::LONG)
+        .arg(build_res_dir_arg())
 }
 
 fn build_res_dir_arg() -> Arg<'static, 'static> {
@@ -193,6 +624,48 @@ fn build_res_dir_arg() -> Arg<'static, 'static> {
         .required(true)
 }
 
+fn build_overlay_res_dir_arg() -> Arg<'static, 'static> {
+    Arg::with_name(constants::args::OVERLAY_RES_DIR)
+        .help(doc::common::OVERLAY_RES_DIR_SHORT)
+        .long_help(doc::common::OVERLAY_RES_DIR_LONG.trim_start())
+        .long(constants::args::OVERLAY_RES_DIR)
+        .takes_value(true)
+        .multiple(true)
+        .number_of_values(1)
+}
+
+fn build_project_dir_arg() -> Arg<'static, 'static> {
+    Arg::with_name(constants::args::PROJECT_DIR)
+        .help(doc::common::PROJECT_DIR_SHORT)
+        .long_help(doc::common::PROJECT_DIR_LONG.trim_start())
+        .long(constants::args::PROJECT_DIR)
+        .takes_value(true)
+}
+
+fn build_csv_delimiter_arg(help: &'static str) -> Arg<'static, 'static> {
+    Arg::with_name(constants::args::CSV_DELIMITER)
+        .help(help)
+        .long(constants::args::CSV_DELIMITER)
+        .takes_value(true)
+        .validator(csv_delimiter_validator)
+        .default_value(",")
+}
+
+fn build_csv_formula_safe_arg() -> Arg<'static, 'static> {
+    Arg::with_name(constants::args::CSV_FORMULA_SAFE)
+        .help(doc::common::CSV_FORMULA_SAFE)
+        .long(constants::args::CSV_FORMULA_SAFE)
+        .takes_value(false)
+        .required(false)
+}
+
+fn build_state_file_arg() -> Arg<'static, 'static> {
+    Arg::with_name(constants::args::STATE_FILE)
+        .help(doc::common::STATE_FILE)
+        .long(constants::args::STATE_FILE)
+        .takes_value(true)
+}
+
 fn build_mapping_arg(short_help: &'static str, long_help: &'static str) -> Arg<'static, 'static> {
     Arg::with_name(constants::args::MAPPING)
         .help(short_help)
@@ -203,7 +676,26 @@ fn build_mapping_arg(short_help: &'static str, long_help: &'static str) -> Arg<'
         .multiple(true)
 }
 
-#[cfg_attr(feature = "cargo-clippy", allow(clippy::needless_pass_by_value))]
+#[allow(clippy::needless_pass_by_value)]
+fn csv_delimiter_validator(delimiter: String) -> Result<(), String> {
+    csv_dialect::parse_delimiter(&delimiter)
+        .map(|_| ())
+        .map_err(|error| error.to_string())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn rate_validator(rate: String) -> Result<(), String> {
+    if constants::TEXT_TO_NUMBER_REGEX.is_match(&rate) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Rate should be of the format xx=0.12; Found: {}",
+            rate
+        ))
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
 fn mapping_validator(mapping: String) -> Result<(), String> {
     let valid_mapping = match constants::TEXT_TO_TEXT_REGEX.captures(&mapping) {
         None => false,
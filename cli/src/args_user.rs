@@ -4,7 +4,16 @@ use std::fmt;
 use clap::ArgMatches;
 use console::style;
 
-use android_localization_core;
+use android_localization_core::csv_dialect;
+use android_localization_core::csv_dialect::CsvDialect;
+use android_localization_core::format::Format;
+use android_localization_core::grouping::Grouping;
+use android_localization_core::localize::OutputOptions;
+use android_localization_core::localize::OutputPolicy;
+use android_localization_core::stats;
+use android_localization_core::translation_provider::HttpTranslationProvider;
+use android_localization_core::translation_provider::TranslationProvider;
+use android_localization_core::warnings;
 use android_localization_utilities::DevExpt;
 
 use crate::constants;
@@ -25,19 +34,69 @@ pub fn execute_for_matches(matches: ArgMatches) -> Result<(), ()> {
         return validate(validations_command);
     }
 
+    if let Some(pseudolocalize_command) =
+        matches.subcommand_matches(constants::commands::PSEUDOLOCALIZE)
+    {
+        return pseudolocalize(pseudolocalize_command);
+    }
+
     err_with_failure(String::from("Command couldn't be recognized"))
 }
 
 fn localize(matches: &ArgMatches) -> Result<(), ()> {
-    let result = android_localization_core::localize::localize(
-        matches
-            .value_of(constants::args::RES_DIR)
-            .expt(arg_missing_msg(constants::args::RES_DIR)),
-        matches
-            .value_of(constants::args::LOCALIZE_OUTPUT_DIR)
-            .expt(arg_missing_msg(constants::args::LOCALIZE_OUTPUT_DIR)),
-        build_mappings(matches),
-    );
+    if matches.is_present(constants::args::LOCALIZE_STATS) {
+        return stats(matches);
+    }
+
+    let output_dir = matches
+        .value_of(constants::args::LOCALIZE_OUTPUT_DIR)
+        .expt(arg_missing_msg(constants::args::LOCALIZE_OUTPUT_DIR));
+    let format = matches
+        .value_of(constants::args::LOCALIZE_FORMAT)
+        .expt(arg_missing_msg(constants::args::LOCALIZE_FORMAT))
+        .parse::<Format>()
+        .ok()
+        .expt("Looks like format's possible values aren't in sync with `Format`");
+    let grouping = matches
+        .value_of(constants::args::LOCALIZE_GROUPING)
+        .expt(arg_missing_msg(constants::args::LOCALIZE_GROUPING))
+        .parse::<Grouping>()
+        .ok()
+        .expt("Looks like grouping's possible values aren't in sync with `Grouping`");
+    let output_options = OutputOptions::default()
+        .with_format(format)
+        .with_csv_dialect(build_csv_dialect(matches))
+        .with_output_policy(build_output_policy(matches))
+        .with_grouping(grouping)
+        .with_suggestions(matches.is_present(constants::args::LOCALIZE_SUGGEST))
+        .with_fill_exact_matches(matches.is_present(constants::args::LOCALIZE_FILL_EXACT_MATCHES));
+    let translation_provider = matches
+        .value_of(constants::args::LOCALIZE_TRANSLATION_ENDPOINT)
+        .map(|endpoint| HttpTranslationProvider::new(String::from(endpoint)));
+    let translation_provider = translation_provider
+        .as_ref()
+        .map(|provider| provider as &dyn TranslationProvider);
+    let result = with_warnings_printed(|| match matches.value_of(constants::args::PROJECT_DIR) {
+        Some(project_dir) => android_localization_core::localize::localize_project(
+            project_dir,
+            output_dir,
+            output_options,
+            matches.value_of(constants::args::STATE_FILE),
+            translation_provider,
+            build_mappings(matches),
+        ),
+        None => android_localization_core::localize::localize_with_overlays(
+            matches
+                .value_of(constants::args::RES_DIR)
+                .expt(arg_missing_msg(constants::args::RES_DIR)),
+            &build_overlay_res_dirs(matches),
+            output_dir,
+            output_options,
+            matches.value_of(constants::args::STATE_FILE),
+            translation_provider,
+            build_mappings(matches),
+        ),
+    });
 
     match result {
         Err(error) => exit_based_on_result("", Err(error)),
@@ -55,16 +114,68 @@ fn localize(matches: &ArgMatches) -> Result<(), ()> {
     }
 }
 
+fn stats(matches: &ArgMatches) -> Result<(), ()> {
+    let output_options = OutputOptions::default()
+        .with_fill_exact_matches(matches.is_present(constants::args::LOCALIZE_FILL_EXACT_MATCHES));
+    let result = with_warnings_printed(|| match matches.value_of(constants::args::PROJECT_DIR) {
+        Some(project_dir) => android_localization_core::localize::stats_project(
+            project_dir,
+            output_options,
+            matches.value_of(constants::args::STATE_FILE),
+            build_mappings(matches),
+        ),
+        None => android_localization_core::localize::stats_with_overlays(
+            matches
+                .value_of(constants::args::RES_DIR)
+                .expt(arg_missing_msg(constants::args::RES_DIR)),
+            &build_overlay_res_dirs(matches),
+            output_options,
+            matches.value_of(constants::args::STATE_FILE),
+            build_mappings(matches),
+        ),
+    });
+
+    match result {
+        Err(error) => exit_based_on_result("", Err(error)),
+        Ok(stats_list) if stats_list.is_empty() => {
+            err_with_warning(String::from("Nothing found to localize"))
+        }
+
+        Ok(stats_list) => {
+            let rates = build_rates(matches);
+            match matches.value_of(constants::args::LOCALIZE_STATS_FORMAT) {
+                Some("json") => println!("{}", stats::format_to_json(&stats_list, &rates)),
+                _ => print!("{}", stats::format_to_string(&stats_list, &rates)),
+            }
+
+            Ok(())
+        }
+    }
+}
+
 fn localized(matches: &ArgMatches) -> Result<(), ()> {
-    let result = android_localization_core::localized::localized(
-        matches
-            .value_of(constants::args::RES_DIR)
-            .expt(arg_missing_msg(constants::args::RES_DIR)),
-        matches
-            .value_of(constants::args::LOCALIZED_INPUT_FILE)
-            .expt(arg_missing_msg(constants::args::LOCALIZED_INPUT_FILE)),
-        build_mappings(matches),
-    );
+    let input_file = matches
+        .value_of(constants::args::LOCALIZED_INPUT_FILE)
+        .expt(arg_missing_msg(constants::args::LOCALIZED_INPUT_FILE));
+    let result = with_warnings_printed(|| match matches.value_of(constants::args::PROJECT_DIR) {
+        Some(project_dir) => android_localization_core::localized::localized_project(
+            project_dir,
+            input_file,
+            build_csv_dialect(matches),
+            matches.value_of(constants::args::STATE_FILE),
+            build_mappings(matches),
+        ),
+        None => android_localization_core::localized::localized_with_overlays(
+            matches
+                .value_of(constants::args::RES_DIR)
+                .expt(arg_missing_msg(constants::args::RES_DIR)),
+            &build_overlay_res_dirs(matches),
+            input_file,
+            build_csv_dialect(matches),
+            matches.value_of(constants::args::STATE_FILE),
+            build_mappings(matches),
+        ),
+    });
 
     match result {
         Err(error) => exit_based_on_result("", Err(error)),
@@ -83,12 +194,16 @@ fn localized(matches: &ArgMatches) -> Result<(), ()> {
 }
 
 fn validate(matches: &ArgMatches) -> Result<(), ()> {
-    let result = android_localization_core::validator::validate(
-        matches
-            .value_of(constants::args::RES_DIR)
-            .expt(arg_missing_msg(constants::args::RES_DIR)),
-        !matches.is_present(constants::args::SKIP_UNLOCALIZED),
-    );
+    let result = with_warnings_printed(|| {
+        android_localization_core::validator::validate_with_overlays(
+            matches
+                .value_of(constants::args::RES_DIR)
+                .expt(arg_missing_msg(constants::args::RES_DIR)),
+            &build_overlay_res_dirs(matches),
+            !matches.is_present(constants::args::SKIP_UNLOCALIZED),
+            matches.value_of(constants::args::GLOSSARY),
+        )
+    });
 
     match result {
         Err(error) => exit_based_on_result("", Err(error)),
@@ -105,6 +220,81 @@ fn validate(matches: &ArgMatches) -> Result<(), ()> {
     }
 }
 
+fn pseudolocalize(matches: &ArgMatches) -> Result<(), ()> {
+    let result = with_warnings_printed(|| {
+        android_localization_core::pseudolocalize::pseudolocalize(
+            matches
+                .value_of(constants::args::RES_DIR)
+                .expt(arg_missing_msg(constants::args::RES_DIR)),
+        )
+    });
+
+    match result {
+        Err(error) => exit_based_on_result("", Err(error)),
+        Ok(file_names) => {
+            if file_names.is_empty() {
+                err_with_warning(String::from("Nothing found to pseudolocalize"))
+            } else {
+                ok_with_success(format!(
+                    "{} - \n\n{}",
+                    "Pseudolocalized texts written to",
+                    file_names.join("\n")
+                ))
+            }
+        }
+    }
+}
+
+fn build_overlay_res_dirs<'a>(matches: &'a ArgMatches) -> Vec<&'a str> {
+    matches
+        .values_of(constants::args::OVERLAY_RES_DIR)
+        .map_or_else(Vec::new, Iterator::collect)
+}
+
+fn build_output_policy(matches: &ArgMatches) -> OutputPolicy {
+    if matches.is_present(constants::args::LOCALIZE_MERGE) {
+        OutputPolicy::Merge
+    } else if matches.is_present(constants::args::LOCALIZE_OVERWRITE) {
+        OutputPolicy::Overwrite
+    } else {
+        OutputPolicy::Fail
+    }
+}
+
+/// Args that the command doesn't take are left at their defaults
+fn build_csv_dialect(matches: &ArgMatches) -> CsvDialect {
+    let mut dialect = CsvDialect::default()
+        .with_bom(matches.is_present(constants::args::LOCALIZE_CSV_BOM))
+        .with_formula_safe(matches.is_present(constants::args::CSV_FORMULA_SAFE));
+    if let Some(delimiter) = matches.value_of(constants::args::CSV_DELIMITER) {
+        dialect = dialect.with_delimiter(
+            csv_dialect::parse_delimiter(delimiter)
+                .ok()
+                .expt("Looks like delimiter validator doesn't work!"),
+        );
+    }
+
+    if let Some(encoding) = matches.value_of(constants::args::LOCALIZE_CSV_ENCODING) {
+        dialect = dialect.with_encoding(
+            encoding
+                .parse()
+                .ok()
+                .expt("Looks like encoding's possible values aren't in sync with `Encoding`"),
+        );
+    }
+
+    if let Some(quote_style) = matches.value_of(constants::args::LOCALIZE_CSV_QUOTE) {
+        dialect = dialect.with_quote_style(
+            quote_style
+                .parse()
+                .ok()
+                .expt("Looks like quote's possible values aren't in sync with `QuoteStyle`"),
+        );
+    }
+
+    dialect
+}
+
 fn build_mappings(matches: &ArgMatches) -> HashMap<String, String> {
     match matches.values_of(constants::args::MAPPING) {
         None => HashMap::new(),
@@ -136,10 +326,48 @@ fn arg_missing_msg(arg_name: &str) -> String {
     format!("{} arg is missing", arg_name)
 }
 
+fn build_rates(matches: &ArgMatches) -> HashMap<String, f64> {
+    match matches.values_of(constants::args::LOCALIZE_RATE) {
+        None => HashMap::new(),
+        Some(values) => values
+            .map(|rate| {
+                let captures = constants::TEXT_TO_NUMBER_REGEX
+                    .captures(rate)
+                    .expt(invalid_rate_validator_msg());
+                (
+                    String::from(captures.get(1).expt(invalid_rate_validator_msg()).as_str()),
+                    captures
+                        .get(2)
+                        .expt(invalid_rate_validator_msg())
+                        .as_str()
+                        .parse()
+                        .ok()
+                        .expt(invalid_rate_validator_msg()),
+                )
+            })
+            .collect(),
+    }
+}
+
+fn invalid_rate_validator_msg() -> &'static str {
+    "Looks like rate validator doesn't work!"
+}
+
 fn invalid_mapping_validator_msg() -> &'static str {
     "Looks like mapping validator doesn't work!"
 }
 
+/// Runs the command & prints out the warnings it raised before its result
+/// is dealt with
+fn with_warnings_printed<T, F: FnOnce() -> T>(command: F) -> T {
+    let (result, warnings) = warnings::collect(command);
+    for warning in warnings {
+        eprintln!("{}", style(format!("Warning: {}", warning)).yellow());
+    }
+
+    result
+}
+
 fn exit_based_on_result<E: fmt::Display>(
     success_prefix: &str,
     result: Result<Vec<String>, E>,
@@ -6,16 +6,37 @@ pub mod commands {
     pub const LOCALIZE: &str = "localize";
     pub const LOCALIZED: &str = "localized";
     pub const VALIDATE: &str = "validate";
+    pub const PSEUDOLOCALIZE: &str = "pseudolocalize";
 }
 
 pub mod args {
     pub const RES_DIR: &str = "res-dir";
+    pub const PROJECT_DIR: &str = "project-dir";
+    pub const OVERLAY_RES_DIR: &str = "overlay-res-dir";
     pub const LOCALIZE_OUTPUT_DIR: &str = "output-dir";
+    pub const LOCALIZE_FORMAT: &str = "format";
+    pub const LOCALIZE_GROUPING: &str = "grouping";
+    pub const CSV_DELIMITER: &str = "csv-delimiter";
+    pub const CSV_FORMULA_SAFE: &str = "csv-formula-safe";
+    pub const LOCALIZE_CSV_BOM: &str = "csv-bom";
+    pub const LOCALIZE_CSV_ENCODING: &str = "csv-encoding";
+    pub const LOCALIZE_CSV_QUOTE: &str = "csv-quote";
+    pub const LOCALIZE_OVERWRITE: &str = "overwrite";
+    pub const LOCALIZE_MERGE: &str = "merge";
+    pub const LOCALIZE_SUGGEST: &str = "suggest";
+    pub const LOCALIZE_FILL_EXACT_MATCHES: &str = "fill-exact-matches";
+    pub const LOCALIZE_TRANSLATION_ENDPOINT: &str = "translation-endpoint";
+    pub const LOCALIZE_STATS: &str = "stats";
+    pub const LOCALIZE_STATS_FORMAT: &str = "stats-format";
+    pub const LOCALIZE_RATE: &str = "rate";
     pub const LOCALIZED_INPUT_FILE: &str = "input-file";
     pub const MAPPING: &str = "mapping";
+    pub const STATE_FILE: &str = "state-file";
     pub const SKIP_UNLOCALIZED: &str = "skip-unlocalized";
+    pub const GLOSSARY: &str = "glossary";
 }
 
 lazy_static::lazy_static! {
-    pub static ref TEXT_TO_TEXT_REGEX: Regex = Regex::new("^([a-zA-Z]+)=([a-zA-Z]+)$").expt("Invalid regex!");
+    pub static ref TEXT_TO_TEXT_REGEX: Regex = Regex::new("^([a-zA-Z0-9+_-]+)=([a-zA-Z0-9+_-]+)$").expt("Invalid regex!");
+    pub static ref TEXT_TO_NUMBER_REGEX: Regex = Regex::new(r"^([a-zA-Z0-9+_-]+)=(\d+(?:\.\d+)?)$").expt("Invalid regex!");
 }
@@ -4,6 +4,7 @@ mod args_parser;
 mod args_user;
 mod constants;
 
+#[allow(clippy::result_unit_err)]
 pub fn execute_for_commands<I, T>(itr: I) -> Result<(), ()>
 where
     I: IntoIterator<Item = T>,
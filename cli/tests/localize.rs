@@ -1,9 +1,10 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
 use std::process::{Command, Output};
+use std::thread;
 
 use tempfile::TempDir;
 
-use test_utilities;
-
 #[test]
 fn succeeds_with_mapping() {
     let temp_dir = tempfile::tempdir().unwrap();
@@ -23,10 +24,11 @@ fn succeeds_with_mapping() {
         .output()
         .unwrap();
 
-    assert_status_and_stdout(output);
+    assert_status_and_stdout(output, "to_localize_french_spanish.csv");
     assert_output_files(
         temp_dir,
         "./tests_data/localize/success/output_with_mapping/",
+        "to_localize_french_spanish.csv",
     );
 }
 
@@ -45,10 +47,354 @@ fn succeeds_without_mapping() {
         .output()
         .unwrap();
 
-    assert_status_and_stdout(output);
+    assert_status_and_stdout(output, "to_localize_es_fr.csv");
     assert_output_files(
         temp_dir,
         "./tests_data/localize/success/output_without_mapping/",
+        "to_localize_es_fr.csv",
+    );
+}
+
+#[test]
+fn succeeds_with_xliff_format() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "localize",
+            "--res-dir",
+            "./tests_data/localize/success/input",
+            "--output-dir",
+            temp_dir.path().to_str().unwrap(),
+            "--mapping",
+            "fr=french",
+            "--format",
+            "xliff",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .contains("to_localize.xlf\n"));
+    test_utilities::file::assert_eq_of_file_contents(
+        &format!("{}/to_localize.xlf", temp_dir.path().to_str().unwrap()),
+        "./tests_data/localize/success/output_xliff/to_localize.xlf",
+    );
+}
+
+#[test]
+fn succeeds_with_po_format() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "localize",
+            "--res-dir",
+            "./tests_data/localize/success/input",
+            "--output-dir",
+            temp_dir.path().to_str().unwrap(),
+            "--mapping",
+            "fr=french",
+            "--format",
+            "po",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    for file_name in &["to_localize.pot", "to_localize_french.po"] {
+        test_utilities::file::assert_eq_of_file_contents(
+            &format!("{}/{}", temp_dir.path().to_str().unwrap(), file_name),
+            &format!("./tests_data/localize/success/output_po/{}", file_name),
+        );
+    }
+}
+
+#[test]
+fn succeeds_with_ios_format() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "localize",
+            "--res-dir",
+            "./tests_data/localize/success/input",
+            "--output-dir",
+            temp_dir.path().to_str().unwrap(),
+            "--mapping",
+            "fr=french",
+            "--format",
+            "ios",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    for file_name in &[
+        "en.lproj/Localizable.strings",
+        "french.lproj/Localizable.strings",
+    ] {
+        test_utilities::file::assert_eq_of_file_contents(
+            &format!("{}/{}", temp_dir.path().to_str().unwrap(), file_name),
+            &format!("./tests_data/localize/success/output_ios/{}", file_name),
+        );
+    }
+}
+
+#[test]
+fn succeeds_for_project() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "localize",
+            "--project-dir",
+            "./tests_data/localize/project/input",
+            "--output-dir",
+            temp_dir.path().to_str().unwrap(),
+            "--mapping",
+            "fr=french",
+        ])
+        .output()
+        .unwrap();
+
+    assert_status_and_stdout(output, "to_localize_french.csv");
+    assert_output_files(
+        temp_dir,
+        "./tests_data/localize/project/output/",
+        "to_localize_french.csv",
+    );
+}
+
+#[test]
+fn succeeds_with_csv_dialect() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "localize",
+            "--project-dir",
+            "./tests_data/localize/project/input",
+            "--output-dir",
+            temp_dir.path().to_str().unwrap(),
+            "--mapping",
+            "fr=french",
+            "--csv-delimiter",
+            ";",
+            "--csv-bom",
+            "--csv-quote",
+            "always",
+        ])
+        .output()
+        .unwrap();
+
+    assert_status_and_stdout(output, "to_localize_french.csv");
+    assert_output_files(
+        temp_dir,
+        "./tests_data/localize/project/output_csv_dialect/",
+        "to_localize_french.csv",
+    );
+}
+
+#[test]
+fn succeeds_with_a_file_per_locale() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "localize",
+            "--res-dir",
+            "./tests_data/localize/success/input",
+            "--output-dir",
+            temp_dir.path().to_str().unwrap(),
+            "--grouping",
+            "per-locale",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    for file_name in &["to_localize_es.csv", "to_localize_fr.csv"] {
+        test_utilities::file::assert_eq_of_file_contents(
+            &format!("{}/{}", temp_dir.path().to_str().unwrap(), file_name),
+            &format!(
+                "./tests_data/localize/success/output_per_locale/{}",
+                file_name
+            ),
+        );
+    }
+}
+
+#[test]
+fn merges_into_existing_files() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let output_file_path = temp_dir.path().join("to_localize_french.csv");
+    std::fs::write(
+        &output_file_path,
+        "module,string_name,default_locale,french\napp/src/main/res,greeting,Hello,Bonjour\n",
+    )
+    .unwrap();
+
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "localize",
+            "--project-dir",
+            "./tests_data/localize/project/input",
+            "--output-dir",
+            temp_dir.path().to_str().unwrap(),
+            "--mapping",
+            "fr=french",
+            "--merge",
+        ])
+        .output()
+        .unwrap();
+
+    assert_status_and_stdout(output, "to_localize_french.csv");
+    assert_eq!(
+        test_utilities::file::read_content(&output_file_path),
+        "module,string_name,default_locale,french\napp/src/main/res,greeting,Hello,Bonjour\nlib/src/main/res,settings,Settings,\n"
+    );
+}
+
+#[test]
+fn succeeds_with_suggestions() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "localize",
+            "--res-dir",
+            "./tests_data/localize/success/input",
+            "--output-dir",
+            temp_dir.path().to_str().unwrap(),
+            "--mapping",
+            "fr=french",
+            "--suggest",
+        ])
+        .output()
+        .unwrap();
+
+    assert_status_and_stdout(output, "to_localize_french.csv");
+    assert_eq!(
+        test_utilities::file::read_content(temp_dir.path().join("to_localize_french.csv")),
+        "string_name,default_locale,french,french (suggestion)\nstring_1,string_1 default locale,,string_2 french\nstring_3,string_3 default locale,,string_2 french\n"
+    );
+}
+
+#[test]
+fn succeeds_with_translation_endpoint() {
+    // Answers a single request with a translation per text
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}/translate", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+
+            let (header, value) = line.split_once(':').unwrap_or((line, ""));
+            if header.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap();
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+        let response = r#"{"translations": ["string_1 draft", "string_3 draft"]}"#;
+        write!(
+            reader.get_mut(),
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            response.len(),
+            response
+        )
+        .unwrap();
+        String::from_utf8(body).unwrap()
+    });
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "localize",
+            "--res-dir",
+            "./tests_data/localize/success/input",
+            "--output-dir",
+            temp_dir.path().to_str().unwrap(),
+            "--mapping",
+            "fr=french",
+            "--translation-endpoint",
+            &endpoint,
+        ])
+        .output()
+        .unwrap();
+
+    assert_status_and_stdout(output, "to_localize_french.csv");
+    assert_eq!(
+        server.join().unwrap(),
+        r#"{"source_language":"en","target_language":"fr","texts":["string_1 default locale","string_3 default locale"]}"#
+    );
+    assert_eq!(
+        test_utilities::file::read_content(temp_dir.path().join("to_localize_french.csv")),
+        "string_name,default_locale,french,french (draft)\nstring_1,string_1 default locale,,string_1 draft\nstring_3,string_3 default locale,,string_3 draft\n"
+    );
+}
+
+#[test]
+fn succeeds_with_stats() {
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "localize",
+            "--res-dir",
+            "./tests_data/localize/success/input",
+            "--stats",
+            "--rate",
+            "fr=0.1",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        r#"locale  strings  words  characters  repeated segments  repeated words  cost
+es            2      6          42                  0               0
+fr            2      6          42                  0               0  0.60
+total         4     12          84                  0               0  0.60
+"#
+    );
+}
+
+#[test]
+fn succeeds_with_stats_in_json() {
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "localize",
+            "--res-dir",
+            "./tests_data/localize/success/input",
+            "--mapping",
+            "fr=french",
+            "--stats",
+            "--stats-format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "{\"locales\":[{\"locale\":\"french\",\"strings\":2,\"words\":6,\"characters\":42,\"repeated_segments\":0,\"repeated_words\":0}],\"total\":{\"strings\":2,\"words\":6,\"characters\":42,\"repeated_segments\":0,\"repeated_words\":0}}\n"
     );
 }
 
@@ -94,7 +440,7 @@ fn errors_are_printed_out() {
         .contains("Res dir path doesn't exist or it is not a directory\n"));
 }
 
-fn assert_status_and_stdout(output: Output) {
+fn assert_status_and_stdout(output: Output, file_name: &str) {
     assert!(output.status.success());
 
     let output = String::from_utf8(output.stdout).unwrap();
@@ -105,15 +451,14 @@ fn assert_status_and_stdout(output: Output) {
         "Texts to be localized written to - "
     );
     assert_eq!(output_lines.next().unwrap(), "");
-    assert!(output_lines.next().unwrap().ends_with("to_localize_1.csv"));
+    assert!(output_lines.next().unwrap().ends_with(file_name));
     assert_eq!(output_lines.next().unwrap(), "");
     assert_eq!(output_lines.next(), None);
 }
 
-fn assert_output_files(temp_dir: TempDir, expected_output_dir_path: &str) {
-    test_utilities::file::assert_eq_of_file_contents_to_either_or(
-        &format!("{}/to_localize_1.csv", temp_dir.path().to_str().unwrap()),
-        &format!("{}/es_fr.csv", expected_output_dir_path),
-        &format!("{}/fr_es.csv", expected_output_dir_path),
+fn assert_output_files(temp_dir: TempDir, expected_output_dir_path: &str, file_name: &str) {
+    test_utilities::file::assert_eq_of_file_contents(
+        &format!("{}/{}", temp_dir.path().to_str().unwrap(), file_name),
+        &format!("{}/{}", expected_output_dir_path, file_name),
     );
 }
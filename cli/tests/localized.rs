@@ -3,8 +3,6 @@ use std::process::{Command, Output};
 
 use tempfile::TempDir;
 
-use test_utilities;
-
 #[test]
 fn succeeds_with_mapping() {
     execute_with_copied_sample_res(
@@ -57,6 +55,67 @@ fn succeeds_without_mapping() {
     )
 }
 
+#[test]
+fn round_trips_formula_safe_csv() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let res_path = temp_dir.path().join("res");
+    let default_strings =
+        test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+    let fr_strings = test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+    test_utilities::file::write_content(
+        default_strings.path,
+        r#"<resources><string name="sum">=1+2</string></resources>"#,
+    );
+    test_utilities::file::write_content(&fr_strings.path, "<resources></resources>");
+
+    // Formulae are escaped in the exported CSV
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "localize",
+            "--res-dir",
+            res_path.to_str().unwrap(),
+            "--output-dir",
+            temp_dir.path().to_str().unwrap(),
+            "--mapping",
+            "fr=french",
+            "--csv-formula-safe",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let csv_path = temp_dir.path().join("to_localize_french.csv");
+    assert_eq!(
+        test_utilities::file::read_content(&csv_path),
+        "string_name,default_locale,french\nsum,'=1+2,\n"
+    );
+
+    // & unescaped when the localized CSV is read back
+    test_utilities::file::write_content(
+        &csv_path,
+        "string_name,default_locale,french\nsum,'=1+2,'=3+4\n",
+    );
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "localized",
+            "--res-dir",
+            res_path.to_str().unwrap(),
+            "--input-file",
+            csv_path.to_str().unwrap(),
+            "--mapping",
+            "french=fr",
+            "--csv-formula-safe",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(test_utilities::file::read_content(&fr_strings.path)
+        .contains(r#"<string name="sum">=3+4</string>"#));
+}
+
 #[test]
 fn warns_if_nothing_new_localized() {
     execute_with_copied_sample_res(
@@ -109,7 +168,7 @@ fn errors_are_printed_out() {
 
 fn execute_with_copied_sample_res<F>(temp_dir: TempDir, input_type: &str, test: F)
 where
-    F: FnOnce(String) -> (),
+    F: FnOnce(String),
 {
     // Build paths
     let mut res_path = temp_dir.path().to_path_buf();
@@ -177,14 +236,14 @@ fn assert_status_and_stdout(output: Output) {
     assert_eq!(output_lines.next().unwrap(), "");
     test_utilities::eq::assert_eq_to_either_or_by(
         output_lines.next().unwrap(),
-        &fr_values,
-        &es_values,
+        fr_values,
+        es_values,
         |actual, expected| actual.contains("strings.xml") && actual.contains(expected),
     );
     test_utilities::eq::assert_eq_to_either_or_by(
         output_lines.next().unwrap(),
-        &fr_values,
-        &es_values,
+        fr_values,
+        es_values,
         |actual, expected| actual.contains("strings.xml") && actual.contains(expected),
     );
     assert_eq!(output_lines.next().unwrap(), "");
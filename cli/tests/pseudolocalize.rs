@@ -0,0 +1,90 @@
+use std::fs;
+use std::io::Write;
+use std::process::Command;
+
+#[test]
+fn writes_pseudolocales() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let res_dir_path = temp_dir.path().join("res");
+    let default_values_dir_path =
+        test_utilities::res::setup_values_dir_for_default_locale(res_dir_path.clone());
+    fs::copy(
+        "./tests_data/pseudolocalize/input/values/strings.xml",
+        format!("{}/strings.xml", default_values_dir_path),
+    )
+    .unwrap();
+
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "pseudolocalize",
+            "--res-dir",
+            res_dir_path.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let output = String::from_utf8(output.stdout).unwrap();
+    let mut output_lines = output.split('\n');
+
+    // To make path testing windows friendly, we just test whether the appropriate
+    // values dir are present
+    assert_eq!(
+        output_lines.next().unwrap(),
+        "Pseudolocalized texts written to - "
+    );
+    assert_eq!(output_lines.next().unwrap(), "");
+    assert!(output_lines.next().unwrap().contains("values-en-rXA"));
+    assert!(output_lines.next().unwrap().contains("values-ar-rXB"));
+    assert_eq!(output_lines.next().unwrap(), "");
+    assert_eq!(output_lines.next(), None);
+
+    test_utilities::file::assert_eq_of_file_contents(
+        "./tests_data/pseudolocalize/output/en_xa_strings.xml",
+        res_dir_path
+            .join("values-en-rXA")
+            .join("strings.xml")
+            .to_str()
+            .unwrap(),
+    );
+
+    test_utilities::file::assert_eq_of_file_contents(
+        "./tests_data/pseudolocalize/output/ar_xb_strings.xml",
+        res_dir_path
+            .join("values-ar-rXB")
+            .join("strings.xml")
+            .to_str()
+            .unwrap(),
+    );
+}
+
+#[test]
+fn warns_if_nothing_to_pseudolocalize() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let res_dir_path = temp_dir.path().join("res");
+    let mut default_strings =
+        test_utilities::res::setup_empty_strings_for_default_locale(res_dir_path.clone());
+    default_strings
+        .file
+        .write_all(
+            br#"<resources><string name="app_name" translatable="false">App</string></resources>"#,
+        )
+        .unwrap();
+
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "pseudolocalize",
+            "--res-dir",
+            res_dir_path.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("Nothing found to pseudolocalize\n"));
+}
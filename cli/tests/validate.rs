@@ -1,7 +1,5 @@
 use std::process::Command;
 
-use test_utilities;
-
 #[test]
 fn success_is_printed_out() {
     let output = Command::new("cargo")
@@ -31,14 +29,14 @@ fn success_is_printed_out() {
     assert_eq!(output_lines.next().unwrap(), "");
     test_utilities::eq::assert_eq_to_either_or_by(
         output_lines.next().unwrap(),
-        &default_values,
-        &fr_values,
+        default_values,
+        fr_values,
         |actual, expected| actual.contains("strings.xml") && actual.contains(expected),
     );
     test_utilities::eq::assert_eq_to_either_or_by(
         output_lines.next().unwrap(),
-        &default_values,
-        &fr_values,
+        default_values,
+        fr_values,
         |actual, expected| actual.contains("strings.xml") && actual.contains(expected),
     );
     assert_eq!(output_lines.next().unwrap(), "");
@@ -81,3 +79,45 @@ fn errors_can_skip_unlocalized() {
         .unwrap()
         .contains("Found 3 issues across 2 files!\n"));
 }
+
+#[test]
+fn errors_for_overridden_strings_of_overlays() {
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "validate",
+            "--res-dir",
+            "./tests_data/validate/overlay_input/main/res",
+            "--overlay-res-dir",
+            "./tests_data/validate/overlay_input/free/res",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("Found 1 issue across 1 file!\n"));
+}
+
+#[test]
+fn errors_for_glossary_terms() {
+    let output = Command::new("cargo")
+        .args(vec![
+            "run",
+            "validate",
+            "--res-dir",
+            "./tests_data/validate/valid_input",
+            "--glossary",
+            "./tests_data/validate/glossary.csv",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let output = String::from_utf8(output.stderr).unwrap();
+    assert!(output.contains(
+        "Error 1 (glossary term): Expected [chaine_1] for [string_1] in string_1 french\n"
+    ));
+    assert!(output.contains("Found 1 issue across 1 file!\n"));
+}
@@ -0,0 +1,317 @@
+use std::fmt;
+
+use crate::android_string::AndroidString;
+use crate::constants;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Quantity {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl Quantity {
+    pub fn parse(quantity: &str) -> Option<Quantity> {
+        match quantity {
+            constants::quantities::ZERO => Some(Quantity::Zero),
+            constants::quantities::ONE => Some(Quantity::One),
+            constants::quantities::TWO => Some(Quantity::Two),
+            constants::quantities::FEW => Some(Quantity::Few),
+            constants::quantities::MANY => Some(Quantity::Many),
+            constants::quantities::OTHER => Some(Quantity::Other),
+            _ => None,
+        }
+    }
+
+    /// Quantities the plurals of a language (Eg., `fr` or `pt`) need as laid out
+    /// by the CLDR plural rules. Languages that aren't listed need the same ones
+    /// as English. Android's legacy codes (`in`, `iw` & `ji`) are listed too
+    pub fn required_by(language: &str) -> &'static [Quantity] {
+        use Quantity::*;
+        match language.to_lowercase().as_str() {
+            "bm" | "bo" | "dz" | "id" | "ig" | "ii" | "in" | "ja" | "jbo" | "jv" | "jw" | "kde"
+            | "kea" | "km" | "ko" | "lkt" | "lo" | "ms" | "my" | "nqo" | "osa" | "sah" | "ses"
+            | "sg" | "su" | "th" | "to" | "tpi" | "vi" | "wo" | "yo" | "yue" | "zh" => &[Other],
+            "ksh" | "lag" | "lv" | "prg" => &[Zero, One, Other],
+            "he" | "iu" | "iw" | "naq" | "sat" | "se" | "sma" | "smi" | "smj" | "smn" | "sms" => {
+                &[One, Two, Other]
+            }
+            "bs" | "hr" | "mo" | "ro" | "sh" | "shi" | "sr" => &[One, Few, Other],
+            "ca" | "es" | "fr" | "it" | "pt" | "vec" => &[One, Many, Other],
+            "dsb" | "gd" | "hsb" | "sl" => &[One, Two, Few, Other],
+            "be" | "cs" | "lt" | "pl" | "ru" | "sk" | "uk" => &[One, Few, Many, Other],
+            "br" | "ga" | "gv" | "mt" => &[One, Two, Few, Many, Other],
+            "ar" | "ars" | "cy" | "kw" => &[Zero, One, Two, Few, Many, Other],
+            _ => &[One, Other],
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Quantity::Zero => constants::quantities::ZERO,
+            Quantity::One => constants::quantities::ONE,
+            Quantity::Two => constants::quantities::TWO,
+            Quantity::Few => constants::quantities::FEW,
+            Quantity::Many => constants::quantities::MANY,
+            Quantity::Other => constants::quantities::OTHER,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct PluralItem {
+    quantity: Quantity,
+    value: String,
+}
+
+impl PluralItem {
+    pub fn new(quantity: Quantity, value: String) -> PluralItem {
+        PluralItem { quantity, value }
+    }
+
+    pub fn quantity(&self) -> Quantity {
+        self.quantity
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct AndroidPlural {
+    name: String,
+    items: Vec<PluralItem>,
+    is_localizable: bool,
+    /// Name of the file (in the values dir) this was read from
+    source_file: Option<String>,
+    /// The XML comment right before this in the file it was read from
+    comment: Option<String>,
+}
+
+impl AndroidPlural {
+    pub fn new(name: String, items: Vec<PluralItem>, is_localizable: bool) -> AndroidPlural {
+        AndroidPlural {
+            name,
+            items,
+            is_localizable,
+            source_file: None,
+            comment: None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn items(&self) -> &[PluralItem] {
+        &self.items
+    }
+
+    pub fn is_localizable(&self) -> bool {
+        self.is_localizable
+    }
+
+    pub fn source_file(&self) -> Option<&str> {
+        self.source_file.as_deref()
+    }
+
+    pub fn with_source_file(mut self, source_file: Option<String>) -> AndroidPlural {
+        self.source_file = source_file;
+        self
+    }
+
+    pub fn with_comment(mut self, comment: Option<String>) -> AndroidPlural {
+        self.comment = comment;
+        self
+    }
+
+    pub fn into_unlocalizable(mut self) -> AndroidPlural {
+        self.is_localizable = false;
+        self
+    }
+
+    /// Plurals are shipped off to be localized as one string per quantity. Since
+    /// resource names can't have `[` in them, the built names can't clash with
+    /// the names of regular strings
+    pub fn flattened_name(plural_name: &str, quantity: Quantity) -> String {
+        format!("{}[{}]", plural_name, quantity.as_str())
+    }
+
+    /// Look @ `flattened_name`. Returns the name of the plural & the quantity
+    /// if the passed in name is a flattened one
+    pub fn unflatten_name(flattened_name: &str) -> Option<(&str, Quantity)> {
+        if !flattened_name.ends_with(']') {
+            return None;
+        }
+
+        let open_index = flattened_name.rfind('[')?;
+        let quantity = Quantity::parse(&flattened_name[open_index + 1..flattened_name.len() - 1])?;
+        Some((&flattened_name[..open_index], quantity))
+    }
+
+    /// Quantities of `quantities` this plural doesn't have an item for
+    pub fn missing_quantities(&self, quantities: &[Quantity]) -> Vec<Quantity> {
+        quantities
+            .iter()
+            .filter(|quantity| !self.items.iter().any(|item| item.quantity == **quantity))
+            .copied()
+            .collect()
+    }
+
+    /// Value of the item with the quantity. Falls back to the value of the
+    /// `other` item since that is what Android does
+    pub fn value_of(&self, quantity: Quantity) -> Option<&str> {
+        let find = |quantity: Quantity| self.items.iter().find(|item| item.quantity == quantity);
+        find(quantity)
+            .or_else(|| find(Quantity::Other))
+            .map(|item| item.value.as_str())
+    }
+
+    /// Same plural but with an item per quantity of `quantities`. Quantities
+    /// this plural doesn't have get the value of its `other` item (or are left
+    /// out if it doesn't have one either)
+    pub fn with_quantities(&self, quantities: &[Quantity]) -> AndroidPlural {
+        let items = quantities
+            .iter()
+            .filter_map(|quantity| {
+                self.value_of(*quantity)
+                    .map(|value| PluralItem::new(*quantity, String::from(value)))
+            })
+            .collect();
+
+        AndroidPlural {
+            name: self.name.clone(),
+            items,
+            is_localizable: self.is_localizable,
+            source_file: self.source_file.clone(),
+            comment: self.comment.clone(),
+        }
+    }
+
+    /// Same plural but without the items of `quantities`
+    pub fn without_quantities(mut self, quantities: &[Quantity]) -> AndroidPlural {
+        self.items
+            .retain(|item| !quantities.contains(&item.quantity));
+        self
+    }
+
+    /// Items of `other` with the quantities this plural doesn't have are added
+    /// to this plural
+    pub fn with_missing_items_from(mut self, other: &AndroidPlural) -> AndroidPlural {
+        let missing_quantities = self.missing_quantities(
+            &other
+                .items
+                .iter()
+                .map(PluralItem::quantity)
+                .collect::<Vec<Quantity>>(),
+        );
+
+        self.items.extend(
+            other
+                .items
+                .iter()
+                .filter(|item| missing_quantities.contains(&item.quantity))
+                .cloned(),
+        );
+        self.items.sort_by_key(PluralItem::quantity);
+        self
+    }
+
+    /// Builds one `AndroidString` per quantity. Look @ `flattened_name`
+    pub fn flatten(&self) -> Vec<AndroidString> {
+        self.items
+            .iter()
+            .map(|item| {
+                AndroidString::new(
+                    AndroidPlural::flattened_name(&self.name, item.quantity),
+                    item.value.clone(),
+                    self.is_localizable,
+                )
+                .with_source_file(self.source_file.clone())
+                .with_comment(self.comment.clone())
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for AndroidPlural {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Localizable: {}; Name: {}; Quantities: {}",
+            self.is_localizable(),
+            self.name(),
+            self.items
+                .iter()
+                .map(|item| item.quantity.as_str())
+                .collect::<Vec<&str>>()
+                .join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::android_string::AndroidString;
+
+    use super::AndroidPlural;
+    use super::PluralItem;
+    use super::Quantity;
+
+    /// To expose a convenient way to build for tests
+    impl AndroidPlural {
+        pub fn localizable<N: Into<String>>(
+            name: N,
+            items: Vec<(Quantity, &str)>,
+        ) -> AndroidPlural {
+            AndroidPlural::new(name.into(), build_items(items), true)
+        }
+
+        pub fn unlocalizable<N: Into<String>>(
+            name: N,
+            items: Vec<(Quantity, &str)>,
+        ) -> AndroidPlural {
+            AndroidPlural::new(name.into(), build_items(items), false)
+        }
+    }
+
+    fn build_items(items: Vec<(Quantity, &str)>) -> Vec<PluralItem> {
+        items
+            .into_iter()
+            .map(|(quantity, value)| PluralItem::new(quantity, String::from(value)))
+            .collect()
+    }
+
+    #[test]
+    fn flattens() {
+        test_utilities::list::assert_strict_list_eq(
+            AndroidPlural::unlocalizable(
+                "p1",
+                vec![
+                    (Quantity::One, "one value"),
+                    (Quantity::Other, "other value"),
+                ],
+            )
+            .flatten(),
+            vec![
+                AndroidString::unlocalizable("p1[one]", "one value"),
+                AndroidString::unlocalizable("p1[other]", "other value"),
+            ],
+        )
+    }
+
+    #[test]
+    fn unflattens_name() {
+        assert_eq!(
+            AndroidPlural::unflatten_name("p1[few]"),
+            Some(("p1", Quantity::Few))
+        );
+        assert_eq!(AndroidPlural::unflatten_name("p1"), None);
+        assert_eq!(AndroidPlural::unflatten_name("p1[0]"), None);
+        assert_eq!(AndroidPlural::unflatten_name("p1[some]"), None);
+    }
+}
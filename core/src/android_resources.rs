@@ -0,0 +1,98 @@
+use crate::android_plural::AndroidPlural;
+use crate::android_string::AndroidString;
+use crate::android_string_array::AndroidStringArray;
+
+/// All the resources from a strings file that this utility understands
+#[derive(Clone, Debug, Default, Hash, PartialEq)]
+pub struct AndroidResources {
+    strings: Vec<AndroidString>,
+    plurals: Vec<AndroidPlural>,
+    string_arrays: Vec<AndroidStringArray>,
+}
+
+impl AndroidResources {
+    pub fn new(
+        strings: Vec<AndroidString>,
+        plurals: Vec<AndroidPlural>,
+        string_arrays: Vec<AndroidStringArray>,
+    ) -> AndroidResources {
+        AndroidResources {
+            strings,
+            plurals,
+            string_arrays,
+        }
+    }
+
+    pub fn strings(&self) -> &[AndroidString] {
+        &self.strings
+    }
+
+    pub fn plurals(&self) -> &[AndroidPlural] {
+        &self.plurals
+    }
+
+    pub fn string_arrays(&self) -> &[AndroidStringArray] {
+        &self.string_arrays
+    }
+
+    /// Tags every resource with the name of the file it was read from
+    pub fn with_source_file(self, source_file: &str) -> AndroidResources {
+        let source_file = Some(String::from(source_file));
+        AndroidResources {
+            strings: map(self.strings, |s| s.with_source_file(source_file.clone())),
+            plurals: map(self.plurals, |p| p.with_source_file(source_file.clone())),
+            string_arrays: map(self.string_arrays, |a| {
+                a.with_source_file(source_file.clone())
+            }),
+        }
+    }
+
+    pub fn into_unlocalizable(self) -> AndroidResources {
+        AndroidResources {
+            strings: map(self.strings, AndroidString::into_unlocalizable),
+            plurals: map(self.plurals, AndroidPlural::into_unlocalizable),
+            string_arrays: map(self.string_arrays, AndroidStringArray::into_unlocalizable),
+        }
+    }
+
+    /// Adds all the resources from `other` after the ones in here
+    pub fn extend(&mut self, other: AndroidResources) {
+        self.strings.extend(other.strings);
+        self.plurals.extend(other.plurals);
+        self.string_arrays.extend(other.string_arrays);
+    }
+
+    pub fn into_parts(
+        self,
+    ) -> (
+        Vec<AndroidString>,
+        Vec<AndroidPlural>,
+        Vec<AndroidStringArray>,
+    ) {
+        (self.strings, self.plurals, self.string_arrays)
+    }
+}
+
+impl From<Vec<AndroidString>> for AndroidResources {
+    fn from(strings: Vec<AndroidString>) -> Self {
+        AndroidResources::new(strings, vec![], vec![])
+    }
+}
+
+fn map<T, F: FnMut(T) -> T>(items: Vec<T>, f: F) -> Vec<T> {
+    items.into_iter().map(f).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::android_string::AndroidString;
+
+    use super::AndroidResources;
+
+    /// To expose a convenient way to get at the strings for tests
+    impl AndroidResources {
+        pub fn into_strings(self) -> Vec<AndroidString> {
+            self.strings
+        }
+    }
+}
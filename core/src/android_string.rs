@@ -5,6 +5,15 @@ pub struct AndroidString {
     name: String,
     value: String,
     is_localizable: bool,
+    /// Name of the file (in the values dir) this was read from
+    source_file: Option<String>,
+    /// The XML comment right before this in the file it was read from
+    comment: Option<String>,
+    /// Max count of chars a localized value can have (from `tools:maxLength`)
+    max_length: Option<usize>,
+    /// The res dir (of a project or of a variant) this was read from. Look @
+    /// `ResDir::module` & `SourceSet::name`
+    module: Option<String>,
 }
 
 impl AndroidString {
@@ -13,6 +22,10 @@ impl AndroidString {
             name,
             value,
             is_localizable,
+            source_file: None,
+            comment: None,
+            max_length: None,
+            module: None,
         }
     }
 
@@ -27,6 +40,47 @@ impl AndroidString {
     pub fn is_localizable(&self) -> bool {
         self.is_localizable
     }
+
+    pub fn source_file(&self) -> Option<&str> {
+        self.source_file.as_deref()
+    }
+
+    pub fn with_source_file(mut self, source_file: Option<String>) -> AndroidString {
+        self.source_file = source_file;
+        self
+    }
+
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    pub fn with_comment(mut self, comment: Option<String>) -> AndroidString {
+        self.comment = comment;
+        self
+    }
+
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    pub fn with_max_length(mut self, max_length: Option<usize>) -> AndroidString {
+        self.max_length = max_length;
+        self
+    }
+
+    pub fn module(&self) -> Option<&str> {
+        self.module.as_deref()
+    }
+
+    pub fn with_module(mut self, module: Option<String>) -> AndroidString {
+        self.module = module;
+        self
+    }
+
+    pub fn into_unlocalizable(mut self) -> AndroidString {
+        self.is_localizable = false;
+        self
+    }
 }
 
 impl fmt::Display for AndroidString {
@@ -0,0 +1,154 @@
+use std::fmt;
+
+use crate::android_string::AndroidString;
+
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct AndroidStringArray {
+    name: String,
+    items: Vec<String>,
+    is_localizable: bool,
+    /// Name of the file (in the values dir) this was read from
+    source_file: Option<String>,
+    /// The XML comment right before this in the file it was read from
+    comment: Option<String>,
+}
+
+impl AndroidStringArray {
+    pub fn new(name: String, items: Vec<String>, is_localizable: bool) -> AndroidStringArray {
+        AndroidStringArray {
+            name,
+            items,
+            is_localizable,
+            source_file: None,
+            comment: None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    pub fn is_localizable(&self) -> bool {
+        self.is_localizable
+    }
+
+    pub fn source_file(&self) -> Option<&str> {
+        self.source_file.as_deref()
+    }
+
+    pub fn with_source_file(mut self, source_file: Option<String>) -> AndroidStringArray {
+        self.source_file = source_file;
+        self
+    }
+
+    pub fn with_comment(mut self, comment: Option<String>) -> AndroidStringArray {
+        self.comment = comment;
+        self
+    }
+
+    pub fn into_unlocalizable(mut self) -> AndroidStringArray {
+        self.is_localizable = false;
+        self
+    }
+
+    /// String arrays are shipped off to be localized as one string per item. Since
+    /// resource names can't have `[` in them, the built names can't clash with
+    /// the names of regular strings
+    pub fn flattened_name(array_name: &str, index: usize) -> String {
+        format!("{}[{}]", array_name, index)
+    }
+
+    /// Look @ `flattened_name`. Returns the name of the array & the index of
+    /// the item if the passed in name is a flattened one
+    pub fn unflatten_name(flattened_name: &str) -> Option<(&str, usize)> {
+        if !flattened_name.ends_with(']') {
+            return None;
+        }
+
+        let open_index = flattened_name.rfind('[')?;
+        let index = &flattened_name[open_index + 1..flattened_name.len() - 1];
+        if index.is_empty() || !index.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        Some((&flattened_name[..open_index], index.parse().ok()?))
+    }
+
+    /// Builds one `AndroidString` per item. Look @ `flattened_name`
+    pub fn flatten(&self) -> Vec<AndroidString> {
+        self.items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                AndroidString::new(
+                    AndroidStringArray::flattened_name(&self.name, index),
+                    item.clone(),
+                    self.is_localizable,
+                )
+                .with_source_file(self.source_file.clone())
+                .with_comment(self.comment.clone())
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for AndroidStringArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Localizable: {}; Name: {}; Items: {}",
+            self.is_localizable(),
+            self.name(),
+            self.items.join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::android_string::AndroidString;
+
+    use super::AndroidStringArray;
+
+    /// To expose a convenient way to build for tests
+    impl AndroidStringArray {
+        pub fn localizable<N: Into<String>>(name: N, items: Vec<&str>) -> AndroidStringArray {
+            AndroidStringArray::new(name.into(), build_items(items), true)
+        }
+
+        pub fn unlocalizable<N: Into<String>>(name: N, items: Vec<&str>) -> AndroidStringArray {
+            AndroidStringArray::new(name.into(), build_items(items), false)
+        }
+    }
+
+    fn build_items(items: Vec<&str>) -> Vec<String> {
+        items.into_iter().map(String::from).collect()
+    }
+
+    #[test]
+    fn flattens() {
+        test_utilities::list::assert_strict_list_eq(
+            AndroidStringArray::localizable("a1", vec!["first", "second"]).flatten(),
+            vec![
+                AndroidString::localizable("a1[0]", "first"),
+                AndroidString::localizable("a1[1]", "second"),
+            ],
+        )
+    }
+
+    #[test]
+    fn unflattens_name() {
+        assert_eq!(
+            AndroidStringArray::unflatten_name("a1[12]"),
+            Some(("a1", 12))
+        );
+        assert_eq!(AndroidStringArray::unflatten_name("a1"), None);
+        assert_eq!(AndroidStringArray::unflatten_name("a1[]"), None);
+        assert_eq!(AndroidStringArray::unflatten_name("a1[-1]"), None);
+        assert_eq!(AndroidStringArray::unflatten_name("a1[one]"), None);
+    }
+}
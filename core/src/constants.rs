@@ -1,22 +1,248 @@
 pub mod elements {
     pub const RESOURCES: &str = "resources";
     pub const STRING: &str = "string";
+    pub const PLURALS: &str = "plurals";
+    pub const STRING_ARRAY: &str = "string-array";
+    pub const ITEM: &str = "item";
 }
 
 pub mod attributes {
     pub const NAME: &str = "name";
     pub const LOCALIZABLE: &str = "translatable";
+    pub const QUANTITY: &str = "quantity";
+    /// In the tools namespace
+    pub const MAX_LENGTH: &str = "maxLength";
 }
 
 pub mod flags {
     pub const FALSE: &str = "false";
 }
 
+pub mod quantities {
+    pub const ZERO: &str = "zero";
+    pub const ONE: &str = "one";
+    pub const TWO: &str = "two";
+    pub const FEW: &str = "few";
+    pub const MANY: &str = "many";
+    pub const OTHER: &str = "other";
+}
+
+pub mod namespaces {
+    pub const TOOLS: &str = "http://schemas.android.com/tools";
+    pub const TOOLS_PREFIX: &str = "tools";
+    pub const XLIFF: &str = "urn:oasis:names:tc:xliff:document:1.2";
+    pub const XLIFF_PREFIX: &str = "xliff";
+}
+
+pub mod po {
+    pub const MSGCTXT: &str = "msgctxt";
+    pub const MSGID: &str = "msgid";
+    pub const MSGSTR: &str = "msgstr";
+    pub const EXTRACTED_COMMENT: &str = "#.";
+    pub const REFERENCE: &str = "#:";
+    pub const FLAGS: &str = "#,";
+    pub const OBSOLETE: &str = "#~";
+    pub const FUZZY: &str = "fuzzy";
+    pub const LANGUAGE_HEADER: &str = "Language";
+    pub const CONTENT_TYPE_HEADER: &str = "Content-Type: text/plain; charset=UTF-8";
+}
+
+/// For formats with a single key per string (like PO & iOS strings files)
+pub mod keys {
+    /// Between the module & the name of a string
+    pub const MODULE_SEPARATOR: char = '/';
+}
+
+pub mod ios {
+    pub const LPROJ_EXTENSION: &str = "lproj";
+    pub const BASE_LPROJ_NAME: &str = "Base";
+    pub const DEFAULT_LPROJ_NAME: &str = "en";
+    pub const STRINGS_FILE_NAME: &str = "Localizable";
+    pub const PLIST_DOCTYPE: &str = r#"<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">"#;
+    pub const PLIST_VERSION: &str = "1.0";
+    /// Name of the variable in the format key of a plural
+    pub const PLURAL_VARIABLE: &str = "value";
+    pub const DEFAULT_VALUE_TYPE: &str = "d";
+    pub mod elements {
+        pub const PLIST: &str = "plist";
+        pub const DICT: &str = "dict";
+        pub const KEY: &str = "key";
+        pub const STRING: &str = "string";
+    }
+
+    pub mod keys {
+        pub const FORMAT_KEY: &str = "NSStringLocalizedFormatKey";
+        pub const SPEC_TYPE_KEY: &str = "NSStringFormatSpecTypeKey";
+        pub const VALUE_TYPE_KEY: &str = "NSStringFormatValueTypeKey";
+        pub const PLURAL_RULE_TYPE: &str = "NSStringPluralRuleType";
+    }
+}
+
+pub mod csv {
+    pub const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+    pub const UTF16_LE_BOM: &[u8] = &[0xFF, 0xFE];
+    pub const UTF16_BE_BOM: &[u8] = &[0xFE, 0xFF];
+    pub const FORMULA_ESCAPE: char = '\'';
+    pub const FORMULA_TRIGGERS: &[char] = &['=', '+', '-', '@'];
+    /// Context for translators. Written out right after the default values &
+    /// skipped on reading
+    pub const DESCRIPTION_HEADER: &str = "description";
+    pub const MAX_LENGTH_HEADER: &str = "max_length";
+    /// Of the strings to be localized when some of them have changed since they
+    /// were localized. Skipped on reading along with the old values
+    pub const STATUS_HEADER: &str = "status";
+    pub const STATUS_NEW: &str = "new";
+    pub const STATUS_CHANGED: &str = "changed";
+    /// Of the columns with the old values. Eg., `french (old)`
+    pub const OLD_VALUES_HEADER_SUFFIX: &str = " (old)";
+    /// Of the columns with the suggestions from the translation memory. Eg.,
+    /// `french (suggestion)`. Skipped on reading
+    pub const SUGGESTIONS_HEADER_SUFFIX: &str = " (suggestion)";
+    /// Of the columns with the drafts from machine translation (look @
+    /// `TranslationProvider`). Eg., `french (draft)`. Skipped on reading
+    pub const DRAFTS_HEADER_SUFFIX: &str = " (draft)";
+}
+
+/// Look @ `HttpTranslationProvider`
+pub mod translation_provider {
+    /// Placeholders for what machine translation mustn't touch (look @ `mask`).
+    /// Eg., `⟦0⟧`
+    pub const PLACEHOLDER_START: &str = "\u{27e6}";
+    pub const PLACEHOLDER_END: &str = "\u{27e7}";
+    /// The language of the default values sent out for translation
+    pub const DEFAULT_SOURCE_LANGUAGE: &str = "en";
+    pub const SOURCE_LANGUAGE_FIELD: &str = "source_language";
+    pub const TARGET_LANGUAGE_FIELD: &str = "target_language";
+    pub const TEXTS_FIELD: &str = "texts";
+    pub const TRANSLATIONS_FIELD: &str = "translations";
+    /// For every request (to connect, send & read back)
+    pub const TIMEOUT_SECS: u64 = 60;
+}
+
+/// Look @ `TranslationMemory`
+pub mod translation_memory {
+    /// How similar (100 - edit distance as a percentage of the length of the
+    /// longer value) a default value has to be for its translation to be
+    /// suggested
+    pub const MIN_SIMILARITY_PERCENT: usize = 75;
+}
+
+/// Look @ `Glossary`. Every other column of a glossary file is a locale ID
+pub mod glossary {
+    pub const TERM_HEADER: &str = "term";
+    pub const DO_NOT_TRANSLATE_HEADER: &str = "do_not_translate";
+}
+
+/// Look @ `pseudo`
+pub mod pseudo {
+    /// Locales Android reserves for pseudolocalization
+    pub const ACCENTED_LOCALE: &str = "en-rXA";
+    pub const BIDI_LOCALE: &str = "ar-rXB";
+    /// How much longer accented texts are made (most languages are longer than
+    /// English)
+    pub const EXPANSION_PERCENT: usize = 30;
+    pub const PADDING_WORDS: &[&str] = &[
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+    pub const ACCENTED_LOWERCASE: [char; 26] = [
+        'å', 'ƀ', 'ç', 'ð', 'é', 'ƒ', 'ĝ', 'ĥ', 'î', 'ĵ', 'ķ', 'ļ', 'ɱ', 'ñ', 'ö', 'þ', 'ǫ', 'ŕ',
+        'š', 'ţ', 'û', 'ṽ', 'ŵ', 'ẋ', 'ý', 'ž',
+    ];
+    pub const ACCENTED_UPPERCASE: [char; 26] = [
+        'Å', 'Ɓ', 'Ç', 'Ð', 'É', 'Ƒ', 'Ĝ', 'Ĥ', 'Î', 'Ĵ', 'Ķ', 'Ļ', 'Ṁ', 'Ñ', 'Ö', 'Þ', 'Ǫ', 'Ŕ',
+        'Š', 'Ţ', 'Û', 'Ṽ', 'Ŵ', 'Ẋ', 'Ý', 'Ž',
+    ];
+    /// RLM & RLO
+    pub const BIDI_WORD_START: &str = "\u{200f}\u{202e}";
+    /// PDF & RLM
+    pub const BIDI_WORD_END: &str = "\u{202c}\u{200f}";
+}
+
+pub mod xliff {
+    pub const VERSION: &str = "1.2";
+    /// The default locale of a res dir isn't known. It is mostly English
+    pub const DEFAULT_SOURCE_LANGUAGE: &str = "en";
+    pub mod elements {
+        pub const XLIFF: &str = "xliff";
+        pub const FILE: &str = "file";
+        pub const BODY: &str = "body";
+        pub const GROUP: &str = "group";
+        pub const TRANS_UNIT: &str = "trans-unit";
+        pub const SOURCE: &str = "source";
+        pub const TARGET: &str = "target";
+        pub const NOTE: &str = "note";
+        pub const PH: &str = "ph";
+    }
+
+    pub mod attributes {
+        pub const VERSION: &str = "version";
+        pub const ORIGINAL: &str = "original";
+        pub const DATATYPE: &str = "datatype";
+        pub const SOURCE_LANGUAGE: &str = "source-language";
+        pub const TARGET_LANGUAGE: &str = "target-language";
+        pub const ID: &str = "id";
+        pub const RESNAME: &str = "resname";
+        pub const TRANSLATE: &str = "translate";
+        pub const STATE: &str = "state";
+        pub const FROM: &str = "from";
+    }
+
+    pub mod values {
+        pub const PLAINTEXT: &str = "plaintext";
+        pub const NO: &str = "no";
+        pub const DEVELOPER: &str = "developer";
+    }
+
+    /// Targets in any other state aren't ready to be used
+    pub mod states {
+        pub const NEEDS_TRANSLATION: &str = "needs-translation";
+        pub const TRANSLATED: &str = "translated";
+        pub const SIGNED_OFF: &str = "signed-off";
+        pub const FINAL: &str = "final";
+    }
+}
+
 pub mod fs {
     pub const BASE_VALUES_DIR_NAME: &str = "values";
     pub const STRING_FILE_NAME: &str = "strings.xml";
+    pub const DO_NOT_TRANSLATE_FILE_NAME_PREFIX: &str = "donottranslate";
+    pub const SRC_DIR_NAME: &str = "src";
+    pub const RES_DIR_NAME: &str = "res";
+    pub const BUILD_DIR_NAME: &str = "build";
 }
 
 pub mod extn {
     pub const CSV: &str = "csv";
+    pub const XML: &str = "xml";
+    pub const XLIFF: &str = "xlf";
+    pub const XLIFF_LONG: &str = "xliff";
+    pub const PO: &str = "po";
+    pub const POT: &str = "pot";
+    pub const XLSX: &str = "xlsx";
+    pub const STRINGS: &str = "strings";
+    pub const STRINGSDICT: &str = "stringsdict";
+    pub const JSON: &str = "json";
+    pub const PROPERTIES: &str = "properties";
+    pub const ARB: &str = "arb";
+}
+
+pub mod arb {
+    pub const LOCALE_KEY: &str = "@@locale";
+    pub const TEMPLATE_LOCALE: &str = "en";
+    /// Keys of the metadata of messages are the keys of the messages prefixed
+    /// with this
+    pub const METADATA_PREFIX: char = '@';
+    pub const DESCRIPTION: &str = "description";
+    pub const PLACEHOLDERS: &str = "placeholders";
+    pub const TYPE: &str = "type";
+    pub const PLACEHOLDER_PREFIX: &str = "arg";
+    pub const STRING_TYPE: &str = "String";
+    pub const INT_TYPE: &str = "int";
+    pub const DOUBLE_TYPE: &str = "double";
+    pub const NUM_TYPE: &str = "num";
+}
+
+pub mod json {
+    pub const SOURCE: &str = "source";
+    pub const TARGET: &str = "target";
 }
@@ -0,0 +1,155 @@
+use std::str::FromStr;
+
+use crate::error::InnerError;
+
+/// How CSV files are written out & read back. Spreadsheet apps (like Excel)
+/// save CSV files with a delimiter that depends on the system's locale & in
+/// an encoding that isn't always UTF-8. On reading, the encoding is figured
+/// out from the BOM (look @ `csv_reader::decode`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CsvDialect {
+    delimiter: u8,
+    bom: bool,
+    encoding: Encoding,
+    quote_style: QuoteStyle,
+    formula_safe: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Encoding {
+    Utf8,
+    /// Little endian & always with a BOM (like Excel's "Unicode Text")
+    Utf16,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QuoteStyle {
+    /// Only the fields with a delimiter, a quote or a line break are quoted
+    Necessary,
+    Always,
+}
+
+impl CsvDialect {
+    pub fn with_delimiter(mut self, delimiter: u8) -> CsvDialect {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Only matters for UTF-8 since UTF-16 is always written with a BOM
+    pub fn with_bom(mut self, bom: bool) -> CsvDialect {
+        self.bom = bom;
+        self
+    }
+
+    pub fn with_encoding(mut self, encoding: Encoding) -> CsvDialect {
+        self.encoding = encoding;
+        self
+    }
+
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> CsvDialect {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// Look @ `formula::escape`. Values are escaped on writing & unescaped on
+    /// reading
+    pub fn with_formula_safe(mut self, formula_safe: bool) -> CsvDialect {
+        self.formula_safe = formula_safe;
+        self
+    }
+
+    pub fn delimiter(&self) -> u8 {
+        self.delimiter
+    }
+
+    pub fn bom(&self) -> bool {
+        self.bom
+    }
+
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    pub fn quote_style(&self) -> QuoteStyle {
+        self.quote_style
+    }
+
+    pub fn formula_safe(&self) -> bool {
+        self.formula_safe
+    }
+}
+
+impl Default for CsvDialect {
+    /// Comma separated UTF-8 without a BOM
+    fn default() -> Self {
+        CsvDialect {
+            delimiter: b',',
+            bom: false,
+            encoding: Encoding::Utf8,
+            quote_style: QuoteStyle::Necessary,
+            formula_safe: false,
+        }
+    }
+}
+
+/// Either a single ASCII char or `tab`
+pub fn parse_delimiter(s: &str) -> Result<u8, InnerError> {
+    match s {
+        "tab" | "\\t" | "\t" => Ok(b'\t'),
+        _ if s.len() == 1 && s.is_ascii() && s != "\"" => Ok(s.as_bytes()[0]),
+        _ => Err(format!(
+            "{} isn't a supported delimiter (a single ASCII char other than \" or tab)",
+            s
+        )
+        .into()),
+    }
+}
+
+impl FromStr for Encoding {
+    type Err = InnerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "utf-8" | "utf8" => Ok(Encoding::Utf8),
+            "utf-16" | "utf16" => Ok(Encoding::Utf16),
+            _ => Err(format!("{} isn't a supported encoding (utf-8 or utf-16)", s).into()),
+        }
+    }
+}
+
+impl FromStr for QuoteStyle {
+    type Err = InnerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "necessary" => Ok(QuoteStyle::Necessary),
+            "always" => Ok(QuoteStyle::Always),
+            _ => Err(format!("{} isn't a supported quote style (necessary or always)", s).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Encoding;
+    use super::QuoteStyle;
+
+    #[test]
+    fn parses_delimiters() {
+        assert_eq!(super::parse_delimiter(";").unwrap(), b';');
+        assert_eq!(super::parse_delimiter("tab").unwrap(), b'\t');
+        assert_eq!(super::parse_delimiter("\\t").unwrap(), b'\t');
+        assert!(super::parse_delimiter(";;").is_err());
+        assert!(super::parse_delimiter("\"").is_err());
+        assert!(super::parse_delimiter("é").is_err());
+    }
+
+    #[test]
+    fn parses_encodings_and_quote_styles() {
+        assert_eq!("UTF-8".parse::<Encoding>().unwrap(), Encoding::Utf8);
+        assert_eq!("utf-16".parse::<Encoding>().unwrap(), Encoding::Utf16);
+        assert!("latin-1".parse::<Encoding>().is_err());
+        assert_eq!("always".parse::<QuoteStyle>().unwrap(), QuoteStyle::Always);
+        assert!("never".parse::<QuoteStyle>().is_err());
+    }
+}
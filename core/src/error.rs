@@ -13,10 +13,14 @@ pub struct Error {
 #[derive(Debug)]
 pub enum ErrorKind {
     Csv(csv::Error),
+    Http(Box<ureq::Error>),
     Io(io::Error),
+    Json(serde_json::Error),
     Message(String),
     XmlRead(xml::reader::Error),
     XmlWrite(xml::writer::Error),
+    XlsxRead(calamine::XlsxError),
+    XlsxWrite(rust_xlsxwriter::XlsxError),
 }
 
 /// Components that don't know the path, should return this which could be
@@ -40,10 +44,14 @@ impl error::Error for Error {
     fn cause(&self) -> Option<&dyn error::Error> {
         match &self.kind {
             ErrorKind::Csv(error) => Some(error),
+            ErrorKind::Http(error) => Some(error.as_ref()),
             ErrorKind::Io(error) => Some(error),
+            ErrorKind::Json(error) => Some(error),
             ErrorKind::Message(_message) => None,
             ErrorKind::XmlRead(error) => Some(error),
             ErrorKind::XmlWrite(error) => Some(error),
+            ErrorKind::XlsxRead(error) => Some(error),
+            ErrorKind::XlsxWrite(error) => Some(error),
         }
     }
 }
@@ -59,10 +67,14 @@ impl fmt::Display for ErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self {
             ErrorKind::Csv(error) => fmt::Display::fmt(error, f),
+            ErrorKind::Http(error) => fmt::Display::fmt(error, f),
             ErrorKind::Io(error) => fmt::Display::fmt(error, f),
+            ErrorKind::Json(error) => fmt::Display::fmt(error, f),
             ErrorKind::Message(message) => fmt::Display::fmt(message, f),
             ErrorKind::XmlRead(error) => fmt::Display::fmt(error, f),
             ErrorKind::XmlWrite(error) => fmt::Display::fmt(error, f),
+            ErrorKind::XlsxRead(error) => fmt::Display::fmt(error, f),
+            ErrorKind::XlsxWrite(error) => fmt::Display::fmt(error, f),
         }
     }
 }
@@ -90,6 +102,14 @@ impl From<csv::Error> for InnerError {
     }
 }
 
+impl From<ureq::Error> for InnerError {
+    fn from(error: ureq::Error) -> Self {
+        InnerError {
+            kind: ErrorKind::Http(Box::new(error)),
+        }
+    }
+}
+
 impl From<io::Error> for InnerError {
     fn from(error: io::Error) -> Self {
         InnerError {
@@ -98,6 +118,14 @@ impl From<io::Error> for InnerError {
     }
 }
 
+impl From<serde_json::Error> for InnerError {
+    fn from(error: serde_json::Error) -> Self {
+        InnerError {
+            kind: ErrorKind::Json(error),
+        }
+    }
+}
+
 impl From<String> for InnerError {
     fn from(message: String) -> Self {
         InnerError {
@@ -128,6 +156,22 @@ impl From<xml::writer::Error> for InnerError {
     }
 }
 
+impl From<calamine::XlsxError> for InnerError {
+    fn from(error: calamine::XlsxError) -> Self {
+        InnerError {
+            kind: ErrorKind::XlsxRead(error),
+        }
+    }
+}
+
+impl From<rust_xlsxwriter::XlsxError> for InnerError {
+    fn from(error: rust_xlsxwriter::XlsxError) -> Self {
+        InnerError {
+            kind: ErrorKind::XlsxWrite(error),
+        }
+    }
+}
+
 /// To easily add context to errors
 pub trait ResultExt<T> {
     fn with_context<S: Into<String>>(self, context: S) -> Result<T, Error>;
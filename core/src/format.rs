@@ -0,0 +1,112 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::constants;
+use crate::error::InnerError;
+
+/// Formats the texts to be localized are written out in & read back from
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Csv,
+    Xliff,
+    Po,
+    Xlsx,
+    /// `Localizable.strings` & `Localizable.stringsdict` files in `.lproj` dirs
+    Ios,
+    /// i18next style
+    Json,
+    /// Java resource bundles
+    Properties,
+    /// Flutter
+    Arb,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Csv => constants::extn::CSV,
+            Format::Xliff => constants::extn::XLIFF,
+            Format::Po => constants::extn::PO,
+            Format::Xlsx => constants::extn::XLSX,
+            Format::Ios => constants::extn::STRINGS,
+            Format::Json => constants::extn::JSON,
+            Format::Properties => constants::extn::PROPERTIES,
+            Format::Arb => constants::extn::ARB,
+        }
+    }
+
+    /// Figured out from the extension of the file. Anything unknown is
+    /// considered to be a CSV file
+    pub fn from_file_path(file_path: &str) -> Format {
+        let extension = Path::new(file_path)
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        match extension.as_deref() {
+            Some(constants::extn::XLIFF) | Some(constants::extn::XLIFF_LONG) => Format::Xliff,
+            Some(constants::extn::PO) | Some(constants::extn::POT) => Format::Po,
+            Some(constants::extn::XLSX) => Format::Xlsx,
+            Some(constants::extn::STRINGS) | Some(constants::extn::STRINGSDICT) => Format::Ios,
+            Some(constants::extn::JSON) => Format::Json,
+            Some(constants::extn::PROPERTIES) => Format::Properties,
+            Some(constants::extn::ARB) => Format::Arb,
+            _ => Format::Csv,
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = InnerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Format::Csv),
+            "xliff" => Ok(Format::Xliff),
+            "po" => Ok(Format::Po),
+            "xlsx" => Ok(Format::Xlsx),
+            "ios" => Ok(Format::Ios),
+            "json" => Ok(Format::Json),
+            "properties" => Ok(Format::Properties),
+            "arb" => Ok(Format::Arb),
+            _ => Err(format!(
+                "{} isn't a supported format (csv, xliff, po, xlsx, ios, json, properties or arb)",
+                s
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Format;
+
+    #[test]
+    fn figures_out_format_from_file_path() {
+        assert_eq!(Format::from_file_path("a/b.xlf"), Format::Xliff);
+        assert_eq!(Format::from_file_path("a/b.XLIFF"), Format::Xliff);
+        assert_eq!(Format::from_file_path("a/b.po"), Format::Po);
+        assert_eq!(Format::from_file_path("a/b.xlsx"), Format::Xlsx);
+        assert_eq!(
+            Format::from_file_path("a/fr.lproj/Localizable.strings"),
+            Format::Ios
+        );
+        assert_eq!(Format::from_file_path("a/b.stringsdict"), Format::Ios);
+        assert_eq!(Format::from_file_path("a/b.json"), Format::Json);
+        assert_eq!(
+            Format::from_file_path("a/b_fr.properties"),
+            Format::Properties
+        );
+        assert_eq!(Format::from_file_path("a/b_fr.arb"), Format::Arb);
+        assert_eq!(Format::from_file_path("a/b.csv"), Format::Csv);
+        assert_eq!(Format::from_file_path("a/b"), Format::Csv);
+    }
+
+    #[test]
+    fn parses_format() {
+        assert_eq!("xliff".parse::<Format>().unwrap(), Format::Xliff);
+        assert_eq!(
+            "xml".parse::<Format>().unwrap_err().to_string(),
+            "xml isn't a supported format (csv, xliff, po, xlsx, ios, json, properties or arb)"
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use std::str::FromStr;
+
+use crate::error::InnerError;
+
+/// How the locales are split across the CSV files (or the sheets of an XLSX
+/// workbook) the texts to be localized are written out to
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Grouping {
+    /// Locales requiring the same strings to be localized go together
+    #[default]
+    ByStrings,
+    PerLocale,
+    /// Every locale goes into the same file with a row per string any of them
+    /// requires. Cells of the locales that don't require a string are left
+    /// empty too
+    Single,
+}
+
+impl FromStr for Grouping {
+    type Err = InnerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "by-strings" => Ok(Grouping::ByStrings),
+            "per-locale" => Ok(Grouping::PerLocale),
+            "single" => Ok(Grouping::Single),
+            _ => Err(format!(
+                "{} isn't a supported grouping (by-strings, per-locale or single)",
+                s
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grouping;
+
+    #[test]
+    fn parses_groupings() {
+        assert_eq!(
+            "by-strings".parse::<Grouping>().unwrap(),
+            Grouping::ByStrings
+        );
+        assert_eq!(
+            "per-locale".parse::<Grouping>().unwrap(),
+            Grouping::PerLocale
+        );
+        assert_eq!("single".parse::<Grouping>().unwrap(), Grouping::Single);
+        assert_eq!(
+            "all".parse::<Grouping>().unwrap_err().to_string(),
+            "all isn't a supported grouping (by-strings, per-locale or single)"
+        );
+    }
+}
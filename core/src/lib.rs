@@ -1,16 +1,30 @@
 pub use validate::formatter;
 pub use validate::validator;
 
+mod android_plural;
+mod android_resources;
 mod android_string;
+mod android_string_array;
 mod constants;
+pub mod csv_dialect;
 mod error;
+pub mod format;
+pub mod grouping;
 mod localizable_strings;
 pub mod localize;
 pub mod localized;
 mod localized_string;
 mod localized_strings;
 mod ops;
+pub mod pseudolocalize;
+mod qualifiers;
 mod reader;
+mod state;
+pub mod stats;
+mod strings_document;
+mod translation_memory;
+pub mod translation_provider;
 mod util;
 mod validate;
+pub mod warnings;
 mod writer;
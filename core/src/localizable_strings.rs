@@ -1,9 +1,26 @@
+use std::collections::BTreeMap;
+
 use crate::android_string::AndroidString;
+use crate::qualifiers::Locale;
+
+/// Module & name of a string
+pub type StringKey = (Option<String>, String);
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct LocalizableStrings {
     to_locale: String,
+    /// ID of the locale (like `fr` or `pt-rBR`) if it is known. `to_locale` is
+    /// the name it is mapped to
+    to_locale_id: Option<String>,
     default_locale_strings: Vec<AndroidString>,
+    /// Localized values of the strings whose default values have changed since
+    /// they were localized (look @ `State`)
+    old_values: BTreeMap<StringKey, String>,
+    /// Translations of the same or similar default values (look @
+    /// `TranslationMemory`)
+    suggestions: BTreeMap<StringKey, String>,
+    /// Machine translations of the strings (look @ `TranslationProvider`)
+    drafts: BTreeMap<StringKey, String>,
 }
 
 impl LocalizableStrings {
@@ -13,15 +30,109 @@ impl LocalizableStrings {
     ) -> LocalizableStrings {
         LocalizableStrings {
             to_locale,
+            to_locale_id: None,
             default_locale_strings,
+            old_values: BTreeMap::new(),
+            suggestions: BTreeMap::new(),
+            drafts: BTreeMap::new(),
         }
     }
 
+    pub fn with_to_locale_id(mut self, to_locale_id: String) -> LocalizableStrings {
+        self.to_locale_id = Some(to_locale_id);
+        self
+    }
+
+    pub fn with_old_values(
+        mut self,
+        old_values: BTreeMap<StringKey, String>,
+    ) -> LocalizableStrings {
+        self.old_values = old_values;
+        self
+    }
+
+    pub fn with_suggestions(
+        mut self,
+        suggestions: BTreeMap<StringKey, String>,
+    ) -> LocalizableStrings {
+        self.suggestions = suggestions;
+        self
+    }
+
+    pub fn with_drafts(mut self, drafts: BTreeMap<StringKey, String>) -> LocalizableStrings {
+        self.drafts = drafts;
+        self
+    }
+
     pub fn to_locale(&self) -> &str {
         &self.to_locale
     }
 
+    /// Falls back to the locale's name if its ID isn't known
+    pub fn to_locale_id(&self) -> &str {
+        self.to_locale_id.as_deref().unwrap_or(&self.to_locale)
+    }
+
+    /// BCP 47 language tag of the locale (look @ `Locale::language_tag`). Falls
+    /// back to `to_locale_id` if it isn't a valid locale qualifier
+    pub fn to_language_tag(&self) -> String {
+        self.to_locale_id().parse::<Locale>().map_or_else(
+            |_| String::from(self.to_locale_id()),
+            |locale| locale.language_tag(),
+        )
+    }
+
     pub fn default_locale_strings(&self) -> &[AndroidString] {
         &self.default_locale_strings
     }
+
+    pub fn old_values(&self) -> &BTreeMap<StringKey, String> {
+        &self.old_values
+    }
+
+    /// Strings without an old value are yet to be localized
+    pub fn old_value_of(&self, string: &AndroidString) -> Option<&str> {
+        self.old_values.get(&key_of(string)).map(String::as_str)
+    }
+
+    pub fn suggestions(&self) -> &BTreeMap<StringKey, String> {
+        &self.suggestions
+    }
+
+    pub fn suggestion_of(&self, string: &AndroidString) -> Option<&str> {
+        self.suggestions.get(&key_of(string)).map(String::as_str)
+    }
+
+    pub fn drafts(&self) -> &BTreeMap<StringKey, String> {
+        &self.drafts
+    }
+
+    pub fn draft_of(&self, string: &AndroidString) -> Option<&str> {
+        self.drafts.get(&key_of(string)).map(String::as_str)
+    }
+}
+
+pub fn key_of(string: &AndroidString) -> StringKey {
+    (
+        string.module().map(String::from),
+        String::from(string.name()),
+    )
+}
+
+/// Every string to be localized (for any of the locales) once. Strings are in
+/// the order they first appear in
+pub fn union(localizable_strings_list: &[LocalizableStrings]) -> Vec<AndroidString> {
+    let mut strings: Vec<AndroidString> = vec![];
+    for localizable_strings in localizable_strings_list {
+        for string in localizable_strings.default_locale_strings() {
+            if !strings
+                .iter()
+                .any(|s| s.name() == string.name() && s.module() == string.module())
+            {
+                strings.push(string.clone());
+            }
+        }
+    }
+
+    strings
 }
@@ -1,17 +1,125 @@
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fs;
 use std::fs::File;
 use std::path::Path;
 use std::path::PathBuf;
 
+use crate::android_plural::AndroidPlural;
 use crate::android_string::AndroidString;
+use crate::android_string_array::AndroidStringArray;
 use crate::constants;
+use crate::csv_dialect::CsvDialect;
 use crate::error::{Error, ResultExt};
+use crate::format::Format;
+use crate::grouping::Grouping;
+use crate::localizable_strings;
 use crate::localizable_strings::LocalizableStrings;
+use crate::localizable_strings::StringKey;
+use crate::localized;
+use crate::localized_string::LocalizedString;
+use crate::localized_strings::LocalizedStrings;
 use crate::ops::filter;
+use crate::ops::sort;
+use crate::qualifiers::Locale;
+use crate::reader::csv_reader;
+use crate::state::State;
+use crate::stats;
+use crate::stats::LocaleStats;
+use crate::translation_memory::TranslationMemory;
+use crate::translation_provider::TranslationProvider;
 use crate::util::foreign_locale_ids_finder;
-use crate::util::xml_utilities;
+use crate::util::res_dirs_finder;
+use crate::util::source_sets::MergedStrings;
+use crate::util::source_sets::SourceSets;
+use crate::writer::arb_writer;
 use crate::writer::csv_writer;
+use crate::writer::json_writer;
+use crate::writer::po_writer;
+use crate::writer::properties_writer;
+use crate::writer::strings_writer;
+use crate::writer::stringsdict_writer;
+use crate::writer::xliff_writer;
+use crate::writer::xlsx_writer;
+
+/// What to do with the output files that already exist
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputPolicy {
+    /// Nothing is written out over an existing file
+    Fail,
+    Overwrite,
+    /// Only for CSV files. Values already filled in an existing file are kept in
+    /// the rewritten file (look @ `csv_writer::Writer::with_existing_values`)
+    Merge,
+}
+
+/// How the texts to be localized are written out. By default, they are written
+/// out into CSV files (look @ `CsvDialect`) grouped by the strings required by
+/// the locales (look @ `Grouping`), no existing file is written over & the
+/// translation memory isn't used (look @ `TranslationMemory`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OutputOptions {
+    format: Format,
+    csv_dialect: CsvDialect,
+    output_policy: OutputPolicy,
+    grouping: Grouping,
+    suggestions: bool,
+    fill_exact_matches: bool,
+}
+
+impl OutputOptions {
+    pub fn with_format(mut self, format: Format) -> OutputOptions {
+        self.format = format;
+        self
+    }
+
+    pub fn with_csv_dialect(mut self, csv_dialect: CsvDialect) -> OutputOptions {
+        self.csv_dialect = csv_dialect;
+        self
+    }
+
+    pub fn with_output_policy(mut self, output_policy: OutputPolicy) -> OutputOptions {
+        self.output_policy = output_policy;
+        self
+    }
+
+    /// Only matters for CSV files & XLSX workbooks. Every other format has a
+    /// fixed layout
+    pub fn with_grouping(mut self, grouping: Grouping) -> OutputOptions {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Translations of the same or similar default values (look @
+    /// `TranslationMemory`) are written out along with the strings. Only CSV
+    /// files have a place for them (look @ `csv_writer::Writer::write`)
+    pub fn with_suggestions(mut self, suggestions: bool) -> OutputOptions {
+        self.suggestions = suggestions;
+        self
+    }
+
+    /// Strings whose default values have already been localized (for another
+    /// string; look @ `TranslationMemory`) are written straight into the foreign
+    /// strings files with the same translations instead of being written out.
+    /// Only strings are filled in. Plurals & string arrays are always written out
+    pub fn with_fill_exact_matches(mut self, fill_exact_matches: bool) -> OutputOptions {
+        self.fill_exact_matches = fill_exact_matches;
+        self
+    }
+}
+
+impl Default for OutputOptions {
+    fn default() -> Self {
+        OutputOptions {
+            format: Format::Csv,
+            csv_dialect: CsvDialect::default(),
+            output_policy: OutputPolicy::Fail,
+            grouping: Grouping::default(),
+            suggestions: false,
+            fill_exact_matches: false,
+        }
+    }
+}
 
 /// Returns the list of output files created by this call. These aren't guaranteed
 /// to be valid paths to files. Sometimes, if a file's path can't be expressed by
@@ -19,12 +127,42 @@ use crate::writer::csv_writer;
 pub fn localize<S: ::std::hash::BuildHasher>(
     res_dir_path: &str,
     output_dir_path: &str,
+    output_options: OutputOptions,
     locale_id_to_name_map: HashMap<String, String, S>,
 ) -> Result<Vec<String>, Error> {
-    let locale_id_to_name_map = foreign_locale_ids_finder::build_map_if_empty_or_return(
-        locale_id_to_name_map,
+    localize_with_overlays(
         res_dir_path,
-    )?;
+        &[],
+        output_dir_path,
+        output_options,
+        None,
+        None,
+        locale_id_to_name_map,
+    )
+}
+
+/// Same as `localize` but for the merged view of the res dir & its overlays
+/// (look @ `SourceSets`). When there are overlays, every string is written out
+/// along with the name of the source set it is from (in the module column).
+/// Output files are named after the locales in them. With a state file (look
+/// @ `State`), the strings whose default values have changed since they were
+/// localized are written out too (along with their old localized values).
+/// Strings filled in from the translation memory (look @
+/// `OutputOptions::with_fill_exact_matches`) are recorded in the state file &
+/// the foreign strings files they are written into are returned too. With a
+/// translation provider, machine translations of the strings are written out
+/// as drafts (only into CSV files; look @ `TranslationProvider`)
+pub fn localize_with_overlays<S: ::std::hash::BuildHasher>(
+    res_dir_path: &str,
+    overlay_res_dir_paths: &[&str],
+    output_dir_path: &str,
+    output_options: OutputOptions,
+    state_file_path: Option<&str>,
+    translation_provider: Option<&dyn TranslationProvider>,
+    locale_id_to_name_map: HashMap<String, String, S>,
+) -> Result<Vec<String>, Error> {
+    let source_sets = SourceSets::new(res_dir_path, overlay_res_dir_paths);
+    let locale_id_to_name_map = source_sets.build_map_if_empty_or_return(locale_id_to_name_map)?;
 
     if locale_id_to_name_map.is_empty() {
         return Err(Error::new(
@@ -33,23 +171,398 @@ pub fn localize<S: ::std::hash::BuildHasher>(
         ));
     }
 
+    let mut state = read_state(state_file_path)?;
     create_output_dir_if_required(output_dir_path)?;
 
-    // Read default strings
-    let res_dir_path = Path::new(res_dir_path);
-    let mut localizable_default_strings = filter::find_localizable_strings(
-        xml_utilities::read_default_strings(res_dir_path)?.into_strings(),
-    );
+    let locale_name_to_id_map = invert(&locale_id_to_name_map);
+    let (localizable_strings_list, filled_strings_list) = find_strings_to_localize(
+        &source_sets,
+        locale_id_to_name_map,
+        state.as_ref().map(|state| StaleCheck {
+            state,
+            module: None,
+        }),
+        output_options,
+    )?;
 
-    // For all languages, write out strings requiring localization
-    write_out_strings_to_localize(
-        res_dir_path,
+    // For all languages, write out strings requiring localization. Foreign
+    // strings files are only touched once that goes through
+    let localizable_strings_list = add_drafts(
         output_dir_path,
+        output_options,
+        translation_provider,
+        localizable_strings_list,
+    )?;
+    let mut output_files_paths =
+        write_out_strings_to_localize(output_dir_path, output_options, localizable_strings_list)?;
+    let filled_files_paths = fill_strings(
+        &source_sets,
+        filled_strings_list,
+        &locale_name_to_id_map,
+        state.as_mut(),
+        None,
+    )?;
+
+    write_state(state_file_path, state, &filled_files_paths)?;
+    output_files_paths.extend(filled_files_paths);
+    Ok(output_files_paths)
+}
+
+/// Same as `localize` but for every res dir of the project (look @ `ResDir`).
+/// All the strings go into the same output files with a module column so that
+/// `localized_project` can route them back. Every res dir is only localized
+/// into the locales it already has a values dir for. Look @
+/// `localize_with_overlays` for the state file & the translation provider
+pub fn localize_project<S: ::std::hash::BuildHasher>(
+    project_dir_path: &str,
+    output_dir_path: &str,
+    output_options: OutputOptions,
+    state_file_path: Option<&str>,
+    translation_provider: Option<&dyn TranslationProvider>,
+    locale_id_to_name_map: HashMap<String, String, S>,
+) -> Result<Vec<String>, Error> {
+    let mut state = read_state(state_file_path)?;
+    let (localizable_strings_list, filled_strings_by_res_dir) = find_project_strings_to_localize(
+        project_dir_path,
+        output_options,
+        state.as_ref(),
         locale_id_to_name_map,
-        &mut localizable_default_strings,
+    )?;
+
+    create_output_dir_if_required(output_dir_path)?;
+    let localizable_strings_list = add_drafts(
+        output_dir_path,
+        output_options,
+        translation_provider,
+        localizable_strings_list,
+    )?;
+    let mut output_files_paths =
+        write_out_strings_to_localize(output_dir_path, output_options, localizable_strings_list)?;
+
+    let mut filled_files_paths = vec![];
+    for (source_sets, filled_strings_list, locale_name_to_id_map, module) in
+        filled_strings_by_res_dir
+    {
+        filled_files_paths.extend(fill_strings(
+            &source_sets,
+            filled_strings_list,
+            &locale_name_to_id_map,
+            state.as_mut(),
+            Some(&module),
+        )?);
+    }
+
+    write_state(state_file_path, state, &filled_files_paths)?;
+    output_files_paths.extend(filled_files_paths);
+    Ok(output_files_paths)
+}
+
+/// Instead of writing out the strings to be localized, returns how much there
+/// is to be localized for every locale (look @ `LocaleStats`). The strings are
+/// found the same way as `localize_with_overlays` does. Nothing is written out
+pub fn stats_with_overlays<S: ::std::hash::BuildHasher>(
+    res_dir_path: &str,
+    overlay_res_dir_paths: &[&str],
+    output_options: OutputOptions,
+    state_file_path: Option<&str>,
+    locale_id_to_name_map: HashMap<String, String, S>,
+) -> Result<Vec<LocaleStats>, Error> {
+    let source_sets = SourceSets::new(res_dir_path, overlay_res_dir_paths);
+    let locale_id_to_name_map = source_sets.build_map_if_empty_or_return(locale_id_to_name_map)?;
+
+    if locale_id_to_name_map.is_empty() {
+        return Err(Error::new(
+            res_dir_path,
+            "Res dir doesn't have any non-default values dir with strings file!",
+        ));
+    }
+
+    let state = read_state(state_file_path)?;
+    let (localizable_strings_list, _) = find_strings_to_localize(
+        &source_sets,
+        locale_id_to_name_map,
+        state.as_ref().map(|state| StaleCheck {
+            state,
+            module: None,
+        }),
+        output_options,
+    )?;
+
+    Ok(stats::compute(&localizable_strings_list))
+}
+
+/// Same as `stats_with_overlays` but for every res dir of the project. Look @
+/// `localize_project`
+pub fn stats_project<S: ::std::hash::BuildHasher>(
+    project_dir_path: &str,
+    output_options: OutputOptions,
+    state_file_path: Option<&str>,
+    locale_id_to_name_map: HashMap<String, String, S>,
+) -> Result<Vec<LocaleStats>, Error> {
+    let state = read_state(state_file_path)?;
+    let (localizable_strings_list, _) = find_project_strings_to_localize(
+        project_dir_path,
+        output_options,
+        state.as_ref(),
+        locale_id_to_name_map,
+    )?;
+
+    Ok(stats::compute(&localizable_strings_list))
+}
+
+/// Strings filled in from the translation memory for a res dir along with
+/// what is needed to write them into its foreign strings files
+type FilledStrings = (
+    SourceSets,
+    Vec<LocalizedStrings>,
+    HashMap<String, String>,
+    String,
+);
+
+/// Strings of every res dir are merged by locale. Look @ `localize_project`
+fn find_project_strings_to_localize<S: ::std::hash::BuildHasher>(
+    project_dir_path: &str,
+    output_options: OutputOptions,
+    state: Option<&State>,
+    locale_id_to_name_map: HashMap<String, String, S>,
+) -> Result<(Vec<LocalizableStrings>, Vec<FilledStrings>), Error> {
+    let mut strings_to_localize_by_locale_name: BTreeMap<String, StringsWithValues> =
+        BTreeMap::new();
+    let mut locale_name_to_id_map: HashMap<String, String> = HashMap::new();
+    let mut filled_strings_by_res_dir = vec![];
+    let mut has_foreign_locales = false;
+    for res_dir in res_dirs_finder::find(project_dir_path)? {
+        if !res_dir
+            .path()
+            .join(constants::fs::BASE_VALUES_DIR_NAME)
+            .is_dir()
+        {
+            continue;
+        }
+
+        let res_dir_path = res_dir.path().to_string_lossy();
+        let res_dir_locale_id_to_name_map =
+            build_map_for_res_dir(&locale_id_to_name_map, &res_dir_path)?;
+        has_foreign_locales |= !res_dir_locale_id_to_name_map.is_empty();
+
+        let source_sets = SourceSets::new(&res_dir_path, &[]);
+        let res_dir_locale_name_to_id_map = invert(&res_dir_locale_id_to_name_map);
+        let (localizable_strings_list, filled_strings_list) = find_strings_to_localize(
+            &source_sets,
+            res_dir_locale_id_to_name_map,
+            state.map(|state| StaleCheck {
+                state,
+                module: Some(res_dir.module()),
+            }),
+            output_options,
+        )?;
+
+        let module = || Some(String::from(res_dir.module()));
+        let with_module = |values: &BTreeMap<StringKey, String>| {
+            values
+                .iter()
+                .map(|((_, name), value)| ((module(), name.clone()), value.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        for localizable_strings in localizable_strings_list {
+            locale_name_to_id_map.insert(
+                String::from(localizable_strings.to_locale()),
+                String::from(localizable_strings.to_locale_id()),
+            );
+            let (strings, old_values, suggestions) = strings_to_localize_by_locale_name
+                .entry(String::from(localizable_strings.to_locale()))
+                .or_default();
+            strings.extend(
+                localizable_strings
+                    .default_locale_strings()
+                    .iter()
+                    .map(|s| s.clone().with_module(module())),
+            );
+            old_values.extend(with_module(localizable_strings.old_values()));
+            suggestions.extend(with_module(localizable_strings.suggestions()));
+        }
+
+        if !filled_strings_list.is_empty() {
+            filled_strings_by_res_dir.push((
+                source_sets,
+                filled_strings_list,
+                res_dir_locale_name_to_id_map,
+                String::from(res_dir.module()),
+            ));
+        }
+    }
+
+    if !has_foreign_locales {
+        return Err(Error::new(
+            project_dir_path,
+            "Project doesn't have any res dir with non-default values dir with strings file!",
+        ));
+    }
+
+    let localizable_strings_list = strings_to_localize_by_locale_name
+        .into_iter()
+        .map(|(locale_name, (strings, old_values, suggestions))| {
+            let locale_id = locale_name_to_id_map
+                .get(&locale_name)
+                .cloned()
+                .unwrap_or_else(|| locale_name.clone());
+            LocalizableStrings::new(locale_name, strings)
+                .with_to_locale_id(locale_id)
+                .with_old_values(old_values)
+                .with_suggestions(suggestions)
+        })
+        .collect();
+
+    Ok((localizable_strings_list, filled_strings_by_res_dir))
+}
+
+/// Strings to be localized for a locale along with their old values &
+/// suggestions (look @ `LocalizableStrings`)
+type StringsWithValues = (
+    Vec<AndroidString>,
+    BTreeMap<StringKey, String>,
+    BTreeMap<StringKey, String>,
+);
+
+/// Look @ `State`. Strings of a project are tracked along with their modules
+/// (look @ `ResDir::module`)
+#[derive(Clone, Copy)]
+struct StaleCheck<'a> {
+    state: &'a State,
+    module: Option<&'a str>,
+}
+
+fn read_state(state_file_path: Option<&str>) -> Result<Option<State>, Error> {
+    state_file_path
+        .map(|path| State::read(Path::new(path)).with_context(path))
+        .transpose()
+}
+
+/// The state only changes when strings are filled in (look @
+/// `OutputOptions::with_fill_exact_matches`). Otherwise, it isn't written out
+fn write_state(
+    state_file_path: Option<&str>,
+    state: Option<State>,
+    filled_files_paths: &[String],
+) -> Result<(), Error> {
+    match (state_file_path, state) {
+        (Some(path), Some(state)) if !filled_files_paths.is_empty() => {
+            state.write(Path::new(path)).with_context(path)
+        }
+        _ => Ok(()),
+    }
+}
+
+fn invert<S: ::std::hash::BuildHasher>(
+    locale_id_to_name_map: &HashMap<String, String, S>,
+) -> HashMap<String, String> {
+    locale_id_to_name_map
+        .iter()
+        .map(|(locale_id, locale_name)| (locale_name.clone(), locale_id.clone()))
+        .collect()
+}
+
+/// Look @ `OutputOptions::with_fill_exact_matches`. Returns the list of foreign
+/// strings files updated
+fn fill_strings(
+    source_sets: &SourceSets,
+    filled_strings_list: Vec<LocalizedStrings>,
+    locale_name_to_id_map: &HashMap<String, String>,
+    state: Option<&mut State>,
+    module: Option<&str>,
+) -> Result<Vec<String>, Error> {
+    if filled_strings_list.is_empty() {
+        return Ok(vec![]);
+    }
+
+    localized::handle_localized(
+        source_sets,
+        filled_strings_list,
+        locale_name_to_id_map,
+        state,
+        module,
     )
 }
 
+/// Machine translations (look @ `TranslationProvider`) are added as drafts.
+/// Only CSV files have a place for them (look @ `csv_writer::Writer::write`)
+fn add_drafts(
+    output_dir_path: &str,
+    output_options: OutputOptions,
+    translation_provider: Option<&dyn TranslationProvider>,
+    localizable_strings_list: Vec<LocalizableStrings>,
+) -> Result<Vec<LocalizableStrings>, Error> {
+    let translation_provider = match translation_provider {
+        Some(translation_provider) if !localizable_strings_list.is_empty() => translation_provider,
+        _ => return Ok(localizable_strings_list),
+    };
+
+    if output_options.format != Format::Csv {
+        return Err(Error::new(
+            output_dir_path,
+            "Only CSV files can have drafts written into",
+        ));
+    }
+
+    let mut drafts_by_locale_name = translation_provider
+        .translate(&localizable_strings_list)?
+        .into_iter()
+        .map(|localized_strings| {
+            let locale_name = String::from(localized_strings.locale());
+            let drafts = localized_strings
+                .into_strings()
+                .into_iter()
+                .map(|string| {
+                    (
+                        (
+                            string.module().map(String::from),
+                            String::from(string.name()),
+                        ),
+                        String::from(string.localized()),
+                    )
+                })
+                .collect::<BTreeMap<StringKey, String>>();
+            (locale_name, drafts)
+        })
+        .collect::<HashMap<_, _>>();
+
+    Ok(localizable_strings_list
+        .into_iter()
+        .map(|localizable_strings| {
+            let drafts = drafts_by_locale_name
+                .remove(localizable_strings.to_locale())
+                .unwrap_or_default();
+            localizable_strings.with_drafts(drafts)
+        })
+        .collect())
+}
+
+/// Without a mapping, the one built off of the res dir is used. With one, only
+/// the locales the res dir has a values dir for are kept
+fn build_map_for_res_dir<S: ::std::hash::BuildHasher>(
+    locale_id_to_name_map: &HashMap<String, String, S>,
+    res_dir_path: &str,
+) -> Result<HashMap<String, String>, Error> {
+    if locale_id_to_name_map.is_empty() {
+        return foreign_locale_ids_finder::build_map_if_empty_or_return(
+            HashMap::new(),
+            res_dir_path,
+        );
+    }
+
+    let locale_ids = foreign_locale_ids_finder::find(res_dir_path)?
+        .into_iter()
+        .map(|locale| locale.to_string())
+        .collect::<Vec<String>>();
+
+    Ok(locale_id_to_name_map
+        .iter()
+        .filter(|(locale_id, _)| locale_ids.contains(locale_id))
+        .map(|(locale_id, locale_name)| (locale_id.clone(), locale_name.clone()))
+        .collect())
+}
+
 fn create_output_dir_if_required(output_dir_path: &str) -> Result<(), Error> {
     let output_path = PathBuf::from(output_dir_path);
     if output_path.is_file() {
@@ -65,47 +578,529 @@ fn create_output_dir_if_required(output_dir_path: &str) -> Result<(), Error> {
     }
 }
 
-fn write_out_strings_to_localize<S: ::std::hash::BuildHasher>(
-    res_dir_path: &Path,
-    output_dir_path: &str,
+/// Strings to be localized & the ones filled in from the translation memory
+/// (look @ `OutputOptions::with_fill_exact_matches`), by locale
+type FoundStrings = (Vec<LocalizableStrings>, Vec<LocalizedStrings>);
+
+/// Only locales with strings requiring localization (or with strings filled
+/// in) are returned
+fn find_strings_to_localize<S: ::std::hash::BuildHasher>(
+    source_sets: &SourceSets,
+    locale_id_to_name_map: HashMap<String, String, S>,
+    stale_check: Option<StaleCheck>,
+    output_options: OutputOptions,
+) -> Result<FoundStrings, Error> {
+    // Read default strings, plurals & string arrays
+    let merged_default_strings = source_sets.read_default_strings()?;
+    let (default_strings, default_plurals, default_string_arrays) =
+        merged_default_strings.combine().into_parts();
+    let mut localizable_default_strings = filter::find_localizable_strings(default_strings);
+    let localizable_default_plurals = filter::find_localizable_plurals(default_plurals);
+    let localizable_default_string_arrays =
+        filter::find_localizable_string_arrays(default_string_arrays);
+
+    find_missing_strings_per_locale(
+        source_sets,
+        &merged_default_strings,
+        locale_id_to_name_map,
+        &mut localizable_default_strings,
+        &localizable_default_plurals,
+        &localizable_default_string_arrays,
+        stale_check,
+        output_options,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn find_missing_strings_per_locale<S: ::std::hash::BuildHasher>(
+    source_sets: &SourceSets,
+    merged_default_strings: &MergedStrings,
     locale_id_to_name_map: HashMap<String, String, S>,
     localizable_default_strings: &mut [AndroidString],
-) -> Result<Vec<String>, Error> {
+    localizable_default_plurals: &[AndroidPlural],
+    localizable_default_string_arrays: &[AndroidStringArray],
+    stale_check: Option<StaleCheck>,
+    output_options: OutputOptions,
+) -> Result<FoundStrings, Error> {
+    // Sorted so that the output files (& the columns in them) are always the same
+    let mut locale_ids_and_names = locale_id_to_name_map.into_iter().collect::<Vec<_>>();
+    locale_ids_and_names.sort_by(|(_, name1), (_, name2)| name1.cmp(name2));
+
+    // Plurals & string arrays are flattened the same way they are shipped off
+    let mut flattened_default_strings = localizable_default_strings.to_vec();
+    for plural in localizable_default_plurals {
+        flattened_default_strings.extend(plural.flatten());
+    }
+
+    for string_array in localizable_default_string_arrays {
+        flattened_default_strings.extend(string_array.flatten());
+    }
+
     let mut localizable_strings_list = vec![];
-    for (locale_id, locale_name) in locale_id_to_name_map {
-        let mut foreign_strings =
-            xml_utilities::read_foreign_strings(res_dir_path, &locale_id)?.into_strings();
+    let mut filled_strings_list = vec![];
+    for (locale_id, locale_name) in locale_ids_and_names {
+        let locale: Locale = locale_id.parse().with_context(locale_id.clone())?;
+        let (mut foreign_strings, mut foreign_plurals, mut foreign_string_arrays) = source_sets
+            .read_foreign_strings(&locale, merged_default_strings)?
+            .combine()
+            .into_parts();
 
-        let strings_to_localize =
+        let mut strings_to_localize =
             filter::find_missing_strings(&mut foreign_strings, localizable_default_strings);
 
+        // Strings localized against default values that have changed since
+        let mut old_values_by_name = HashMap::new();
+        if let Some(StaleCheck { state, module }) = stale_check {
+            for foreign_string in &foreign_strings {
+                let default_string = localizable_default_strings
+                    .iter()
+                    .find(|s| s.name() == foreign_string.name());
+                if let Some(default_string) = default_string {
+                    if state.is_stale(
+                        &locale_id,
+                        module,
+                        default_string.name(),
+                        default_string.value(),
+                    ) {
+                        old_values_by_name.insert(
+                            String::from(foreign_string.name()),
+                            String::from(foreign_string.value()),
+                        );
+                        strings_to_localize.push(default_string.clone());
+                    }
+                }
+            }
+
+            sort::sort_android_strings_by_name(&mut strings_to_localize);
+
+            // Stale quantities & string arrays are shipped off along with the
+            // missing ones
+            foreign_plurals = drop_stale_plural_items(
+                foreign_plurals,
+                localizable_default_plurals,
+                |name, default_value| state.is_stale(&locale_id, module, name, default_value),
+                &mut old_values_by_name,
+            );
+            foreign_string_arrays = drop_stale_string_arrays(
+                foreign_string_arrays,
+                localizable_default_string_arrays,
+                |name, default_value| state.is_stale(&locale_id, module, name, default_value),
+                &mut old_values_by_name,
+            );
+        }
+
+        // Plurals are shipped off as one string per quantity the locale needs
+        for plural in filter::find_missing_plurals(
+            &foreign_plurals,
+            localizable_default_plurals,
+            locale.plural_quantities(),
+        ) {
+            strings_to_localize.extend(plural.flatten());
+        }
+
+        // String arrays are shipped off as one string per item
+        for string_array in filter::find_missing_string_arrays(
+            &foreign_string_arrays,
+            localizable_default_string_arrays,
+        ) {
+            strings_to_localize.extend(string_array.flatten());
+        }
+
+        let translation_memory = if output_options.suggestions || output_options.fill_exact_matches
+        {
+            // Stale translations weren't made for the current default values
+            let mut flattened_foreign_strings = foreign_strings
+                .iter()
+                .filter(|s| !old_values_by_name.contains_key(s.name()))
+                .cloned()
+                .collect::<Vec<AndroidString>>();
+            for plural in &foreign_plurals {
+                flattened_foreign_strings.extend(plural.flatten());
+            }
+
+            for string_array in &foreign_string_arrays {
+                flattened_foreign_strings.extend(string_array.flatten());
+            }
+
+            TranslationMemory::build(&flattened_default_strings, &flattened_foreign_strings)
+        } else {
+            TranslationMemory::default()
+        };
+
+        let mut filled_strings = vec![];
+        if output_options.fill_exact_matches {
+            strings_to_localize.retain(|string| {
+                let is_fillable = !old_values_by_name.contains_key(string.name())
+                    && localizable_default_strings
+                        .iter()
+                        .any(|s| s.name() == string.name());
+                match translation_memory.exact_match(string.value()) {
+                    Some(translation) if is_fillable => {
+                        filled_strings.push(LocalizedString::new(
+                            String::from(string.name()),
+                            String::from(string.value()),
+                            String::from(translation),
+                        ));
+                        false
+                    }
+
+                    _ => true,
+                }
+            });
+        }
+
+        if !filled_strings.is_empty() {
+            filled_strings_list.push(LocalizedStrings::new(locale_name.clone(), filled_strings));
+        }
+
+        if source_sets.has_overlays() {
+            strings_to_localize = strings_to_localize
+                .into_iter()
+                .map(|string| {
+                    let index = merged_default_strings
+                        .index_of_flattened(string.name())
+                        .unwrap_or(0);
+                    let source_set_name = String::from(source_sets.get(index).name());
+                    string.with_module(Some(source_set_name))
+                })
+                .collect();
+        }
+
         if !strings_to_localize.is_empty() {
-            localizable_strings_list.push(LocalizableStrings::new(locale_name, strings_to_localize))
+            let old_values = strings_to_localize
+                .iter()
+                .filter_map(|string| {
+                    old_values_by_name.get(string.name()).map(|value| {
+                        (
+                            (
+                                string.module().map(String::from),
+                                String::from(string.name()),
+                            ),
+                            value.clone(),
+                        )
+                    })
+                })
+                .collect();
+
+            let suggestions = strings_to_localize
+                .iter()
+                .filter(|_| output_options.suggestions)
+                .filter_map(|string| {
+                    translation_memory
+                        .suggest(string.value())
+                        .map(|suggestion| {
+                            (
+                                localizable_strings::key_of(string),
+                                String::from(suggestion),
+                            )
+                        })
+                })
+                .collect();
+
+            localizable_strings_list.push(
+                LocalizableStrings::new(locale_name, strings_to_localize)
+                    .with_to_locale_id(locale_id.clone())
+                    .with_old_values(old_values)
+                    .with_suggestions(suggestions),
+            )
         }
     }
 
-    if !localizable_strings_list.is_empty() {
-        let mut sink_provider = FileProvider::new(String::from(output_dir_path));
-        csv_writer::write(localizable_strings_list, &mut sink_provider)?;
+    Ok((localizable_strings_list, filled_strings_list))
+}
+
+/// Items whose default value has changed since they were localized (look @
+/// `State::is_stale`) are dropped. Their localized values are put in
+/// `old_values_by_name` by their flattened names
+fn drop_stale_plural_items<F>(
+    foreign_plurals: Vec<AndroidPlural>,
+    default_plurals: &[AndroidPlural],
+    is_stale: F,
+    old_values_by_name: &mut HashMap<String, String>,
+) -> Vec<AndroidPlural>
+where
+    F: Fn(&str, &str) -> bool,
+{
+    foreign_plurals
+        .into_iter()
+        .map(|foreign_plural| {
+            let default_plural = match default_plurals
+                .iter()
+                .find(|p| p.name() == foreign_plural.name())
+            {
+                None => return foreign_plural,
+                Some(default_plural) => default_plural,
+            };
 
-        Ok(sink_provider.into_created_files())
-    } else {
-        Ok(vec![])
+            let mut stale_quantities = vec![];
+            for item in foreign_plural.items() {
+                let name = AndroidPlural::flattened_name(foreign_plural.name(), item.quantity());
+                let is_item_stale = default_plural
+                    .value_of(item.quantity())
+                    .is_some_and(|default_value| is_stale(&name, default_value));
+                if is_item_stale {
+                    old_values_by_name.insert(name, String::from(item.value()));
+                    stale_quantities.push(item.quantity());
+                }
+            }
+
+            foreign_plural.without_quantities(&stale_quantities)
+        })
+        .collect()
+}
+
+/// String arrays are only ever localized as a whole (look @
+/// `extract::extract_string_arrays_from_flattened`). So, arrays with any item
+/// whose default value has changed since it was localized are dropped. The
+/// localized values of all their items are put in `old_values_by_name` by
+/// their flattened names
+fn drop_stale_string_arrays<F>(
+    foreign_string_arrays: Vec<AndroidStringArray>,
+    default_string_arrays: &[AndroidStringArray],
+    is_stale: F,
+    old_values_by_name: &mut HashMap<String, String>,
+) -> Vec<AndroidStringArray>
+where
+    F: Fn(&str, &str) -> bool,
+{
+    foreign_string_arrays
+        .into_iter()
+        .filter(|foreign_string_array| {
+            let default_string_array = match default_string_arrays
+                .iter()
+                .find(|a| a.name() == foreign_string_array.name())
+            {
+                None => return true,
+                Some(default_string_array) => default_string_array,
+            };
+
+            let default_items = default_string_array.flatten();
+            let foreign_items = foreign_string_array.flatten();
+            let is_array_stale =
+                foreign_items
+                    .iter()
+                    .zip(&default_items)
+                    .any(|(foreign_item, default_item)| {
+                        is_stale(foreign_item.name(), default_item.value())
+                    });
+
+            if is_array_stale {
+                for foreign_item in foreign_items {
+                    old_values_by_name.insert(
+                        String::from(foreign_item.name()),
+                        String::from(foreign_item.value()),
+                    );
+                }
+            }
+
+            !is_array_stale
+        })
+        .collect()
+}
+
+fn write_out_strings_to_localize(
+    output_dir_path: &str,
+    output_options: OutputOptions,
+    localizable_strings_list: Vec<LocalizableStrings>,
+) -> Result<Vec<String>, Error> {
+    if localizable_strings_list.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let OutputOptions {
+        format,
+        csv_dialect,
+        output_policy,
+        grouping,
+        ..
+    } = output_options;
+    if output_policy == OutputPolicy::Merge && format != Format::Csv {
+        return Err(Error::new(
+            output_dir_path,
+            "Only CSV files can be merged into",
+        ));
+    }
+
+    let mut sink_provider =
+        FileProvider::new(String::from(output_dir_path), csv_dialect, output_policy);
+    match format {
+        Format::Csv => csv_writer::write(
+            localizable_strings_list,
+            csv_dialect,
+            grouping,
+            &mut sink_provider,
+        )?,
+
+        // All the locales go into the same file
+        Format::Xliff => {
+            let (sink, path) =
+                sink_provider.create_output_file("to_localize", Format::Xliff.extension())?;
+            xliff_writer::write(
+                sink,
+                localizable_strings_list,
+                constants::xliff::DEFAULT_SOURCE_LANGUAGE,
+            )
+            .with_context(path)?
+        }
+
+        // All the locales go into the same file
+        Format::Json => {
+            let (sink, path) =
+                sink_provider.create_output_file("to_localize", Format::Json.extension())?;
+            json_writer::write(sink, localizable_strings_list).with_context(path)?
+        }
+
+        // A sheet per group of locales (look @ `Grouping`) in the same file
+        Format::Xlsx => {
+            let (sink, path) =
+                sink_provider.create_output_file("to_localize", Format::Xlsx.extension())?;
+            xlsx_writer::write(sink, localizable_strings_list, grouping).with_context(path)?
+        }
+
+        // A template with every string & a file per locale
+        Format::Po => {
+            let template_strings = localizable_strings::union(&localizable_strings_list);
+            let (sink, path) =
+                sink_provider.create_output_file("to_localize", constants::extn::POT)?;
+            po_writer::write(sink, None, &template_strings).with_context(path)?;
+
+            for localizable_strings in &localizable_strings_list {
+                let (sink, path) = sink_provider.create_output_file(
+                    &format!("to_localize_{}", localizable_strings.to_locale()),
+                    constants::extn::PO,
+                )?;
+                po_writer::write(
+                    sink,
+                    Some(&localizable_strings.to_language_tag()),
+                    localizable_strings.default_locale_strings(),
+                )
+                .with_context(path)?
+            }
+        }
+
+        // A base file with every string (& their values) & a file per locale to
+        // be filled in
+        Format::Properties => {
+            let (sink, path) =
+                sink_provider.create_output_file("to_localize", Format::Properties.extension())?;
+            properties_writer::write(
+                sink,
+                &localizable_strings::union(&localizable_strings_list),
+                true,
+            )
+            .with_context(path)?;
+
+            for localizable_strings in &localizable_strings_list {
+                let (sink, path) = sink_provider.create_output_file(
+                    &format!("to_localize_{}", localizable_strings.to_locale()),
+                    Format::Properties.extension(),
+                )?;
+                properties_writer::write(sink, localizable_strings.default_locale_strings(), false)
+                    .with_context(path)?
+            }
+        }
+
+        // A template with every string (& their values) & a file per locale to
+        // be filled in
+        Format::Arb => {
+            let (sink, path) =
+                sink_provider.create_output_file("to_localize", Format::Arb.extension())?;
+            arb_writer::write(
+                sink,
+                constants::arb::TEMPLATE_LOCALE,
+                &localizable_strings::union(&localizable_strings_list),
+                true,
+            )
+            .with_context(path)?;
+
+            for localizable_strings in &localizable_strings_list {
+                let (sink, path) = sink_provider.create_output_file(
+                    &format!("to_localize_{}", localizable_strings.to_locale()),
+                    Format::Arb.extension(),
+                )?;
+                arb_writer::write(
+                    sink,
+                    localizable_strings.to_locale(),
+                    localizable_strings.default_locale_strings(),
+                    false,
+                )
+                .with_context(path)?
+            }
+        }
+
+        // Default `.lproj` with every string (& their values) & a `.lproj` per
+        // locale to be filled in
+        Format::Ios => {
+            write_out_lproj(
+                &mut sink_provider,
+                constants::ios::DEFAULT_LPROJ_NAME,
+                &localizable_strings::union(&localizable_strings_list),
+                true,
+            )?;
+
+            for localizable_strings in &localizable_strings_list {
+                write_out_lproj(
+                    &mut sink_provider,
+                    localizable_strings.to_locale(),
+                    localizable_strings.default_locale_strings(),
+                    false,
+                )?
+            }
+        }
+    }
+
+    Ok(sink_provider.into_created_files())
+}
+
+/// Plurals go into the `.stringsdict` file & everything else into the
+/// `.strings` file
+fn write_out_lproj(
+    sink_provider: &mut FileProvider,
+    lproj_name: &str,
+    strings: &[AndroidString],
+    include_values: bool,
+) -> Result<(), Error> {
+    let (plural_strings, other_strings): (Vec<AndroidString>, Vec<AndroidString>) = strings
+        .iter()
+        .cloned()
+        .partition(|s| AndroidPlural::unflatten_name(s.name()).is_some());
+
+    let output_file_name = format!(
+        "{}.{}/{}",
+        lproj_name,
+        constants::ios::LPROJ_EXTENSION,
+        constants::ios::STRINGS_FILE_NAME
+    );
+
+    if !other_strings.is_empty() {
+        let (sink, path) =
+            sink_provider.create_output_file(&output_file_name, constants::extn::STRINGS)?;
+        strings_writer::write(sink, &other_strings, include_values).with_context(path)?;
+    }
+
+    if !plural_strings.is_empty() {
+        let (sink, path) =
+            sink_provider.create_output_file(&output_file_name, constants::extn::STRINGSDICT)?;
+        stringsdict_writer::write(sink, &plural_strings, include_values).with_context(path)?;
     }
+
+    Ok(())
 }
 
 struct FileProvider {
-    count_of_files_created: usize,
     sink_dir: String,
+    csv_dialect: CsvDialect,
+    output_policy: OutputPolicy,
     created_files: Vec<String>,
 }
 
 impl FileProvider {
-    fn new(sink_dir: String) -> FileProvider {
+    fn new(sink_dir: String, csv_dialect: CsvDialect, output_policy: OutputPolicy) -> FileProvider {
         FileProvider {
             sink_dir,
+            csv_dialect,
+            output_policy,
             created_files: Vec::new(),
-            count_of_files_created: 0,
         }
     }
 
@@ -113,17 +1108,31 @@ impl FileProvider {
         self.created_files
     }
 
-    /// Returns the created output file along with its path (if path computation
-    /// is possible; if not, it passes out a fallback value)
-    fn create_output_file(&mut self, output_file_name: &str) -> Result<(File, String), Error> {
+    fn build_output_path(&self, output_file_name: &str, extension: &str) -> PathBuf {
         let mut output_path = PathBuf::from(&self.sink_dir);
         output_path.push(output_file_name);
-        output_path.set_extension(constants::extn::CSV);
+        output_path.set_extension(extension);
+        output_path
+    }
+
+    /// Returns the created output file along with its path (if path computation
+    /// is possible; if not, it passes out a fallback value)
+    fn create_output_file(
+        &mut self,
+        output_file_name: &str,
+        extension: &str,
+    ) -> Result<(File, String), Error> {
+        let output_path = self.build_output_path(output_file_name, extension);
         let output_path_or_fb = String::from(output_path.to_str().unwrap_or(output_file_name));
 
-        if output_path.exists() {
+        if output_path.exists() && self.output_policy == OutputPolicy::Fail {
             Err(Error::new(output_path_or_fb, "Output file already exists!"))
         } else {
+            // Output file name can have a dir in it
+            if let Some(parent) = output_path.parent() {
+                fs::create_dir_all(parent).with_context(output_path_or_fb.clone())?;
+            }
+
             match File::create(output_path) {
                 Ok(file) => {
                     self.created_files.push(output_path_or_fb.clone());
@@ -137,10 +1146,23 @@ impl FileProvider {
 }
 
 impl csv_writer::SinkProvider for FileProvider {
-    fn execute_with_new_sink(&mut self, writer: csv_writer::Writer) -> Result<(), Error> {
-        self.count_of_files_created += 1;
-        let filename = format!("to_localize_{}", self.count_of_files_created);
-        let (mut sink, path) = self.create_output_file(&filename)?;
+    /// Files are named after the locales in them. Eg., `to_localize_fr_it.csv`
+    fn execute_with_new_sink(&mut self, mut writer: csv_writer::Writer) -> Result<(), Error> {
+        let filename = format!("to_localize_{}", writer.locales().join("_"));
+        let output_path = self.build_output_path(&filename, constants::extn::CSV);
+        if self.output_policy == OutputPolicy::Merge && output_path.is_file() {
+            let context = output_path.to_string_lossy();
+            let allow_only_locales = writer.locales().into_iter().map(String::from).collect();
+            let existing_strings_list = csv_reader::read(
+                File::open(&output_path).with_context(context.clone())?,
+                self.csv_dialect,
+                allow_only_locales,
+            )
+            .with_context(context)?;
+            writer = writer.with_existing_values(existing_strings_list);
+        }
+
+        let (mut sink, path) = self.create_output_file(&filename, constants::extn::CSV)?;
         writer.write(&mut sink).with_context(path)
     }
 }
@@ -157,7 +1179,23 @@ mod tests {
 
     use test_utilities;
 
+    use crate::android_plural::AndroidPlural;
+    use crate::android_plural::Quantity;
     use crate::android_string::AndroidString;
+    use crate::android_string_array::AndroidStringArray;
+    use crate::constants;
+    use crate::csv_dialect::CsvDialect;
+    use crate::error::Error;
+    use crate::format::Format;
+    use crate::localizable_strings::LocalizableStrings;
+    use crate::localize::OutputOptions;
+    use crate::localize::OutputPolicy;
+    use crate::localized_string::LocalizedString;
+    use crate::localized_strings::LocalizedStrings;
+    use crate::stats::LocaleStats;
+    use crate::translation_provider::TranslationProvider;
+    use crate::util::source_sets::MergedStrings;
+    use crate::util::source_sets::SourceSets;
 
     #[test]
     fn errors_for_empty_locale_id_to_name_map() {
@@ -166,8 +1204,13 @@ mod tests {
         res_dir_path.push("res");
         fs::create_dir(res_dir_path.clone()).unwrap();
 
-        let error =
-            super::localize(res_dir_path.to_str().unwrap(), "", HashMap::new()).unwrap_err();
+        let error = super::localize(
+            res_dir_path.to_str().unwrap(),
+            "",
+            OutputOptions::default(),
+            HashMap::new(),
+        )
+        .unwrap_err();
         assert_eq!(
             error.context(),
             &String::from(res_dir_path.to_str().unwrap())
@@ -177,6 +1220,31 @@ mod tests {
             .ends_with("Res dir doesn't have any non-default values dir with strings file!"))
     }
 
+    #[test]
+    fn errors_for_invalid_locale_id() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_dir_path = temp_dir.path().to_path_buf();
+        res_dir_path.push("res");
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_dir_path.clone());
+        test_utilities::file::write_content(default_strings.path, "<resources></resources>");
+
+        let mut locale_id_to_name_map = HashMap::new();
+        locale_id_to_name_map.insert(String::from("fr-night"), String::from("french"));
+
+        let error = super::localize(
+            res_dir_path.to_str().unwrap(),
+            "",
+            OutputOptions::default(),
+            locale_id_to_name_map,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "fr-night: fr-night isn't a valid locale qualifier (Eg., fr, pt-rBR, b+sr+Latn)"
+        )
+    }
+
     #[test]
     fn create_output_dir_if_required_errors_if_output_dir_is_a_file_instead() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -201,11 +1269,17 @@ mod tests {
         let mut output_file_path = output_dir_path.to_path_buf();
         output_file_path.push("op_file.csv");
 
-        File::create(&output_file_path.clone()).unwrap();
+        File::create(output_file_path.clone()).unwrap();
         let output_dir_path = output_dir_path.to_str().unwrap();
 
-        let mut file_provider = super::FileProvider::new(String::from(output_dir_path));
-        let error = file_provider.create_output_file("op_file").unwrap_err();
+        let mut file_provider = super::FileProvider::new(
+            String::from(output_dir_path),
+            CsvDialect::default(),
+            OutputPolicy::Fail,
+        );
+        let error = file_provider
+            .create_output_file("op_file", constants::extn::CSV)
+            .unwrap_err();
 
         assert!(error.to_string().ends_with("Output file already exists!"));
         assert_eq!(
@@ -227,10 +1301,12 @@ mod tests {
         let temp_dir = tempfile::tempdir().unwrap();
         let (file_paths, output_dir) = test_write_out_strings_to_localize(
             &temp_dir,
-            &contents,
-            &contents,
-            &contents,
+            contents,
+            contents,
+            contents,
             default_strings,
+            vec![],
+            vec![],
         );
 
         test_utilities::list::assert_list_is_empty(file_paths);
@@ -253,10 +1329,12 @@ mod tests {
         let temp_dir = tempfile::tempdir().unwrap();
         let (file_paths, output_dir) = test_write_out_strings_to_localize(
             &temp_dir,
-            &contents,
-            &contents,
-            &contents,
+            contents,
+            contents,
+            contents,
             default_strings,
+            vec![],
+            vec![],
         );
 
         assert_eq!(file_paths.len(), 1);
@@ -274,24 +1352,888 @@ mod tests {
                 .unwrap()
                 .to_str()
                 .unwrap(),
-            "to_localize_1.csv"
+            "to_localize_french_spanish.csv"
         );
 
-        test_utilities::eq::assert_eq_to_either_or(
-            test_utilities::file::read_content(&Path::new(&file_paths.into_iter().next().unwrap())),
-            String::from("string_name,default_locale,spanish,french\nstring_1,string value,,\nstring_2,string value,,\n"),
+        assert_eq!(
+            test_utilities::file::read_content(Path::new(&file_paths.into_iter().next().unwrap())),
             String::from("string_name,default_locale,french,spanish\nstring_1,string value,,\nstring_2,string value,,\n")
         );
     }
 
-    /// Returns the output of the method call to `write_out_strings_to_localize`
-    /// & the output dir path (built by the test)
-    fn test_write_out_strings_to_localize(
-        temp_dir: &TempDir,
-        french_values_file_content: &str,
+    #[test]
+    fn write_out_strings_to_localize_writes_out_missing_quantities_of_plurals() {
+        let contents = r##"
+			<?xml version="1.0" encoding="utf-8"?>
+			<resources>
+			    <plurals name="plural_1">
+			        <item quantity="few">few value</item>
+			    </plurals>
+			</resources>
+		"##;
+
+        let default_plurals = vec![
+            AndroidPlural::localizable("plural_1", vec![(Quantity::Other, "other value")]),
+            AndroidPlural::localizable(
+                "plural_2",
+                vec![
+                    (Quantity::One, "one value"),
+                    (Quantity::Other, "other value"),
+                ],
+            ),
+        ];
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let (file_paths, _) = test_write_out_strings_to_localize(
+            &temp_dir,
+            contents,
+            contents,
+            contents,
+            vec![AndroidString::localizable("string_1", "string value")],
+            default_plurals,
+            vec![],
+        );
+
+        assert_eq!(
+            test_utilities::file::read_content(Path::new(&file_paths.into_iter().next().unwrap())),
+            String::from("string_name,default_locale,french,spanish\nstring_1,string value,,\nplural_1[one],other value,,\nplural_1[many],other value,,\nplural_1[other],other value,,\nplural_2[one],one value,,\nplural_2[many],other value,,\nplural_2[other],other value,,\n")
+        );
+    }
+
+    #[test]
+    fn write_out_strings_to_localize_writes_out_missing_string_arrays() {
+        let contents = r##"
+			<?xml version="1.0" encoding="utf-8"?>
+			<resources>
+			    <string-array name="array_1">
+			        <item>first</item>
+			    </string-array>
+			</resources>
+		"##;
+
+        let default_string_arrays = vec![
+            AndroidStringArray::localizable("array_1", vec!["first", "second"]),
+            AndroidStringArray::localizable("array_2", vec!["first", "second"]),
+        ];
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let (file_paths, _) = test_write_out_strings_to_localize(
+            &temp_dir,
+            contents,
+            contents,
+            contents,
+            vec![],
+            vec![],
+            default_string_arrays,
+        );
+
+        assert_eq!(
+            test_utilities::file::read_content(Path::new(&file_paths.into_iter().next().unwrap())),
+            String::from("string_name,default_locale,french,spanish\narray_2[0],first,,\narray_2[1],second,,\n")
+        );
+    }
+
+    #[test]
+    fn localize_project_writes_out_strings_of_every_module() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let project_path = temp_dir.path().join("project");
+        let app_res_path = project_path
+            .join("app")
+            .join("src")
+            .join("main")
+            .join("res");
+        let lib_res_path = project_path
+            .join("lib")
+            .join("src")
+            .join("main")
+            .join("res");
+        let output_dir_path = temp_dir.path().join("output");
+
+        let app_default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(app_res_path.clone());
+        let app_fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(app_res_path.clone(), "fr");
+        let lib_default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(lib_res_path.clone());
+        let lib_fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(lib_res_path.clone(), "fr");
+
+        // Library doesn't have any translations yet & app is missing one
+        test_utilities::file::write_content(
+            app_default_strings.path,
+            r##"<resources><string name="s1">one</string><string name="s2">two</string></resources>"##,
+        );
+        test_utilities::file::write_content(
+            app_fr_strings.path,
+            r##"<resources><string name="s1">un</string></resources>"##,
+        );
+        test_utilities::file::write_content(
+            lib_default_strings.path,
+            r##"<resources><string name="s1">one</string></resources>"##,
+        );
+        test_utilities::file::write_content(lib_fr_strings.path, "<resources></resources>");
+
+        let mut locale_id_to_name_map = HashMap::new();
+        locale_id_to_name_map.insert(String::from("fr"), String::from("french"));
+
+        let file_paths = super::localize_project(
+            project_path.to_str().unwrap(),
+            output_dir_path.to_str().unwrap(),
+            OutputOptions::default(),
+            None,
+            None,
+            locale_id_to_name_map,
+        )
+        .unwrap();
+
+        assert_eq!(file_paths.len(), 1);
+        assert_eq!(
+            test_utilities::file::read_content(Path::new(&file_paths[0])),
+            "module,string_name,default_locale,french\napp/src/main/res,s2,two,\nlib/src/main/res,s1,one,\n"
+        );
+    }
+
+    #[test]
+    fn localize_with_overlays_writes_out_strings_with_their_source_sets() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let main_res_path = temp_dir.path().join("main").join("res");
+        let free_res_path = temp_dir.path().join("free").join("res");
+        let output_dir_path = temp_dir.path().join("output");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(main_res_path.clone());
+        let free_default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(free_res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(main_res_path.clone(), "fr");
+
+        // Translation of `s2` is for the value overridden by `free`
+        test_utilities::file::write_content(
+            default_strings.path,
+            r##"<resources><string name="s1">one</string><string name="s2">two</string><string name="s3">three</string></resources>"##,
+        );
+        test_utilities::file::write_content(
+            free_default_strings.path,
+            r##"<resources><string name="s2">free two</string></resources>"##,
+        );
+        test_utilities::file::write_content(
+            fr_strings.path,
+            r##"<resources><string name="s1">un</string><string name="s2">deux</string></resources>"##,
+        );
+
+        let file_paths = super::localize_with_overlays(
+            main_res_path.to_str().unwrap(),
+            &[free_res_path.to_str().unwrap()],
+            output_dir_path.to_str().unwrap(),
+            OutputOptions::default(),
+            None,
+            None,
+            HashMap::new(),
+        )
+        .unwrap();
+
+        assert_eq!(file_paths.len(), 1);
+        assert_eq!(
+            test_utilities::file::read_content(Path::new(&file_paths[0])),
+            "module,string_name,default_locale,fr\nfree,s2,free two,\nmain,s3,three,\n"
+        );
+    }
+
+    #[test]
+    fn localize_with_overlays_overwrites_or_merges_into_existing_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let res_path = temp_dir.path().join("res");
+        let output_dir_path = temp_dir.path().join("output");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+        test_utilities::file::write_content(
+            default_strings.path,
+            r##"<resources><string name="s1">one</string><string name="s2">two</string></resources>"##,
+        );
+        test_utilities::file::write_content(fr_strings.path, "<resources></resources>");
+
+        let localize = |output_policy| {
+            super::localize_with_overlays(
+                res_path.to_str().unwrap(),
+                &[],
+                output_dir_path.to_str().unwrap(),
+                OutputOptions::default().with_output_policy(output_policy),
+                None,
+                None,
+                HashMap::new(),
+            )
+        };
+
+        // Partially translated file from an earlier run
+        let file_paths = localize(OutputPolicy::Fail).unwrap();
+        assert_eq!(
+            file_paths,
+            vec![String::from(
+                output_dir_path.join("to_localize_fr.csv").to_str().unwrap()
+            )]
+        );
+        test_utilities::file::write_content(
+            &file_paths[0],
+            "string_name,default_locale,fr\ns1,one,un\ns2,old two,deux\n",
+        );
+
+        let error = localize(OutputPolicy::Fail).unwrap_err();
+        assert!(error.to_string().ends_with("Output file already exists!"));
+
+        localize(OutputPolicy::Merge).unwrap();
+        assert_eq!(
+            test_utilities::file::read_content(&file_paths[0]),
+            "string_name,default_locale,fr\ns1,one,un\ns2,two,\n"
+        );
+
+        localize(OutputPolicy::Overwrite).unwrap();
+        assert_eq!(
+            test_utilities::file::read_content(&file_paths[0]),
+            "string_name,default_locale,fr\ns1,one,\ns2,two,\n"
+        );
+    }
+
+    #[test]
+    fn localize_with_overlays_writes_out_strings_changed_since_they_were_localized() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let res_path = temp_dir.path().join("res");
+        let output_dir_path = temp_dir.path().join("output");
+        let state_file_path = temp_dir.path().join("state.json");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+        test_utilities::file::write_content(
+            default_strings.path.clone(),
+            r##"<resources><string name="s1">one</string><string name="s2">two</string><string name="s3">three</string></resources>"##,
+        );
+        test_utilities::file::write_content(fr_strings.path, "<resources></resources>");
+
+        let localize = || {
+            super::localize_with_overlays(
+                res_path.to_str().unwrap(),
+                &[],
+                output_dir_path.to_str().unwrap(),
+                OutputOptions::default().with_output_policy(OutputPolicy::Overwrite),
+                Some(state_file_path.to_str().unwrap()),
+                None,
+                HashMap::new(),
+            )
+            .unwrap()
+        };
+
+        let file_paths = localize();
+        assert_eq!(
+            test_utilities::file::read_content(&file_paths[0]),
+            "string_name,default_locale,fr\ns1,one,\ns2,two,\ns3,three,\n"
+        );
+
+        // Only some of the strings are localized
+        test_utilities::file::write_content(
+            &file_paths[0],
+            "string_name,default_locale,fr\ns1,one,un\ns2,two,deux\ns3,three,\n",
+        );
+        crate::localized::localized_with_overlays(
+            res_path.to_str().unwrap(),
+            &[],
+            &file_paths[0],
+            CsvDialect::default(),
+            Some(state_file_path.to_str().unwrap()),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        test_utilities::file::write_content(
+            default_strings.path,
+            r##"<resources><string name="s1">one!</string><string name="s2">two</string><string name="s3">three</string></resources>"##,
+        );
+
+        localize();
+        assert_eq!(
+            test_utilities::file::read_content(&file_paths[0]),
+            "string_name,default_locale,status,fr,fr (old)\ns1,one!,changed,,un\ns3,three,new,,\n"
+        );
+    }
+
+    #[test]
+    fn localize_with_overlays_writes_out_plural_quantities_changed_since_they_were_localized() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let res_path = temp_dir.path().join("res");
+        let output_dir_path = temp_dir.path().join("output");
+        let state_file_path = temp_dir.path().join("state.json");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+        test_utilities::file::write_content(
+            default_strings.path.clone(),
+            r##"<resources><plurals name="p1"><item quantity="one">file</item><item quantity="other">files</item></plurals></resources>"##,
+        );
+        test_utilities::file::write_content(fr_strings.path, "<resources></resources>");
+
+        let localize = || {
+            super::localize_with_overlays(
+                res_path.to_str().unwrap(),
+                &[],
+                output_dir_path.to_str().unwrap(),
+                OutputOptions::default().with_output_policy(OutputPolicy::Overwrite),
+                Some(state_file_path.to_str().unwrap()),
+                None,
+                HashMap::new(),
+            )
+            .unwrap()
+        };
+
+        let file_paths = localize();
+        test_utilities::file::write_content(
+            &file_paths[0],
+            "string_name,default_locale,fr\np1[one],file,fichier\np1[many],files,de fichiers\np1[other],files,fichiers\n",
+        );
+        crate::localized::localized_with_overlays(
+            res_path.to_str().unwrap(),
+            &[],
+            &file_paths[0],
+            CsvDialect::default(),
+            Some(state_file_path.to_str().unwrap()),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        // `many` falls back to the value of `other` too
+        test_utilities::file::write_content(
+            default_strings.path,
+            r##"<resources><plurals name="p1"><item quantity="one">file</item><item quantity="other">documents</item></plurals></resources>"##,
+        );
+
+        localize();
+        assert_eq!(
+            test_utilities::file::read_content(&file_paths[0]),
+            "string_name,default_locale,status,fr,fr (old)\np1[many],documents,changed,,de fichiers\np1[other],documents,changed,,fichiers\n"
+        );
+    }
+
+    #[test]
+    fn localize_with_overlays_writes_out_string_arrays_changed_since_they_were_localized() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let res_path = temp_dir.path().join("res");
+        let output_dir_path = temp_dir.path().join("output");
+        let state_file_path = temp_dir.path().join("state.json");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+        test_utilities::file::write_content(
+            default_strings.path.clone(),
+            r##"<resources><string-array name="a1"><item>one</item><item>two</item></string-array><string-array name="a2"><item>three</item></string-array></resources>"##,
+        );
+        test_utilities::file::write_content(fr_strings.path, "<resources></resources>");
+
+        let localize = || {
+            super::localize_with_overlays(
+                res_path.to_str().unwrap(),
+                &[],
+                output_dir_path.to_str().unwrap(),
+                OutputOptions::default().with_output_policy(OutputPolicy::Overwrite),
+                Some(state_file_path.to_str().unwrap()),
+                None,
+                HashMap::new(),
+            )
+            .unwrap()
+        };
+
+        let file_paths = localize();
+        test_utilities::file::write_content(
+            &file_paths[0],
+            "string_name,default_locale,fr\na1[0],one,un\na1[1],two,deux\na2[0],three,trois\n",
+        );
+        crate::localized::localized_with_overlays(
+            res_path.to_str().unwrap(),
+            &[],
+            &file_paths[0],
+            CsvDialect::default(),
+            Some(state_file_path.to_str().unwrap()),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        // Arrays are only ever localized as a whole
+        test_utilities::file::write_content(
+            default_strings.path,
+            r##"<resources><string-array name="a1"><item>one</item><item>two!</item></string-array><string-array name="a2"><item>three</item></string-array></resources>"##,
+        );
+
+        localize();
+        assert_eq!(
+            test_utilities::file::read_content(&file_paths[0]),
+            "string_name,default_locale,status,fr,fr (old)\na1[0],one,changed,,un\na1[1],two!,changed,,deux\n"
+        );
+    }
+
+    #[test]
+    fn localize_with_overlays_writes_out_suggestions_from_translation_memory() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let res_path = temp_dir.path().join("res");
+        let output_dir_path = temp_dir.path().join("output");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+        test_utilities::file::write_content(
+            default_strings.path,
+            r##"<resources><string name="cancel_button">Cancel</string><string name="save">Save the note</string><string name="s1">Cancel</string><string name="s2">Save the notes</string><string name="s3">Delete</string><plurals name="p1"><item quantity="one">Cancel</item></plurals></resources>"##,
+        );
+        test_utilities::file::write_content(
+            fr_strings.path,
+            r##"<resources><string name="cancel_button">Annuler</string><string name="save">Enregistrer la note</string></resources>"##,
+        );
+
+        let file_paths = super::localize_with_overlays(
+            res_path.to_str().unwrap(),
+            &[],
+            output_dir_path.to_str().unwrap(),
+            OutputOptions::default().with_suggestions(true),
+            None,
+            None,
+            HashMap::new(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            test_utilities::file::read_content(&file_paths[0]),
+            "string_name,default_locale,fr,fr (suggestion)\ns1,Cancel,,Annuler\ns2,Save the notes,,Enregistrer la note\ns3,Delete,,\np1[one],Cancel,,Annuler\n"
+        );
+    }
+
+    /// Shouts out every value except for the ones of plurals
+    struct ShoutingTranslationProvider;
+
+    impl TranslationProvider for ShoutingTranslationProvider {
+        fn translate(
+            &self,
+            strings_list: &[LocalizableStrings],
+        ) -> Result<Vec<LocalizedStrings>, Error> {
+            Ok(strings_list
+                .iter()
+                .map(|localizable_strings| {
+                    LocalizedStrings::new(
+                        String::from(localizable_strings.to_locale()),
+                        localizable_strings
+                            .default_locale_strings()
+                            .iter()
+                            .filter(|s| !s.name().contains('['))
+                            .map(|s| {
+                                LocalizedString::new(
+                                    String::from(s.name()),
+                                    String::from(s.value()),
+                                    format!(
+                                        "{} ({})",
+                                        s.value().to_uppercase(),
+                                        localizable_strings.to_locale()
+                                    ),
+                                )
+                            })
+                            .collect(),
+                    )
+                })
+                .collect())
+        }
+    }
+
+    #[test]
+    fn localize_with_overlays_writes_out_drafts_from_translation_provider() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let res_path = temp_dir.path().join("res");
+        let output_dir_path = temp_dir.path().join("output");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+        test_utilities::file::write_content(fr_strings.path, "<resources></resources>");
+        test_utilities::file::write_content(
+            default_strings.path,
+            r##"<resources><string name="s1">Cancel</string><plurals name="p1"><item quantity="one">Save</item></plurals></resources>"##,
+        );
+
+        let file_paths = super::localize_with_overlays(
+            res_path.to_str().unwrap(),
+            &[],
+            output_dir_path.to_str().unwrap(),
+            OutputOptions::default(),
+            None,
+            Some(&ShoutingTranslationProvider),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            test_utilities::file::read_content(&file_paths[0]),
+            "string_name,default_locale,fr,fr (draft)\ns1,Cancel,,CANCEL (fr)\np1[one],Save,,\n"
+        );
+    }
+
+    #[test]
+    fn localize_with_overlays_errors_for_drafts_into_non_csv_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let res_path = temp_dir.path().join("res");
+        let output_dir_path = temp_dir.path().join("output");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+        test_utilities::file::write_content(fr_strings.path, "<resources></resources>");
+        test_utilities::file::write_content(
+            default_strings.path,
+            r##"<resources><string name="s1">Cancel</string></resources>"##,
+        );
+
+        let error = super::localize_with_overlays(
+            res_path.to_str().unwrap(),
+            &[],
+            output_dir_path.to_str().unwrap(),
+            OutputOptions::default().with_format(Format::Json),
+            None,
+            Some(&ShoutingTranslationProvider),
+            HashMap::new(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "{}: Only CSV files can have drafts written into",
+                output_dir_path.to_str().unwrap()
+            )
+        );
+        assert!(!output_dir_path.join("to_localize.json").exists());
+    }
+
+    #[test]
+    fn stats_with_overlays_counts_strings_to_localize() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let res_path = temp_dir.path().join("res");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+        test_utilities::file::write_content(
+            default_strings.path,
+            r##"<resources><string name="s1">Cancel</string><string name="s2">Save the note</string><plurals name="p1"><item quantity="one">%d note</item></plurals></resources>"##,
+        );
+        test_utilities::file::write_content(
+            fr_strings.path,
+            r##"<resources><string name="s1">Annuler</string></resources>"##,
+        );
+
+        let stats_list = super::stats_with_overlays(
+            res_path.to_str().unwrap(),
+            &[],
+            OutputOptions::default(),
+            None,
+            HashMap::new(),
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            stats_list,
+            vec![LocaleStats {
+                locale: String::from("fr"),
+                strings: 2,
+                words: 4,
+                characters: 15,
+                repeated_segments: 0,
+                repeated_words: 0,
+            }],
+        );
+    }
+
+    #[test]
+    fn localize_with_overlays_fills_in_exact_matches_from_translation_memory() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let res_path = temp_dir.path().join("res");
+        let output_dir_path = temp_dir.path().join("output");
+        let state_file_path = temp_dir.path().join("state.json");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+        test_utilities::file::write_content(
+            default_strings.path,
+            r##"<resources><string name="cancel_button">Cancel</string><string name="s1">Cancel</string><string name="s2">Cancel it</string><plurals name="p1"><item quantity="one">Cancel</item></plurals></resources>"##,
+        );
+        test_utilities::file::write_content(
+            fr_strings.path.clone(),
+            "<resources>\n    <string name=\"cancel_button\">Annuler</string>\n</resources>\n",
+        );
+
+        let file_paths = super::localize_with_overlays(
+            res_path.to_str().unwrap(),
+            &[],
+            output_dir_path.to_str().unwrap(),
+            OutputOptions::default().with_fill_exact_matches(true),
+            Some(state_file_path.to_str().unwrap()),
+            None,
+            HashMap::new(),
+        )
+        .unwrap();
+
+        // Plurals are never filled in
+        assert_eq!(file_paths.len(), 2);
+        assert_eq!(
+            test_utilities::file::read_content(&file_paths[0]),
+            "string_name,default_locale,fr\ns2,Cancel it,\np1[one],Cancel,\n"
+        );
+
+        assert_eq!(file_paths[1], fr_strings.path);
+        assert_eq!(
+            test_utilities::file::read_content(&fr_strings.path),
+            "<resources>\n    <string name=\"cancel_button\">Annuler</string>\n    <string name=\"s1\">Annuler</string>\n</resources>\n"
+        );
+
+        // Filled in strings are tracked like the localized ones
+        assert!(test_utilities::file::read_content(&state_file_path).contains("\"s1\""));
+    }
+
+    #[test]
+    fn localize_with_overlays_errors_for_merging_into_non_csv_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let res_path = temp_dir.path().join("res");
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+        test_utilities::file::write_content(
+            default_strings.path,
+            r##"<resources><string name="s1">one</string></resources>"##,
+        );
+        test_utilities::file::write_content(fr_strings.path, "<resources></resources>");
+
+        let error = super::localize_with_overlays(
+            res_path.to_str().unwrap(),
+            &[],
+            temp_dir.path().join("output").to_str().unwrap(),
+            OutputOptions::default()
+                .with_format(Format::Xliff)
+                .with_output_policy(OutputPolicy::Merge),
+            None,
+            None,
+            HashMap::new(),
+        )
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .ends_with("Only CSV files can be merged into"));
+    }
+
+    #[test]
+    fn localize_writes_out_a_single_xliff_file_for_all_locales() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let res_path = temp_dir.path().join("res");
+        let output_dir_path = temp_dir.path().join("output");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+        let es_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "es");
+
+        test_utilities::file::write_content(
+            default_strings.path,
+            r##"<resources><string name="s1">one</string><string name="s2">two</string></resources>"##,
+        );
+        test_utilities::file::write_content(
+            fr_strings.path,
+            r##"<resources><string name="s1">un</string></resources>"##,
+        );
+        test_utilities::file::write_content(es_strings.path, "<resources></resources>");
+
+        let mut locale_id_to_name_map = HashMap::new();
+        locale_id_to_name_map.insert(String::from("fr"), String::from("fr"));
+        let file_paths = super::localize_with_overlays(
+            res_path.to_str().unwrap(),
+            &[],
+            output_dir_path.to_str().unwrap(),
+            OutputOptions::default().with_format(Format::Xliff),
+            None,
+            None,
+            locale_id_to_name_map,
+        )
+        .unwrap();
+
+        assert_eq!(
+            file_paths,
+            vec![String::from(
+                output_dir_path.join("to_localize.xlf").to_str().unwrap()
+            )]
+        );
+        assert_eq!(
+            test_utilities::file::read_content(Path::new(&file_paths[0])),
+            r##"<?xml version="1.0" encoding="UTF-8"?>
+<xliff xmlns="urn:oasis:names:tc:xliff:document:1.2" version="1.2">
+  <file original="strings.xml" datatype="plaintext" source-language="en" target-language="fr">
+    <body>
+      <trans-unit id="s2" resname="s2">
+        <source>two</source>
+        <target state="needs-translation" />
+      </trans-unit>
+    </body>
+  </file>
+</xliff>"##
+        );
+    }
+
+    #[test]
+    fn localize_writes_out_a_po_template_and_a_po_file_per_locale() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let res_path = temp_dir.path().join("res");
+        let output_dir_path = temp_dir.path().join("output");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        test_utilities::file::write_content(
+            default_strings.path,
+            r##"<resources>
+    <!-- Greeting on the home screen -->
+    <string name="s1">Say "hi"</string>
+    <string name="s2">two</string>
+</resources>"##,
+        );
+        test_utilities::file::write_content(
+            fr_strings.path,
+            r##"<resources><string name="s2">deux</string></resources>"##,
+        );
+
+        let file_paths = super::localize_with_overlays(
+            res_path.to_str().unwrap(),
+            &[],
+            output_dir_path.to_str().unwrap(),
+            OutputOptions::default().with_format(Format::Po),
+            None,
+            None,
+            HashMap::new(),
+        )
+        .unwrap();
+
+        let expected_entry = r##"#. Greeting on the home screen
+#: strings.xml
+msgctxt "s1"
+msgid "Say \"hi\""
+msgstr ""
+"##;
+
+        assert_eq!(
+            file_paths,
+            vec![
+                String::from(output_dir_path.join("to_localize.pot").to_str().unwrap()),
+                String::from(output_dir_path.join("to_localize_fr.po").to_str().unwrap()),
+            ]
+        );
+        assert_eq!(
+            test_utilities::file::read_content(Path::new(&file_paths[0])),
+            format!(
+                "msgid \"\"\nmsgstr \"\"\n\"Content-Type: text/plain; charset=UTF-8\\n\"\n\n{}",
+                expected_entry
+            )
+        );
+        assert_eq!(
+            test_utilities::file::read_content(Path::new(&file_paths[1])),
+            format!(
+                "msgid \"\"\nmsgstr \"\"\n\"Content-Type: text/plain; charset=UTF-8\\n\"\n\"Language: fr\\n\"\n\n{}",
+                expected_entry
+            )
+        );
+    }
+
+    #[test]
+    fn localize_writes_out_lproj_dirs_for_default_and_every_locale() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let res_path = temp_dir.path().join("res");
+        let output_dir_path = temp_dir.path().join("output");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        test_utilities::file::write_content(
+            default_strings.path,
+            r##"<resources>
+    <string name="s1">Don\'t %1$s</string>
+    <plurals name="p1">
+        <item quantity="other">%d files</item>
+    </plurals>
+</resources>"##,
+        );
+        test_utilities::file::write_content(fr_strings.path, "<resources></resources>");
+
+        let file_paths = super::localize_with_overlays(
+            res_path.to_str().unwrap(),
+            &[],
+            output_dir_path.to_str().unwrap(),
+            OutputOptions::default().with_format(Format::Ios),
+            None,
+            None,
+            HashMap::new(),
+        )
+        .unwrap();
+
+        let output_file_path = |lproj: &str, file_name: &str| {
+            String::from(
+                output_dir_path
+                    .join(lproj)
+                    .join(file_name)
+                    .to_str()
+                    .unwrap(),
+            )
+        };
+
+        assert_eq!(
+            file_paths,
+            vec![
+                output_file_path("en.lproj", "Localizable.strings"),
+                output_file_path("en.lproj", "Localizable.stringsdict"),
+                output_file_path("fr.lproj", "Localizable.strings"),
+                output_file_path("fr.lproj", "Localizable.stringsdict"),
+            ]
+        );
+        assert_eq!(
+            test_utilities::file::read_content(Path::new(&file_paths[0])),
+            "\"s1\" = \"Don't %1$@\";\n"
+        );
+        assert_eq!(
+            test_utilities::file::read_content(Path::new(&file_paths[2])),
+            "\"s1\" = \"\";\n"
+        );
+        assert!(
+            test_utilities::file::read_content(Path::new(&file_paths[1]))
+                .contains("<key>other</key>\n        <string>%d files</string>")
+        );
+    }
+
+    /// Returns the output of the method call to `write_out_strings_to_localize`
+    /// & the output dir path (built by the test)
+    fn test_write_out_strings_to_localize(
+        temp_dir: &TempDir,
+        french_values_file_content: &str,
         spanish_values_file_content: &str,
         unmapped_german_values_file_content: &str,
         mut default_strings: Vec<AndroidString>,
+        default_plurals: Vec<AndroidPlural>,
+        default_string_arrays: Vec<AndroidStringArray>,
     ) -> (Vec<String>, PathBuf) {
         // Build paths
         let mut res_path = temp_dir.path().to_path_buf();
@@ -329,10 +2271,20 @@ mod tests {
 
         // Perform action
         let result = super::write_out_strings_to_localize(
-            &res_path,
             output_dir_path.to_str().unwrap(),
-            locale_id_to_name_map,
-            &mut default_strings,
+            OutputOptions::default(),
+            super::find_missing_strings_per_locale(
+                &SourceSets::new(res_path.to_str().unwrap(), &[]),
+                &MergedStrings::default(),
+                locale_id_to_name_map,
+                &mut default_strings,
+                &default_plurals,
+                &default_string_arrays,
+                None,
+                OutputOptions::default(),
+            )
+            .unwrap()
+            .0,
         )
         .unwrap();
 
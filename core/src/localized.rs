@@ -1,20 +1,46 @@
-use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::fs;
 use std::fs::File;
-use std::hash::{Hash, Hasher};
-use std::ops::Add;
 use std::path::Path;
+use std::path::PathBuf;
 
+use android_localization_utilities::DevExpt;
+
+use crate::android_plural::AndroidPlural;
+use crate::android_plural::Quantity;
+use crate::android_resources::AndroidResources;
 use crate::android_string::AndroidString;
+use crate::android_string_array::AndroidStringArray;
 use crate::constants;
-use crate::error::{Error, ResultExt};
+use crate::csv_dialect::CsvDialect;
+use crate::error::{Error, InnerError, ResultExt};
+use crate::format::Format;
+use crate::localized_string::LocalizedString;
+use crate::localized_strings::LocalizedStrings;
 use crate::ops::dedup;
 use crate::ops::extract;
 use crate::ops::filter;
 use crate::ops::merge;
+use crate::qualifiers::Locale;
+use crate::reader::arb_reader;
 use crate::reader::csv_reader;
+use crate::reader::ios_reader;
+use crate::reader::json_reader;
+use crate::reader::po_reader;
+use crate::reader::properties_reader;
+use crate::reader::xliff_reader;
+use crate::reader::xlsx_reader;
+use crate::state::State;
+use crate::strings_document::StringsDocument;
 use crate::util::foreign_locale_ids_finder;
+use crate::util::res_dirs_finder;
+use crate::util::source_sets::MergedStrings;
+use crate::util::source_sets::ResourceKey;
+use crate::util::source_sets::SourceSets;
 use crate::util::xml_utilities;
+use crate::warnings;
+use crate::writer::document_writer;
 use crate::writer::xml_writer;
 
 /// Returns the list of output files updated by this call. These aren't guaranteed
@@ -25,10 +51,32 @@ pub fn localized<S: ::std::hash::BuildHasher>(
     localized_text_file_path: &str,
     locale_name_to_id_map: HashMap<String, String, S>,
 ) -> Result<Vec<String>, Error> {
-    let locale_name_to_id_map = foreign_locale_ids_finder::build_map_if_empty_or_return(
-        locale_name_to_id_map,
+    localized_with_overlays(
         res_dir_path,
-    )?;
+        &[],
+        localized_text_file_path,
+        CsvDialect::default(),
+        None,
+        locale_name_to_id_map,
+    )
+}
+
+/// Same as `localized` but for the merged view of the res dir & its overlays
+/// (look @ `SourceSets`). A translation goes into the source set its existing
+/// translation is in. If there is none, it goes into the source set the default
+/// value is from. CSV files are read in the passed in `csv_dialect`. With a
+/// state file (look @ `State`), the default values the translations were made
+/// against are recorded in it
+pub fn localized_with_overlays<S: ::std::hash::BuildHasher>(
+    res_dir_path: &str,
+    overlay_res_dir_paths: &[&str],
+    localized_text_file_path: &str,
+    csv_dialect: CsvDialect,
+    state_file_path: Option<&str>,
+    locale_name_to_id_map: HashMap<String, String, S>,
+) -> Result<Vec<String>, Error> {
+    let source_sets = SourceSets::new(res_dir_path, overlay_res_dir_paths);
+    let locale_name_to_id_map = source_sets.build_map_if_empty_or_return(locale_name_to_id_map)?;
 
     if locale_name_to_id_map.is_empty() {
         return Err(Error::new(
@@ -37,57 +85,341 @@ pub fn localized<S: ::std::hash::BuildHasher>(
         ));
     }
 
-    // Read default strings
-    let res_dir_path = Path::new(res_dir_path);
-    let mut localizable_default_strings = filter::find_localizable_strings(
-        xml_utilities::read_default_strings(res_dir_path)?.into_strings(),
-    );
+    // Read all new localized strings
+    let new_localized_foreign_strings_list = read_localized_text_file(
+        localized_text_file_path,
+        csv_dialect,
+        &locale_name_to_id_map,
+    )?;
 
     // For all languages, handle localized text
-    handle_localized(
-        res_dir_path,
-        localized_text_file_path,
-        locale_name_to_id_map,
-        &mut localizable_default_strings,
-    )
+    let mut state = read_state(state_file_path)?;
+    let updated_files_paths = handle_localized(
+        &source_sets,
+        new_localized_foreign_strings_list,
+        &locale_name_to_id_map,
+        state.as_mut(),
+        None,
+    )?;
+
+    write_state(state_file_path, state)?;
+    Ok(updated_files_paths)
 }
 
-fn handle_localized<S: ::std::hash::BuildHasher>(
-    res_dir_path: &Path,
+/// Same as `localized` but for a text file written out by `localize_project`.
+/// Every string is routed back to the res dir of its module (look @ `ResDir`).
+/// Look @ `localized_with_overlays` for the state file
+pub fn localized_project<S: ::std::hash::BuildHasher>(
+    project_dir_path: &str,
     localized_text_file_path: &str,
+    csv_dialect: CsvDialect,
+    state_file_path: Option<&str>,
     locale_name_to_id_map: HashMap<String, String, S>,
-    localizable_default_strings: &mut [AndroidString],
 ) -> Result<Vec<String>, Error> {
-    // Read all new localized strings
-    let new_localized_foreign_strings_list = csv_reader::read(
-        File::open(localized_text_file_path)
-            .with_context(String::from(localized_text_file_path))?,
-        locale_name_to_id_map
-            .keys()
-            .map(|s: &String| String::clone(s))
-            .collect(),
-    )
-    .with_context(localized_text_file_path)?;
+    let res_dirs = res_dirs_finder::find(project_dir_path)?;
+    let locale_name_to_id_map = if locale_name_to_id_map.is_empty() {
+        let mut map = HashMap::new();
+        for res_dir in &res_dirs {
+            map.extend(foreign_locale_ids_finder::build_map_if_empty_or_return(
+                HashMap::new(),
+                &res_dir.path().to_string_lossy(),
+            )?);
+        }
+
+        map
+    } else {
+        locale_name_to_id_map.into_iter().collect()
+    };
+
+    if locale_name_to_id_map.is_empty() {
+        return Err(Error::new(
+            project_dir_path,
+            "Project doesn't have any res dir with non-default values dir with strings file!",
+        ));
+    }
+
+    // Group all new localized strings by their modules
+    let mut grouped: BTreeMap<String, BTreeMap<String, Vec<LocalizedString>>> = BTreeMap::new();
+    for new_localized_foreign_strings in read_localized_text_file(
+        localized_text_file_path,
+        csv_dialect,
+        &locale_name_to_id_map,
+    )? {
+        let locale_name = String::from(new_localized_foreign_strings.locale());
+        for string in new_localized_foreign_strings.into_strings() {
+            let module = String::from(string.module().ok_or_else(|| {
+                Error::new(
+                    localized_text_file_path,
+                    "Localized text file doesn't have a module column",
+                )
+            })?);
+
+            grouped
+                .entry(module)
+                .or_default()
+                .entry(locale_name.clone())
+                .or_default()
+                .push(string);
+        }
+    }
+
+    let mut state = read_state(state_file_path)?;
+    let mut updated_files_paths = vec![];
+    for (module, strings_by_locale_name) in grouped {
+        let res_dir = res_dirs
+            .iter()
+            .find(|res_dir| res_dir.module() == module)
+            .ok_or_else(|| Error::new(module.clone(), "Module isn't a res dir of the project"))?;
+
+        // Res dirs are only localized into the locales they already have
+        let locale_ids = foreign_locale_ids_finder::find(&res_dir.path().to_string_lossy())?
+            .into_iter()
+            .map(|locale| locale.to_string())
+            .collect::<Vec<String>>();
+        let res_dir_locale_name_to_id_map: HashMap<String, String> = locale_name_to_id_map
+            .iter()
+            .filter(|(_, locale_id)| locale_ids.contains(locale_id))
+            .map(|(locale_name, locale_id)| (locale_name.clone(), locale_id.clone()))
+            .collect();
+
+        let new_localized_foreign_strings_list = strings_by_locale_name
+            .into_iter()
+            .filter(|(locale_name, _)| res_dir_locale_name_to_id_map.contains_key(locale_name))
+            .map(|(locale_name, strings)| LocalizedStrings::new(locale_name, strings))
+            .collect::<Vec<LocalizedStrings>>();
+
+        if !new_localized_foreign_strings_list.is_empty() {
+            updated_files_paths.extend(handle_localized(
+                &SourceSets::new(&res_dir.path().to_string_lossy(), &[]),
+                new_localized_foreign_strings_list,
+                &res_dir_locale_name_to_id_map,
+                state.as_mut(),
+                Some(&module),
+            )?);
+        }
+    }
+
+    write_state(state_file_path, state)?;
+    Ok(updated_files_paths)
+}
+
+fn read_state(state_file_path: Option<&str>) -> Result<Option<State>, Error> {
+    state_file_path
+        .map(|path| State::read(Path::new(path)).with_context(path))
+        .transpose()
+}
+
+fn write_state(state_file_path: Option<&str>, state: Option<State>) -> Result<(), Error> {
+    match (state_file_path, state) {
+        (Some(path), Some(state)) => state.write(Path::new(path)).with_context(path),
+        _ => Ok(()),
+    }
+}
+
+/// The format is figured out from the extension of the file (look @ `Format`)
+fn read_localized_text_file<S: ::std::hash::BuildHasher>(
+    localized_text_file_path: &str,
+    csv_dialect: CsvDialect,
+    locale_name_to_id_map: &HashMap<String, String, S>,
+) -> Result<Vec<LocalizedStrings>, Error> {
+    let path = Path::new(localized_text_file_path);
+    let open = || {
+        File::open(localized_text_file_path).with_context(String::from(localized_text_file_path))
+    };
+    let allow_only_locales = locale_name_to_id_map
+        .keys()
+        .map(|s: &String| String::clone(s))
+        .collect();
+
+    match Format::from_file_path(localized_text_file_path) {
+        Format::Csv => csv_reader::read(open()?, csv_dialect, allow_only_locales),
+        Format::Xliff => {
+            let localized_strings_list =
+                xliff_reader::read(open()?).with_context(localized_text_file_path)?;
+            return Ok(localized_strings_list
+                .into_iter()
+                .flat_map(|localized_strings| map_locale(localized_strings, locale_name_to_id_map))
+                .collect());
+        }
+        Format::Po => {
+            let localized_strings =
+                po_reader::read(open()?).with_context(localized_text_file_path)?;
+            let locale = String::from(localized_strings.locale());
+            let localized_strings_list = map_locale(localized_strings, locale_name_to_id_map);
+            if localized_strings_list.is_empty() {
+                return Err(Error::new(
+                    localized_text_file_path,
+                    format!("PO file is for {}, which isn't any of the locales", locale),
+                ));
+            }
+
+            return Ok(localized_strings_list);
+        }
+        Format::Xlsx => xlsx_reader::read(open()?, allow_only_locales),
+        Format::Json => json_reader::read(open()?, allow_only_locales),
+        Format::Ios => return Ok(map_locale(ios_reader::read(path)?, locale_name_to_id_map)),
+        Format::Properties => {
+            let localized_strings = properties_reader::read_bundle(path)?;
+            return Ok(map_locale(localized_strings, locale_name_to_id_map));
+        }
+        Format::Arb => {
+            let localized_strings = arb_reader::read_bundle(path)?;
+            return Ok(map_locale(localized_strings, locale_name_to_id_map));
+        }
+    }
+    .with_context(localized_text_file_path)
+}
+
+/// Files of formats that carry their locales (like a `.lproj` dir or the
+/// `target-language` of XLIFF files) can refer to them by their names, IDs or
+/// language tags. Locales that aren't mapped are skipped
+fn map_locale<S: ::std::hash::BuildHasher>(
+    localized_strings: LocalizedStrings,
+    locale_name_to_id_map: &HashMap<String, String, S>,
+) -> Vec<LocalizedStrings> {
+    match find_locale_name(localized_strings.locale(), locale_name_to_id_map) {
+        None => vec![],
+        Some(locale_name) => vec![LocalizedStrings::new(
+            locale_name,
+            localized_strings.into_strings(),
+        )],
+    }
+}
+
+/// Language tags are matched regardless of their case & the separator used
+/// (Eg., `pt-BR`, `pt_br`). Look @ `Locale::language_tag`
+fn find_locale_name<S: ::std::hash::BuildHasher>(
+    locale: &str,
+    locale_name_to_id_map: &HashMap<String, String, S>,
+) -> Option<String> {
+    if locale_name_to_id_map.contains_key(locale) {
+        return Some(String::from(locale));
+    }
+
+    let language_tag = locale.replace('_', "-").to_lowercase();
+    locale_name_to_id_map
+        .iter()
+        .find(|(_, id)| {
+            *id == locale
+                || id
+                    .parse::<Locale>()
+                    .is_ok_and(|id| id.language_tag().to_lowercase() == language_tag)
+        })
+        .map(|(name, _)| name.clone())
+}
+
+/// Strings, plural quantities & string array items that are localized are
+/// recorded in the state along with the passed in `module` (look @ `State`). `localize` also
+/// fills in strings through this (look @ `OutputOptions::with_fill_exact_matches`)
+pub(crate) fn handle_localized<S: ::std::hash::BuildHasher>(
+    source_sets: &SourceSets,
+    new_localized_foreign_strings_list: Vec<LocalizedStrings>,
+    locale_name_to_id_map: &HashMap<String, String, S>,
+    mut state: Option<&mut State>,
+    module: Option<&str>,
+) -> Result<Vec<String>, Error> {
+    // Read default strings & flatten plurals & string arrays the same way they
+    // were shipped off
+    let merged_default_strings = source_sets.read_default_strings()?;
+    let (default_strings, default_plurals, default_string_arrays) =
+        merged_default_strings.combine().into_parts();
+    let mut localizable_default_strings = filter::find_localizable_strings(default_strings);
+    let localizable_default_plurals = filter::find_localizable_plurals(default_plurals);
+    let localizable_default_string_arrays =
+        filter::find_localizable_string_arrays(default_string_arrays);
+    for string_array in &localizable_default_string_arrays {
+        localizable_default_strings.extend(string_array.flatten());
+    }
 
     let mut updated_files_paths = vec![];
     for new_localized_foreign_strings in new_localized_foreign_strings_list {
         let locale_id = locale_name_to_id_map
             .get(new_localized_foreign_strings.locale())
             .expect("Read locale doesn't have a mapping! Please let the dev know about this issue");
+        let locale: Locale = locale_id.parse().with_context(locale_id.clone())?;
 
-        let existing_foreign_strings =
-            xml_utilities::read_foreign_strings(res_dir_path, locale_id)?.into_strings();
-        let existing_foreign_strings_hash = compute_hash_of(&existing_foreign_strings);
+        // Plurals are shipped off with the quantities the locale needs on top
+        // of the ones the default plurals have (look @ `filter::find_missing_plurals`)
+        let mut localizable_default_strings = localizable_default_strings.clone();
+        for plural in &localizable_default_plurals {
+            let required_plural = plural.with_quantities(locale.plural_quantities());
+            localizable_default_strings.extend(
+                plural
+                    .clone()
+                    .with_missing_items_from(&required_plural)
+                    .flatten(),
+            );
+        }
+
+        let (merged_foreign_strings, documents) =
+            source_sets.read_foreign_strings_and_documents(&locale, &merged_default_strings)?;
+        let mut foreign_files = documents
+            .into_iter()
+            .map(|(path, document)| ForeignFile::new(path, document))
+            .collect::<Vec<ForeignFile>>();
+        let existing_foreign_resources = merged_foreign_strings.combine();
 
-        // Read already localized foreign strings for locale
+        // Read already localized foreign strings, plurals & string arrays for locale
+        let (existing_foreign_strings, existing_foreign_plurals, existing_foreign_string_arrays) =
+            existing_foreign_resources.clone().into_parts();
         let mut already_localized_foreign_strings =
             filter::find_localizable_strings(existing_foreign_strings);
+        let already_localized_foreign_plurals =
+            filter::find_localizable_plurals(existing_foreign_plurals);
+        let already_localized_foreign_string_arrays =
+            filter::find_localizable_string_arrays(existing_foreign_string_arrays);
 
-        // Extract android strings out of the newly localized strings
-        let mut new_localized_foreign_strings = extract::extract_android_strings_from_localized(
-            &mut new_localized_foreign_strings.into_strings(),
-            localizable_default_strings,
-        );
+        // Extract android strings, plurals & string arrays out of the newly localized strings
+        let (new_localized_foreign_strings, mismatched_localized_strings) =
+            extract::extract_android_strings_from_localized(
+                &mut new_localized_foreign_strings.into_strings(),
+                &mut localizable_default_strings,
+            );
+
+        for localized_string in mismatched_localized_strings {
+            warnings::warn(
+                locale_id,
+                format!(
+                    "Translation of {} is skipped as it was made for a different default value ({})",
+                    localized_string.name(),
+                    localized_string.default()
+                ),
+            );
+        }
+
+        let (new_localized_foreign_strings, new_localized_foreign_plurals) =
+            extract::extract_plurals_from_flattened(new_localized_foreign_strings);
+
+        let (mut new_localized_foreign_strings, new_localized_foreign_string_arrays) =
+            extract::extract_string_arrays_from_flattened(
+                new_localized_foreign_strings,
+                &localizable_default_string_arrays,
+            );
+
+        // Plurals & string arrays are recorded by their flattened names
+        if let Some(state) = state.as_deref_mut() {
+            let new_localized_flattened_strings = new_localized_foreign_strings
+                .iter()
+                .cloned()
+                .chain(
+                    new_localized_foreign_plurals
+                        .iter()
+                        .flat_map(AndroidPlural::flatten),
+                )
+                .chain(
+                    new_localized_foreign_string_arrays
+                        .iter()
+                        .flat_map(AndroidStringArray::flatten),
+                );
+            for string in new_localized_flattened_strings {
+                let default_string = localizable_default_strings
+                    .iter()
+                    .find(|s| s.name() == string.name());
+                if let Some(default_string) = default_string {
+                    state.record(locale_id, module, string.name(), default_string.value());
+                }
+            }
+        }
 
         // Merge already existing & newly localized strings
         let mut to_be_written_foreign_strings = merge::merge_and_group_strings(
@@ -98,17 +430,79 @@ fn handle_localized<S: ::std::hash::BuildHasher>(
         // There could be duplicates!
         dedup::dedup_grouped_strings(&mut to_be_written_foreign_strings);
 
-        let new_foreign_strings_hash = compute_hash_of(&to_be_written_foreign_strings);
+        // Android has nothing to fall back on for plurals without `other`. These
+        // are written out once it is localized too (`localize` keeps asking for it)
+        let to_be_written_foreign_plurals = merge::merge_plurals(
+            new_localized_foreign_plurals,
+            already_localized_foreign_plurals,
+        )
+        .into_iter()
+        .filter(|plural| {
+            let has_other = plural
+                .items()
+                .iter()
+                .any(|item| item.quantity() == Quantity::Other);
+            if !has_other && !existing_foreign_resources.plurals().contains(plural) {
+                warnings::warn(
+                    locale_id,
+                    format!(
+                        "Translation of {} is skipped as it doesn't have the other quantity",
+                        plural.name()
+                    ),
+                );
+            }
+
+            has_other
+        })
+        .collect();
+
+        let to_be_written_foreign_resources = AndroidResources::new(
+            to_be_written_foreign_strings,
+            to_be_written_foreign_plurals,
+            merge::merge_string_arrays(
+                new_localized_foreign_string_arrays,
+                already_localized_foreign_string_arrays,
+            ),
+        );
+
+        // Only the resources that have changed are rewritten. Everything else in the
+        // files (comments, other resources etc.) is left as is
+        for (file_path, resources) in group_by_target_file(
+            &TargetFileFinder {
+                source_sets,
+                locale: &locale,
+                merged_default_strings: &merged_default_strings,
+                merged_foreign_strings: &merged_foreign_strings,
+            },
+            to_be_written_foreign_resources,
+        ) {
+            let foreign_file = find_or_add_foreign_file(&mut foreign_files, file_path);
+
+            if upsert_changed_resources(
+                &mut foreign_file.document,
+                &existing_foreign_resources,
+                resources,
+            )
+            .with_context(foreign_file.path.to_string_lossy())?
+            {
+                foreign_file.is_updated = true;
+            }
+        }
+
+        // If a file's content isn't getting updated, needn't touch it
+        for foreign_file in foreign_files.into_iter().filter(|f| f.is_updated) {
+            let output_file_path = String::from(foreign_file.path.to_string_lossy());
 
-        // Write out foreign strings back to file
-        let (mut file, output_file_path) =
-            writable_empty_foreign_strings_file(res_dir_path, locale_id)?;
-        xml_writer::write(&mut file, to_be_written_foreign_strings)
-            .with_context(output_file_path.clone())?;
+            // Overlays may not have the values dir of the locale yet
+            if let Some(values_dir_path) = foreign_file.path.parent() {
+                fs::create_dir_all(values_dir_path).with_context(output_file_path.clone())?;
+            }
 
-        // If the file's content isn't getting updated, needn't include it in the
-        // updated files list
-        if new_foreign_strings_hash != existing_foreign_strings_hash {
+            // empties out the file if it has any content
+            let mut file =
+                File::create(&foreign_file.path).with_context(output_file_path.clone())?;
+            document_writer::write(&mut file, &foreign_file.document)
+                .with_context(output_file_path.clone())?;
             updated_files_paths.push(output_file_path);
         }
     }
@@ -116,31 +510,156 @@ fn handle_localized<S: ::std::hash::BuildHasher>(
     Ok(updated_files_paths)
 }
 
-/// Returns the created output file along with its path (if path computation
-/// is possible; if not, it passes out a fallback value)
-fn writable_empty_foreign_strings_file(
-    res_dir_path: &Path,
-    locale_id: &str,
-) -> Result<(File, String), Error> {
-    let values_dir_name = String::from(constants::fs::BASE_VALUES_DIR_NAME);
-    let values_dir_name = values_dir_name.add(&format!("-{}", locale_id));
-
-    let mut strings_file_path = res_dir_path.to_path_buf();
-    strings_file_path.push(values_dir_name);
-    strings_file_path.push(constants::fs::STRING_FILE_NAME);
-    let output_path_or_fb = String::from(strings_file_path.to_str().unwrap_or(locale_id));
-
-    // empties out the file if it has any content
-    Ok((
-        File::create(strings_file_path).with_context(output_path_or_fb.clone())?,
-        output_path_or_fb,
-    ))
+/// A strings file of the foreign locale & its content
+struct ForeignFile {
+    path: PathBuf,
+    document: StringsDocument,
+    is_updated: bool,
+}
+
+impl ForeignFile {
+    fn new(path: PathBuf, document: StringsDocument) -> ForeignFile {
+        ForeignFile {
+            path,
+            document,
+            is_updated: false,
+        }
+    }
+}
+
+/// If there is no file with the passed in path yet, an empty one is added
+fn find_or_add_foreign_file(
+    foreign_files: &mut Vec<ForeignFile>,
+    path: PathBuf,
+) -> &mut ForeignFile {
+    match foreign_files.iter().position(|f| f.path == path) {
+        Some(index) => &mut foreign_files[index],
+        None => {
+            foreign_files.push(ForeignFile::new(path, StringsDocument::empty()));
+            foreign_files.last_mut().expt("Just pushed a file!")
+        }
+    }
+}
+
+/// Strings, plurals & string arrays going into a file
+type ResourcesParts = (
+    Vec<AndroidString>,
+    Vec<AndroidPlural>,
+    Vec<AndroidStringArray>,
+);
+
+/// Resources are written into the file their older translations are in. New
+/// ones go into the file with the same name as the file the default resource
+/// is in (in the values dir of the source set the default resource is from).
+/// Every resource is tagged with the name of the file it is going into
+fn group_by_target_file(
+    target_file_finder: &TargetFileFinder,
+    resources: AndroidResources,
+) -> Vec<(PathBuf, AndroidResources)> {
+    let mut grouped: BTreeMap<PathBuf, ResourcesParts> = BTreeMap::new();
+    let (strings, plurals, string_arrays) = resources.into_parts();
+    for string in strings {
+        let key = ResourceKey::String(String::from(string.name()));
+        let (file_path, file_name) = target_file_finder.find(&key, string.source_file());
+        let parts = grouped.entry(file_path).or_default();
+        parts.0.push(string.with_source_file(Some(file_name)));
+    }
+
+    for plural in plurals {
+        let key = ResourceKey::Plural(String::from(plural.name()));
+        let (file_path, file_name) = target_file_finder.find(&key, plural.source_file());
+        let parts = grouped.entry(file_path).or_default();
+        parts.1.push(plural.with_source_file(Some(file_name)));
+    }
+
+    for string_array in string_arrays {
+        let key = ResourceKey::StringArray(String::from(string_array.name()));
+        let (file_path, file_name) = target_file_finder.find(&key, string_array.source_file());
+        let parts = grouped.entry(file_path).or_default();
+        parts.2.push(string_array.with_source_file(Some(file_name)));
+    }
+
+    grouped
+        .into_iter()
+        .map(|(file_path, (strings, plurals, string_arrays))| {
+            (
+                file_path,
+                AndroidResources::new(strings, plurals, string_arrays),
+            )
+        })
+        .collect()
+}
+
+struct TargetFileFinder<'a> {
+    source_sets: &'a SourceSets,
+    locale: &'a Locale,
+    merged_default_strings: &'a MergedStrings,
+    merged_foreign_strings: &'a MergedStrings,
+}
+
+impl<'a> TargetFileFinder<'a> {
+    /// Returns the path & the name of the file the resource should go into
+    fn find(&self, key: &ResourceKey, default_file_name: Option<&str>) -> (PathBuf, String) {
+        if let Some(existing_file_path) = self.merged_foreign_strings.file_path_of(key) {
+            let existing_file_path = PathBuf::from(existing_file_path);
+            if let Some(file_name) = existing_file_path.file_name() {
+                let file_name = String::from(file_name.to_string_lossy());
+                return (existing_file_path, file_name);
+            }
+        }
+
+        let file_name = String::from(default_file_name.unwrap_or(constants::fs::STRING_FILE_NAME));
+        let source_set = self
+            .source_sets
+            .get(self.merged_default_strings.index_of(key).unwrap_or(0));
+        (
+            xml_utilities::foreign_file_path(source_set.res_dir_path(), self.locale, &file_name),
+            file_name,
+        )
+    }
 }
 
-fn compute_hash_of(strings: &[AndroidString]) -> u64 {
-    let mut hasher = DefaultHasher::new();
-    strings.hash(&mut hasher);
-    hasher.finish()
+/// Returns whether the document has been updated
+fn upsert_changed_resources(
+    document: &mut StringsDocument,
+    existing_resources: &AndroidResources,
+    resources: AndroidResources,
+) -> Result<bool, InnerError> {
+    let indent = document.indent();
+    let line_ending = document.line_ending();
+    let mut is_document_updated = false;
+
+    let (strings, plurals, string_arrays) = resources.into_parts();
+    for string in strings {
+        if !existing_resources.strings().contains(&string) {
+            let markup = xml_writer::build_string_element(&string)?;
+            document.upsert(constants::elements::STRING, string.name(), markup);
+            is_document_updated = true;
+        }
+    }
+
+    for plural in plurals {
+        if !existing_resources.plurals().contains(&plural) {
+            let markup = xml_writer::build_plurals_element(&plural, &indent, line_ending)?;
+            document.upsert(constants::elements::PLURALS, plural.name(), markup);
+            is_document_updated = true;
+        }
+    }
+
+    for string_array in string_arrays {
+        if !existing_resources.string_arrays().contains(&string_array) {
+            let markup =
+                xml_writer::build_string_array_element(&string_array, &indent, line_ending)?;
+            document.upsert(
+                constants::elements::STRING_ARRAY,
+                string_array.name(),
+                markup,
+            );
+            is_document_updated = true;
+        }
+    }
+
+    Ok(is_document_updated)
 }
 
 #[cfg(test)]
@@ -148,13 +667,19 @@ mod tests {
     use std::collections::HashMap;
     use std::fs;
     use std::fs::File;
-    use std::io::Read;
     use std::io::Write;
+    use std::path::Path;
 
     use test_utilities;
 
+    use crate::android_plural::AndroidPlural;
+    use crate::android_plural::Quantity;
+    use crate::android_resources::AndroidResources;
     use crate::android_string::AndroidString;
+    use crate::android_string_array::AndroidStringArray;
+    use crate::csv_dialect::CsvDialect;
     use crate::util::xml_utilities;
+    use crate::warnings;
     use crate::writer::xml_writer;
 
     #[test]
@@ -204,7 +729,8 @@ mod tests {
             vec![
                 AndroidString::localizable("s1", "english value 1"),
                 AndroidString::localizable("s2", "english value 2"),
-            ],
+            ]
+            .into(),
         )
         .unwrap();
 
@@ -213,7 +739,8 @@ mod tests {
             vec![
                 AndroidString::localizable("s1", "french old value 1"),
                 AndroidString::localizable("s2", "french old value 2"),
-            ],
+            ]
+            .into(),
         )
         .unwrap();
 
@@ -222,7 +749,8 @@ mod tests {
             vec![
                 AndroidString::localizable("s1", "spanish old value 1"),
                 AndroidString::localizable("s2", "spanish old value 2"),
-            ],
+            ]
+            .into(),
         )
         .unwrap();
 
@@ -231,14 +759,14 @@ mod tests {
             AndroidString::localizable("s2", "german old value 2"),
         ];
 
-        xml_writer::write(&mut de_strings.file, german_android_strings.clone()).unwrap();
+        xml_writer::write(&mut de_strings.file, german_android_strings.clone().into()).unwrap();
 
         let chinese_android_strings = vec![
             AndroidString::localizable("s1", "chinese old value 1"),
             AndroidString::localizable("s2", "chinese old value 2"),
         ];
 
-        xml_writer::write(&mut zh_strings.file, chinese_android_strings.clone()).unwrap();
+        xml_writer::write(&mut zh_strings.file, chinese_android_strings.clone().into()).unwrap();
 
         fs::create_dir_all(localized_dir_path).unwrap();
         let mut localized_file = File::create(localized_file_path.clone()).unwrap();
@@ -271,9 +799,7 @@ s2, english value 2,,spanish new value 2,german new value 2,            ",
         );
 
         test_utilities::list::assert_strict_list_eq(
-            xml_utilities::read_foreign_strings(&res_path, "fr")
-                .unwrap()
-                .into_strings(),
+            read_foreign_resources(&res_path, "fr").into_strings(),
             vec![
                 AndroidString::localizable("s1", "french new value 1"),
                 AndroidString::localizable("s2", "french old value 2"),
@@ -281,9 +807,7 @@ s2, english value 2,,spanish new value 2,german new value 2,            ",
         );
 
         test_utilities::list::assert_strict_list_eq(
-            xml_utilities::read_foreign_strings(&res_path, "es")
-                .unwrap()
-                .into_strings(),
+            read_foreign_resources(&res_path, "es").into_strings(),
             vec![
                 AndroidString::localizable("s1", "spanish old value 1"),
                 AndroidString::localizable("s2", "spanish new value 2"),
@@ -292,42 +816,1350 @@ s2, english value 2,,spanish new value 2,german new value 2,            ",
 
         // German must not have changed since it wasn't included in the mapping
         test_utilities::list::assert_strict_list_eq(
-            xml_utilities::read_foreign_strings(&res_path, "de")
-                .unwrap()
-                .into_strings(),
+            read_foreign_resources(&res_path, "de").into_strings(),
             german_android_strings,
         );
 
         // Chinese must not have changed since the localized text only container blank string
         // & already present localized value
         test_utilities::list::assert_strict_list_eq(
-            xml_utilities::read_foreign_strings(&res_path, "zh")
-                .unwrap()
-                .into_strings(),
+            read_foreign_resources(&res_path, "zh").into_strings(),
             chinese_android_strings,
         );
     }
 
     #[test]
-    fn writable_empty_foreign_strings_file_creates_file() {
-        let res_path = tempfile::tempdir().unwrap();
+    fn updates_plurals() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
 
-        let mut fr_strings = test_utilities::res::setup_empty_strings_for_locale(&res_path, "fr");
-        fr_strings.file.write_all(b"example old content").unwrap();
+        let mut default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let mut fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
 
-        let (mut file_with_new_content, file_path) =
-            super::writable_empty_foreign_strings_file(res_path.path(), "fr").unwrap();
-        file_with_new_content
-            .write_all(b"example new content")
-            .unwrap();
+        let mut localized_file_path = temp_dir.path().to_path_buf();
+        localized_file_path.push("localized.csv");
 
-        let mut file_contents = String::new();
-        File::open(&fr_strings.path)
-            .unwrap()
-            .read_to_string(&mut file_contents)
-            .unwrap();
+        // Write out required contents into files
+        xml_writer::write(
+            &mut default_strings.file,
+            AndroidResources::new(
+                vec![AndroidString::localizable("s1", "english value 1")],
+                vec![
+                    AndroidPlural::localizable(
+                        "p1",
+                        vec![
+                            (Quantity::One, "english one"),
+                            (Quantity::Other, "english other"),
+                        ],
+                    ),
+                    AndroidPlural::localizable("p2", vec![(Quantity::Other, "english other")]),
+                ],
+                vec![],
+            ),
+        )
+        .unwrap();
+
+        xml_writer::write(
+            &mut fr_strings.file,
+            AndroidResources::new(
+                vec![],
+                vec![AndroidPlural::localizable(
+                    "p2",
+                    vec![(Quantity::Other, "french old other")],
+                )],
+                vec![],
+            ),
+        )
+        .unwrap();
+
+        test_utilities::file::write_content(
+            &localized_file_path,
+            "string_name, default_locale, fr
+s1, english value 1, french value 1
+p1[one], english one, french one
+p1[other], english other, french other",
+        );
+
+        // Perform action
+        super::localized(
+            res_path.to_str().unwrap(),
+            localized_file_path.to_str().unwrap(),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        // Assert appropriate output
+        assert_eq!(
+            read_foreign_resources(&res_path, "fr"),
+            AndroidResources::new(
+                vec![AndroidString::localizable("s1", "french value 1")],
+                vec![
+                    AndroidPlural::localizable("p2", vec![(Quantity::Other, "french old other")]),
+                    AndroidPlural::localizable(
+                        "p1",
+                        vec![
+                            (Quantity::One, "french one"),
+                            (Quantity::Other, "french other")
+                        ],
+                    ),
+                ],
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn merges_plural_quantities_and_skips_plurals_without_other() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
+
+        let mut default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let mut fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        let mut localized_file_path = temp_dir.path().to_path_buf();
+        localized_file_path.push("localized.csv");
+
+        // Write out required contents into files
+        xml_writer::write(
+            &mut default_strings.file,
+            AndroidResources::new(
+                vec![],
+                vec![
+                    AndroidPlural::localizable(
+                        "p1",
+                        vec![
+                            (Quantity::One, "english one"),
+                            (Quantity::Other, "english other"),
+                        ],
+                    ),
+                    AndroidPlural::localizable(
+                        "p2",
+                        vec![
+                            (Quantity::One, "english one"),
+                            (Quantity::Other, "english other"),
+                        ],
+                    ),
+                ],
+                vec![],
+            ),
+        )
+        .unwrap();
+
+        xml_writer::write(
+            &mut fr_strings.file,
+            AndroidResources::new(
+                vec![],
+                vec![AndroidPlural::localizable(
+                    "p1",
+                    vec![
+                        (Quantity::One, "french old one"),
+                        (Quantity::Other, "french old other"),
+                    ],
+                )],
+                vec![],
+            ),
+        )
+        .unwrap();
+
+        test_utilities::file::write_content(
+            &localized_file_path,
+            "string_name, default_locale, fr
+p1[many], english other, french many
+p2[one], english one, french one",
+        );
+
+        // Perform action
+        let (result, warnings) = warnings::collect(|| {
+            super::localized(
+                res_path.to_str().unwrap(),
+                localized_file_path.to_str().unwrap(),
+                HashMap::new(),
+            )
+        });
+        result.unwrap();
 
-        assert_eq!(file_contents, "example new content");
-        assert_eq!(file_path, fr_strings.path)
+        // Assert appropriate output
+        assert_eq!(
+            read_foreign_resources(&res_path, "fr"),
+            AndroidResources::new(
+                vec![],
+                vec![AndroidPlural::localizable(
+                    "p1",
+                    vec![
+                        (Quantity::One, "french old one"),
+                        (Quantity::Many, "french many"),
+                        (Quantity::Other, "french old other")
+                    ],
+                )],
+                vec![]
+            )
+        );
+        assert_eq!(
+            warnings
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>(),
+            vec!["fr: Translation of p2 is skipped as it doesn't have the other quantity"]
+        );
+    }
+
+    #[test]
+    fn updates_string_arrays_only_if_all_items_are_localized() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
+
+        let mut default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let mut fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        let mut localized_file_path = temp_dir.path().to_path_buf();
+        localized_file_path.push("localized.csv");
+
+        // Write out required contents into files
+        xml_writer::write(
+            &mut default_strings.file,
+            AndroidResources::new(
+                vec![],
+                vec![],
+                vec![
+                    AndroidStringArray::localizable("a1", vec!["english 1", "english 2"]),
+                    AndroidStringArray::localizable("a2", vec!["english 1", "english 2"]),
+                    AndroidStringArray::localizable("a3", vec!["english 1"]),
+                ],
+            ),
+        )
+        .unwrap();
+
+        xml_writer::write(
+            &mut fr_strings.file,
+            AndroidResources::new(
+                vec![],
+                vec![],
+                vec![AndroidStringArray::localizable("a3", vec!["french old 1"])],
+            ),
+        )
+        .unwrap();
+
+        test_utilities::file::write_content(
+            &localized_file_path,
+            "string_name, default_locale, fr
+a1[1], english 2, french 2
+a1[0], english 1, french 1
+a2[0], english 1, french 1",
+        );
+
+        // Perform action
+        super::localized(
+            res_path.to_str().unwrap(),
+            localized_file_path.to_str().unwrap(),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        // Assert appropriate output
+        assert_eq!(
+            read_foreign_resources(&res_path, "fr"),
+            AndroidResources::new(
+                vec![],
+                vec![],
+                vec![
+                    AndroidStringArray::localizable("a3", vec!["french old 1"]),
+                    AndroidStringArray::localizable("a1", vec!["french 1", "french 2"]),
+                ],
+            )
+        );
+    }
+
+    #[test]
+    fn leaves_everything_other_than_changed_resources_as_is() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
+
+        let mut default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        let mut localized_file_path = temp_dir.path().to_path_buf();
+        localized_file_path.push("localized.csv");
+
+        // Write out required contents into files
+        xml_writer::write(
+            &mut default_strings.file,
+            AndroidResources::new(
+                vec![
+                    AndroidString::localizable("s1", "english 1"),
+                    AndroidString::localizable("s2", "english 2"),
+                    AndroidString::localizable("s3", "english 3"),
+                ],
+                vec![AndroidPlural::localizable(
+                    "p1",
+                    vec![(Quantity::Other, "english other")],
+                )],
+                vec![],
+            ),
+        )
+        .unwrap();
+
+        test_utilities::file::write_content(
+            &fr_strings.path,
+            r##"<?xml version="1.0" encoding="utf-8"?>
+<!-- Header -->
+<resources xmlns:tools="http://schemas.android.com/tools">
+  <!-- Strings -->
+  <string name="s1" tools:ignore="Typos">french old 1</string>
+  <string name="s2">french 2</string>
+  <dimen name="d1">8dp</dimen>
+  <string name="s4" translatable="false">french 4</string>
+</resources>
+"##,
+        );
+
+        test_utilities::file::write_content(
+            &localized_file_path,
+            "string_name, default_locale, fr
+s1, english 1, french new 1
+s3, english 3, french 3
+p1[other], english other, french other",
+        );
+
+        // Perform action
+        super::localized(
+            res_path.to_str().unwrap(),
+            localized_file_path.to_str().unwrap(),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        // Assert appropriate output
+        assert_eq!(
+            test_utilities::file::read_content(&fr_strings.path),
+            r##"<?xml version="1.0" encoding="utf-8"?>
+<!-- Header -->
+<resources xmlns:tools="http://schemas.android.com/tools">
+  <!-- Strings -->
+  <string name="s1">french new 1</string>
+  <string name="s2">french 2</string>
+  <dimen name="d1">8dp</dimen>
+  <string name="s4" translatable="false">french 4</string>
+  <string name="s3">french 3</string>
+  <plurals name="p1">
+      <item quantity="other">french other</item>
+  </plurals>
+</resources>
+"##
+        );
+    }
+
+    #[test]
+    fn writes_inline_markup_verbatim() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        let mut localized_file_path = temp_dir.path().to_path_buf();
+        localized_file_path.push("localized.csv");
+
+        // Write out required contents into files
+        test_utilities::file::write_content(
+            &default_strings.path,
+            r##"<resources xmlns:xliff="urn:oasis:names:tc:xliff:document:1.2">
+    <string name="s1"><b>Hi</b> <xliff:g id="name">%1$s</xliff:g> &amp; co</string>
+</resources>"##,
+        );
+
+        test_utilities::file::write_content(
+            &fr_strings.path,
+            r##"<resources xmlns:xliff="urn:oasis:names:tc:xliff:document:1.2">
+</resources>"##,
+        );
+
+        test_utilities::file::write_content(
+            &localized_file_path,
+            r##"string_name,default_locale,fr
+s1,"<b>Hi</b> <xliff:g id=""name"">%1$s</xliff:g> &amp; co","<b>Salut</b> <xliff:g id=""name"">%1$s</xliff:g> &amp; cie""##,
+        );
+
+        // Perform action
+        super::localized(
+            res_path.to_str().unwrap(),
+            localized_file_path.to_str().unwrap(),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        // Assert appropriate output
+        assert_eq!(
+            test_utilities::file::read_content(&fr_strings.path),
+            r##"<resources xmlns:xliff="urn:oasis:names:tc:xliff:document:1.2">
+    <string name="s1"><b>Salut</b> <xliff:g id="name">%1$s</xliff:g> &amp; cie</string>
+</resources>"##
+        );
+    }
+
+    #[test]
+    fn declares_namespaces_missing_from_foreign_files() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        let mut localized_file_path = temp_dir.path().to_path_buf();
+        localized_file_path.push("localized.csv");
+
+        // Write out required contents into files
+        test_utilities::file::write_content(
+            &default_strings.path,
+            r##"<resources xmlns:xliff="urn:oasis:names:tc:xliff:document:1.2">
+    <string name="s1">Hi <xliff:g id="name">%1$s</xliff:g></string>
+    <string name="s2">Bye</string>
+</resources>"##,
+        );
+
+        test_utilities::file::write_content(
+            &fr_strings.path,
+            r##"<resources>
+    <string name="s2">Au revoir</string>
+</resources>"##,
+        );
+
+        test_utilities::file::write_content(
+            &localized_file_path,
+            r##"string_name,default_locale,fr
+s1,"Hi <xliff:g id=""name"">%1$s</xliff:g>","Salut <xliff:g id=""name"">%1$s</xliff:g>""##,
+        );
+
+        // Perform action
+        super::localized(
+            res_path.to_str().unwrap(),
+            localized_file_path.to_str().unwrap(),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        // Assert appropriate output
+        assert_eq!(
+            test_utilities::file::read_content(&fr_strings.path),
+            r##"<resources xmlns:xliff="urn:oasis:names:tc:xliff:document:1.2">
+    <string name="s2">Au revoir</string>
+    <string name="s1">Salut <xliff:g id="name">%1$s</xliff:g></string>
+</resources>"##
+        );
+
+        assert_eq!(
+            read_foreign_resources(&res_path, "fr").strings(),
+            &[
+                AndroidString::localizable("s2", "Au revoir"),
+                AndroidString::localizable("s1", "Salut <xliff:g id=\"name\">%1$s</xliff:g>"),
+            ]
+        );
+    }
+
+    #[test]
+    fn updates_strings_files_from_xliff() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let pt_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "pt-rBR");
+
+        let mut localized_file_path = temp_dir.path().to_path_buf();
+        localized_file_path.push("localized.xlf");
+
+        // Write out required contents into files
+        test_utilities::file::write_content(
+            &default_strings.path,
+            r##"<resources>
+    <string name="s1"><b>one</b> &amp; it\'s %1$s</string>
+    <string name="s2">two</string>
+</resources>"##,
+        );
+
+        test_utilities::file::write_content(&pt_strings.path, "<resources>\n</resources>");
+
+        // Only `s1` is done being translated. Translations of alternatives
+        // must be skipped
+        test_utilities::file::write_content(
+            &localized_file_path,
+            r##"<?xml version="1.0" encoding="UTF-8"?>
+<xliff xmlns="urn:oasis:names:tc:xliff:document:1.2" version="1.2">
+  <file original="strings.xml" datatype="plaintext" source-language="en" target-language="pt-BR">
+    <body>
+      <trans-unit id="s1" resname="s1">
+        <source><ph id="1">&lt;b></ph>one<ph id="2">&lt;/b></ph> &amp; it's <ph id="3">%1$s</ph></source>
+        <target state="final"><ph id="1">&lt;b></ph>um<ph id="2">&lt;/b></ph> &amp; é <ph id="3">%1$s</ph></target>
+        <alt-trans>
+          <source>one</source>
+          <target>um</target>
+        </alt-trans>
+      </trans-unit>
+      <trans-unit id="s2" resname="s2">
+        <source>two</source>
+        <target state="needs-translation">dois</target>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>"##,
+        );
+
+        // Perform action
+        let mut map = HashMap::new();
+        map.insert(String::from("portuguese"), String::from("pt-rBR"));
+        super::localized(
+            res_path.to_str().unwrap(),
+            localized_file_path.to_str().unwrap(),
+            map,
+        )
+        .unwrap();
+
+        // Assert appropriate output
+        assert_eq!(
+            test_utilities::file::read_content(&pt_strings.path),
+            r##"<resources>
+    <string name="s1"><b>um</b> &amp; é %1$s</string>
+</resources>"##
+        );
+    }
+
+    #[test]
+    fn updates_strings_files_from_po() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let pt_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "pt-rBR");
+
+        let mut localized_file_path = temp_dir.path().to_path_buf();
+        localized_file_path.push("to_localize_portuguese.po");
+
+        // Write out required contents into files
+        test_utilities::file::write_content(
+            &default_strings.path,
+            r##"<resources>
+    <string name="s1"><b>one</b></string>
+    <string name="s2">two</string>
+    <string name="s3">three</string>
+</resources>"##,
+        );
+
+        test_utilities::file::write_content(&pt_strings.path, "<resources>\n</resources>");
+
+        // `s2` is fuzzy & `s3` was translated from a stale default value. PO
+        // editors write out the language the gettext way
+        test_utilities::file::write_content(
+            &localized_file_path,
+            r##"msgid ""
+msgstr ""
+"Language: pt_BR\n"
+
+msgctxt "s1"
+msgid "<b>one</b>"
+msgstr "<b>um</b>"
+
+#, fuzzy
+msgctxt "s2"
+msgid "two"
+msgstr "dois"
+
+msgctxt "s3"
+msgid "old three"
+msgstr "três"
+"##,
+        );
+
+        // Perform action
+        let mut map = HashMap::new();
+        map.insert(String::from("portuguese"), String::from("pt-rBR"));
+        let (result, warnings) = warnings::collect(|| {
+            super::localized(
+                res_path.to_str().unwrap(),
+                localized_file_path.to_str().unwrap(),
+                map,
+            )
+        });
+        result.unwrap();
+
+        // Assert appropriate output
+        assert_eq!(
+            test_utilities::file::read_content(&pt_strings.path),
+            r##"<resources>
+    <string name="s1"><b>um</b></string>
+</resources>"##
+        );
+        assert_eq!(
+            warnings
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>(),
+            vec!["pt-rBR: Translation of s3 is skipped as it was made for a different default value (old three)"]
+        );
+    }
+
+    #[test]
+    fn errors_for_po_file_of_unknown_locale() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        let mut localized_file_path = temp_dir.path().to_path_buf();
+        localized_file_path.push("to_localize_german.po");
+
+        // Write out required contents into files
+        test_utilities::file::write_content(
+            &default_strings.path,
+            "<resources>\n    <string name=\"s1\">one</string>\n</resources>",
+        );
+
+        test_utilities::file::write_content(&fr_strings.path, "<resources>\n</resources>");
+        test_utilities::file::write_content(
+            &localized_file_path,
+            "msgid \"\"\nmsgstr \"Language: de\\n\"\n\nmsgctxt \"s1\"\nmsgid \"one\"\nmsgstr \"eins\"",
+        );
+
+        // Perform action
+        let error = super::localized(
+            res_path.to_str().unwrap(),
+            localized_file_path.to_str().unwrap(),
+            HashMap::new(),
+        )
+        .unwrap_err();
+
+        // Assert appropriate output
+        assert_eq!(
+            error.context(),
+            &String::from(localized_file_path.to_str().unwrap())
+        );
+        assert!(error
+            .to_string()
+            .ends_with("PO file is for de, which isn't any of the locales"));
+        assert_eq!(
+            test_utilities::file::read_content(&fr_strings.path),
+            "<resources>\n</resources>"
+        );
+    }
+
+    #[test]
+    fn updates_strings_files_from_json() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        let mut localized_file_path = temp_dir.path().to_path_buf();
+        localized_file_path.push("to_localize.json");
+
+        // Write out required contents into files
+        test_utilities::file::write_content(
+            &default_strings.path,
+            r##"<resources>
+    <string name="s1"><b>one</b></string>
+    <string name="s2">two</string>
+    <string name="s3">three</string>
+</resources>"##,
+        );
+
+        test_utilities::file::write_content(&fr_strings.path, "<resources>\n</resources>");
+
+        // `s2` isn't translated & `s3` was translated from a stale default value
+        test_utilities::file::write_content(
+            &localized_file_path,
+            r##"{
+  "fr": {
+    "s1": { "source": "<b>one</b>", "target": "<b>un</b>" },
+    "s2": { "source": "two", "target": "" },
+    "s3": { "source": "old three", "target": "trois" }
+  }
+}"##,
+        );
+
+        // Perform action
+        super::localized(
+            res_path.to_str().unwrap(),
+            localized_file_path.to_str().unwrap(),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        // Assert appropriate output
+        assert_eq!(
+            test_utilities::file::read_content(&fr_strings.path),
+            r##"<resources>
+    <string name="s1"><b>un</b></string>
+</resources>"##
+        );
+    }
+
+    #[test]
+    fn updates_strings_files_from_properties_and_arb() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+        let es_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "es");
+
+        // Write out required contents into files
+        test_utilities::file::write_content(
+            &default_strings.path,
+            r##"<resources>
+    <string name="s1">%1$s has %2$d messages</string>
+    <string name="s2">Don\'t</string>
+</resources>"##,
+        );
+
+        test_utilities::file::write_content(&fr_strings.path, "<resources>\n</resources>");
+        test_utilities::file::write_content(&es_strings.path, "<resources>\n</resources>");
+        test_utilities::file::write_content(
+            temp_dir.path().join("messages.properties"),
+            "s1=%1$s has %2$d messages\ns2=Don't",
+        );
+        test_utilities::file::write_content(
+            temp_dir.path().join("messages_fr.properties"),
+            "s1=%1$s a %2$d messages\ns2=Ne pas",
+        );
+        test_utilities::file::write_content(
+            temp_dir.path().join("app.arb"),
+            r##"{
+  "s1": "{name} has {count} messages",
+  "@s1": { "placeholders": { "name": {}, "count": { "type": "int" } } },
+  "s2": "Don't"
+}"##,
+        );
+        test_utilities::file::write_content(
+            temp_dir.path().join("app_es.arb"),
+            r##"{ "@@locale": "es", "s1": "{name} tiene {count} mensajes", "s2": "" }"##,
+        );
+
+        // Perform action
+        for file_name in &["messages_fr.properties", "app_es.arb"] {
+            super::localized(
+                res_path.to_str().unwrap(),
+                temp_dir.path().join(file_name).to_str().unwrap(),
+                HashMap::new(),
+            )
+            .unwrap();
+        }
+
+        // Assert appropriate output
+        assert_eq!(
+            test_utilities::file::read_content(&fr_strings.path),
+            r##"<resources>
+    <string name="s1">%1$s a %2$d messages</string>
+    <string name="s2">Ne pas</string>
+</resources>"##
+        );
+        assert_eq!(
+            test_utilities::file::read_content(&es_strings.path),
+            r##"<resources>
+    <string name="s1">%1$s tiene %2$d mensajes</string>
+</resources>"##
+        );
+    }
+
+    #[test]
+    fn updates_strings_files_from_properties_and_arb_with_defaults_matched_as_plain_text() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+        let es_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "es");
+
+        // Write out required contents into files
+        test_utilities::file::write_content(
+            &default_strings.path,
+            r##"<resources>
+    <string name="greeting">Hello %1$s, you\'re "here" &amp; 50%%</string>
+    <string name="farewell">Bye</string>
+</resources>"##,
+        );
+
+        test_utilities::file::write_content(&fr_strings.path, "<resources>\n</resources>");
+        test_utilities::file::write_content(&es_strings.path, "<resources>\n</resources>");
+        test_utilities::file::write_content(
+            temp_dir.path().join("messages.properties"),
+            "greeting=Hello %1$s, you're \"here\" & 50%%\nfarewell=Goodbye",
+        );
+        test_utilities::file::write_content(
+            temp_dir.path().join("messages_fr.properties"),
+            "greeting=Bonjour %1$s & 50%%\nfarewell=Au revoir",
+        );
+        test_utilities::file::write_content(
+            temp_dir.path().join("app.arb"),
+            r##"{ "greeting": "Hello {arg1}, you're \"here\" & 50%", "farewell": "Goodbye" }"##,
+        );
+        test_utilities::file::write_content(
+            temp_dir.path().join("app_es.arb"),
+            r##"{ "@@locale": "es", "greeting": "Hola {arg1} & 50%", "farewell": "Adiós" }"##,
+        );
+
+        // Perform action
+        for file_name in &["messages_fr.properties", "app_es.arb"] {
+            super::localized(
+                res_path.to_str().unwrap(),
+                temp_dir.path().join(file_name).to_str().unwrap(),
+                HashMap::new(),
+            )
+            .unwrap();
+        }
+
+        // Assert appropriate output. `farewell` was translated from an older default
+        assert_eq!(
+            test_utilities::file::read_content(&fr_strings.path),
+            r##"<resources>
+    <string name="greeting">Bonjour %1$s &amp; 50%%</string>
+</resources>"##
+        );
+        assert_eq!(
+            test_utilities::file::read_content(&es_strings.path),
+            r##"<resources>
+    <string name="greeting">Hola %1$s &amp; 50%%</string>
+</resources>"##
+        );
+    }
+
+    #[test]
+    fn updates_strings_files_from_lproj() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        let base_lproj_path = temp_dir.path().join("en.lproj");
+        let fr_lproj_path = temp_dir.path().join("fr.lproj");
+        fs::create_dir(&base_lproj_path).unwrap();
+        fs::create_dir(&fr_lproj_path).unwrap();
+
+        // Write out required contents into files
+        test_utilities::file::write_content(
+            &default_strings.path,
+            r##"<resources>
+    <string name="s1">Don\'t %1$s</string>
+    <string name="s2">two</string>
+    <plurals name="p1">
+        <item quantity="one">%d file</item>
+        <item quantity="other">%d files</item>
+    </plurals>
+</resources>"##,
+        );
+
+        test_utilities::file::write_content(&fr_strings.path, "<resources>\n</resources>");
+        test_utilities::file::write_content(
+            base_lproj_path.join("Localizable.strings"),
+            "\"s1\" = \"Don't %1$@\";\n\"s2\" = \"two\";",
+        );
+        test_utilities::file::write_content(
+            fr_lproj_path.join("Localizable.strings"),
+            "\"s1\" = \"N'%1$@ pas\";\n\"s2\" = \"\";",
+        );
+
+        let plurals = |one: &str, other: &str| {
+            format!(
+                r##"<plist version="1.0"><dict><key>p1</key><dict>
+<key>NSStringLocalizedFormatKey</key><string>%#@value@</string>
+<key>value</key><dict>
+<key>NSStringFormatSpecTypeKey</key><string>NSStringPluralRuleType</string>
+<key>one</key><string>{}</string>
+<key>other</key><string>{}</string>
+</dict></dict></dict></plist>"##,
+                one, other
+            )
+        };
+        test_utilities::file::write_content(
+            base_lproj_path.join("Localizable.stringsdict"),
+            plurals("%d file", "%d files"),
+        );
+        test_utilities::file::write_content(
+            fr_lproj_path.join("Localizable.stringsdict"),
+            plurals("%d fichier", "%d fichiers"),
+        );
+
+        // Perform action
+        super::localized(
+            res_path.to_str().unwrap(),
+            fr_lproj_path.join("Localizable.strings").to_str().unwrap(),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        // Assert appropriate output
+        assert_eq!(
+            test_utilities::file::read_content(&fr_strings.path),
+            r##"<resources>
+    <string name="s1">N\'%1$s pas</string>
+    <plurals name="p1">
+        <item quantity="one">%d fichier</item>
+        <item quantity="other">%d fichiers</item>
+    </plurals>
+</resources>"##
+        );
+    }
+
+    #[test]
+    fn updates_strings_files_from_lproj_with_defaults_matched_as_plain_text() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        let base_lproj_path = temp_dir.path().join("Base.lproj");
+        let fr_lproj_path = temp_dir.path().join("fr.lproj");
+        fs::create_dir(&base_lproj_path).unwrap();
+        fs::create_dir(&fr_lproj_path).unwrap();
+
+        // Write out required contents into files
+        test_utilities::file::write_content(
+            &default_strings.path,
+            r##"<resources>
+    <string name="s1">Hello %1$s, you\'re "here" &amp; 50%%</string>
+    <string name="s2">two</string>
+</resources>"##,
+        );
+
+        test_utilities::file::write_content(&fr_strings.path, "<resources>\n</resources>");
+        test_utilities::file::write_content(
+            base_lproj_path.join("Localizable.strings"),
+            "\"s1\" = \"Hello %1$@, you're \\\"here\\\" & 50%%\";\n\"s2\" = \"old two\";",
+        );
+        test_utilities::file::write_content(
+            fr_lproj_path.join("Localizable.strings"),
+            "\"s1\" = \"Bonjour %1$@ & 50%%\";\n\"s2\" = \"deux\";",
+        );
+
+        // Perform action
+        super::localized(
+            res_path.to_str().unwrap(),
+            fr_lproj_path.join("Localizable.strings").to_str().unwrap(),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        // Assert appropriate output. `s2` was translated from an older default
+        assert_eq!(
+            test_utilities::file::read_content(&fr_strings.path),
+            r##"<resources>
+    <string name="s1">Bonjour %1$s &amp; 50%%</string>
+</resources>"##
+        );
+    }
+
+    #[test]
+    fn updates_strings_files_from_xlsx() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        let mut localized_file_path = temp_dir.path().to_path_buf();
+        localized_file_path.push("localized.xlsx");
+
+        // Write out required contents into files
+        test_utilities::file::write_content(
+            &default_strings.path,
+            r##"<resources>
+    <string name="s1">=one</string>
+    <string name="s2">007</string>
+</resources>"##,
+        );
+
+        test_utilities::file::write_content(&fr_strings.path, "<resources>\n</resources>");
+
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        for (row, values) in vec![
+            vec!["string_name", "default_locale", "fr"],
+            vec!["s1", "=one", "=un"],
+            vec!["s2", "007", "007"],
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            for (column, value) in values.into_iter().enumerate() {
+                worksheet
+                    .write_string(row as u32, column as u16, value)
+                    .unwrap();
+            }
+        }
+
+        workbook.save(&localized_file_path).unwrap();
+
+        // Perform action
+        super::localized(
+            res_path.to_str().unwrap(),
+            localized_file_path.to_str().unwrap(),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        // Assert appropriate output
+        assert_eq!(
+            test_utilities::file::read_content(&fr_strings.path),
+            r##"<resources>
+    <string name="s1">=un</string>
+    <string name="s2">007</string>
+</resources>"##
+        );
+    }
+
+    #[test]
+    fn writes_into_matching_foreign_files() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut res_path = temp_dir.path().to_path_buf();
+        res_path.push("res");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let default_settings_strings =
+            test_utilities::res::setup_empty_resource_file_for_default_locale(
+                res_path.clone(),
+                "strings_settings.xml",
+            );
+        let default_do_not_translate_strings =
+            test_utilities::res::setup_empty_resource_file_for_default_locale(
+                res_path.clone(),
+                "donottranslate.xml",
+            );
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        let mut localized_file_path = temp_dir.path().to_path_buf();
+        localized_file_path.push("localized.csv");
+
+        // Write out required contents into files
+        test_utilities::file::write_content(
+            &default_strings.path,
+            r##"<resources>
+    <string name="s1">one</string>
+</resources>
+"##,
+        );
+
+        test_utilities::file::write_content(
+            &default_settings_strings.path,
+            r##"<resources>
+    <string name="s2">two</string>
+    <string name="s3">three</string>
+</resources>
+"##,
+        );
+
+        test_utilities::file::write_content(
+            &default_do_not_translate_strings.path,
+            r##"<resources>
+    <string name="s4">four</string>
+</resources>
+"##,
+        );
+
+        // `s2`'s older translation is in `strings.xml`
+        test_utilities::file::write_content(
+            &fr_strings.path,
+            r##"<resources>
+    <string name="s2">deux ancien</string>
+</resources>
+"##,
+        );
+
+        test_utilities::file::write_content(
+            &localized_file_path,
+            r##"string_name,default_locale,fr
+s1,one,un
+s2,two,deux
+s3,three,trois
+s4,four,quatre"##,
+        );
+
+        // Perform action
+        let mut updated_files_paths = super::localized(
+            res_path.to_str().unwrap(),
+            localized_file_path.to_str().unwrap(),
+            HashMap::new(),
+        )
+        .unwrap();
+        updated_files_paths.sort();
+
+        // Assert appropriate output
+        let fr_settings_strings_path = xml_utilities::foreign_file_path(
+            &res_path,
+            &"fr".parse().unwrap(),
+            "strings_settings.xml",
+        );
+        let fr_settings_strings_path = String::from(fr_settings_strings_path.to_str().unwrap());
+        test_utilities::list::assert_strict_list_eq(
+            updated_files_paths,
+            vec![fr_strings.path.clone(), fr_settings_strings_path.clone()],
+        );
+
+        assert_eq!(
+            test_utilities::file::read_content(&fr_strings.path),
+            r##"<resources>
+    <string name="s2">deux</string>
+    <string name="s1">un</string>
+</resources>
+"##
+        );
+
+        assert_eq!(
+            test_utilities::file::read_content(&fr_settings_strings_path),
+            r##"<?xml version="1.0" encoding="utf-8"?>
+<resources>
+    <string name="s3">trois</string>
+</resources>
+"##
+        );
+    }
+
+    #[test]
+    fn localized_project_routes_strings_to_their_modules() {
+        // Build paths
+        let temp_dir = tempfile::tempdir().unwrap();
+        let project_path = temp_dir.path();
+        let app_res_path = project_path
+            .join("app")
+            .join("src")
+            .join("main")
+            .join("res");
+        let lib_res_path = project_path
+            .join("lib")
+            .join("src")
+            .join("main")
+            .join("res");
+
+        let app_default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(app_res_path.clone());
+        let app_fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(app_res_path.clone(), "fr");
+        let lib_default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(lib_res_path.clone());
+        let lib_fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(lib_res_path.clone(), "fr");
+
+        let localized_file_path = project_path.join("localized.csv");
+
+        // Write out required contents into files
+        let default_content = r##"<resources>
+    <string name="s1">one</string>
+</resources>
+"##;
+        test_utilities::file::write_content(&app_default_strings.path, default_content);
+        test_utilities::file::write_content(&lib_default_strings.path, default_content);
+        test_utilities::file::write_content(&app_fr_strings.path, "<resources>\n</resources>\n");
+        test_utilities::file::write_content(&lib_fr_strings.path, "<resources>\n</resources>\n");
+        test_utilities::file::write_content(
+            &localized_file_path,
+            r##"module,string_name,default_locale,fr
+app/src/main/res,s1,one,un app
+lib/src/main/res,s1,one,un lib"##,
+        );
+
+        // Perform action
+        let mut updated_files_paths = super::localized_project(
+            project_path.to_str().unwrap(),
+            localized_file_path.to_str().unwrap(),
+            CsvDialect::default(),
+            None,
+            HashMap::new(),
+        )
+        .unwrap();
+        updated_files_paths.sort();
+
+        // Assert appropriate output
+        test_utilities::list::assert_strict_list_eq(
+            updated_files_paths,
+            vec![app_fr_strings.path.clone(), lib_fr_strings.path.clone()],
+        );
+
+        assert_eq!(
+            test_utilities::file::read_content(&app_fr_strings.path),
+            "<resources>\n    <string name=\"s1\">un app</string>\n</resources>\n"
+        );
+
+        assert_eq!(
+            test_utilities::file::read_content(&lib_fr_strings.path),
+            "<resources>\n    <string name=\"s1\">un lib</string>\n</resources>\n"
+        );
+    }
+
+    #[test]
+    fn localized_project_errors_for_unknown_module() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let project_path = temp_dir.path();
+        let res_path = project_path
+            .join("app")
+            .join("src")
+            .join("main")
+            .join("res");
+        test_utilities::res::setup_empty_strings_for_locale(res_path, "fr");
+
+        let localized_file_path = project_path.join("localized.csv");
+        test_utilities::file::write_content(
+            &localized_file_path,
+            "module,string_name,default_locale,fr\nlib/src/main/res,s1,one,un",
+        );
+
+        let error = super::localized_project(
+            project_path.to_str().unwrap(),
+            localized_file_path.to_str().unwrap(),
+            CsvDialect::default(),
+            None,
+            HashMap::new(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "lib/src/main/res: Module isn't a res dir of the project"
+        );
+    }
+
+    #[test]
+    fn localized_with_overlays_writes_into_source_set_of_default_strings() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let main_res_path = temp_dir.path().join("main").join("res");
+        let free_res_path = temp_dir.path().join("free").join("res");
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(main_res_path.clone());
+        let free_default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(free_res_path.clone());
+        let fr_strings =
+            test_utilities::res::setup_empty_strings_for_locale(main_res_path.clone(), "fr");
+        let localized_file_path = temp_dir.path().join("localized.csv");
+
+        test_utilities::file::write_content(
+            &default_strings.path,
+            r##"<resources><string name="s1">one</string><string name="s2">two</string></resources>"##,
+        );
+        test_utilities::file::write_content(
+            &free_default_strings.path,
+            r##"<resources><string name="s2">free two</string></resources>"##,
+        );
+        test_utilities::file::write_content(
+            &fr_strings.path,
+            "<resources>\n    <string name=\"s2\">deux</string>\n</resources>\n",
+        );
+        test_utilities::file::write_content(
+            &localized_file_path,
+            "module,string_name,default_locale,fr\nmain,s1,one,un\nfree,s2,free two,deux gratuit",
+        );
+
+        let mut updated_files_paths = super::localized_with_overlays(
+            main_res_path.to_str().unwrap(),
+            &[free_res_path.to_str().unwrap()],
+            localized_file_path.to_str().unwrap(),
+            CsvDialect::default(),
+            None,
+            HashMap::new(),
+        )
+        .unwrap();
+        updated_files_paths.sort();
+
+        let free_fr_strings_path = free_res_path.join("values-fr").join("strings.xml");
+        let free_fr_strings_path = String::from(free_fr_strings_path.to_str().unwrap());
+        test_utilities::list::assert_strict_list_eq(
+            updated_files_paths,
+            vec![free_fr_strings_path.clone(), fr_strings.path.clone()],
+        );
+
+        assert_eq!(
+            test_utilities::file::read_content(&fr_strings.path),
+            "<resources>\n    <string name=\"s2\">deux</string>\n    <string name=\"s1\">un</string>\n</resources>\n"
+        );
+
+        assert_eq!(
+            test_utilities::file::read_content(&free_fr_strings_path),
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<resources>\n    <string name=\"s2\">deux gratuit</string>\n</resources>\n"
+        );
+    }
+
+    /// Source files are dropped to keep the expectations short
+    fn read_foreign_resources(res_path: &Path, locale_id: &str) -> AndroidResources {
+        let (strings, plurals, string_arrays) = xml_utilities::combine_resources(
+            &xml_utilities::read_foreign_strings(res_path, &locale_id.parse().unwrap()).unwrap(),
+        )
+        .into_parts();
+
+        AndroidResources::new(
+            strings
+                .into_iter()
+                .map(|s| s.with_source_file(None))
+                .collect(),
+            plurals
+                .into_iter()
+                .map(|p| p.with_source_file(None))
+                .collect(),
+            string_arrays
+                .into_iter()
+                .map(|a| a.with_source_file(None))
+                .collect(),
+        )
     }
 }
@@ -3,6 +3,8 @@ pub struct LocalizedString {
     name: String,
     default: String,
     localized: String,
+    /// The res dir (of a project) this is meant for. Look @ `ResDir::module`
+    module: Option<String>,
 }
 
 impl LocalizedString {
@@ -11,6 +13,7 @@ impl LocalizedString {
             name,
             default,
             localized,
+            module: None,
         }
     }
 
@@ -25,6 +28,15 @@ impl LocalizedString {
     pub fn localized(&self) -> &str {
         &self.localized
     }
+
+    pub fn module(&self) -> Option<&str> {
+        self.module.as_deref()
+    }
+
+    pub fn with_module(mut self, module: Option<String>) -> LocalizedString {
+        self.module = module;
+        self
+    }
 }
 
 #[cfg(test)]
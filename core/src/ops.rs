@@ -1,5 +1,13 @@
+pub mod arb;
 pub mod dedup;
 pub mod extract;
 pub mod filter;
+pub mod formula;
+pub mod ios;
+pub mod keys;
+pub mod mask;
 pub mod merge;
+pub mod pseudo;
 pub mod sort;
+pub mod text;
+pub mod tokens;
@@ -0,0 +1,212 @@
+use regex::Captures;
+use regex::Regex;
+
+use android_localization_utilities::DevExpt;
+
+use crate::constants;
+use crate::ops::text;
+
+lazy_static::lazy_static! {
+    static ref ANDROID_SPECIFIER: Regex =
+        Regex::new(r"%%|%(?:(\d+)\$)?[-#+ 0,(]*\d*(?:\.\d+)?([a-zA-Z])").expt("Invalid regex!");
+    static ref ARB_PLACEHOLDER: Regex =
+        Regex::new(r"\{([A-Za-z_][A-Za-z0-9_]*)\}").expt("Invalid regex!");
+}
+
+/// A placeholder (`{arg1}`) of an ARB message along with its type
+#[derive(Clone, Debug, PartialEq)]
+pub struct Placeholder {
+    name: String,
+    placeholder_type: String,
+}
+
+impl Placeholder {
+    pub fn new(name: String, placeholder_type: String) -> Placeholder {
+        Placeholder {
+            name,
+            placeholder_type,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn placeholder_type(&self) -> &str {
+        &self.placeholder_type
+    }
+}
+
+/// Converts a value from a strings file into an ARB message. The value is turned
+/// into plain text (look @ `text::to_plain_text`) & every format specifier
+/// (`%1$s` or `%d`) into a placeholder named after its position (`{arg1}`).
+/// Placeholders are ordered by their positions
+pub fn to_arb_value(android_value: &str) -> (String, Vec<Placeholder>) {
+    let plain_text = text::to_plain_text(android_value);
+    let mut placeholders: Vec<(usize, Placeholder)> = vec![];
+    let mut next_position = 0;
+    let has_specifiers = ANDROID_SPECIFIER
+        .captures_iter(&plain_text)
+        .any(|captures| &captures[0] != "%%");
+
+    let value = ANDROID_SPECIFIER.replace_all(&plain_text, |captures: &Captures| {
+        if &captures[0] == "%%" {
+            // `%%` is only an escape in formatted strings
+            return String::from(if has_specifiers { "%" } else { "%%" });
+        }
+
+        let position = match captures.get(1).and_then(|m| m.as_str().parse().ok()) {
+            Some(position) => position,
+            None => {
+                next_position += 1;
+                next_position
+            }
+        };
+
+        let name = format!("{}{}", constants::arb::PLACEHOLDER_PREFIX, position);
+        if !placeholders.iter().any(|(p, _)| *p == position) {
+            placeholders.push((
+                position,
+                Placeholder::new(name.clone(), String::from(to_type(&captures[2]))),
+            ));
+        }
+
+        format!("{{{}}}", name)
+    });
+
+    placeholders.sort_by_key(|(position, _)| *position);
+    (
+        value.into_owned(),
+        placeholders.into_iter().map(|(_, p)| p).collect(),
+    )
+}
+
+/// Reverse of `to_arb_value`. Every placeholder (`{name}`) is turned into a
+/// format specifier with the position of the placeholder among `placeholders`
+/// (like Flutter does for the parameters of generated methods). Without any
+/// `placeholders`, positions are figured out from the order the placeholders
+/// first appear in. Anything else (like ICU plurals) is left as is
+pub fn to_android_value(arb_value: &str, placeholders: &[Placeholder]) -> String {
+    let mut seen_names: Vec<String> = vec![];
+    let mut value = String::with_capacity(arb_value.len());
+    let mut last_end = 0;
+    let mut has_specifiers = false;
+    let mut literals = vec![];
+    for captures in ARB_PLACEHOLDER.captures_iter(arb_value) {
+        let name = &captures[1];
+        let specifier = if placeholders.is_empty() {
+            if !seen_names.iter().any(|n| n == name) {
+                seen_names.push(String::from(name));
+            }
+
+            let position = seen_names
+                .iter()
+                .position(|n| n == name)
+                .expt("Missing name!");
+            Some(format!("%{}$s", position + 1))
+        } else {
+            placeholders
+                .iter()
+                .position(|p| p.name() == name)
+                .map(|position| {
+                    format!(
+                        "%{}${}",
+                        position + 1,
+                        to_conversion(placeholders[position].placeholder_type())
+                    )
+                })
+        };
+
+        if let Some(specifier) = specifier {
+            let whole = captures.get(0).expt("Missing match!");
+            literals.push((&arb_value[last_end..whole.start()], Some(specifier)));
+            last_end = whole.end();
+            has_specifiers = true;
+        }
+    }
+
+    literals.push((&arb_value[last_end..], None));
+    for (literal, specifier) in literals {
+        if has_specifiers {
+            value.push_str(&literal.replace('%', "%%"));
+        } else {
+            value.push_str(literal);
+        }
+
+        if let Some(specifier) = specifier {
+            value.push_str(&specifier);
+        }
+    }
+
+    text::to_android_value(&value)
+}
+
+fn to_type(conversion: &str) -> &'static str {
+    match conversion {
+        "d" | "x" | "X" | "o" => constants::arb::INT_TYPE,
+        "f" | "e" | "E" | "g" | "G" => constants::arb::DOUBLE_TYPE,
+        _ => constants::arb::STRING_TYPE,
+    }
+}
+
+fn to_conversion(placeholder_type: &str) -> &'static str {
+    match placeholder_type {
+        constants::arb::INT_TYPE => "d",
+        constants::arb::DOUBLE_TYPE | constants::arb::NUM_TYPE => "f",
+        _ => "s",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Placeholder;
+
+    #[test]
+    fn converts_to_arb_value() {
+        assert_eq!(
+            super::to_arb_value(r#"Don\'t %2$s %1$d%% &amp; %2$s"#),
+            (
+                String::from("Don't {arg2} {arg1}% & {arg2}"),
+                vec![placeholder("arg1", "int"), placeholder("arg2", "String")]
+            )
+        );
+        assert_eq!(
+            super::to_arb_value("%s of %.2f"),
+            (
+                String::from("{arg1} of {arg2}"),
+                vec![placeholder("arg1", "String"), placeholder("arg2", "double")]
+            )
+        );
+        assert_eq!(
+            super::to_arb_value("100%%"),
+            (String::from("100%%"), vec![])
+        );
+    }
+
+    #[test]
+    fn converts_to_android_value() {
+        assert_eq!(
+            super::to_android_value(
+                "N'{name} {count}% {count, plural, one{x}}",
+                &[placeholder("count", "int"), placeholder("name", "String")]
+            ),
+            r#"N\'%2$s %1$d%% {count, plural, one{x}}"#
+        );
+        assert_eq!(
+            super::to_android_value("{b} {a} {b} 100%", &[]),
+            "%1$s %2$s %1$s 100%%"
+        );
+        assert_eq!(super::to_android_value("100%", &[]), "100%");
+    }
+
+    #[test]
+    fn round_trips() {
+        let value = r#"Don\'t %2$s %1$d%% &amp; %2$s"#;
+        let (arb_value, placeholders) = super::to_arb_value(value);
+        assert_eq!(super::to_android_value(&arb_value, &placeholders), value);
+    }
+
+    fn placeholder(name: &str, placeholder_type: &str) -> Placeholder {
+        Placeholder::new(String::from(name), String::from(placeholder_type))
+    }
+}
@@ -1,43 +1,171 @@
+use crate::android_plural::AndroidPlural;
+use crate::android_plural::PluralItem;
 use crate::android_string::AndroidString;
+use crate::android_string_array::AndroidStringArray;
 use crate::localized_string::LocalizedString;
 use crate::ops::sort;
+use crate::ops::text;
 use crate::util::two_pointer_traversal;
 
 /// Localized strings will be converted into `AndroidString` only if both the name
 /// & the default value from `LocalizedString` match up with whatever is in the
-/// default string
+/// default string. Default values are compared as plain text (look @
+/// `text::to_plain_text`) since formats other than CSV can't carry escapes &
+/// entities as is. Localized strings whose default values don't match are
+/// returned too
 pub fn extract_android_strings_from_localized(
     localized_strings: &mut [LocalizedString],
     default_strings: &mut [AndroidString],
-) -> Vec<AndroidString> {
+) -> (Vec<AndroidString>, Vec<LocalizedString>) {
     // Sort both the incoming strings
     sort::sort_android_strings_by_name(default_strings);
     sort::sort_localized_strings_by_name(localized_strings);
 
     let mut result = Vec::with_capacity(localized_strings.len()); // Max number of expected strings
+    let mut mismatched = vec![];
     two_pointer_traversal::compare(
         localized_strings,
         default_strings,
         |localized_string, default_string| localized_string.name().cmp(default_string.name()),
         |localized_string, default_string| {
-            if localized_string.default() == default_string.value() {
-                result.push(AndroidString::new(
-                    String::from(localized_string.name()),
-                    String::from(localized_string.localized()),
-                    default_string.is_localizable(),
-                ));
+            if text::to_plain_text(localized_string.default())
+                != text::to_plain_text(default_string.value())
+            {
+                mismatched.push(localized_string.clone());
+            } else {
+                result.push(
+                    AndroidString::new(
+                        String::from(localized_string.name()),
+                        String::from(localized_string.localized()),
+                        default_string.is_localizable(),
+                    )
+                    .with_source_file(default_string.source_file().map(String::from)),
+                );
             }
         },
     );
 
-    result
+    (result, mismatched)
+}
+
+/// Name, items, localizability & source file of a plural being built
+type PluralParts = (String, Vec<PluralItem>, bool, Option<String>);
+
+/// Strings with flattened plural names (look @ `AndroidPlural::flattened_name`)
+/// are grouped back into plurals. The rest of the strings are returned as is
+pub fn extract_plurals_from_flattened(
+    android_strings: Vec<AndroidString>,
+) -> (Vec<AndroidString>, Vec<AndroidPlural>) {
+    let mut strings = Vec::with_capacity(android_strings.len());
+    let mut plurals_parts: Vec<PluralParts> = vec![];
+    for android_string in android_strings {
+        match AndroidPlural::unflatten_name(android_string.name()) {
+            None => strings.push(android_string),
+            Some((plural_name, quantity)) => {
+                let item = PluralItem::new(quantity, String::from(android_string.value()));
+                match plurals_parts
+                    .iter_mut()
+                    .find(|(name, _, _, _)| name == plural_name)
+                {
+                    Some((_, items, _, _)) => items.push(item),
+                    None => plurals_parts.push((
+                        String::from(plural_name),
+                        vec![item],
+                        android_string.is_localizable(),
+                        android_string.source_file().map(String::from),
+                    )),
+                }
+            }
+        }
+    }
+
+    let plurals = plurals_parts
+        .into_iter()
+        .map(|(name, mut items, is_localizable, source_file)| {
+            items.sort_by_key(PluralItem::quantity);
+            AndroidPlural::new(name, items, is_localizable).with_source_file(source_file)
+        })
+        .collect();
+
+    (strings, plurals)
+}
+
+/// Name, indexed items, localizability & source file of a string array being built
+type StringArrayParts = (String, Vec<(usize, String)>, bool, Option<String>);
+
+/// Strings with flattened string array names (look @ `AndroidStringArray::flattened_name`)
+/// are grouped back into string arrays. Since dropping an item would shift the
+/// indices of the ones after it, a string array is built only if all the items
+/// of the array with the same name from `default_string_arrays` are present.
+/// Flattened strings of incomplete arrays are dropped. The rest of the strings
+/// are returned as is
+pub fn extract_string_arrays_from_flattened(
+    android_strings: Vec<AndroidString>,
+    default_string_arrays: &[AndroidStringArray],
+) -> (Vec<AndroidString>, Vec<AndroidStringArray>) {
+    let mut strings = Vec::with_capacity(android_strings.len());
+    let mut string_arrays_parts: Vec<StringArrayParts> = vec![];
+    for android_string in android_strings {
+        match AndroidStringArray::unflatten_name(android_string.name()) {
+            None => strings.push(android_string),
+            Some((array_name, index)) => {
+                let item = (index, String::from(android_string.value()));
+                match string_arrays_parts
+                    .iter_mut()
+                    .find(|(name, _, _, _)| name == array_name)
+                {
+                    Some((_, items, _, _)) => items.push(item),
+                    None => string_arrays_parts.push((
+                        String::from(array_name),
+                        vec![item],
+                        android_string.is_localizable(),
+                        android_string.source_file().map(String::from),
+                    )),
+                }
+            }
+        }
+    }
+
+    let string_arrays = string_arrays_parts
+        .into_iter()
+        .filter_map(|(name, mut items, is_localizable, source_file)| {
+            let default_items_count = default_string_arrays
+                .iter()
+                .find(|a| a.name() == name)?
+                .items()
+                .len();
+
+            items.sort_by_key(|(index, _)| *index);
+            items.dedup_by_key(|(index, _)| *index);
+            let is_complete = items.len() == default_items_count
+                && items.iter().enumerate().all(|(i, (index, _))| i == *index);
+
+            if is_complete {
+                Some(
+                    AndroidStringArray::new(
+                        name,
+                        items.into_iter().map(|(_, item)| item).collect(),
+                        is_localizable,
+                    )
+                    .with_source_file(source_file),
+                )
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    (strings, string_arrays)
 }
 
 #[cfg(test)]
 mod tests {
     use test_utilities;
 
+    use crate::android_plural::AndroidPlural;
+    use crate::android_plural::Quantity;
     use crate::android_string::AndroidString;
+    use crate::android_string_array::AndroidStringArray;
     use crate::localized_string::LocalizedString;
 
     #[test]
@@ -55,7 +183,7 @@ mod tests {
             LocalizedString::build("string_2", "english 2 value", "french 2 value"),
         ];
 
-        let strings = super::extract_android_strings_from_localized(
+        let (strings, mismatched) = super::extract_android_strings_from_localized(
             &mut localized_strings,
             &mut default_strings,
         );
@@ -66,6 +194,99 @@ mod tests {
                 AndroidString::unlocalizable("string_2", "french 2 value"),
                 AndroidString::localizable("string_3", "french 3 value"),
             ],
+        );
+        test_utilities::list::assert_strict_list_eq(
+            mismatched,
+            vec![LocalizedString::build(
+                "string_4",
+                "english 4 value",
+                "french 4 value",
+            )],
         )
     }
+
+    #[test]
+    fn extracts_with_default_values_compared_as_plain_text() {
+        let mut default_strings = vec![AndroidString::localizable(
+            "string_1",
+            r#"Hello %1$s, you\'re "here" &amp; 50%%"#,
+        )];
+
+        let mut localized_strings = vec![LocalizedString::build(
+            "string_1",
+            r#"Hello %1$s, you\'re \"here\" &amp; 50%%"#,
+            "french value",
+        )];
+
+        let (strings, mismatched) = super::extract_android_strings_from_localized(
+            &mut localized_strings,
+            &mut default_strings,
+        );
+
+        test_utilities::list::assert_strict_list_eq(
+            strings,
+            vec![AndroidString::localizable("string_1", "french value")],
+        );
+        test_utilities::list::assert_list_is_empty(mismatched)
+    }
+
+    #[test]
+    fn extracts_plurals_from_flattened() {
+        let (strings, plurals) = super::extract_plurals_from_flattened(vec![
+            AndroidString::localizable("p1[other]", "other value"),
+            AndroidString::localizable("s1", "string value"),
+            AndroidString::unlocalizable("p2[few]", "few value"),
+            AndroidString::localizable("p1[one]", "one value"),
+        ]);
+
+        test_utilities::list::assert_strict_list_eq(
+            strings,
+            vec![AndroidString::localizable("s1", "string value")],
+        );
+
+        test_utilities::list::assert_strict_list_eq(
+            plurals,
+            vec![
+                AndroidPlural::localizable(
+                    "p1",
+                    vec![
+                        (Quantity::One, "one value"),
+                        (Quantity::Other, "other value"),
+                    ],
+                ),
+                AndroidPlural::unlocalizable("p2", vec![(Quantity::Few, "few value")]),
+            ],
+        );
+    }
+
+    #[test]
+    fn extracts_complete_string_arrays_from_flattened() {
+        let (strings, string_arrays) = super::extract_string_arrays_from_flattened(
+            vec![
+                AndroidString::localizable("a1[1]", "second"),
+                AndroidString::localizable("s1", "string value"),
+                AndroidString::localizable("a1[0]", "first"),
+                AndroidString::localizable("a2[1]", "second"),
+                AndroidString::unlocalizable("a3[0]", "first"),
+            ],
+            &[
+                AndroidStringArray::localizable("a1", vec!["1", "2"]),
+                AndroidStringArray::localizable("a2", vec!["1", "2"]),
+                AndroidStringArray::unlocalizable("a3", vec!["1"]),
+            ],
+        );
+
+        test_utilities::list::assert_strict_list_eq(
+            strings,
+            vec![AndroidString::localizable("s1", "string value")],
+        );
+
+        test_utilities::list::assert_strict_list_eq(
+            string_arrays,
+            vec![
+                AndroidStringArray::localizable("a1", vec!["first", "second"]),
+                AndroidStringArray::unlocalizable("a3", vec!["first"]),
+            ],
+        );
+    }
 }
@@ -1,12 +1,69 @@
 use std::cmp::Ordering;
+use std::collections::HashSet;
 
+use crate::android_plural::AndroidPlural;
+use crate::android_plural::Quantity;
 use crate::android_string::AndroidString;
+use crate::android_string_array::AndroidStringArray;
 use crate::ops::sort;
 
 pub fn find_localizable_strings(strings: Vec<AndroidString>) -> Vec<AndroidString> {
     strings.into_iter().filter(|s| s.is_localizable()).collect()
 }
 
+pub fn find_localizable_plurals(plurals: Vec<AndroidPlural>) -> Vec<AndroidPlural> {
+    plurals.into_iter().filter(|p| p.is_localizable()).collect()
+}
+
+pub fn find_localizable_string_arrays(
+    string_arrays: Vec<AndroidStringArray>,
+) -> Vec<AndroidStringArray> {
+    string_arrays
+        .into_iter()
+        .filter(|a| a.is_localizable())
+        .collect()
+}
+
+/// Plurals of `all_plurals` with only the items of the `required_quantities`
+/// (Eg., the ones a locale needs; look @ `Locale::plural_quantities`) that the
+/// plural with the same name in `lacking_plurals` doesn't have. Quantities that
+/// aren't in the plurals of `all_plurals` get the value of their `other` item
+/// (look @ `AndroidPlural::with_quantities`). Plurals without any such items
+/// are left out
+pub fn find_missing_plurals(
+    lacking_plurals: &[AndroidPlural],
+    all_plurals: &[AndroidPlural],
+    required_quantities: &[Quantity],
+) -> Vec<AndroidPlural> {
+    all_plurals
+        .iter()
+        .map(
+            |plural| match lacking_plurals.iter().find(|p| p.name() == plural.name()) {
+                None => plural.with_quantities(required_quantities),
+                Some(lacking_plural) => {
+                    plural.with_quantities(&lacking_plural.missing_quantities(required_quantities))
+                }
+            },
+        )
+        .filter(|plural| !plural.items().is_empty())
+        .collect()
+}
+
+/// A string array is considered missing only if `lacking_string_arrays` doesn't
+/// have a string array with the same name
+pub fn find_missing_string_arrays(
+    lacking_string_arrays: &[AndroidStringArray],
+    all_string_arrays: &[AndroidStringArray],
+) -> Vec<AndroidStringArray> {
+    let lacking_string_array_names: HashSet<&str> =
+        lacking_string_arrays.iter().map(|a| a.name()).collect();
+    all_string_arrays
+        .iter()
+        .filter(|a| !lacking_string_array_names.contains(a.name()))
+        .cloned()
+        .collect()
+}
+
 /// It is assumed that neither lists have strings with the same names. If they
 /// do, the result is undefined! This method doesn't check whether `all_strings`
 /// contains everything that is contained in `lacking_strings`
@@ -57,7 +114,10 @@ pub fn find_missing_strings(
 mod tests {
     use test_utilities;
 
+    use crate::android_plural::AndroidPlural;
+    use crate::android_plural::Quantity;
     use crate::android_string::AndroidString;
+    use crate::android_string_array::AndroidStringArray;
 
     #[test]
     fn finds_localizable_strings() {
@@ -102,4 +162,71 @@ mod tests {
             ],
         )
     }
+
+    #[test]
+    fn finds_missing_quantities_of_plurals() {
+        let lacking_plurals = vec![
+            AndroidPlural::localizable(
+                "common_plural",
+                vec![(Quantity::One, "un"), (Quantity::Few, "peu")],
+            ),
+            AndroidPlural::localizable(
+                "complete_plural",
+                vec![
+                    (Quantity::One, "un"),
+                    (Quantity::Many, "beaucoup"),
+                    (Quantity::Other, "autre"),
+                ],
+            ),
+            AndroidPlural::localizable("only_in_lacking_plurals", vec![]),
+        ];
+
+        let all_plurals = vec![
+            AndroidPlural::localizable("only_in_all_plurals", vec![(Quantity::One, "one")]),
+            AndroidPlural::localizable(
+                "common_plural",
+                vec![(Quantity::One, "one"), (Quantity::Other, "other")],
+            ),
+            AndroidPlural::localizable(
+                "complete_plural",
+                vec![(Quantity::One, "one"), (Quantity::Other, "other")],
+            ),
+        ];
+
+        test_utilities::list::assert_strict_list_eq(
+            super::find_missing_plurals(
+                &lacking_plurals,
+                &all_plurals,
+                &[Quantity::One, Quantity::Many, Quantity::Other],
+            ),
+            vec![
+                AndroidPlural::localizable("only_in_all_plurals", vec![(Quantity::One, "one")]),
+                AndroidPlural::localizable(
+                    "common_plural",
+                    vec![(Quantity::Many, "other"), (Quantity::Other, "other")],
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn finds_missing_string_arrays() {
+        let lacking_string_arrays = vec![
+            AndroidStringArray::localizable("common_array", vec!["first"]),
+            AndroidStringArray::localizable("only_in_lacking_arrays", vec![]),
+        ];
+
+        let all_string_arrays = vec![
+            AndroidStringArray::localizable("only_in_all_arrays", vec!["first"]),
+            AndroidStringArray::localizable("common_array", vec!["first", "second"]),
+        ];
+
+        test_utilities::list::assert_strict_list_eq(
+            super::find_missing_string_arrays(&lacking_string_arrays, &all_string_arrays),
+            vec![AndroidStringArray::localizable(
+                "only_in_all_arrays",
+                vec!["first"],
+            )],
+        )
+    }
 }
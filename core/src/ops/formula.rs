@@ -0,0 +1,61 @@
+use crate::constants;
+
+/// Spreadsheet apps run cells starting with `=`, `+`, `-` or `@` as formulas.
+/// Such values get a leading `'` (which spreadsheet apps take to mean text).
+/// Values that already look escaped (`'=1`) get another `'` so that
+/// `unescape` always gets back the exact value
+pub fn escape(value: &str) -> String {
+    if needs_escape(value) {
+        format!("{}{}", constants::csv::FORMULA_ESCAPE, value)
+    } else {
+        String::from(value)
+    }
+}
+
+/// Reverse of `escape`
+pub fn unescape(value: &str) -> &str {
+    if value.starts_with(constants::csv::FORMULA_ESCAPE)
+        && needs_escape(&value[constants::csv::FORMULA_ESCAPE.len_utf8()..])
+    {
+        &value[constants::csv::FORMULA_ESCAPE.len_utf8()..]
+    } else {
+        value
+    }
+}
+
+fn needs_escape(value: &str) -> bool {
+    value
+        .trim_start_matches(constants::csv::FORMULA_ESCAPE)
+        .starts_with(constants::csv::FORMULA_TRIGGERS)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn escapes_formulas() {
+        assert_eq!(super::escape("=1+1"), "'=1+1");
+        assert_eq!(super::escape("+1 555"), "'+1 555");
+        assert_eq!(super::escape("- item"), "'- item");
+        assert_eq!(super::escape("@string/s1"), "'@string/s1");
+        assert_eq!(super::escape("''=1"), "'''=1");
+        assert_eq!(super::escape("a=1"), "a=1");
+        assert_eq!(super::escape("'a"), "'a");
+        assert_eq!(super::escape(""), "");
+    }
+
+    #[test]
+    fn unescapes_formulas() {
+        assert_eq!(super::unescape("'=1+1"), "=1+1");
+        assert_eq!(super::unescape("'''=1"), "''=1");
+        assert_eq!(super::unescape("=1+1"), "=1+1");
+        assert_eq!(super::unescape("'a"), "'a");
+        assert_eq!(super::unescape("'"), "'");
+    }
+
+    #[test]
+    fn round_trips() {
+        for value in &["=1", "'=1", "''@a", "'", "'a", "a", "-", ""] {
+            assert_eq!(super::unescape(&super::escape(value)), *value);
+        }
+    }
+}
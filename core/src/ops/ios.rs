@@ -0,0 +1,94 @@
+use regex::Captures;
+use regex::Regex;
+
+use android_localization_utilities::DevExpt;
+
+use crate::ops::text;
+
+lazy_static::lazy_static! {
+    static ref ANDROID_STRING_SPECIFIER: Regex =
+        Regex::new(r"%%|%(\d+\$)?([-#+ 0,(]*\d*(?:\.\d+)?)s").expt("Invalid regex!");
+    static ref IOS_OBJECT_SPECIFIER: Regex =
+        Regex::new(r"%%|%(\d+\$)?([-#+ 0,(]*\d*(?:\.\d+)?)@").expt("Invalid regex!");
+    static ref IOS_SPECIFIER: Regex =
+        Regex::new(r"%%|%(?:\d+\$)?[-#+ 0,(]*\d*(?:\.\d+)?(?:ll|l|h)?([a-zA-Z@])")
+            .expt("Invalid regex!");
+}
+
+/// Converts a value from a strings file into plain text as it would be shown
+/// in iOS (look @ `text::to_plain_text`). String format specifiers (`%1$s`) are
+/// turned into object ones (`%1$@`)
+pub fn to_ios_value(android_value: &str) -> String {
+    replace_specifiers(
+        &ANDROID_STRING_SPECIFIER,
+        &text::to_plain_text(android_value),
+        "@",
+    )
+}
+
+/// Reverse of `to_ios_value`. Inline markup (`<b>`) is left as is
+pub fn to_android_value(ios_value: &str) -> String {
+    text::to_android_value(&replace_specifiers(&IOS_OBJECT_SPECIFIER, ios_value, "s"))
+}
+
+/// Conversion of the first format specifier in an iOS value (`d` for `%1$d`),
+/// if there is one
+pub fn value_type(ios_value: &str) -> Option<&str> {
+    IOS_SPECIFIER
+        .captures_iter(ios_value)
+        .find_map(|captures| captures.get(1))
+        .map(|m| m.as_str())
+}
+
+fn replace_specifiers(regex: &Regex, text: &str, conversion: &str) -> String {
+    regex
+        .replace_all(text, |captures: &Captures| {
+            if &captures[0] == "%%" {
+                String::from("%%")
+            } else {
+                format!(
+                    "%{}{}{}",
+                    captures.get(1).map_or("", |m| m.as_str()),
+                    captures.get(2).map_or("", |m| m.as_str()),
+                    conversion
+                )
+            }
+        })
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn converts_to_ios_value() {
+        assert_eq!(
+            super::to_ios_value(r#"Don\'t \"%1$s\" &amp; %2$d\n<b>%s</b> 100%% é"#),
+            "Don't \"%1$@\" & %2$d\n<b>%@</b> 100%% \u{e9}"
+        );
+        assert_eq!(super::to_ios_value(r#""  spaced  ""#), "  spaced  ");
+        assert_eq!(super::to_ios_value(r#"\@home \?"#), "@home ?");
+        assert_eq!(super::to_ios_value("a & b &#233;"), "a & b \u{e9}");
+    }
+
+    #[test]
+    fn converts_to_android_value() {
+        assert_eq!(
+            super::to_android_value("Don't \"%1$@\" & %2$d\n<b>%@</b> 100%%"),
+            r#"Don\'t \"%1$s\" &amp; %2$d\n<b>%s</b> 100%%"#
+        );
+        assert_eq!(super::to_android_value("@home ?"), r#"\@home ?"#);
+    }
+
+    #[test]
+    fn finds_value_type() {
+        assert_eq!(super::value_type("100%% of %1$ld files"), Some("d"));
+        assert_eq!(super::value_type("%@ & %d"), Some("@"));
+        assert_eq!(super::value_type("none"), None);
+    }
+
+    #[test]
+    fn round_trips() {
+        let value = r#"Don\'t \"%1$s\" &amp; %2$d\n<b>%s</b>"#;
+        assert_eq!(super::to_android_value(&super::to_ios_value(value)), value);
+    }
+}
@@ -0,0 +1,39 @@
+use crate::constants;
+
+/// Keys for formats that don't have a place for the module of a string (look @
+/// `ResDir::module`) carry it as a prefix. Eg., `app/src/main/res/s1`
+pub fn build(name: &str, module: Option<&str>) -> String {
+    match module {
+        None => String::from(name),
+        Some(module) => format!("{}{}{}", module, constants::keys::MODULE_SEPARATOR, name),
+    }
+}
+
+/// Reverse of `build`
+pub fn split(key: &str) -> (Option<String>, &str) {
+    match key.rfind(constants::keys::MODULE_SEPARATOR) {
+        None => (None, key),
+        Some(index) => (Some(String::from(&key[..index])), &key[index + 1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn builds_keys() {
+        assert_eq!(super::build("s1", None), "s1");
+        assert_eq!(
+            super::build("s1", Some("app/src/main/res")),
+            "app/src/main/res/s1"
+        );
+    }
+
+    #[test]
+    fn splits_keys() {
+        assert_eq!(super::split("s1"), (None, "s1"));
+        assert_eq!(
+            super::split("app/src/main/res/s1"),
+            (Some(String::from("app/src/main/res")), "s1")
+        );
+    }
+}
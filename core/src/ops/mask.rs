@@ -0,0 +1,91 @@
+use crate::constants;
+use crate::ops::text;
+use crate::ops::tokens;
+use crate::ops::tokens::Segment;
+
+/// Format specifiers & markup (look @ `Segment`) in a value are swapped for
+/// numbered placeholders so that machine translation can't mangle them. The
+/// rest is turned into plain text (escapes & entities are resolved). Eg.,
+/// `Hi <b>%1$s</b>, it\'s &amp;` => `Hi ⟦0⟧, it's &`. Returns the masked value
+/// along with what the placeholders stand for (in order)
+pub fn mask(android_value: &str) -> (String, Vec<String>) {
+    let mut masked_value = String::with_capacity(android_value.len());
+    let mut masked_parts: Vec<String> = vec![];
+    for segment in tokens::segment(android_value) {
+        match segment {
+            Segment::Text(text) => masked_value.push_str(&text),
+            Segment::Verbatim(verbatim) => {
+                masked_value.push_str(&placeholder(masked_parts.len()));
+                masked_parts.push(verbatim);
+            }
+        }
+    }
+
+    (masked_value, masked_parts)
+}
+
+/// The translated text is escaped back into an Android value (a raw `<` can't
+/// be in one) & the placeholders are swapped back for what they stand for (look
+/// @ `mask`). If any of them is missing or repeated, there is no telling where
+/// the markup should go & `None` is returned
+pub fn unmask(masked_value: &str, masked_parts: &[String]) -> Option<String> {
+    let mut value = text::to_android_value(masked_value).replace('<', "&lt;");
+    for (index, masked_part) in masked_parts.iter().enumerate() {
+        let placeholder = placeholder(index);
+        if value.matches(&placeholder).count() != 1 {
+            return None;
+        }
+
+        value = value.replacen(&placeholder, masked_part, 1);
+    }
+
+    Some(value)
+}
+
+fn placeholder(index: usize) -> String {
+    format!(
+        "{}{}{}",
+        constants::translation_provider::PLACEHOLDER_START,
+        index,
+        constants::translation_provider::PLACEHOLDER_END
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn masks_format_specifiers_and_markup() {
+        let (masked_value, masked_parts) =
+            super::mask(r#"Hi <b>%1$s</b>, it\'s <xliff:g id="n">%2$d</xliff:g> pm &amp; &lt;3"#);
+
+        assert_eq!(masked_value, "Hi ⟦0⟧, it's ⟦1⟧ pm & <3");
+        assert_eq!(
+            masked_parts,
+            vec![
+                String::from("<b>%1$s</b>"),
+                String::from(r#"<xliff:g id="n">%2$d</xliff:g>"#),
+            ]
+        );
+    }
+
+    #[test]
+    fn unmasks_translated_values() {
+        let (_, masked_parts) = super::mask("Hi <b>%1$s</b> & %2$d");
+        assert_eq!(
+            super::unmask("⟦1⟧ ⟦0⟧ salut", &masked_parts),
+            Some(String::from("%2$d <b>%1$s</b> salut"))
+        );
+        assert_eq!(super::unmask("⟦0⟧ salut", &masked_parts), None);
+        assert_eq!(super::unmask("⟦0⟧ ⟦0⟧ ⟦1⟧ salut", &masked_parts), None);
+        assert_eq!(super::unmask("salut", &[]), Some(String::from("salut")));
+    }
+
+    #[test]
+    fn escapes_translated_text_while_unmasking() {
+        let (_, masked_parts) = super::mask(r#"<b>%1$s</b> it\'s &amp; &lt;3"#);
+        assert_eq!(
+            super::unmask(r#"⟦0⟧ c'est "&" <3"#, &masked_parts),
+            Some(String::from(r#"<b>%1$s</b> c\'est \"&amp;\" &lt;3"#))
+        );
+    }
+}
@@ -1,6 +1,10 @@
+use std::collections::HashSet;
+
 use android_localization_utilities::DevExpt;
 
+use crate::android_plural::AndroidPlural;
 use crate::android_string::AndroidString;
+use crate::android_string_array::AndroidStringArray;
 use crate::ops::sort;
 
 /// While grouping strings, strings from `strings_1` take precedence over `strings_2` in case both
@@ -53,11 +57,57 @@ pub fn merge_and_group_strings(
     result
 }
 
+/// Items of the plurals from `plurals_1` replace the items with the same
+/// quantities of the plurals with the same name from `plurals_2`. The rest of
+/// the items of those are kept. The returned plurals are sorted by name
+pub fn merge_plurals(
+    plurals_1: Vec<AndroidPlural>,
+    plurals_2: Vec<AndroidPlural>,
+) -> Vec<AndroidPlural> {
+    let mut result = plurals_2;
+    for plural in plurals_1 {
+        match result.iter_mut().find(|p| p.name() == plural.name()) {
+            None => result.push(plural),
+            Some(existing_plural) => {
+                *existing_plural = plural.with_missing_items_from(existing_plural)
+            }
+        }
+    }
+
+    sort::sort_android_plurals_by_name(&mut result);
+    result
+}
+
+/// String arrays from `string_arrays_1` replace the string arrays with the same name
+/// from `string_arrays_2`. The returned string arrays are sorted by name
+pub fn merge_string_arrays(
+    string_arrays_1: Vec<AndroidStringArray>,
+    string_arrays_2: Vec<AndroidStringArray>,
+) -> Vec<AndroidStringArray> {
+    let string_arrays_1_names: HashSet<String> = string_arrays_1
+        .iter()
+        .map(|a| String::from(a.name()))
+        .collect();
+
+    let mut result = string_arrays_1;
+    result.extend(
+        string_arrays_2
+            .into_iter()
+            .filter(|a| !string_arrays_1_names.contains(a.name())),
+    );
+
+    sort::sort_android_string_arrays_by_name(&mut result);
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use test_utilities;
 
+    use crate::android_plural::AndroidPlural;
+    use crate::android_plural::Quantity;
     use crate::android_string::AndroidString;
+    use crate::android_string_array::AndroidStringArray;
 
     #[test]
     fn merges_and_groups() {
@@ -108,4 +158,62 @@ mod tests {
             ],
         )
     }
+
+    #[test]
+    fn merges_plurals() {
+        let plurals = super::merge_plurals(
+            vec![
+                AndroidPlural::localizable("plural_3", vec![(Quantity::One, "from list 1")]),
+                AndroidPlural::localizable("plural_1", vec![(Quantity::One, "from list 1")]),
+            ],
+            vec![
+                AndroidPlural::localizable(
+                    "plural_1",
+                    vec![
+                        (Quantity::One, "from list 2"),
+                        (Quantity::Few, "from list 2"),
+                    ],
+                ),
+                AndroidPlural::localizable("plural_2", vec![(Quantity::One, "from list 2")]),
+            ],
+        );
+
+        test_utilities::list::assert_strict_list_eq(
+            plurals,
+            vec![
+                AndroidPlural::localizable(
+                    "plural_1",
+                    vec![
+                        (Quantity::One, "from list 1"),
+                        (Quantity::Few, "from list 2"),
+                    ],
+                ),
+                AndroidPlural::localizable("plural_2", vec![(Quantity::One, "from list 2")]),
+                AndroidPlural::localizable("plural_3", vec![(Quantity::One, "from list 1")]),
+            ],
+        )
+    }
+
+    #[test]
+    fn merges_string_arrays() {
+        let string_arrays = super::merge_string_arrays(
+            vec![
+                AndroidStringArray::localizable("array_2", vec!["from list 1"]),
+                AndroidStringArray::localizable("array_1", vec!["from list 1"]),
+            ],
+            vec![
+                AndroidStringArray::localizable("array_1", vec!["from list 2", "again"]),
+                AndroidStringArray::localizable("array_3", vec!["from list 2"]),
+            ],
+        );
+
+        test_utilities::list::assert_strict_list_eq(
+            string_arrays,
+            vec![
+                AndroidStringArray::localizable("array_1", vec!["from list 1"]),
+                AndroidStringArray::localizable("array_2", vec!["from list 1"]),
+                AndroidStringArray::localizable("array_3", vec!["from list 2"]),
+            ],
+        )
+    }
 }
@@ -0,0 +1,121 @@
+use crate::constants;
+use crate::ops::tokens;
+use crate::ops::tokens::Token;
+
+/// Letters are swapped for accented ones & the text is padded with words (to
+/// be ~30% longer) & bracketed. Eg., `Hello %1$s` => `[Ĥéļļö %1$s one]`. This
+/// helps catch hardcoded & truncated texts
+pub fn accent(android_value: &str) -> String {
+    if is_left_as_is(android_value) {
+        return String::from(android_value);
+    }
+
+    let mut value = String::with_capacity(android_value.len() * 2);
+    let mut text_length = 0;
+    value.push('[');
+    for token in tokens::tokenize(android_value) {
+        match token {
+            Token::Verbatim(verbatim) => value.push_str(verbatim),
+            Token::Text(c) => {
+                text_length += 1;
+                value.push(accented(c));
+            }
+        }
+    }
+
+    let padding_length = (text_length * constants::pseudo::EXPANSION_PERCENT).div_ceil(100);
+    let mut padded_length = 0;
+    for word in constants::pseudo::PADDING_WORDS.iter().cycle() {
+        if padded_length >= padding_length {
+            break;
+        }
+
+        value.push(' ');
+        value.push_str(word);
+        padded_length += word.len() + 1;
+    }
+
+    value.push(']');
+    value
+}
+
+/// Every word (a run of text between whitespace & whatever is left as is) is
+/// wrapped with RTL marks & overrides so that the text is shown right to left
+/// (the same way Android's own `ar-XB` pseudolocale does). This helps catch
+/// layouts that don't mirror
+pub fn bidi(android_value: &str) -> String {
+    if is_left_as_is(android_value) {
+        return String::from(android_value);
+    }
+
+    let mut value = String::with_capacity(android_value.len() * 2);
+    let mut in_word = false;
+    for token in tokens::tokenize(android_value) {
+        let is_word_char = matches!(token, Token::Text(c) if !c.is_whitespace());
+        if is_word_char && !in_word {
+            value.push_str(constants::pseudo::BIDI_WORD_START);
+        } else if !is_word_char && in_word {
+            value.push_str(constants::pseudo::BIDI_WORD_END);
+        }
+
+        in_word = is_word_char;
+        match token {
+            Token::Verbatim(verbatim) => value.push_str(verbatim),
+            Token::Text(c) => value.push(c),
+        }
+    }
+
+    if in_word {
+        value.push_str(constants::pseudo::BIDI_WORD_END);
+    }
+
+    value
+}
+
+/// Empty values & references to other resources (`@string/name` or
+/// `?attr/name`) have nothing to be localized
+fn is_left_as_is(android_value: &str) -> bool {
+    android_value.is_empty() || android_value.starts_with('@') || android_value.starts_with('?')
+}
+
+fn accented(c: char) -> char {
+    if c.is_ascii_lowercase() {
+        constants::pseudo::ACCENTED_LOWERCASE[(c as u8 - b'a') as usize]
+    } else if c.is_ascii_uppercase() {
+        constants::pseudo::ACCENTED_UPPERCASE[(c as u8 - b'A') as usize]
+    } else {
+        c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn accents() {
+        assert_eq!(super::accent("Hello"), "[Ĥéļļö one]");
+        assert_eq!(
+            super::accent(r#"Hi <b>%1$s</b>, it\'s <xliff:g id="n">%2$d</xliff:g> pm"#),
+            r#"[Ĥî <b>%1$s</b>, îţ\'š <xliff:g id="n">%2$d</xliff:g> þɱ one]"#
+        );
+        assert_eq!(
+            super::accent("Save all the things now"),
+            "[Šåṽé åļļ ţĥé ţĥîñĝš ñöŵ one two]"
+        );
+        assert_eq!(super::accent("%d"), "[%d]");
+        assert_eq!(super::accent(""), "");
+        assert_eq!(super::accent("@string/other"), "@string/other");
+    }
+
+    #[test]
+    fn wraps_words_for_bidi() {
+        assert_eq!(
+            super::bidi("Hi <b>%1$s</b> now"),
+            "\u{200f}\u{202e}Hi\u{202c}\u{200f} <b>%1$s</b> \u{200f}\u{202e}now\u{202c}\u{200f}"
+        );
+        assert_eq!(
+            super::bidi("a\\nb"),
+            "\u{200f}\u{202e}a\u{202c}\u{200f}\\n\u{200f}\u{202e}b\u{202c}\u{200f}"
+        );
+        assert_eq!(super::bidi("?attr/name"), "?attr/name");
+    }
+}
@@ -1,6 +1,8 @@
 use std::cmp::Ordering;
 
+use crate::android_plural::AndroidPlural;
 use crate::android_string::AndroidString;
+use crate::android_string_array::AndroidStringArray;
 use crate::localized_string::LocalizedString;
 
 /// In place, stable sorting
@@ -10,6 +12,16 @@ pub fn sort_android_strings_by_name(strings: &mut [AndroidString]) {
     strings.sort_by(|s1, s2| s1.name().cmp(s2.name()));
 }
 
+/// In place, stable sorting
+pub fn sort_android_plurals_by_name(plurals: &mut [AndroidPlural]) {
+    plurals.sort_by(|p1, p2| p1.name().cmp(p2.name()));
+}
+
+/// In place, stable sorting
+pub fn sort_android_string_arrays_by_name(string_arrays: &mut [AndroidStringArray]) {
+    string_arrays.sort_by(|a1, a2| a1.name().cmp(a2.name()));
+}
+
 /// In place, stable sorting
 pub fn sort_localized_strings_by_name(strings: &mut [LocalizedString]) {
     // Not using `sort_by_key` as I can't figure out how to specify
@@ -0,0 +1,120 @@
+/// Converts a value from a strings file into plain text as it would be shown
+/// to the user. Android escapes (`\'`, `\n` etc.) & XML entities are resolved.
+/// Inline markup & format specifiers are left as is
+pub fn to_plain_text(android_value: &str) -> String {
+    let value = strip_enclosing_quotes(android_value);
+    let mut text = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') => text.push('\n'),
+                Some('t') => text.push('\t'),
+                Some('u') => {
+                    let hex: String = chars.clone().take(4).collect();
+                    match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                        Some(unicode) if hex.len() == 4 => {
+                            text.push(unicode);
+                            chars.nth(3);
+                        }
+                        _ => text.push_str("\\u"),
+                    }
+                }
+                Some(other) => text.push(other),
+                None => text.push('\\'),
+            },
+
+            '&' => {
+                let entity: String = chars.clone().take_while(|c| *c != ';').collect();
+                let length = entity.chars().count();
+                match resolve_entity(&entity) {
+                    Some(resolved) if chars.clone().nth(length) == Some(';') => {
+                        text.push(resolved);
+                        chars.nth(length); // Skip over the entity & `;`
+                    }
+                    _ => text.push('&'),
+                }
+            }
+
+            _ => text.push(c),
+        }
+    }
+
+    text
+}
+
+/// Reverse of `to_plain_text`
+pub fn to_android_value(text: &str) -> String {
+    let mut value = String::with_capacity(text.len());
+    for (index, c) in text.chars().enumerate() {
+        match c {
+            '\\' => value.push_str("\\\\"),
+            '\'' => value.push_str("\\'"),
+            '"' => value.push_str("\\\""),
+            '\n' => value.push_str("\\n"),
+            '\t' => value.push_str("\\t"),
+            '&' => value.push_str("&amp;"),
+            '@' | '?' if index == 0 => {
+                value.push('\\');
+                value.push(c)
+            }
+            _ => value.push(c),
+        }
+    }
+
+    value
+}
+
+fn strip_enclosing_quotes(value: &str) -> &str {
+    if value.len() >= 2
+        && value.starts_with('"')
+        && value.ends_with('"')
+        && !value.ends_with("\\\"")
+    {
+        &value[1..value.len() - 1]
+    } else {
+        value
+    }
+}
+
+fn resolve_entity(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        _ => {
+            let code = entity.strip_prefix('#')?;
+            let code = match code.strip_prefix('x') {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn converts_to_plain_text() {
+        assert_eq!(
+            super::to_plain_text(r#"Don\'t \"%1$s\" &amp; %2$d\n<b>%s</b> \u00e9"#),
+            "Don't \"%1$s\" & %2$d\n<b>%s</b> \u{e9}"
+        );
+        assert_eq!(super::to_plain_text(r#""  spaced  ""#), "  spaced  ");
+        assert_eq!(super::to_plain_text(r#"\@home \?"#), "@home ?");
+        assert_eq!(super::to_plain_text("a & b &#233;"), "a & b \u{e9}");
+    }
+
+    #[test]
+    fn converts_to_android_value() {
+        assert_eq!(
+            super::to_android_value("Don't \"%1$s\" & %2$d\n<b>%s</b>"),
+            r#"Don\'t \"%1$s\" &amp; %2$d\n<b>%s</b>"#
+        );
+        assert_eq!(super::to_android_value("@home ?"), r#"\@home ?"#);
+    }
+}
@@ -0,0 +1,199 @@
+use regex::Regex;
+
+use android_localization_utilities::DevExpt;
+
+use crate::ops::text;
+
+lazy_static::lazy_static! {
+    static ref FORMAT_SPECIFIER: Regex =
+        Regex::new(r"^(?:%%|%(?:\d+\$)?[-#+ 0,(]*\d*(?:\.\d+)?[a-zA-Z])").expt("Invalid regex!");
+}
+
+/// Parts of a value from a strings file
+#[derive(Debug, PartialEq)]
+pub enum Token<'a> {
+    /// Shown to the user as is
+    Text(char),
+    /// Markup, CDATA sections, the content of `xliff:g` elements, escapes,
+    /// entities & format specifiers. These are never touched
+    Verbatim(&'a str),
+}
+
+/// Splits a value into the text shown to the user & everything else
+pub fn tokenize(android_value: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = android_value;
+    while let Some(c) = rest.chars().next() {
+        let verbatim_length = match c {
+            '<' if rest.starts_with("<![CDATA[") => Some(length_till(rest, "]]>")),
+            '<' if rest.starts_with("<!--") => Some(length_till(rest, "-->")),
+            '<' if rest.starts_with("<xliff:g") => {
+                let start_tag_length = length_till(rest, ">");
+                if rest[..start_tag_length].ends_with("/>") {
+                    Some(start_tag_length)
+                } else {
+                    Some(length_till(rest, "</xliff:g>"))
+                }
+            }
+            '<' => Some(length_till(rest, ">")),
+            '\\' => Some(escape_length(rest)),
+            '&' => entity_length(rest),
+            '%' => FORMAT_SPECIFIER.find(rest).map(|m| m.end()),
+            _ => None,
+        };
+
+        match verbatim_length {
+            Some(length) => {
+                tokens.push(Token::Verbatim(&rest[..length]));
+                rest = &rest[length..];
+            }
+
+            None => {
+                tokens.push(Token::Text(c));
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    tokens
+}
+
+/// Runs of a value from a strings file
+#[derive(Debug, PartialEq)]
+pub enum Segment {
+    /// Plain text with the escapes & entities in it resolved (look @
+    /// `text::to_plain_text`)
+    Text(String),
+    /// Markup, CDATA sections, the content of `xliff:g` elements & format
+    /// specifiers
+    Verbatim(String),
+}
+
+/// Like `tokenize` but consecutive tokens are put together & escapes &
+/// entities are resolved into text
+pub fn segment(android_value: &str) -> Vec<Segment> {
+    let mut segments = vec![];
+    let mut current: Option<Segment> = None;
+    for token in tokenize(android_value) {
+        let (is_text, part) = match token {
+            Token::Text(c) => (true, c.to_string()),
+            Token::Verbatim(part) => (
+                part.starts_with('\\') || part.starts_with('&'),
+                String::from(part),
+            ),
+        };
+
+        match (&mut current, is_text) {
+            (Some(Segment::Text(run)), true) | (Some(Segment::Verbatim(run)), false) => {
+                run.push_str(&part)
+            }
+            _ => {
+                segments.extend(current.take());
+                current = Some(match is_text {
+                    true => Segment::Text(part),
+                    false => Segment::Verbatim(part),
+                });
+            }
+        }
+    }
+
+    segments.extend(current);
+    segments
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Text(run) => Segment::Text(text::to_plain_text(&run)),
+            verbatim => verbatim,
+        })
+        .collect()
+}
+
+/// Length till the end of the first `end` in `rest` (or of `rest` itself if
+/// there isn't any)
+fn length_till(rest: &str, end: &str) -> usize {
+    rest.find(end).map_or(rest.len(), |index| index + end.len())
+}
+
+/// `\uXXXX` or a backslash followed by a char
+fn escape_length(rest: &str) -> usize {
+    let mut chars = rest.chars().skip(1);
+    match chars.next() {
+        None => 1,
+        Some('u')
+            if rest
+                .get(2..6)
+                .is_some_and(|hex| hex.chars().all(|c| c.is_ascii_hexdigit())) =>
+        {
+            6
+        }
+        Some(c) => 1 + c.len_utf8(),
+    }
+}
+
+/// `&amp;`, `&#233;` etc. A lone `&` (which can only be in a CDATA section) is
+/// text
+fn entity_length(rest: &str) -> Option<usize> {
+    let index = rest.find(';')?;
+    let name = &rest[1..index];
+    if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '#') {
+        Some(index + 1)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Segment;
+    use super::Token;
+
+    #[test]
+    fn tokenizes() {
+        assert_eq!(
+            super::tokenize(
+                r#"a<b>b</b>%1$s\n\u00e9&amp;&<![CDATA[<c>]]><xliff:g id="x">%d y</xliff:g><xliff:g id="z"/>%"#
+            ),
+            vec![
+                Token::Text('a'),
+                Token::Verbatim("<b>"),
+                Token::Text('b'),
+                Token::Verbatim("</b>"),
+                Token::Verbatim("%1$s"),
+                Token::Verbatim("\\n"),
+                Token::Verbatim("\\u00e9"),
+                Token::Verbatim("&amp;"),
+                Token::Text('&'),
+                Token::Verbatim("<![CDATA[<c>]]>"),
+                Token::Verbatim(r#"<xliff:g id="x">%d y</xliff:g>"#),
+                Token::Verbatim(r#"<xliff:g id="z"/>"#),
+                Token::Text('%'),
+            ]
+        )
+    }
+
+    #[test]
+    fn tokenizes_escapes_followed_by_multi_byte_chars() {
+        assert_eq!(
+            super::tokenize("\\uab€\\é"),
+            vec![
+                Token::Verbatim("\\u"),
+                Token::Text('a'),
+                Token::Text('b'),
+                Token::Text('€'),
+                Token::Verbatim("\\é"),
+            ]
+        )
+    }
+
+    #[test]
+    fn segments_into_text_and_everything_else() {
+        assert_eq!(
+            super::segment(r#"Hi <b>%1$s</b>, it\'s &amp; <![CDATA[<c>]]>"#),
+            vec![
+                Segment::Text(String::from("Hi ")),
+                Segment::Verbatim(String::from("<b>%1$s</b>")),
+                Segment::Text(String::from(", it's & ")),
+                Segment::Verbatim(String::from("<![CDATA[<c>]]>")),
+            ]
+        )
+    }
+}
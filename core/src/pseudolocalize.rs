@@ -0,0 +1,236 @@
+use std::fs;
+use std::fs::File;
+use std::path::Path;
+
+use crate::android_plural::AndroidPlural;
+use crate::android_plural::PluralItem;
+use crate::android_resources::AndroidResources;
+use crate::android_string::AndroidString;
+use crate::android_string_array::AndroidStringArray;
+use crate::constants;
+use crate::error::{Error, ResultExt};
+use crate::ops::pseudo;
+use crate::qualifiers::Locale;
+use crate::util::xml_utilities;
+use crate::writer::xml_writer;
+
+/// Pseudolocalizes a single value. Look @ `pseudo`
+type PseudolocalizeValue = fn(&str) -> String;
+
+/// Writes out the pseudolocales (`en-rXA` with accented & expanded texts &
+/// `ar-rXB` with right to left texts; look @ `pseudo`) of every localizable
+/// resource in the default values dir. Every default file gets a file with the
+/// same name in the values dir of each pseudolocale. These files are generated
+/// & are written over every time.
+///
+/// Returns the list of output files written by this call. These aren't guaranteed
+/// to be valid paths to files. Sometimes, if a file's path can't be expressed by
+/// `String` (in case it has non UTF-8 chars), it could just be the file's name
+pub fn pseudolocalize(res_dir_path: &str) -> Result<Vec<String>, Error> {
+    let default_strings = xml_utilities::read_default_strings(Path::new(res_dir_path))?;
+    let pseudolocales: [(&str, PseudolocalizeValue); 2] = [
+        (constants::pseudo::ACCENTED_LOCALE, pseudo::accent),
+        (constants::pseudo::BIDI_LOCALE, pseudo::bidi),
+    ];
+
+    let mut written_files_paths = vec![];
+    for (locale_id, pseudolocalize_value) in pseudolocales {
+        let locale: Locale = locale_id.parse().with_context(locale_id)?;
+        for strings_with_path in &default_strings {
+            let resources =
+                pseudolocalize_resources(strings_with_path.resources(), pseudolocalize_value);
+            if resources == AndroidResources::default() {
+                continue;
+            }
+
+            let file_name = Path::new(strings_with_path.path()).file_name().map_or_else(
+                || String::from(constants::fs::STRING_FILE_NAME),
+                |name| String::from(name.to_string_lossy()),
+            );
+            let file_path =
+                xml_utilities::foreign_file_path(Path::new(res_dir_path), &locale, &file_name);
+            let output_file_path = String::from(file_path.to_string_lossy());
+            if let Some(values_dir_path) = file_path.parent() {
+                fs::create_dir_all(values_dir_path).with_context(output_file_path.clone())?;
+            }
+
+            let mut file = File::create(&file_path).with_context(output_file_path.clone())?;
+            xml_writer::write(&mut file, resources).with_context(output_file_path.clone())?;
+            written_files_paths.push(output_file_path);
+        }
+    }
+
+    Ok(written_files_paths)
+}
+
+/// Only localizable resources are kept
+fn pseudolocalize_resources(
+    resources: &AndroidResources,
+    pseudolocalize_value: PseudolocalizeValue,
+) -> AndroidResources {
+    AndroidResources::new(
+        resources
+            .strings()
+            .iter()
+            .filter(|s| s.is_localizable())
+            .map(|s| {
+                AndroidString::new(
+                    String::from(s.name()),
+                    pseudolocalize_value(s.value()),
+                    true,
+                )
+            })
+            .collect(),
+        resources
+            .plurals()
+            .iter()
+            .filter(|p| p.is_localizable())
+            .map(|p| {
+                AndroidPlural::new(
+                    String::from(p.name()),
+                    p.items()
+                        .iter()
+                        .map(|item| {
+                            PluralItem::new(item.quantity(), pseudolocalize_value(item.value()))
+                        })
+                        .collect(),
+                    true,
+                )
+            })
+            .collect(),
+        resources
+            .string_arrays()
+            .iter()
+            .filter(|a| a.is_localizable())
+            .map(|a| {
+                AndroidStringArray::new(
+                    String::from(a.name()),
+                    a.items()
+                        .iter()
+                        .map(|item| pseudolocalize_value(item))
+                        .collect(),
+                    true,
+                )
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::fs::File;
+
+    use test_utilities;
+
+    use crate::android_plural::AndroidPlural;
+    use crate::android_plural::Quantity;
+    use crate::android_resources::AndroidResources;
+    use crate::android_string::AndroidString;
+    use crate::android_string_array::AndroidStringArray;
+    use crate::writer::xml_writer;
+
+    #[test]
+    fn writes_pseudolocales() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let res_dir_path = temp_dir.path().join("res");
+        let default_values_dir_path =
+            test_utilities::res::setup_values_dir_for_default_locale(res_dir_path.clone());
+
+        xml_writer::write(
+            &mut File::create(format!("{}/strings.xml", default_values_dir_path)).unwrap(),
+            AndroidResources::new(
+                vec![
+                    AndroidString::localizable("s1", "Hi %1$s"),
+                    AndroidString::unlocalizable("s2", "Hi"),
+                ],
+                vec![AndroidPlural::localizable(
+                    "p1",
+                    vec![(Quantity::One, "%d day"), (Quantity::Other, "%d days")],
+                )],
+                vec![AndroidStringArray::localizable("a1", vec!["Yes"])],
+            ),
+        )
+        .unwrap();
+        xml_writer::write(
+            &mut File::create(format!("{}/config.xml", default_values_dir_path)).unwrap(),
+            vec![AndroidString::unlocalizable("url", "https://example.com")].into(),
+        )
+        .unwrap();
+
+        let mut written_files_paths =
+            super::pseudolocalize(res_dir_path.to_str().unwrap()).unwrap();
+        written_files_paths.sort();
+
+        let en_xa_file_path = res_dir_path.join("values-en-rXA").join("strings.xml");
+        let ar_xb_file_path = res_dir_path.join("values-ar-rXB").join("strings.xml");
+        assert_eq!(
+            written_files_paths,
+            vec![
+                String::from(ar_xb_file_path.to_str().unwrap()),
+                String::from(en_xa_file_path.to_str().unwrap()),
+            ]
+        );
+
+        test_utilities::list::assert_strict_list_eq(
+            fs::read_to_string(en_xa_file_path)
+                .unwrap()
+                .lines()
+                .collect::<Vec<&str>>(),
+            vec![
+                r##"<?xml version="1.0" encoding="utf-8"?>"##,
+                r##"<resources>"##,
+                r##"    <string name="s1">[Ĥî %1$s one]</string>"##,
+                r##"    <plurals name="p1">"##,
+                r##"        <item quantity="one">[%d ðåý one]</item>"##,
+                r##"        <item quantity="other">[%d ðåýš one]</item>"##,
+                r##"    </plurals>"##,
+                r##"    <string-array name="a1">"##,
+                r##"        <item>[Ýéš one]</item>"##,
+                r##"    </string-array>"##,
+                r##"</resources>"##,
+            ],
+        );
+
+        test_utilities::list::assert_strict_list_eq(
+            fs::read_to_string(ar_xb_file_path)
+                .unwrap()
+                .lines()
+                .collect::<Vec<&str>>(),
+            vec![
+                r##"<?xml version="1.0" encoding="utf-8"?>"##,
+                r##"<resources>"##,
+                "    <string name=\"s1\">\u{200f}\u{202e}Hi\u{202c}\u{200f} %1$s</string>",
+                r##"    <plurals name="p1">"##,
+                "        <item quantity=\"one\">%d \u{200f}\u{202e}day\u{202c}\u{200f}</item>",
+                "        <item quantity=\"other\">%d \u{200f}\u{202e}days\u{202c}\u{200f}</item>",
+                r##"    </plurals>"##,
+                r##"    <string-array name="a1">"##,
+                "        <item>\u{200f}\u{202e}Yes\u{202c}\u{200f}</item>",
+                r##"    </string-array>"##,
+                r##"</resources>"##,
+            ],
+        );
+    }
+
+    #[test]
+    fn writes_over_existing_pseudolocales() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let res_dir_path = temp_dir.path().join("res");
+        let mut default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_dir_path.clone());
+        xml_writer::write(
+            &mut default_strings.file,
+            vec![AndroidString::localizable("s1", "a")].into(),
+        )
+        .unwrap();
+        let en_xa_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_dir_path.clone(), "en-rXA");
+        fs::write(&en_xa_strings.path, "stale").unwrap();
+
+        super::pseudolocalize(res_dir_path.to_str().unwrap()).unwrap();
+        assert!(fs::read_to_string(&en_xa_strings.path)
+            .unwrap()
+            .contains(r##"<string name="s1">[å one]</string>"##));
+    }
+}
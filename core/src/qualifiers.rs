@@ -0,0 +1,443 @@
+use std::fmt;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use android_localization_utilities::DevExpt;
+
+use crate::android_plural::Quantity;
+use crate::constants;
+use crate::error::InnerError;
+
+lazy_static::lazy_static! {
+    static ref MCC_REGEX: Regex = Regex::new("^mcc[0-9]{3}$").expt("Invalid regex!");
+    static ref MNC_REGEX: Regex = Regex::new("^mnc[0-9]{1,3}$").expt("Invalid regex!");
+    static ref LANGUAGE_REGEX: Regex = Regex::new("^[a-z]{2,3}$").expt("Invalid regex!");
+    static ref REGION_REGEX: Regex = Regex::new("^r([a-z]{2}|[0-9]{3})$").expt("Invalid regex!");
+    static ref SCRIPT_SUBTAG_REGEX: Regex = Regex::new("^[a-z]{4}$").expt("Invalid regex!");
+    static ref REGION_SUBTAG_REGEX: Regex = Regex::new("^([a-z]{2}|[0-9]{3})$").expt("Invalid regex!");
+    static ref VARIANT_SUBTAG_REGEX: Regex = Regex::new("^([a-z0-9]{5,8}|[0-9][a-z0-9]{3})$").expt("Invalid regex!");
+    static ref API_LEVEL_REGEX: Regex = Regex::new("^v([0-9]+)$").expt("Invalid regex!");
+
+    /// Every config that comes after the locale, in the order Android expects
+    /// them to be in. Look @ https://developer.android.com/guide/topics/resources/providing-resources#AlternativeResources
+    static ref OTHER_CONFIG_REGEXES: Vec<Regex> = [
+        "^(feminine|masculine|neuter)$",
+        "^(ldrtl|ldltr)$",
+        "^sw[0-9]+dp$",
+        "^w[0-9]+dp$",
+        "^h[0-9]+dp$",
+        "^(small|normal|large|xlarge)$",
+        "^(long|notlong)$",
+        "^(round|notround)$",
+        "^(widecg|nowidecg)$",
+        "^(highdr|lowdr)$",
+        "^(port|land|square)$",
+        "^(car|desk|television|appliance|watch|vrheadset)$",
+        "^(night|notnight)$",
+        "^(ldpi|mdpi|tvdpi|hdpi|xhdpi|xxhdpi|xxxhdpi|nodpi|anydpi|[0-9]+dpi)$",
+        "^(notouch|stylus|finger)$",
+        "^(keysexposed|keyshidden|keyssoft)$",
+        "^(nokeys|qwerty|12key)$",
+        "^(navexposed|navhidden)$",
+        "^(nonav|dpad|trackball|wheel)$",
+    ]
+    .iter()
+    .map(|regex| Regex::new(regex).expt("Invalid regex!"))
+    .collect();
+}
+
+/// The configuration qualifiers of a resource directory. Eg., `mcc310`, `pt-rBR`
+/// & `night` in `values-mcc310-pt-rBR-night`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Qualifiers {
+    mcc: Option<String>,
+    mnc: Option<String>,
+    locale: Option<Locale>,
+    /// Configs that come after the locale except for the API level (night mode,
+    /// layout direction, screen density etc.)
+    other_configs: Vec<String>,
+    api_level: Option<u32>,
+}
+
+/// The locale part of the qualifiers. It could be expressed either in the
+/// legacy form (`pt-rBR`) or in the BCP 47 form (`b+sr+Latn`). The case of
+/// every part is kept as is so that the directory name can be rebuilt from it
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Locale {
+    language: String,
+    script: Option<String>,
+    region: Option<String>,
+    variants: Vec<String>,
+    is_bcp_47: bool,
+}
+
+impl Qualifiers {
+    /// Returns `None` if the passed in name isn't that of a values dir or if it
+    /// has qualifiers which aren't known or aren't in the order Android expects
+    /// them to be in
+    pub fn from_values_dir_name(dir_name: &str) -> Option<Qualifiers> {
+        if dir_name == constants::fs::BASE_VALUES_DIR_NAME {
+            return Some(Qualifiers::default());
+        }
+
+        let qualifiers = dir_name
+            .strip_prefix(constants::fs::BASE_VALUES_DIR_NAME)?
+            .strip_prefix('-')?;
+        Qualifiers::parse(qualifiers)
+    }
+
+    fn parse(qualifiers: &str) -> Option<Qualifiers> {
+        let mut parsed = Qualifiers::default();
+        let mut parts = qualifiers.split('-').peekable();
+
+        // Every config is optional but those that are present must be in order
+        let mut next_part = parts.next();
+        if let Some(part) = next_part.filter(|part| MCC_REGEX.is_match(&part.to_lowercase())) {
+            parsed.mcc = Some(String::from(part));
+            next_part = parts.next();
+        }
+
+        if let Some(part) = next_part.filter(|part| MNC_REGEX.is_match(&part.to_lowercase())) {
+            parsed.mnc = Some(String::from(part));
+            next_part = parts.next();
+        }
+
+        if let Some(part) = next_part {
+            if let Some(locale) = Locale::parse_bcp_47(part) {
+                parsed.locale = Some(locale);
+                next_part = parts.next();
+            } else if is_language(part) {
+                let region = parts
+                    .peek()
+                    .filter(|part| REGION_REGEX.is_match(&part.to_lowercase()))
+                    .map(|part| String::from(&part[1..]));
+                if region.is_some() {
+                    parts.next();
+                }
+
+                parsed.locale = Some(Locale {
+                    language: String::from(part),
+                    script: None,
+                    region,
+                    variants: vec![],
+                    is_bcp_47: false,
+                });
+                next_part = parts.next();
+            }
+        }
+
+        let mut config_regexes = OTHER_CONFIG_REGEXES.iter();
+        while let Some(part) = next_part {
+            let lowercase_part = part.to_lowercase();
+            if let Some(capture) = API_LEVEL_REGEX.captures(&lowercase_part) {
+                parsed.api_level = Some(capture.get(1)?.as_str().parse().ok()?);
+
+                // API level is always the last qualifier
+                return match parts.next() {
+                    None => Some(parsed),
+                    Some(_) => None,
+                };
+            }
+
+            // Skips configs that aren't present. If none matches, the part is
+            // either unknown or out of order
+            config_regexes.find(|regex| regex.is_match(&lowercase_part))?;
+            parsed.other_configs.push(String::from(part));
+            next_part = parts.next();
+        }
+
+        Some(parsed)
+    }
+
+    pub fn into_locale(self) -> Option<Locale> {
+        self.locale
+    }
+
+    /// Whether the directory with these qualifiers holds the translations of a
+    /// locale. Directories that also have other configs (`values-fr-night`,
+    /// `values-fr-v21` etc.) hold overrides for a specific device configuration
+    pub fn is_locale_only(&self) -> bool {
+        self.locale.is_some()
+            && self.mcc.is_none()
+            && self.mnc.is_none()
+            && self.other_configs.is_empty()
+            && self.api_level.is_none()
+    }
+}
+
+impl Locale {
+    fn parse_bcp_47(part: &str) -> Option<Locale> {
+        let mut subtags = part.strip_prefix("b+")?.split('+').peekable();
+        let language = subtags.next().filter(|subtag| is_language(subtag))?;
+        let script = subtags
+            .next_if(|subtag| SCRIPT_SUBTAG_REGEX.is_match(&subtag.to_lowercase()))
+            .map(String::from);
+        let region = subtags
+            .next_if(|subtag| REGION_SUBTAG_REGEX.is_match(&subtag.to_lowercase()))
+            .map(String::from);
+
+        let mut variants = vec![];
+        for subtag in subtags {
+            if !VARIANT_SUBTAG_REGEX.is_match(&subtag.to_lowercase()) {
+                return None;
+            }
+
+            variants.push(String::from(subtag));
+        }
+
+        Some(Locale {
+            language: String::from(language),
+            script,
+            region,
+            variants,
+            is_bcp_47: true,
+        })
+    }
+
+    /// Whether this is one of the pseudolocales (`en-rXA` or `ar-rXB`) whose
+    /// strings are generated by `pseudolocalize` & aren't translated
+    pub fn is_pseudo(&self) -> bool {
+        let region = match &self.region {
+            None => return false,
+            Some(region) => region.to_uppercase(),
+        };
+
+        self.script.is_none()
+            && self.variants.is_empty()
+            && match self.language.to_lowercase().as_str() {
+                "en" => region == "XA",
+                "ar" => region == "XB",
+                _ => false,
+            }
+    }
+
+    /// Quantities the plurals of this locale need. Look @ `Quantity::required_by`
+    pub fn plural_quantities(&self) -> &'static [Quantity] {
+        Quantity::required_by(&self.language)
+    }
+
+    /// The BCP 47 language tag of this locale (Eg., `pt-BR` for `pt-rBR` &
+    /// `sr-Latn` for `b+sr+Latn`) as expected by translation tools & services.
+    /// Android's legacy language codes are swapped for the current ones
+    pub fn language_tag(&self) -> String {
+        let language = self.language.to_lowercase();
+        let language = match language.as_str() {
+            "in" => "id",
+            "iw" => "he",
+            "ji" => "yi",
+            language => language,
+        };
+
+        let mut subtags = vec![String::from(language)];
+        if let Some(script) = &self.script {
+            let (first, rest) = script.split_at(1);
+            subtags.push(format!("{}{}", first.to_uppercase(), rest.to_lowercase()));
+        }
+
+        subtags.extend(self.region.iter().map(|region| region.to_uppercase()));
+        subtags.extend(self.variants.iter().map(|variant| variant.to_lowercase()));
+        subtags.join("-")
+    }
+
+    /// Name of the values dir which holds the strings of this locale
+    pub fn values_dir_name(&self) -> String {
+        format!("{}-{}", constants::fs::BASE_VALUES_DIR_NAME, self)
+    }
+}
+
+/// Parses the locale as it would appear in a values dir's name. Eg., `fr`,
+/// `pt-rBR`, `b+sr+Latn`
+impl FromStr for Locale {
+    type Err = InnerError;
+
+    fn from_str(locale: &str) -> Result<Self, Self::Err> {
+        Qualifiers::parse(locale)
+            .filter(Qualifiers::is_locale_only)
+            .and_then(Qualifiers::into_locale)
+            .ok_or_else(|| {
+                InnerError::from(format!(
+                    "{} isn't a valid locale qualifier (Eg., fr, pt-rBR, b+sr+Latn)",
+                    locale
+                ))
+            })
+    }
+}
+
+/// Writes out the locale the same way it would appear in a values dir's name
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_bcp_47 {
+            write!(f, "b+{}", self.language)?;
+            let subtags = self.script.iter().chain(&self.region).chain(&self.variants);
+            for subtag in subtags {
+                write!(f, "+{}", subtag)?;
+            }
+
+            Ok(())
+        } else {
+            f.write_str(&self.language)?;
+            match &self.region {
+                None => Ok(()),
+                Some(region) => write!(f, "-r{}", region),
+            }
+        }
+    }
+}
+
+/// `car` is a UI mode & not the ISO 639 code of a language
+fn is_language(part: &str) -> bool {
+    let part = part.to_lowercase();
+    part != "car" && LANGUAGE_REGEX.is_match(&part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Locale;
+    use super::Qualifiers;
+
+    impl Qualifiers {
+        fn locale(&self) -> Option<&Locale> {
+            self.locale.as_ref()
+        }
+    }
+
+    impl Locale {
+        fn language(&self) -> &str {
+            &self.language
+        }
+
+        fn script(&self) -> Option<&str> {
+            self.script.as_deref()
+        }
+
+        fn region(&self) -> Option<&str> {
+            self.region.as_deref()
+        }
+    }
+
+    #[test]
+    fn parses_legacy_locale() {
+        let qualifiers = Qualifiers::from_values_dir_name("values-pt-rBR").unwrap();
+        let locale = qualifiers.locale().unwrap();
+        assert!(qualifiers.is_locale_only());
+        assert_eq!(locale.language(), "pt");
+        assert_eq!(locale.script(), None);
+        assert_eq!(locale.region(), Some("BR"));
+        assert_eq!(locale.to_string(), "pt-rBR");
+    }
+
+    #[test]
+    fn parses_bcp_47_locale() {
+        let qualifiers = Qualifiers::from_values_dir_name("values-b+sr+Latn+RS").unwrap();
+        let locale = qualifiers.locale().unwrap();
+        assert!(qualifiers.is_locale_only());
+        assert_eq!(locale.language(), "sr");
+        assert_eq!(locale.script(), Some("Latn"));
+        assert_eq!(locale.region(), Some("RS"));
+        assert_eq!(locale.to_string(), "b+sr+Latn+RS");
+    }
+
+    #[test]
+    fn parses_locale_along_with_other_configs() {
+        let qualifiers =
+            Qualifiers::from_values_dir_name("values-mcc310-mnc004-fr-rCA-ldrtl-night-v21")
+                .unwrap();
+        assert_eq!(qualifiers.mcc.as_deref(), Some("mcc310"));
+        assert_eq!(qualifiers.mnc.as_deref(), Some("mnc004"));
+        assert_eq!(qualifiers.locale().unwrap().to_string(), "fr-rCA");
+        assert_eq!(qualifiers.other_configs, vec!["ldrtl", "night"]);
+        assert_eq!(qualifiers.api_level, Some(21));
+        assert!(!qualifiers.is_locale_only());
+    }
+
+    #[test]
+    fn parses_configs_without_locale() {
+        for dir_name in &[
+            "values-night",
+            "values-land",
+            "values-car",
+            "values-sw600dp-v21",
+        ] {
+            let qualifiers = Qualifiers::from_values_dir_name(dir_name).unwrap();
+            assert_eq!(qualifiers.locale(), None, "{}", dir_name);
+            assert!(!qualifiers.is_locale_only(), "{}", dir_name);
+        }
+    }
+
+    #[test]
+    fn finds_pseudolocales() {
+        for locale in &["en-rXA", "ar-rXB", "b+en+XA", "b+ar+XB"] {
+            assert!(locale.parse::<Locale>().unwrap().is_pseudo(), "{}", locale);
+        }
+
+        for locale in &["en", "en-rGB", "ar-rXA", "fr-rXB", "b+en+Latn+XA"] {
+            assert!(!locale.parse::<Locale>().unwrap().is_pseudo(), "{}", locale);
+        }
+    }
+
+    #[test]
+    fn parses_default_values_dir() {
+        assert_eq!(
+            Qualifiers::from_values_dir_name("values"),
+            Some(Qualifiers::default())
+        )
+    }
+
+    #[test]
+    fn rejects_invalid_qualifiers() {
+        for dir_name in &[
+            "layout-fr",
+            "values_fr",
+            "values-",
+            "values-french",
+            "values-fr-rBRA",
+            "values-night-fr",
+            "values-night-land",
+            "values-v21-night",
+            "values-b+sr+Latinx+toolongvariant",
+        ] {
+            assert_eq!(
+                Qualifiers::from_values_dir_name(dir_name),
+                None,
+                "{}",
+                dir_name
+            );
+        }
+    }
+
+    #[test]
+    fn parses_locale_from_str() {
+        assert_eq!("fr".parse::<Locale>().unwrap().to_string(), "fr");
+        assert_eq!("zh-rTW".parse::<Locale>().unwrap().to_string(), "zh-rTW");
+        assert_eq!(
+            "b+es+419".parse::<Locale>().unwrap().to_string(),
+            "b+es+419"
+        );
+        assert_eq!(
+            "fr".parse::<Locale>().unwrap().values_dir_name(),
+            "values-fr"
+        );
+    }
+
+    #[test]
+    fn builds_language_tags() {
+        let language_tag_of = |locale: &str| locale.parse::<Locale>().unwrap().language_tag();
+        assert_eq!(language_tag_of("fr"), "fr");
+        assert_eq!(language_tag_of("pt-rBR"), "pt-BR");
+        assert_eq!(language_tag_of("b+sr+Latn"), "sr-Latn");
+        assert_eq!(language_tag_of("b+es+419"), "es-419");
+        assert_eq!(language_tag_of("b+de+CH+1901"), "de-CH-1901");
+        assert_eq!(language_tag_of("in"), "id");
+        assert_eq!(language_tag_of("iw-rIL"), "he-IL");
+    }
+
+    #[test]
+    fn errors_for_invalid_locale_from_str() {
+        assert_eq!(
+            "fr-night".parse::<Locale>().unwrap_err().to_string(),
+            "fr-night isn't a valid locale qualifier (Eg., fr, pt-rBR, b+sr+Latn)"
+        );
+        assert!("night".parse::<Locale>().is_err());
+    }
+}
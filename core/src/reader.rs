@@ -1,2 +1,12 @@
+pub mod arb_reader;
 pub mod csv_reader;
+pub mod document_reader;
+pub mod ios_reader;
+pub mod json_reader;
+pub mod po_reader;
+pub mod properties_reader;
+pub mod strings_reader;
+pub mod stringsdict_reader;
+pub mod xliff_reader;
+pub mod xlsx_reader;
 pub mod xml_reader;
@@ -0,0 +1,225 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::io::Read;
+use std::path::Path;
+
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::constants;
+use crate::error::Error;
+use crate::error::InnerError;
+use crate::error::ResultExt;
+use crate::localized_string::LocalizedString;
+use crate::localized_strings::LocalizedStrings;
+use crate::ops::arb;
+use crate::ops::arb::Placeholder;
+use crate::ops::keys;
+use crate::util::bundle_finder;
+
+/// Messages of an ARB file
+#[derive(Debug, PartialEq)]
+pub struct ArbFile {
+    locale: Option<String>,
+    messages: Vec<ArbMessage>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ArbMessage {
+    key: String,
+    value: String,
+    /// In the order they are declared in
+    placeholders: Vec<Placeholder>,
+}
+
+/// Reads a localized ARB file (`app_fr.arb`) along with the base file of its
+/// bundle (`app.arb`) that the defaults come from (look @ `bundle_finder`). The
+/// locale is picked off of `@@locale` (or the name of the file). Placeholders
+/// declared in the base file take precedence over the ones in the localized
+/// file (look @ `arb::to_android_value`). Default values are matched up with
+/// the default strings as plain text (look @ `extract`). Keys without a default
+/// or a translation are skipped
+pub fn read_bundle(file_path: &Path) -> Result<LocalizedStrings, Error> {
+    let bundle_file = bundle_finder::find(file_path)?;
+    let base_file = read_file(bundle_file.base_path())?;
+    let localized_file = read_file(file_path)?;
+
+    let strings = localized_file
+        .messages
+        .iter()
+        .filter(|message| !message.value.is_empty())
+        .filter_map(|message| {
+            let default = base_file.messages.iter().find(|m| m.key == message.key)?;
+            let placeholders = if default.placeholders.is_empty() {
+                &message.placeholders
+            } else {
+                &default.placeholders
+            };
+
+            let (module, name) = keys::split(&message.key);
+            Some(
+                LocalizedString::new(
+                    String::from(name),
+                    arb::to_android_value(&default.value, placeholders),
+                    arb::to_android_value(&message.value, placeholders),
+                )
+                .with_module(module),
+            )
+        })
+        .collect();
+
+    Ok(LocalizedStrings::new(
+        localized_file
+            .locale
+            .unwrap_or_else(|| String::from(bundle_file.locale())),
+        strings,
+    ))
+}
+
+fn read_file(file_path: &Path) -> Result<ArbFile, Error> {
+    let context = file_path.to_string_lossy();
+    read(File::open(file_path).with_context(context.clone())?).with_context(context)
+}
+
+/// Messages are in the order they are in the file. Anything other than string
+/// messages (& their metadata) is skipped
+pub fn read<S: Read>(source: S) -> Result<ArbFile, InnerError> {
+    let document = match serde_json::from_reader(BufReader::new(source))? {
+        Value::Object(document) => document,
+        _ => return Err("Expected an object with a key per message".into()),
+    };
+
+    let mut messages = vec![];
+    for (key, value) in &document {
+        if key.starts_with(constants::arb::METADATA_PREFIX) {
+            continue;
+        }
+
+        if let Value::String(value) = value {
+            messages.push(ArbMessage {
+                key: key.clone(),
+                value: value.clone(),
+                placeholders: read_placeholders(&document, key),
+            });
+        }
+    }
+
+    Ok(ArbFile {
+        locale: match document.get(constants::arb::LOCALE_KEY) {
+            Some(Value::String(locale)) => Some(locale.clone()),
+            _ => None,
+        },
+        messages,
+    })
+}
+
+fn read_placeholders(document: &Map<String, Value>, key: &str) -> Vec<Placeholder> {
+    let metadata_key = format!("{}{}", constants::arb::METADATA_PREFIX, key);
+    let placeholders = document
+        .get(&metadata_key)
+        .and_then(|metadata| metadata.get(constants::arb::PLACEHOLDERS))
+        .and_then(Value::as_object);
+
+    placeholders
+        .into_iter()
+        .flatten()
+        .map(|(name, placeholder)| {
+            let placeholder_type = placeholder
+                .get(constants::arb::TYPE)
+                .and_then(Value::as_str)
+                .unwrap_or(constants::arb::STRING_TYPE);
+            Placeholder::new(name.clone(), String::from(placeholder_type))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::localized_string::LocalizedString;
+    use crate::localized_strings::LocalizedStrings;
+    use crate::ops::arb::Placeholder;
+
+    use super::ArbFile;
+    use super::ArbMessage;
+
+    #[test]
+    fn reads_messages_with_placeholders() {
+        let arb_file = super::read(
+            r#"{
+  "@@locale": "fr",
+  "s1": "{name} a {count} messages",
+  "@s1": {
+    "description": "Inbox",
+    "placeholders": { "count": { "type": "int" }, "name": {} }
+  },
+  "@@last_modified": "2026-10-17",
+  "s2": "deux"
+}"#
+            .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            arb_file,
+            ArbFile {
+                locale: Some(String::from("fr")),
+                messages: vec![
+                    ArbMessage {
+                        key: String::from("s1"),
+                        value: String::from("{name} a {count} messages"),
+                        placeholders: vec![
+                            Placeholder::new(String::from("count"), String::from("int")),
+                            Placeholder::new(String::from("name"), String::from("String")),
+                        ],
+                    },
+                    ArbMessage {
+                        key: String::from("s2"),
+                        value: String::from("deux"),
+                        placeholders: vec![],
+                    },
+                ],
+            }
+        )
+    }
+
+    #[test]
+    fn reads_bundle_with_defaults() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("app_en.arb"),
+            r#"{
+  "s1": "{name} has {count} messages",
+  "@s1": { "placeholders": { "count": { "type": "int" }, "name": {} } },
+  "lib/s2": "two",
+  "s3": "three"
+}"#,
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("app_en_fr.arb"),
+            r#"{
+  "@@locale": "french",
+  "s1": "{name} a {count} messages",
+  "lib/s2": "deux",
+  "s3": "",
+  "s4": "quatre"
+}"#,
+        )
+        .unwrap();
+
+        let localized_strings = super::read_bundle(&temp_dir.path().join("app_en_fr.arb")).unwrap();
+        assert_eq!(
+            localized_strings,
+            LocalizedStrings::build(
+                "french",
+                vec![
+                    LocalizedString::build("s1", "%2$s has %1$d messages", "%2$s a %1$d messages"),
+                    LocalizedString::build("s2", "two", "deux")
+                        .with_module(Some(String::from("lib"))),
+                ]
+            )
+        )
+    }
+}
@@ -6,29 +6,89 @@ use csv::ReaderBuilder;
 
 use android_localization_utilities::DevExpt;
 
+use crate::constants;
+use crate::csv_dialect::CsvDialect;
 use crate::error::InnerError;
 use crate::localized_string::LocalizedString;
 use crate::localized_strings::LocalizedStrings;
+use crate::ops::formula;
 
+/// Only the delimiter & the formula safe mode of the dialect matter. The encoding
+/// is figured out from the BOM (look @ `decode`) & quoted fields are always read
+/// as such
 pub fn read<S: Read>(
-    source: S,
+    mut source: S,
+    dialect: CsvDialect,
     allow_only_locales: HashSet<String>,
 ) -> Result<Vec<LocalizedStrings>, InnerError> {
+    let mut bytes = vec![];
+    source.read_to_end(&mut bytes)?;
+    let content = decode(bytes)?;
+
     let mut reader = ReaderBuilder::new()
         .has_headers(true) // To treat first row specially
         .flexible(false) // Takes care of making sure that all records are of the same size
-        .trim(csv::Trim::All) // To skip whitespace around commas
-        .from_reader(source); // Read is automatically buffered
+        .trim(csv::Trim::All) // To skip whitespace around delimiters
+        .delimiter(dialect.delimiter())
+        .from_reader(content.as_bytes());
+
+    let headers = reader.headers()?.clone();
+    read_records(
+        &headers,
+        reader.records().map(|record| match record {
+            Ok(record) if dialect.formula_safe() => {
+                Ok(record.iter().map(formula::unescape).collect())
+            }
+            Ok(record) => Ok(record),
+            Err(error) => Err(InnerError::from(error)),
+        }),
+        allow_only_locales,
+    )
+}
 
+/// Files with a UTF-16 BOM (either endianness) are read as UTF-16. Everything
+/// else is read as UTF-8 (with the BOM, if any, stripped)
+fn decode(bytes: Vec<u8>) -> Result<String, InnerError> {
+    let decode_utf16 = |bytes: &[u8], from_bytes: fn([u8; 2]) -> u16| {
+        let units: Vec<u16> = bytes
+            .chunks(2)
+            .map(|pair| from_bytes([pair[0], *pair.get(1).unwrap_or(&0)]))
+            .collect();
+        String::from_utf16(&units).map_err(|_| InnerError::from("Invalid UTF-16 in CSV file"))
+    };
+
+    if bytes.starts_with(constants::csv::UTF16_LE_BOM) {
+        decode_utf16(&bytes[2..], u16::from_le_bytes)
+    } else if bytes.starts_with(constants::csv::UTF16_BE_BOM) {
+        decode_utf16(&bytes[2..], u16::from_be_bytes)
+    } else if bytes.starts_with(constants::csv::UTF8_BOM) {
+        String::from_utf8(bytes[3..].to_vec())
+            .map_err(|_| InnerError::from("Invalid UTF-8 in CSV file"))
+    } else {
+        String::from_utf8(bytes).map_err(|_| InnerError::from("Invalid UTF-8 in CSV file"))
+    }
+}
+
+/// Records are expected to be of the same size as the headers. Look @
+/// `xlsx_reader` for the other format laid out the same way
+pub fn read_records<I: Iterator<Item = Result<csv::StringRecord, InnerError>>>(
+    headers: &csv::StringRecord,
+    records: I,
+    allow_only_locales: HashSet<String>,
+) -> Result<Vec<LocalizedStrings>, InnerError> {
     // Get foreign_locales
-    let filtered_headers = extract_filtered_headers(reader.headers()?, allow_only_locales)?;
+    let filtered_headers = extract_filtered_headers(headers, allow_only_locales)?;
     let mut localized_strings_list: Vec<Vec<LocalizedString>> =
         vec![Vec::new(); filtered_headers.foreign_locales.len()];
 
     // Extract localized record
-    for record in reader.records() {
-        let localized_record =
-            extract_localized_record(&record?, &filtered_headers.foreign_indices_allow_flags)?;
+    for record in records {
+        let localized_record = extract_localized_record(
+            &record?,
+            filtered_headers.has_modules,
+            &filtered_headers.foreign_indices_allow_flags,
+        )?;
+        let module = localized_record.module.clone();
         let string_name = localized_record.string_name.clone();
         let default_value = localized_record.default_value.clone();
 
@@ -41,11 +101,10 @@ pub fn read<S: Read>(
                     .get_mut(index)
                     .expect("Oops! Something is wrong");
 
-                localized_strings.push(LocalizedString::new(
-                    string_name.clone(),
-                    default_value.clone(),
-                    foreign_value,
-                ))
+                localized_strings.push(
+                    LocalizedString::new(string_name.clone(), default_value.clone(), foreign_value)
+                        .with_module(module.clone()),
+                )
             }
         }
     }
@@ -62,11 +121,22 @@ fn extract_filtered_headers(
     record: &csv::StringRecord,
     allow_only_locales: HashSet<String>,
 ) -> Result<FilteredHeaders, InnerError> {
-    if record.len() < 3 {
-        return Err("Too few values in header (at least 3 required)".into());
+    // Exports of projects (look @ `ResDir`) lead with a module column
+    let has_modules = record.get(0) == Some("module");
+    let min_header_count = if has_modules { 4 } else { 3 };
+    if record.len() < min_header_count {
+        return Err(format!(
+            "Too few values in header (at least {} required)",
+            min_header_count
+        )
+        .into());
     }
 
     let mut iterator = record.into_iter();
+    if has_modules {
+        iterator.next();
+    }
+
     let header1 = iterator
         .next()
         .expt("Already checked the length but still fails!");
@@ -86,7 +156,15 @@ fn extract_filtered_headers(
     let mut foreign_locales = vec![];
     for foriegn_locale in iterator {
         let foreign_locale = String::from(foriegn_locale);
-        let allow_index = allow_only_locales.contains(&foreign_locale);
+        // Context for translators (look @ `csv_writer::Writer::write`) isn't
+        // a locale
+        let is_context = foreign_locale == constants::csv::DESCRIPTION_HEADER
+            || foreign_locale == constants::csv::MAX_LENGTH_HEADER
+            || foreign_locale == constants::csv::STATUS_HEADER
+            || foreign_locale.ends_with(constants::csv::OLD_VALUES_HEADER_SUFFIX)
+            || foreign_locale.ends_with(constants::csv::SUGGESTIONS_HEADER_SUFFIX)
+            || foreign_locale.ends_with(constants::csv::DRAFTS_HEADER_SUFFIX);
+        let allow_index = !is_context && allow_only_locales.contains(&foreign_locale);
         foreign_indices_allow_flags.push(allow_index);
         if allow_index {
             foreign_locales.push(foreign_locale);
@@ -94,6 +172,7 @@ fn extract_filtered_headers(
     }
 
     Ok(FilteredHeaders {
+        has_modules,
         foreign_locales,
         foreign_indices_allow_flags,
     })
@@ -101,11 +180,21 @@ fn extract_filtered_headers(
 
 fn extract_localized_record(
     record: &csv::StringRecord,
+    has_modules: bool,
     foreign_indices_allow_flags: &[bool],
 ) -> Result<LocalizedRecord, InnerError> {
     // Since `ReaderBuilder` is set to be not flexible, we can be sure
     // that the this record is going to be as long as the headers record
     let mut iterator = record.into_iter();
+    let module = if has_modules {
+        match iterator.next() {
+            Some(module) if !module.is_empty() => Some(String::from(module)),
+            _ => return Err("module can't be empty for any record".into()),
+        }
+    } else {
+        None
+    };
+
     let string_name = iterator.next().unwrap_or("");
     let default_value = iterator.next().unwrap_or("");
 
@@ -121,6 +210,7 @@ fn extract_localized_record(
     }
 
     Ok(LocalizedRecord {
+        module,
         string_name: String::from(string_name),
         default_value: String::from(default_value),
         foreign_values,
@@ -128,12 +218,14 @@ fn extract_localized_record(
 }
 
 struct FilteredHeaders {
+    has_modules: bool,
     foreign_locales: Vec<String>,
     foreign_indices_allow_flags: Vec<bool>,
 }
 
 #[derive(Debug)]
 struct LocalizedRecord {
+    module: Option<String>,
     string_name: String,
     default_value: String,
     foreign_values: Vec<String>,
@@ -146,6 +238,7 @@ mod tests {
 
     use test_utilities;
 
+    use crate::csv_dialect::CsvDialect;
     use crate::error::InnerError;
     use crate::localized_string::LocalizedString;
     use crate::localized_strings::LocalizedStrings;
@@ -178,6 +271,70 @@ mod tests {
         )
     }
 
+    #[test]
+    fn reads_modules_of_strings() {
+        let strings_list = read_strings_from_file(
+            r#"module, string_name, default_locale, french
+            app/src/main/res, string_1, english 1, french 1
+            lib/src/main/res, string_1, english 1, french 1"#,
+            vec!["french"],
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            strings_list,
+            vec![LocalizedStrings::build(
+                "french",
+                vec![
+                    LocalizedString::build("string_1", "english 1", "french 1")
+                        .with_module(Some(String::from("app/src/main/res"))),
+                    LocalizedString::build("string_1", "english 1", "french 1")
+                        .with_module(Some(String::from("lib/src/main/res"))),
+                ],
+            )],
+        )
+    }
+
+    #[test]
+    fn skips_context_of_strings() {
+        let strings_list = read_strings_from_file(
+            r#"string_name, default_locale, description, max_length, status, french, french (old), french (suggestion), french (draft)
+            string_1, english 1, Used on the checkout button, 20, changed, french 1, old french 1, , drafted french 1
+            string_2, english 2, , , new, french 2, , suggested french 2, "#,
+            vec![
+                "description",
+                "max_length",
+                "status",
+                "french",
+                "french (old)",
+                "french (suggestion)",
+                "french (draft)",
+            ],
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            strings_list,
+            vec![LocalizedStrings::build(
+                "french",
+                vec![
+                    LocalizedString::build("string_1", "english 1", "french 1"),
+                    LocalizedString::build("string_2", "english 2", "french 2"),
+                ],
+            )],
+        )
+    }
+
+    #[test]
+    fn errors_if_module_is_empty() {
+        let error = read_strings_from_file(
+            "module, string_name, default_locale, french\n, a, b, c",
+            vec!["french"],
+        )
+        .unwrap_err();
+        assert_eq!(error.to_string(), "module can't be empty for any record");
+    }
+
     #[test]
     fn errors_if_enough_header_values_are_not_as_expected() {
         let error =
@@ -221,13 +378,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reads_strings_in_dialects() {
+        let expected = vec![LocalizedStrings::build(
+            "french",
+            vec![LocalizedString::build("string_1", "english; 1", "café")],
+        )];
+
+        let content = "string_name;default_locale;french\nstring_1;\"english; 1\";café";
+        let mut utf8_with_bom = vec![0xEF, 0xBB, 0xBF];
+        utf8_with_bom.extend_from_slice(content.as_bytes());
+        let mut utf16_le = vec![0xFF, 0xFE];
+        let mut utf16_be = vec![0xFE, 0xFF];
+        for unit in content.encode_utf16() {
+            utf16_le.extend_from_slice(&unit.to_le_bytes());
+            utf16_be.extend_from_slice(&unit.to_be_bytes());
+        }
+
+        for bytes in &[utf8_with_bom, utf16_le, utf16_be] {
+            test_utilities::list::assert_strict_list_eq(
+                read_strings_from_bytes(
+                    bytes,
+                    CsvDialect::default().with_delimiter(b';'),
+                    vec!["french"],
+                )
+                .unwrap(),
+                expected.clone(),
+            )
+        }
+    }
+
+    #[test]
+    fn reads_formula_safe_strings() {
+        let strings_list = read_strings_from_bytes(
+            b"string_name,default_locale,french\nstring_1,'=1+1,'=2+2\nstring_2,'a,'-b",
+            CsvDialect::default().with_formula_safe(true),
+            vec!["french"],
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            strings_list,
+            vec![LocalizedStrings::build(
+                "french",
+                vec![
+                    LocalizedString::build("string_1", "=1+1", "=2+2"),
+                    LocalizedString::build("string_2", "'a", "-b"),
+                ],
+            )],
+        )
+    }
+
+    #[test]
+    fn errors_if_file_is_not_utf8() {
+        let error = read_strings_from_bytes(
+            b"string_name,default_locale,french\nstring_1,caf\xe9,",
+            CsvDialect::default(),
+            vec!["french"],
+        )
+        .unwrap_err();
+        assert_eq!(error.to_string(), "Invalid UTF-8 in CSV file");
+    }
+
     fn read_strings_from_file(
         file_content: &str,
         allow_only_locales: Vec<&str>,
+    ) -> Result<Vec<LocalizedStrings>, InnerError> {
+        read_strings_from_bytes(
+            file_content.as_bytes(),
+            CsvDialect::default(),
+            allow_only_locales,
+        )
+    }
+
+    fn read_strings_from_bytes(
+        file_content: &[u8],
+        dialect: CsvDialect,
+        allow_only_locales: Vec<&str>,
     ) -> Result<Vec<LocalizedStrings>, InnerError> {
         // Write content to file
         let mut tmpfile: File = tempfile::tempfile().unwrap();
-        tmpfile.write_all(file_content.as_bytes()).unwrap();
+        tmpfile.write_all(file_content).unwrap();
 
         // Seek to start
         tmpfile.seek(SeekFrom::Start(0)).unwrap();
@@ -235,6 +466,7 @@ mod tests {
         // Read strings from file
         super::read(
             tmpfile.try_clone().unwrap(),
+            dialect,
             allow_only_locales.into_iter().map(String::from).collect(),
         )
     }
@@ -0,0 +1,305 @@
+use std::io::Read;
+
+use crate::constants;
+use crate::error::InnerError;
+use crate::strings_document::Node;
+use crate::strings_document::StringsDocument;
+
+/// Reads the strings file into a document that holds on to every byte of the
+/// file. It is expected that the content is well formed XML (parse it through
+/// `xml_reader` to make sure) since this only splits up the content of the root
+/// element into its direct children
+pub fn read<S: Read>(mut source: S) -> Result<StringsDocument, InnerError> {
+    let mut content = String::new();
+    source.read_to_string(&mut content)?;
+
+    // Everything till the end of the root's start tag goes into the prolog
+    let mut index = 0;
+    let (root_tag_name, root_end_index) = loop {
+        if index >= content.len() {
+            return Err("Strings file doesn't have a root element".into());
+        }
+
+        let token_start_index = index;
+        let (token, token_end_index) = next_token(&content, index)?;
+        index = token_end_index;
+        if let Token::StartTag {
+            tag_name,
+            is_self_closing,
+            ..
+        } = token
+        {
+            if is_self_closing {
+                // Open up the root to make room for children
+                let start_tag = &content[token_start_index..token_end_index];
+                let start_tag = start_tag[..start_tag.len() - 2].trim_end();
+                return Ok(StringsDocument::new(
+                    format!("{}{}>", &content[..token_start_index], start_tag),
+                    vec![],
+                    format!("</{}>{}", tag_name, &content[token_end_index..]),
+                ));
+            }
+
+            break (tag_name, token_end_index);
+        }
+    };
+
+    let mut nodes = vec![];
+    let mut raw_start_index = root_end_index;
+    let mut depth = 0;
+    let mut current_element: Option<(String, Option<String>, usize)> = None;
+    loop {
+        if index >= content.len() {
+            return Err(format!("{} element isn't closed", root_tag_name).into());
+        }
+
+        let token_start_index = index;
+        let (token, token_end_index) = next_token(&content, index)?;
+        index = token_end_index;
+        match token {
+            Token::Other => {}
+            Token::StartTag {
+                tag_name,
+                name,
+                is_self_closing,
+            } => {
+                if depth == 0 {
+                    push_raw(&mut nodes, &content[raw_start_index..token_start_index]);
+                    if is_self_closing {
+                        nodes.push(Node::Element {
+                            tag_name,
+                            name,
+                            markup: String::from(&content[token_start_index..token_end_index]),
+                        });
+                        raw_start_index = token_end_index;
+                    } else {
+                        current_element = Some((tag_name, name, token_start_index));
+                        depth = 1;
+                    }
+                } else if !is_self_closing {
+                    depth += 1;
+                }
+            }
+
+            Token::EndTag => {
+                if depth == 0 {
+                    // Root is being closed
+                    push_raw(&mut nodes, &content[raw_start_index..token_start_index]);
+                    return Ok(StringsDocument::new(
+                        String::from(&content[..root_end_index]),
+                        nodes,
+                        String::from(&content[token_start_index..]),
+                    ));
+                }
+
+                depth -= 1;
+                if depth == 0 {
+                    if let Some((tag_name, name, element_start_index)) = current_element.take() {
+                        nodes.push(Node::Element {
+                            tag_name,
+                            name,
+                            markup: String::from(&content[element_start_index..token_end_index]),
+                        });
+                    }
+
+                    raw_start_index = token_end_index;
+                }
+            }
+        }
+    }
+}
+
+enum Token {
+    StartTag {
+        tag_name: String,
+        name: Option<String>,
+        is_self_closing: bool,
+    },
+    EndTag,
+    /// Text, comments, CDATA, processing instructions & declarations
+    Other,
+}
+
+/// Returns the token starting at `start_index` along with the index at which it ends
+fn next_token(content: &str, start_index: usize) -> Result<(Token, usize), InnerError> {
+    let rest = &content[start_index..];
+    if !rest.starts_with('<') {
+        let length = rest.find('<').unwrap_or(rest.len());
+        return Ok((Token::Other, start_index + length));
+    }
+
+    let delimited_tokens = [("<!--", "-->"), ("<![CDATA[", "]]>"), ("<?", "?>")];
+    for (start_delimiter, end_delimiter) in delimited_tokens.iter() {
+        if let Some(delimited) = rest.strip_prefix(start_delimiter) {
+            return match delimited.find(end_delimiter) {
+                None => Err(format!("{} isn't closed", start_delimiter).into()),
+                Some(index) => Ok((
+                    Token::Other,
+                    start_index + start_delimiter.len() + index + end_delimiter.len(),
+                )),
+            };
+        }
+    }
+
+    let length = find_tag_end(rest)?;
+    let tag = &rest[..length];
+    if tag.starts_with("<!") {
+        Ok((Token::Other, start_index + length))
+    } else if tag.starts_with("</") {
+        Ok((Token::EndTag, start_index + length))
+    } else {
+        let tag_name_length = tag[1..]
+            .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+            .unwrap_or_else(|| tag.len() - 1);
+
+        Ok((
+            Token::StartTag {
+                tag_name: String::from(&tag[1..=tag_name_length]),
+                name: find_name_attribute(&tag[tag_name_length + 1..]),
+                is_self_closing: tag.ends_with("/>"),
+            },
+            start_index + length,
+        ))
+    }
+}
+
+/// Returns the length of the tag at the start of `rest`. `>` in quoted attribute
+/// values & in the internal subset of doctype declarations are skipped over
+fn find_tag_end(rest: &str) -> Result<usize, InnerError> {
+    let mut open_quote = None;
+    let mut is_in_internal_subset = false;
+    for (index, c) in rest.char_indices() {
+        match open_quote {
+            Some(quote) if c == quote => open_quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => open_quote = Some(c),
+                '[' => is_in_internal_subset = true,
+                ']' => is_in_internal_subset = false,
+                '>' if !is_in_internal_subset => return Ok(index + 1),
+                _ => {}
+            },
+        }
+    }
+
+    Err("Tag isn't closed".into())
+}
+
+fn find_name_attribute(attributes: &str) -> Option<String> {
+    let mut rest = attributes;
+    loop {
+        rest = rest.trim_start();
+        let attribute_name_length = rest.find(|c: char| c == '=' || c.is_whitespace())?;
+        let attribute_name = &rest[..attribute_name_length];
+        rest = rest[attribute_name_length..].trim_start();
+        rest = rest.strip_prefix('=')?.trim_start();
+
+        let quote = rest.chars().next()?;
+        if quote != '"' && quote != '\'' {
+            return None;
+        }
+
+        let value_length = rest[1..].find(quote)?;
+        if attribute_name == constants::attributes::NAME {
+            return Some(unescape(&rest[1..=value_length]));
+        }
+
+        rest = &rest[value_length + 2..];
+    }
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn push_raw(nodes: &mut Vec<Node>, text: &str) {
+    if !text.is_empty() {
+        nodes.push(Node::Raw(String::from(text)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::strings_document::Node;
+    use crate::strings_document::StringsDocument;
+
+    #[test]
+    fn reads_every_byte_of_the_file() {
+        let content = r##"<?xml version="1.0" encoding="utf-8"?>
+<!-- Header comment -->
+<resources xmlns:tools="http://schemas.android.com/tools">
+    <!-- Comment with <string name="fake">fake</string> -->
+    <string name="s1" tools:ignore="MissingTranslation">a <![CDATA[<b>x</b>]]> &amp; b</string>
+    <plurals name="p1">
+        <item quantity="one">one</item>
+    </plurals>
+    <unknown attr='a > b'><nested/></unknown>
+    <string name="s2"/>
+</resources>
+"##;
+
+        let document = super::read(content.as_bytes()).unwrap();
+        assert_eq!(document.to_string(), content);
+    }
+
+    #[test]
+    fn splits_children_of_root_into_nodes() {
+        let document = super::read(
+            "<resources>\n  <string name='a&amp;b'>v</string><!-- c --><skip/>\n</resources>"
+                .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            document,
+            StringsDocument::new(
+                String::from("<resources>"),
+                vec![
+                    Node::Raw(String::from("\n  ")),
+                    Node::Element {
+                        tag_name: String::from("string"),
+                        name: Some(String::from("a&b")),
+                        markup: String::from("<string name='a&amp;b'>v</string>"),
+                    },
+                    Node::Raw(String::from("<!-- c -->")),
+                    Node::Element {
+                        tag_name: String::from("skip"),
+                        name: None,
+                        markup: String::from("<skip/>"),
+                    },
+                    Node::Raw(String::from("\n")),
+                ],
+                String::from("</resources>"),
+            )
+        );
+    }
+
+    #[test]
+    fn opens_up_self_closing_root() {
+        let mut document =
+            super::read("<?xml version=\"1.0\"?>\n<resources />\n".as_bytes()).unwrap();
+        document.upsert(
+            "string",
+            "s1",
+            String::from("<string name=\"s1\">v</string>"),
+        );
+
+        assert_eq!(
+            document.to_string(),
+            "<?xml version=\"1.0\"?>\n<resources>\n    <string name=\"s1\">v</string>\n</resources>\n"
+        );
+    }
+
+    #[test]
+    fn errors_for_unclosed_root() {
+        let error = super::read("<resources><string name=\"s1\">v</string>".as_bytes())
+            .err()
+            .unwrap();
+        assert_eq!(error.to_string(), "resources element isn't closed");
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::constants;
+use crate::error::Error;
+use crate::error::ResultExt;
+use crate::localized_string::LocalizedString;
+use crate::localized_strings::LocalizedStrings;
+use crate::ops::ios;
+use crate::ops::keys;
+use crate::reader::strings_reader;
+use crate::reader::stringsdict_reader;
+
+/// Reads the `.strings` & `.stringsdict` files (with the same name as the one
+/// at `file_path`) of a `<locale>.lproj` dir. The locale is the name of the
+/// dir. Defaults come from the same files in the sibling `Base.lproj` (or
+/// `en.lproj`) dir. Values are converted into Android ones (look @
+/// `ios::to_android_value`). Since the conversion can't bring back the escapes
+/// & entities of the default values, these are matched up with the default
+/// strings as plain text (look @ `extract`). Keys without a default or a
+/// translation are skipped
+pub fn read(file_path: &Path) -> Result<LocalizedStrings, Error> {
+    let context = file_path.to_string_lossy();
+    let lproj_dir = file_path
+        .parent()
+        .filter(|dir| {
+            dir.extension().and_then(|extension| extension.to_str())
+                == Some(constants::ios::LPROJ_EXTENSION)
+        })
+        .ok_or_else(|| Error::new(context.clone(), "File isn't in a .lproj dir"))?;
+
+    let locale = lproj_dir
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .ok_or_else(|| Error::new(context.clone(), "Locale can't be figured out"))?;
+
+    let file_stem = file_path
+        .file_stem()
+        .ok_or_else(|| Error::new(context.clone(), "File doesn't have a name"))?;
+
+    let default_lproj_dir = [
+        constants::ios::BASE_LPROJ_NAME,
+        constants::ios::DEFAULT_LPROJ_NAME,
+    ]
+    .iter()
+    .map(|name| lproj_dir.with_file_name(format!("{}.{}", name, constants::ios::LPROJ_EXTENSION)))
+    .find(|dir| dir.is_dir())
+    .ok_or_else(|| Error::new(context.clone(), "Neither Base.lproj nor en.lproj exists"))?;
+
+    let defaults: HashMap<String, String> = read_entries(&default_lproj_dir, file_stem)?
+        .into_iter()
+        .collect();
+
+    let strings = read_entries(lproj_dir, file_stem)?
+        .into_iter()
+        .filter(|(_, localized)| !localized.is_empty())
+        .filter_map(|(key, localized)| {
+            let default = defaults.get(&key)?;
+            let (module, name) = keys::split(&key);
+            Some(
+                LocalizedString::new(
+                    String::from(name),
+                    ios::to_android_value(default),
+                    ios::to_android_value(&localized),
+                )
+                .with_module(module),
+            )
+        })
+        .collect();
+
+    Ok(LocalizedStrings::new(locale, strings))
+}
+
+/// Entries of both the `.strings` & `.stringsdict` files (if they exist)
+fn read_entries(
+    lproj_dir: &Path,
+    file_stem: &::std::ffi::OsStr,
+) -> Result<Vec<(String, String)>, Error> {
+    let mut entries = vec![];
+    let file_path = |extension: &str| -> PathBuf {
+        let mut file_path = lproj_dir.join(file_stem);
+        file_path.set_extension(extension);
+        file_path
+    };
+
+    let strings_path = file_path(constants::extn::STRINGS);
+    if strings_path.is_file() {
+        let context = strings_path.to_string_lossy();
+        let file = File::open(&strings_path).with_context(context.clone())?;
+        entries.extend(strings_reader::read(file).with_context(context)?);
+    }
+
+    let stringsdict_path = file_path(constants::extn::STRINGSDICT);
+    if stringsdict_path.is_file() {
+        let context = stringsdict_path.to_string_lossy();
+        let file = File::open(&stringsdict_path).with_context(context.clone())?;
+        entries.extend(stringsdict_reader::read(file).with_context(context)?);
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::localized_string::LocalizedString;
+    use crate::localized_strings::LocalizedStrings;
+
+    #[test]
+    fn reads_localized_strings_with_defaults() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let base_dir = temp_dir.path().join("Base.lproj");
+        let fr_dir = temp_dir.path().join("fr.lproj");
+        fs::create_dir(&base_dir).unwrap();
+        fs::create_dir(&fr_dir).unwrap();
+        fs::write(
+            base_dir.join("Localizable.strings"),
+            "\"s1\" = \"Don't %@\";\n\"lib/s2\" = \"two\";\n\"s3\" = \"three\";",
+        )
+        .unwrap();
+        fs::write(
+            fr_dir.join("Localizable.strings"),
+            "\"s1\" = \"N'%@ pas\";\n\"lib/s2\" = \"deux\";\n\"s3\" = \"\";\n\"s4\" = \"quatre\";",
+        )
+        .unwrap();
+
+        let localized_strings = super::read(&fr_dir.join("Localizable.strings")).unwrap();
+        assert_eq!(
+            localized_strings,
+            LocalizedStrings::build(
+                "fr",
+                vec![
+                    LocalizedString::build("s1", r"Don\'t %s", r"N\'%s pas"),
+                    LocalizedString::build("s2", "two", "deux")
+                        .with_module(Some(String::from("lib"))),
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn errors_if_not_in_lproj_dir() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("Localizable.strings");
+        fs::write(&file_path, "").unwrap();
+
+        let error = super::read(&file_path).unwrap_err();
+        assert!(error.to_string().ends_with("File isn't in a .lproj dir"));
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+use std::io::BufReader;
+use std::io::Read;
+
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::constants;
+use crate::error::InnerError;
+use crate::localized_string::LocalizedString;
+use crate::localized_strings::LocalizedStrings;
+use crate::ops::keys;
+
+/// Reads a JSON document (like the one written by `json_writer`). Every top
+/// level object is for the locale in its key. Objects with a `target` are
+/// strings & every other object is a module (nested or not; `{ "a/b": {} }` &
+/// `{ "a": { "b": {} } }` are the same). Keys of strings can carry their module
+/// too (`"a/b/s1"`). Strings without a `target` value are skipped
+pub fn read<S: Read>(
+    source: S,
+    allow_only_locales: HashSet<String>,
+) -> Result<Vec<LocalizedStrings>, InnerError> {
+    let document = match serde_json::from_reader(BufReader::new(source))? {
+        Value::Object(document) => document,
+        _ => return Err("Expected an object with a key per locale".into()),
+    };
+
+    let mut localized_strings_list = vec![];
+    for (locale, value) in document {
+        if !allow_only_locales.contains(&locale) {
+            continue;
+        }
+
+        let mut strings = vec![];
+        match value {
+            Value::Object(object) => read_object(&object, None, &mut strings)?,
+            _ => return Err(format!("{} must be an object", locale).into()),
+        }
+
+        if !strings.is_empty() {
+            localized_strings_list.push(LocalizedStrings::new(locale, strings));
+        }
+    }
+
+    Ok(localized_strings_list)
+}
+
+fn read_object(
+    object: &Map<String, Value>,
+    path: Option<&str>,
+    strings: &mut Vec<LocalizedString>,
+) -> Result<(), InnerError> {
+    for (key, value) in object {
+        let key_path = match path {
+            None => key.clone(),
+            Some(path) => format!("{}{}{}", path, constants::keys::MODULE_SEPARATOR, key),
+        };
+
+        let child = match value {
+            Value::Object(child) => child,
+            _ => return Err(format!("{} must be an object", key_path).into()),
+        };
+
+        if !child.contains_key(constants::json::TARGET) {
+            read_object(child, Some(&key_path), strings)?;
+            continue;
+        }
+
+        let target = find_string(child, constants::json::TARGET, &key_path)?;
+        let source = find_string(child, constants::json::SOURCE, &key_path)?;
+        if !target.is_empty() {
+            let (module, name) = keys::split(&key_path);
+            strings
+                .push(LocalizedString::new(String::from(name), source, target).with_module(module));
+        }
+    }
+
+    Ok(())
+}
+
+fn find_string(
+    object: &Map<String, Value>,
+    key: &str,
+    key_path: &str,
+) -> Result<String, InnerError> {
+    match object.get(key) {
+        Some(Value::String(value)) => Ok(value.clone()),
+        Some(Value::Null) if key == constants::json::TARGET => Ok(String::new()),
+        _ => Err(format!("{} of {} must be a string", key, key_path).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::localized_string::LocalizedString;
+    use crate::localized_strings::LocalizedStrings;
+
+    #[test]
+    fn reads_nested_and_flat_strings() {
+        let strings_list = super::read(
+            r#"{
+  "french": {
+    "s1": { "source": "one", "target": "un" },
+    "s2": { "source": "two", "target": "" },
+    "app": {
+      "src/main/res": {
+        "s3": { "source": "three", "target": "trois" }
+      }
+    },
+    "lib/src/main/res/s4": { "source": "four", "target": "quatre" }
+  },
+  "german": {
+    "s1": { "source": "one", "target": "eins" }
+  },
+  "spanish": {
+    "s2": { "source": "two", "target": null }
+  }
+}"#
+            .as_bytes(),
+            vec!["french", "spanish"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            strings_list,
+            vec![LocalizedStrings::build(
+                "french",
+                vec![
+                    LocalizedString::build("s1", "one", "un"),
+                    LocalizedString::build("s3", "three", "trois")
+                        .with_module(Some(String::from("app/src/main/res"))),
+                    LocalizedString::build("s4", "four", "quatre")
+                        .with_module(Some(String::from("lib/src/main/res"))),
+                ],
+            )],
+        )
+    }
+
+    #[test]
+    fn errors_for_missing_source() {
+        let error = super::read(
+            r#"{ "french": { "app": { "s1": { "target": "un" } } } }"#.as_bytes(),
+            vec![String::from("french")].into_iter().collect(),
+        )
+        .unwrap_err();
+
+        assert_eq!(error.to_string(), "source of app/s1 must be a string");
+    }
+}
@@ -0,0 +1,276 @@
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+
+use crate::constants;
+use crate::constants::po;
+use crate::error::InnerError;
+use crate::localized_string::LocalizedString;
+use crate::localized_strings::LocalizedStrings;
+
+/// Reads a PO file (like the ones written by `po_writer`) for the locale in
+/// its `Language` header (a language code like `pt_BR` or `pt-BR`, which is
+/// mapped to the locale by the caller). Fuzzy & untranslated entries are
+/// skipped. `msgctxt` carries the name of the string (prefixed with its module
+/// if it has one)
+pub fn read<S: Read>(source: S) -> Result<LocalizedStrings, InnerError> {
+    let entries = read_entries(source)?;
+    let locale = entries
+        .iter()
+        .find(|entry| entry.is_header())
+        .and_then(|header| find_language(header.string.as_deref().unwrap_or_default()))
+        .ok_or("PO file must have a Language header")?;
+
+    let mut strings = vec![];
+    for entry in entries {
+        if entry.is_header() || entry.is_fuzzy {
+            continue;
+        }
+
+        let id = entry.id.unwrap_or_default();
+        let string = entry.string.unwrap_or_default();
+        if string.is_empty() {
+            continue;
+        }
+
+        let context = entry.context.ok_or_else(|| {
+            format!(
+                "msgid \"{}\" doesn't have a msgctxt with the name of the string",
+                id
+            )
+        })?;
+
+        let (module, name) = match context.rfind(constants::keys::MODULE_SEPARATOR) {
+            None => (None, context.as_str()),
+            Some(index) => (Some(String::from(&context[..index])), &context[index + 1..]),
+        };
+
+        strings.push(LocalizedString::new(String::from(name), id, string).with_module(module));
+    }
+
+    Ok(LocalizedStrings::new(locale, strings))
+}
+
+#[derive(Default)]
+struct Entry {
+    is_fuzzy: bool,
+    context: Option<String>,
+    id: Option<String>,
+    string: Option<String>,
+}
+
+impl Entry {
+    fn is_header(&self) -> bool {
+        self.context.is_none() && self.id.as_deref() == Some("")
+    }
+}
+
+enum Field {
+    Context,
+    Id,
+    String,
+}
+
+fn read_entries<S: Read>(source: S) -> Result<Vec<Entry>, InnerError> {
+    let mut entries = vec![];
+    let mut entry = Entry::default();
+    let mut field = None;
+    for (index, line) in BufReader::new(source).lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        let error = || InnerError::from(format!("Unexpected line {}: {}", index + 1, line));
+
+        // An entry is done once its `msgstr` is followed by anything other
+        // than the continuation of it
+        if entry.string.is_some() && !line.starts_with('"') {
+            entries.push(entry);
+            entry = Entry::default();
+            field = None;
+        }
+
+        if line.is_empty() || line.starts_with(po::OBSOLETE) {
+            continue;
+        } else if let Some(flags) = line.strip_prefix(po::FLAGS) {
+            entry.is_fuzzy |= flags.split(',').any(|flag| flag.trim() == po::FUZZY);
+        } else if line.starts_with('#') {
+            continue; // Other comments
+        } else if let Some(text) = strip_keyword(line, po::MSGCTXT) {
+            entry.context = Some(unquote(text).ok_or_else(error)?);
+            field = Some(Field::Context);
+        } else if let Some(text) = strip_keyword(line, po::MSGID) {
+            entry.id = Some(unquote(text).ok_or_else(error)?);
+            field = Some(Field::Id);
+        } else if let Some(text) = strip_keyword(line, po::MSGSTR) {
+            entry.string = Some(unquote(text).ok_or_else(error)?);
+            field = Some(Field::String);
+        } else if line.starts_with('"') {
+            let text = unquote(line).ok_or_else(error)?;
+            let value = match field {
+                None => return Err(error()),
+                Some(Field::Context) => &mut entry.context,
+                Some(Field::Id) => &mut entry.id,
+                Some(Field::String) => &mut entry.string,
+            };
+
+            value.get_or_insert_with(String::new).push_str(&text);
+        } else {
+            // Plural forms (`msgid_plural` & `msgstr[n]`) aren't written out
+            // since plurals are flattened into one string per quantity
+            return Err(error());
+        }
+    }
+
+    if entry.id.is_some() {
+        entries.push(entry);
+    }
+
+    Ok(entries)
+}
+
+fn strip_keyword<'a>(line: &'a str, keyword: &str) -> Option<&'a str> {
+    let text = line.strip_prefix(keyword)?;
+    if text.starts_with(char::is_whitespace) {
+        Some(text.trim_start())
+    } else {
+        None
+    }
+}
+
+fn find_language(header: &str) -> Option<String> {
+    header.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        let value = value.trim();
+        if name.trim() == po::LANGUAGE_HEADER && !value.is_empty() {
+            Some(String::from(value))
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns `None` if the text isn't quoted
+fn unquote(text: &str) -> Option<String> {
+    let text = text.strip_prefix('"')?.strip_suffix('"')?;
+    Some(unescape(text))
+}
+
+/// Look @ `po_writer::escape`. Unknown escapes are left as is
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some('"') => unescaped.push('"'),
+            Some('\\') => unescaped.push('\\'),
+            Some(other) => {
+                unescaped.push('\\');
+                unescaped.push(other)
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::localized_string::LocalizedString;
+    use crate::localized_strings::LocalizedStrings;
+
+    #[test]
+    fn reads_translated_entries() {
+        let localized_strings = read_strings(
+            r#"# Translator comment
+msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+"Language: fr\n"
+
+#. Greeting
+#: strings.xml
+msgctxt "s1"
+msgid "Say \"hi\" \\n "
+"<b>now</b>"
+msgstr "Dis \"salut\" "
+"\\n <b>maintenant</b>"
+
+#, fuzzy
+msgctxt "s2"
+msgid "two"
+msgstr "deux"
+
+msgctxt "s3"
+msgid "three"
+msgstr ""
+
+msgctxt "app/src/main/res/s4"
+msgid "four"
+msgstr "quatre"
+
+#~ msgctxt "s5"
+#~ msgid "five"
+#~ msgstr "cinq"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            localized_strings,
+            LocalizedStrings::build(
+                "fr",
+                vec![
+                    LocalizedString::build(
+                        "s1",
+                        r#"Say "hi" \n <b>now</b>"#,
+                        r#"Dis "salut" \n <b>maintenant</b>"#,
+                    ),
+                    LocalizedString::build("s4", "four", "quatre")
+                        .with_module(Some(String::from("app/src/main/res"))),
+                ],
+            )
+        )
+    }
+
+    #[test]
+    fn errors_without_language_header() {
+        let error = read_strings("msgctxt \"s1\"\nmsgid \"one\"\nmsgstr \"un\"\n").unwrap_err();
+        assert_eq!(error.to_string(), "PO file must have a Language header");
+    }
+
+    #[test]
+    fn errors_for_plural_forms() {
+        let error = read_strings(
+            "msgid \"\"\nmsgstr \"Language: fr\\n\"\n\nmsgid \"one\"\nmsgid_plural \"many\"\n",
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Unexpected line 5: msgid_plural \"many\""
+        );
+    }
+
+    #[test]
+    fn errors_for_entries_without_context() {
+        let error = read_strings(
+            "msgid \"\"\nmsgstr \"Language: fr\\n\"\n\nmsgid \"one\"\nmsgstr \"un\"\n",
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "msgid \"one\" doesn't have a msgctxt with the name of the string"
+        );
+    }
+
+    fn read_strings(content: &str) -> Result<LocalizedStrings, crate::error::InnerError> {
+        super::read(content.as_bytes())
+    }
+}
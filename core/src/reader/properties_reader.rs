@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use crate::error::Error;
+use crate::error::InnerError;
+use crate::error::ResultExt;
+use crate::localized_string::LocalizedString;
+use crate::localized_strings::LocalizedStrings;
+use crate::ops::keys;
+use crate::ops::text;
+use crate::util::bundle_finder;
+
+/// Reads a localized `.properties` file (`messages_fr.properties`) along with
+/// the base file of its bundle (`messages.properties`) that the defaults come
+/// from (look @ `bundle_finder`). Values are converted into Android ones (look
+/// @ `text::to_android_value`). Since the conversion can't bring back the
+/// escapes & entities of the default values, these are matched up with the
+/// default strings as plain text (look @ `extract`). Keys without a default or
+/// a translation are skipped
+pub fn read_bundle(file_path: &Path) -> Result<LocalizedStrings, Error> {
+    let bundle_file = bundle_finder::find(file_path)?;
+    let defaults: HashMap<String, String> =
+        read_file(bundle_file.base_path())?.into_iter().collect();
+
+    let strings = read_file(file_path)?
+        .into_iter()
+        .filter(|(_, localized)| !localized.is_empty())
+        .filter_map(|(key, localized)| {
+            let default = defaults.get(&key)?;
+            let (module, name) = keys::split(&key);
+            Some(
+                LocalizedString::new(
+                    String::from(name),
+                    text::to_android_value(default),
+                    text::to_android_value(&localized),
+                )
+                .with_module(module),
+            )
+        })
+        .collect();
+
+    Ok(LocalizedStrings::new(
+        String::from(bundle_file.locale()),
+        strings,
+    ))
+}
+
+fn read_file(file_path: &Path) -> Result<Vec<(String, String)>, Error> {
+    let context = file_path.to_string_lossy();
+    read(File::open(file_path).with_context(context.clone())?).with_context(context)
+}
+
+/// Reads the keys & values (as plain text) of a `.properties` file. Files can be
+/// UTF-8 or ISO-8859-1
+pub fn read<S: Read>(mut source: S) -> Result<Vec<(String, String)>, InnerError> {
+    let mut bytes = vec![];
+    source.read_to_end(&mut bytes)?;
+    let content = String::from_utf8(bytes)
+        .unwrap_or_else(|error| error.into_bytes().into_iter().map(char::from).collect());
+
+    let mut entries = vec![];
+    let mut lines = content.lines();
+    while let Some(line) = lines.next() {
+        let mut logical_line = String::from(line.trim_start());
+        if logical_line.is_empty() || logical_line.starts_with('#') || logical_line.starts_with('!')
+        {
+            continue;
+        }
+
+        // Lines ending with an odd number of backslashes are continued
+        while logical_line
+            .chars()
+            .rev()
+            .take_while(|c| *c == '\\')
+            .count()
+            % 2
+            == 1
+        {
+            logical_line.pop();
+            match lines.next() {
+                None => break,
+                Some(next_line) => logical_line.push_str(next_line.trim_start()),
+            }
+        }
+
+        entries.push(split_line(&logical_line)?);
+    }
+
+    Ok(entries)
+}
+
+/// Keys & values are built as UTF-16 since characters outside the BMP are
+/// escaped as surrogate pairs (`\uD83D\uDE00`)
+fn split_line(line: &str) -> Result<(String, String), InnerError> {
+    let mut key = vec![];
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescape(&mut chars, &mut key)?,
+            '=' | ':' => break,
+            c if c.is_whitespace() => {
+                // Whitespace can be followed by a separator
+                while chars.peek().is_some_and(|c| c.is_whitespace()) {
+                    chars.next();
+                }
+
+                if let Some('=') | Some(':') = chars.peek() {
+                    chars.next();
+                }
+
+                break;
+            }
+            _ => push(&mut key, c),
+        }
+    }
+
+    while chars.peek().is_some_and(|c| c.is_whitespace()) {
+        chars.next();
+    }
+
+    let mut value = vec![];
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescape(&mut chars, &mut value)?,
+            _ => push(&mut value, c),
+        }
+    }
+
+    Ok((
+        String::from_utf16_lossy(&key),
+        String::from_utf16_lossy(&value),
+    ))
+}
+
+/// Look @ `properties_writer::escape`
+fn unescape<I: Iterator<Item = char>>(
+    chars: &mut I,
+    text: &mut Vec<u16>,
+) -> Result<(), InnerError> {
+    match chars.next() {
+        Some('n') => push(text, '\n'),
+        Some('r') => push(text, '\r'),
+        Some('t') => push(text, '\t'),
+        Some('f') => push(text, '\u{c}'),
+        Some('u') => {
+            let hex: String = chars.take(4).collect();
+            let unit = u16::from_str_radix(&hex, 16)
+                .map_err(|_| format!("Invalid unicode escape \\u{}", hex))?;
+            text.push(unit)
+        }
+        Some(c) => push(text, c),
+        None => {} // Trailing backslash
+    }
+
+    Ok(())
+}
+
+fn push(text: &mut Vec<u16>, c: char) {
+    text.extend_from_slice(c.encode_utf16(&mut [0; 2]))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::localized_string::LocalizedString;
+    use crate::localized_strings::LocalizedStrings;
+
+    #[test]
+    fn reads_properties() {
+        let entries = super::read(
+            r#"# Comment
+! Another comment
+s1=Don't %1$s\n & café 😀
+  s2 : two \
+      lines
+s3 three
+app/src/main/res/s4=\ four
+s\=5=
+"#
+            .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                (
+                    String::from("s1"),
+                    String::from("Don't %1$s\n & caf\u{e9} \u{1f600}")
+                ),
+                (String::from("s2"), String::from("two lines")),
+                (String::from("s3"), String::from("three")),
+                (String::from("app/src/main/res/s4"), String::from(" four")),
+                (String::from("s=5"), String::new()),
+            ]
+        )
+    }
+
+    #[test]
+    fn reads_iso_8859_1_properties() {
+        let entries = super::read(&b"s1=caf\xe9"[..]).unwrap();
+        assert_eq!(
+            entries,
+            vec![(String::from("s1"), String::from("caf\u{e9}"))]
+        )
+    }
+
+    #[test]
+    fn reads_bundle_with_defaults() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("messages.properties"),
+            "s1=Don't %1$s\nlib/s2=two\ns3=three",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("messages_fr.properties"),
+            "s1=N'%1$s pas\nlib/s2=deux\ns3=\ns4=quatre",
+        )
+        .unwrap();
+
+        let localized_strings =
+            super::read_bundle(&temp_dir.path().join("messages_fr.properties")).unwrap();
+        assert_eq!(
+            localized_strings,
+            LocalizedStrings::build(
+                "fr",
+                vec![
+                    LocalizedString::build("s1", r"Don\'t %1$s", r"N\'%1$s pas"),
+                    LocalizedString::build("s2", "two", "deux")
+                        .with_module(Some(String::from("lib"))),
+                ]
+            )
+        )
+    }
+}
@@ -0,0 +1,197 @@
+use std::io::Read;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use crate::error::InnerError;
+
+/// Reads the keys & values (as they are shown in iOS) of an iOS `.strings` file.
+/// Files can be UTF-8 or UTF-16 (with a BOM)
+pub fn read<S: Read>(mut source: S) -> Result<Vec<(String, String)>, InnerError> {
+    let mut bytes = vec![];
+    source.read_to_end(&mut bytes)?;
+    let content = decode(&bytes)?;
+
+    let mut entries = vec![];
+    let mut chars = content.chars().peekable();
+    while skip_whitespace_and_comments(&mut chars)? {
+        let key = read_token(&mut chars)?;
+        skip_whitespace_and_comments(&mut chars)?;
+        let value = match chars.next() {
+            Some('=') => {
+                skip_whitespace_and_comments(&mut chars)?;
+                let value = read_token(&mut chars)?;
+                skip_whitespace_and_comments(&mut chars)?;
+                chars
+                    .next()
+                    .filter(|c| *c == ';')
+                    .ok_or_else(|| format!("{} must be followed by a ;", key))?;
+                value
+            }
+
+            // `"key";` is the same as `"key" = "key";`
+            Some(';') => key.clone(),
+            _ => return Err(format!("{} must be followed by a = or a ;", key).into()),
+        };
+
+        entries.push((key, value));
+    }
+
+    Ok(entries)
+}
+
+fn decode(bytes: &[u8]) -> Result<String, InnerError> {
+    let utf16 = |to_u16: fn([u8; 2]) -> u16| {
+        let units: Vec<u16> = bytes[2..]
+            .chunks(2)
+            .map(|chunk| to_u16([chunk[0], *chunk.get(1).unwrap_or(&0)]))
+            .collect();
+        String::from_utf16(&units).map_err(|_| InnerError::from("Invalid UTF-16 content"))
+    };
+
+    match bytes {
+        [0xFF, 0xFE, ..] => utf16(u16::from_le_bytes),
+        [0xFE, 0xFF, ..] => utf16(u16::from_be_bytes),
+        [0xEF, 0xBB, 0xBF, rest @ ..] => {
+            String::from_utf8(rest.to_vec()).map_err(|_| InnerError::from("Invalid UTF-8 content"))
+        }
+        _ => {
+            String::from_utf8(bytes.to_vec()).map_err(|_| InnerError::from("Invalid UTF-8 content"))
+        }
+    }
+}
+
+/// Returns `false` if there is nothing left
+fn skip_whitespace_and_comments(chars: &mut Peekable<Chars>) -> Result<bool, InnerError> {
+    loop {
+        match chars.peek() {
+            None => return Ok(false),
+            Some(c) if c.is_whitespace() => {
+                chars.next();
+            }
+
+            Some('/') => {
+                chars.next();
+                match chars.next() {
+                    Some('/') => {
+                        chars.find(|c| *c == '\n');
+                    }
+
+                    Some('*') => {
+                        let mut previous = ' ';
+                        loop {
+                            match chars.next() {
+                                None => return Err("Unterminated comment".into()),
+                                Some('/') if previous == '*' => break,
+                                Some(c) => previous = c,
+                            }
+                        }
+                    }
+
+                    _ => return Err("Unexpected /".into()),
+                }
+            }
+
+            Some(_) => return Ok(true),
+        }
+    }
+}
+
+/// Quoted strings or unquoted words
+fn read_token(chars: &mut Peekable<Chars>) -> Result<String, InnerError> {
+    let mut token = String::new();
+    if chars.peek() != Some(&'"') {
+        while let Some(c) = chars.peek() {
+            if c.is_alphanumeric() || "_.-:$/".contains(*c) {
+                token.push(*c);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        return if token.is_empty() {
+            Err(format!("Unexpected {:?}", chars.peek()).into())
+        } else {
+            Ok(token)
+        };
+    }
+
+    chars.next();
+    loop {
+        match chars.next() {
+            None => return Err(format!("Unterminated string {}", token).into()),
+            Some('"') => return Ok(token),
+            Some('\\') => unescape(chars, &mut token)?,
+            Some(c) => token.push(c),
+        }
+    }
+}
+
+/// Look @ `strings_writer::escape`
+fn unescape(chars: &mut Peekable<Chars>, token: &mut String) -> Result<(), InnerError> {
+    match chars.next() {
+        Some('n') => token.push('\n'),
+        Some('r') => token.push('\r'),
+        Some('t') => token.push('\t'),
+        Some('u') | Some('U') => {
+            let hex: String = chars.take(4).collect();
+            let unicode = u32::from_str_radix(&hex, 16)
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or_else(|| format!("Invalid unicode escape \\u{}", hex))?;
+            token.push(unicode)
+        }
+        Some(c) => token.push(c),
+        None => return Err(format!("Unterminated string {}", token).into()),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn reads_strings() {
+        let entries = super::read(
+            r#"/* Greeting
+   on home */
+"s1" = "Say \"hi\" to %1$@\n";
+// Single line comment
+s2 = "caf\U00e9";
+"app/src/main/res/s3"="three" ;
+"s4";
+"#
+            .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                (String::from("s1"), String::from("Say \"hi\" to %1$@\n")),
+                (String::from("s2"), String::from("caf\u{e9}")),
+                (String::from("app/src/main/res/s3"), String::from("three")),
+                (String::from("s4"), String::from("s4")),
+            ]
+        )
+    }
+
+    #[test]
+    fn reads_utf16_strings() {
+        let mut bytes = vec![0xFF, 0xFE];
+        for unit in "\"s1\" = \"un\";".encode_utf16() {
+            bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+
+        assert_eq!(
+            super::read(bytes.as_slice()).unwrap(),
+            vec![(String::from("s1"), String::from("un"))]
+        )
+    }
+
+    #[test]
+    fn errors_for_missing_semicolon() {
+        let error = super::read(r#""s1" = "one""#.as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "s1 must be followed by a ;");
+    }
+}
@@ -0,0 +1,188 @@
+use std::io::BufReader;
+use std::io::Read;
+
+use xml::reader::XmlEvent;
+use xml::ParserConfig;
+
+use crate::android_plural::AndroidPlural;
+use crate::android_plural::Quantity;
+use crate::constants::ios::elements;
+use crate::constants::ios::keys;
+use crate::error::InnerError;
+
+/// Reads the plurals of an iOS `.stringsdict` file as flattened keys (look @
+/// `AndroidPlural::flattened_name`) & values (as they are shown in iOS). The
+/// variable of a plural in its format key is replaced with the value of each
+/// quantity. Quantities unknown to Android & anything other than plurals are
+/// skipped
+pub fn read<S: Read>(source: S) -> Result<Vec<(String, String)>, InnerError> {
+    let root = match parse(source)? {
+        Some(PlistValue::Dict(entries)) => entries,
+        _ => return Err(String::from("Root of a stringsdict must be a dict").into()),
+    };
+
+    let mut flattened = vec![];
+    for (key, value) in root {
+        if let PlistValue::Dict(entries) = value {
+            flattened.extend(flatten_plural(&key, &entries));
+        }
+    }
+
+    Ok(flattened)
+}
+
+#[derive(Debug)]
+enum PlistValue {
+    String(String),
+    Dict(Vec<(String, PlistValue)>),
+    Other,
+}
+
+fn flatten_plural(key: &str, entries: &[(String, PlistValue)]) -> Vec<(String, String)> {
+    let format = match find_string(entries, keys::FORMAT_KEY) {
+        None => return vec![],
+        Some(format) => format,
+    };
+
+    for (variable, value) in entries {
+        let variable_entries = match value {
+            PlistValue::Dict(variable_entries)
+                if find_string(variable_entries, keys::SPEC_TYPE_KEY)
+                    == Some(keys::PLURAL_RULE_TYPE) =>
+            {
+                variable_entries
+            }
+            _ => continue,
+        };
+
+        let placeholder = format!("%#@{}@", variable);
+        return variable_entries
+            .iter()
+            .filter_map(|(quantity, value)| match value {
+                PlistValue::String(value) => Some((Quantity::parse(quantity)?, value)),
+                _ => None,
+            })
+            .map(|(quantity, value)| {
+                (
+                    AndroidPlural::flattened_name(key, quantity),
+                    format.replace(&placeholder, value),
+                )
+            })
+            .collect();
+    }
+
+    vec![]
+}
+
+fn find_string<'a>(entries: &'a [(String, PlistValue)], key: &str) -> Option<&'a str> {
+    entries.iter().find_map(|(k, value)| match value {
+        PlistValue::String(value) if k == key => Some(value.as_str()),
+        _ => None,
+    })
+}
+
+/// Builds the value in the `plist` element
+fn parse<S: Read>(source: S) -> Result<Option<PlistValue>, InnerError> {
+    // Values being built along with the keys they are for
+    let mut stack: Vec<(Option<String>, PlistValue)> = vec![];
+    let mut pending_key: Option<String> = None;
+    let mut text = String::new();
+    let mut root = None;
+
+    let reader = ParserConfig::new().create_reader(BufReader::new(source));
+    for element_or_error in reader {
+        match element_or_error? {
+            XmlEvent::StartElement { name, .. } => {
+                text.clear();
+                match name.local_name.as_str() {
+                    elements::PLIST | elements::KEY => {}
+                    elements::DICT => stack.push((pending_key.take(), PlistValue::Dict(vec![]))),
+                    elements::STRING => {
+                        stack.push((pending_key.take(), PlistValue::String(String::new())))
+                    }
+                    _ => stack.push((pending_key.take(), PlistValue::Other)),
+                }
+            }
+
+            XmlEvent::Characters(t) | XmlEvent::CData(t) => text.push_str(&t),
+            XmlEvent::EndElement { name } => match name.local_name.as_str() {
+                elements::PLIST => {}
+                elements::KEY => pending_key = Some(text.clone()),
+                _ => {
+                    let (key, mut value) = stack
+                        .pop()
+                        .ok_or_else(|| format!("Unexpected {}", name.local_name))?;
+                    if let PlistValue::String(ref mut string) = value {
+                        string.push_str(&text);
+                    }
+
+                    match stack.last_mut() {
+                        None => root = Some(value),
+                        Some((_, PlistValue::Dict(entries))) => {
+                            let key = key.ok_or_else(|| {
+                                format!("{} in a dict without a key", name.local_name)
+                            })?;
+                            entries.push((key, value))
+                        }
+                        Some(_) => {} // Arrays & such aren't needed
+                    }
+                }
+            },
+
+            _ => {} // No op for other events
+        }
+    }
+
+    Ok(root)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn reads_plurals() {
+        let entries = super::read(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>lib/p1</key>
+    <dict>
+        <key>NSStringLocalizedFormatKey</key>
+        <string>Found %#@files@</string>
+        <key>files</key>
+        <dict>
+            <key>NSStringFormatSpecTypeKey</key>
+            <string>NSStringPluralRuleType</string>
+            <key>NSStringFormatValueTypeKey</key>
+            <string>d</string>
+            <key>one</key>
+            <string>%d file &amp; more</string>
+            <key>other</key>
+            <string>%d files</string>
+            <key>unknown</key>
+            <string>skipped</string>
+        </dict>
+    </dict>
+    <key>not_a_plural</key>
+    <string>skipped</string>
+</dict>
+</plist>"#
+                .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                (
+                    String::from("lib/p1[one]"),
+                    String::from("Found %d file & more")
+                ),
+                (
+                    String::from("lib/p1[other]"),
+                    String::from("Found %d files")
+                ),
+            ]
+        )
+    }
+}
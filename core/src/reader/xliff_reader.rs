@@ -0,0 +1,434 @@
+use std::io::BufReader;
+use std::io::Read;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::XmlEvent;
+use xml::ParserConfig;
+
+use crate::constants::xliff::attributes;
+use crate::constants::xliff::elements;
+use crate::constants::xliff::states;
+use crate::constants::xliff::values;
+use crate::error::InnerError;
+use crate::localized_string::LocalizedString;
+use crate::localized_strings::LocalizedStrings;
+use crate::ops::text;
+
+/// Reads an XLIFF 1.2 document (like the one written by `xliff_writer`). Every
+/// `file` is for the locale in its `target-language` (a language tag like
+/// `pt-BR`, which is mapped to the locale by the caller). Only targets that are
+/// `translated`, `signed-off` or `final` (or don't have a state) are read. Units
+/// that aren't to be translated are skipped. Sources & targets are turned back
+/// into Android values: text is escaped & `ph` elements are put in as is
+pub fn read<S: Read>(source: S) -> Result<Vec<LocalizedStrings>, InnerError> {
+    let mut handler = EventsHandler::new();
+    let reader = ParserConfig::new().create_reader(BufReader::new(source));
+    for element_or_error in reader {
+        match element_or_error? {
+            XmlEvent::StartElement {
+                name, attributes, ..
+            } => handler.handle_start_element(&name.local_name, &attributes)?,
+            XmlEvent::Characters(text) | XmlEvent::Whitespace(text) | XmlEvent::CData(text) => {
+                handler.handle_characters(&text)
+            }
+            XmlEvent::EndElement { name } => handler.handle_end_element(&name.local_name),
+            _ => {} // No op for other events
+        }
+    }
+
+    Ok(handler
+        .localized_strings_list
+        .into_iter()
+        .map(|(locale, strings)| LocalizedStrings::new(locale, strings))
+        .collect())
+}
+
+struct EventsHandler {
+    localized_strings_list: Vec<(String, Vec<LocalizedString>)>,
+    /// Index into `localized_strings_list` of the current file
+    current_file_index: Option<usize>,
+    /// Modules of the enclosing groups
+    modules: Vec<Option<String>>,
+    current_unit: Option<TransUnit>,
+    /// Number of open elements in the current unit. Only its direct `source` &
+    /// `target` are captured (& not the ones of `alt-trans` matches for example)
+    depth_in_unit: usize,
+    capturing: Capturing,
+    /// Text of the source or target being captured that is yet to be escaped
+    text: String,
+    is_in_ph: bool,
+}
+
+struct TransUnit {
+    name: String,
+    is_translatable: bool,
+    source: String,
+    target: String,
+    state: Option<String>,
+}
+
+#[derive(PartialEq)]
+enum Capturing {
+    Nothing,
+    Source,
+    Target,
+}
+
+impl EventsHandler {
+    fn new() -> EventsHandler {
+        EventsHandler {
+            localized_strings_list: vec![],
+            current_file_index: None,
+            modules: vec![],
+            current_unit: None,
+            depth_in_unit: 0,
+            capturing: Capturing::Nothing,
+            text: String::new(),
+            is_in_ph: false,
+        }
+    }
+
+    fn handle_start_element(
+        &mut self,
+        name: &str,
+        attributes: &[OwnedAttribute],
+    ) -> Result<(), InnerError> {
+        if self.capturing != Capturing::Nothing {
+            if name == elements::PH && !self.is_in_ph {
+                self.flush_text();
+                self.is_in_ph = true;
+                return Ok(());
+            }
+
+            return Err(format!(
+                "{} has a {} element in it. Only ph elements are expected",
+                self.current_unit.as_ref().map_or("", |unit| &unit.name),
+                name
+            )
+            .into());
+        }
+
+        if let Some(unit) = self.current_unit.as_mut() {
+            if self.depth_in_unit == 0 {
+                match name {
+                    elements::SOURCE => self.capturing = Capturing::Source,
+                    elements::TARGET => {
+                        self.capturing = Capturing::Target;
+                        if let Some(state) = find_attribute(attributes, attributes::STATE) {
+                            unit.state = Some(String::from(state));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            self.depth_in_unit += 1;
+            return Ok(());
+        }
+
+        match name {
+            elements::FILE => {
+                let locale = find_attribute(attributes, attributes::TARGET_LANGUAGE)
+                    .ok_or("file must have a target-language")?;
+                self.current_file_index = Some(self.find_or_add_locale(locale));
+            }
+
+            elements::GROUP => self
+                .modules
+                .push(find_attribute(attributes, attributes::RESNAME).map(String::from)),
+
+            elements::TRANS_UNIT => {
+                let name = find_attribute(attributes, attributes::RESNAME)
+                    .or_else(|| find_attribute(attributes, attributes::ID))
+                    .ok_or("trans-unit must have a resname or an id")?;
+                self.current_unit = Some(TransUnit {
+                    name: String::from(name),
+                    is_translatable: find_attribute(attributes, attributes::TRANSLATE)
+                        != Some(values::NO),
+                    source: String::new(),
+                    target: String::new(),
+                    state: find_attribute(attributes, attributes::STATE).map(String::from),
+                });
+            }
+
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn handle_characters(&mut self, text: &str) {
+        if self.is_in_ph {
+            if let Some(value) = self.captured_value() {
+                value.push_str(text)
+            }
+        } else if self.capturing != Capturing::Nothing {
+            self.text.push_str(text)
+        }
+    }
+
+    fn handle_end_element(&mut self, name: &str) {
+        if self.is_in_ph && name == elements::PH {
+            self.is_in_ph = false;
+            return;
+        }
+
+        if self.depth_in_unit > 0 {
+            self.depth_in_unit -= 1;
+            if self.capturing != Capturing::Nothing {
+                // Only a source or a target could be closed here
+                self.text.truncate(self.text.trim_end().len());
+                self.flush_text();
+                self.capturing = Capturing::Nothing
+            }
+
+            return;
+        }
+
+        match name {
+            elements::FILE => self.current_file_index = None,
+            elements::GROUP => {
+                self.modules.pop();
+            }
+            elements::TRANS_UNIT => {
+                if let Some(unit) = self.current_unit.take() {
+                    self.add_if_translated(unit)
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Android value of the source or target being captured
+    fn captured_value(&mut self) -> Option<&mut String> {
+        let unit = self.current_unit.as_mut()?;
+        match self.capturing {
+            Capturing::Nothing => None,
+            Capturing::Source => Some(&mut unit.source),
+            Capturing::Target => Some(&mut unit.target),
+        }
+    }
+
+    /// Plain text can't have a raw `<` in an Android value
+    fn flush_text(&mut self) {
+        let text = std::mem::take(&mut self.text);
+        if let Some(value) = self.captured_value() {
+            let text = if value.is_empty() {
+                text.trim_start()
+            } else {
+                &text
+            };
+
+            value.push_str(&text::to_android_value(text).replace('<', "&lt;"))
+        }
+    }
+
+    fn add_if_translated(&mut self, unit: TransUnit) {
+        let is_translated = matches!(
+            unit.state.as_deref(),
+            None | Some(states::TRANSLATED) | Some(states::SIGNED_OFF) | Some(states::FINAL)
+        );
+
+        let target = unit.target.trim();
+        if !unit.is_translatable || !is_translated || target.is_empty() {
+            return;
+        }
+
+        let module = self.modules.iter().rev().find_map(Clone::clone);
+        if let Some(index) = self.current_file_index {
+            self.localized_strings_list[index].1.push(
+                LocalizedString::new(
+                    unit.name,
+                    String::from(unit.source.trim()),
+                    String::from(target),
+                )
+                .with_module(module),
+            );
+        }
+    }
+
+    fn find_or_add_locale(&mut self, locale: &str) -> usize {
+        match self
+            .localized_strings_list
+            .iter()
+            .position(|(existing_locale, _)| existing_locale == locale)
+        {
+            Some(index) => index,
+            None => {
+                self.localized_strings_list
+                    .push((String::from(locale), vec![]));
+                self.localized_strings_list.len() - 1
+            }
+        }
+    }
+}
+
+fn find_attribute<'a>(attributes: &'a [OwnedAttribute], name: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|attribute| attribute.name.local_name == name)
+        .map(|attribute| attribute.value.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::InnerError;
+    use crate::localized_string::LocalizedString;
+    use crate::localized_strings::LocalizedStrings;
+
+    #[test]
+    fn reads_translated_targets() {
+        let strings_list = read_strings(
+            r##"<?xml version="1.0" encoding="UTF-8"?>
+<xliff xmlns="urn:oasis:names:tc:xliff:document:1.2" version="1.2">
+  <file original="strings.xml" datatype="plaintext" source-language="en" target-language="fr">
+    <body>
+      <trans-unit id="s1" resname="s1">
+        <source><ph id="1">&lt;b></ph>one<ph id="2">&lt;/b></ph></source>
+        <target state="translated">
+          <ph id="1">&lt;b></ph>un<ph id="2">&lt;/b></ph> &amp; &lt; "l'un"
+        </target>
+        <note from="developer">Count</note>
+      </trans-unit>
+      <trans-unit id="s2" resname="s2">
+        <source>two</source>
+        <target state="needs-translation">deux</target>
+      </trans-unit>
+      <trans-unit id="s3">
+        <source>three</source>
+        <target state="final">trois</target>
+      </trans-unit>
+      <trans-unit id="s4" resname="s4" translate="no">
+        <source>four</source>
+        <target>quatre</target>
+      </trans-unit>
+      <trans-unit id="s5" resname="s5">
+        <source>five</source>
+        <target state="signed-off"></target>
+      </trans-unit>
+    </body>
+  </file>
+  <file original="strings.xml" datatype="plaintext" source-language="en" target-language="de">
+    <body>
+      <trans-unit id="s1" resname="s1">
+        <source>one</source>
+        <target>eins</target>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>"##,
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            strings_list,
+            vec![
+                LocalizedStrings::build(
+                    "fr",
+                    vec![
+                        LocalizedString::build(
+                            "s1",
+                            "<b>one</b>",
+                            r#"<b>un</b> &amp; &lt; \"l\'un\""#,
+                        ),
+                        LocalizedString::build("s3", "three", "trois"),
+                    ],
+                ),
+                LocalizedStrings::build("de", vec![LocalizedString::build("s1", "one", "eins")]),
+            ],
+        )
+    }
+
+    #[test]
+    fn reads_modules_from_groups() {
+        let strings_list = read_strings(
+            r##"<xliff version="1.2">
+  <file target-language="fr">
+    <body>
+      <group resname="app/src/main/res">
+        <trans-unit id="app/src/main/res/s1" resname="s1">
+          <source>one</source>
+          <target>un</target>
+        </trans-unit>
+      </group>
+      <trans-unit id="s2">
+        <source>two</source>
+        <target>deux</target>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>"##,
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            strings_list,
+            vec![LocalizedStrings::build(
+                "fr",
+                vec![
+                    LocalizedString::build("s1", "one", "un")
+                        .with_module(Some(String::from("app/src/main/res"))),
+                    LocalizedString::build("s2", "two", "deux"),
+                ],
+            )],
+        )
+    }
+
+    #[test]
+    fn skips_sources_and_targets_of_alt_trans() {
+        let strings_list = read_strings(
+            r##"<xliff version="1.2">
+  <file target-language="fr">
+    <body>
+      <trans-unit id="s1">
+        <source>Cancel</source>
+        <alt-trans match-quality="90%">
+          <source>Cancel <g id="1">all</g></source>
+          <target>Annuler <g id="1">tout</g></target>
+        </alt-trans>
+        <target state="translated">Annuler</target>
+        <alt-trans>
+          <source>Cancelled</source>
+          <target state="needs-review-translation">Annulé</target>
+        </alt-trans>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>"##,
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            strings_list,
+            vec![LocalizedStrings::build(
+                "fr",
+                vec![LocalizedString::build("s1", "Cancel", "Annuler")],
+            )],
+        )
+    }
+
+    #[test]
+    fn errors_for_elements_in_targets() {
+        let error = read_strings(
+            r##"<xliff><file target-language="fr"><body><trans-unit id="s1">
+<source>one</source><target><g id="1">un</g></target>
+</trans-unit></body></file></xliff>"##,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "s1 has a g element in it. Only ph elements are expected"
+        );
+    }
+
+    #[test]
+    fn errors_for_file_without_target_language() {
+        let error = read_strings(r##"<xliff><file></file></xliff>"##).unwrap_err();
+        assert_eq!(error.to_string(), "file must have a target-language");
+    }
+
+    fn read_strings(content: &str) -> Result<Vec<LocalizedStrings>, InnerError> {
+        super::read(content.as_bytes())
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+use std::io::Read;
+use std::io::Seek;
+
+use calamine::{open_workbook_from_rs, Data, Reader, Xlsx};
+
+use crate::error::InnerError;
+use crate::localized_strings::LocalizedStrings;
+use crate::reader::csv_reader;
+
+/// Reads a workbook (like the one written by `xlsx_writer`). Every sheet is
+/// expected to be laid out the same way as a CSV file (look @ `csv_reader`).
+/// Empty sheets are skipped
+pub fn read<S: Read + Seek>(
+    source: S,
+    allow_only_locales: HashSet<String>,
+) -> Result<Vec<LocalizedStrings>, InnerError> {
+    let mut workbook: Xlsx<S> = open_workbook_from_rs(source)?;
+    let mut localized_strings_list: Vec<LocalizedStrings> = vec![];
+    for (_, range) in workbook.worksheets() {
+        let mut rows = range.rows().map(build_record);
+        let headers = match rows.next() {
+            None => continue,
+            Some(headers) => headers,
+        };
+
+        for localized_strings in
+            csv_reader::read_records(&headers, rows.map(Ok), allow_only_locales.clone())?
+        {
+            // Same locale can be in multiple sheets
+            match localized_strings_list
+                .iter()
+                .position(|s| s.locale() == localized_strings.locale())
+            {
+                None => localized_strings_list.push(localized_strings),
+                Some(index) => {
+                    let existing = localized_strings_list.remove(index);
+                    let locale = String::from(existing.locale());
+                    let mut strings = existing.into_strings();
+                    strings.extend(localized_strings.into_strings());
+                    localized_strings_list.insert(index, LocalizedStrings::new(locale, strings));
+                }
+            }
+        }
+    }
+
+    Ok(localized_strings_list)
+}
+
+/// Cells are trimmed the same way the CSV files are
+fn build_record(row: &[Data]) -> csv::StringRecord {
+    row.iter()
+        .map(|cell| String::from(cell.to_string().trim()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use rust_xlsxwriter::Workbook;
+
+    use crate::localized_string::LocalizedString;
+    use crate::localized_strings::LocalizedStrings;
+
+    #[test]
+    fn reads_strings_from_every_sheet() {
+        let mut workbook = Workbook::new();
+        let sheet1 = workbook.add_worksheet();
+        write_rows(
+            sheet1,
+            vec![
+                vec!["string_name", "default_locale", "french", "spanish"],
+                vec!["s1", "=one", " =un ", ""],
+                vec!["s2", "007", "", "siete"],
+            ],
+        );
+
+        workbook.add_worksheet();
+        let sheet3 = workbook.add_worksheet();
+        write_rows(
+            sheet3,
+            vec![
+                vec!["string_name", "default_locale", "french", "german"],
+                vec!["s3", "three", "trois", "drei"],
+            ],
+        );
+
+        let strings_list = super::read(
+            Cursor::new(workbook.save_to_buffer().unwrap()),
+            vec!["french", "spanish"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            strings_list,
+            vec![
+                LocalizedStrings::build(
+                    "french",
+                    vec![
+                        LocalizedString::build("s1", "=one", "=un"),
+                        LocalizedString::build("s3", "three", "trois"),
+                    ],
+                ),
+                LocalizedStrings::build(
+                    "spanish",
+                    vec![LocalizedString::build("s2", "007", "siete")],
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn reads_modules() {
+        let mut workbook = Workbook::new();
+        write_rows(
+            workbook.add_worksheet(),
+            vec![
+                vec!["module", "string_name", "default_locale", "french"],
+                vec!["app/src/main/res", "s1", "one", "un"],
+            ],
+        );
+
+        let strings_list = super::read(
+            Cursor::new(workbook.save_to_buffer().unwrap()),
+            vec![String::from("french")].into_iter().collect(),
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            strings_list,
+            vec![LocalizedStrings::build(
+                "french",
+                vec![LocalizedString::build("s1", "one", "un")
+                    .with_module(Some(String::from("app/src/main/res")))],
+            )],
+        )
+    }
+
+    fn write_rows(worksheet: &mut rust_xlsxwriter::Worksheet, rows: Vec<Vec<&str>>) {
+        for (row_index, row) in rows.into_iter().enumerate() {
+            for (column_index, value) in row.into_iter().enumerate() {
+                if !value.is_empty() {
+                    worksheet
+                        .write_string(row_index as u32, column_index as u16, value)
+                        .unwrap();
+                }
+            }
+        }
+    }
+}
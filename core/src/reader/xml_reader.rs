@@ -1,9 +1,14 @@
 pub use self::reader::read;
 
+mod array_item_event_handler;
 mod event_handler;
 mod events_handler;
+mod markup_event_handler;
+mod plural_item_event_handler;
+mod plurals_event_handler;
 mod reader;
 mod resources_event_handler;
 mod root_event_handler;
 mod sinking_event_handler;
+mod string_array_event_handler;
 mod string_event_handler;
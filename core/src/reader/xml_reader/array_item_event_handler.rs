@@ -0,0 +1,70 @@
+use xml::attribute::OwnedAttribute;
+
+use crate::error::InnerError;
+use crate::reader::xml_reader::event_handler::EventHandler;
+use crate::reader::xml_reader::markup_event_handler;
+use crate::reader::xml_reader::markup_event_handler::MarkupEventHandler;
+
+pub struct ArrayItemEventHandler {
+    value: String,
+}
+
+impl ArrayItemEventHandler {
+    pub fn new() -> ArrayItemEventHandler {
+        ArrayItemEventHandler {
+            value: String::new(),
+        }
+    }
+}
+
+impl EventHandler for ArrayItemEventHandler {
+    fn build_handler(
+        &self,
+        tag_name: String,
+        attributes: Vec<OwnedAttribute>,
+    ) -> Result<Box<dyn EventHandler>, InnerError> {
+        Ok(Box::new(MarkupEventHandler::new(tag_name, attributes)))
+    }
+
+    fn handle_characters_event(&mut self, text: String) {
+        self.value
+            .push_str(&markup_event_handler::escape_text(&text))
+    }
+
+    fn handle_cdata_event(&mut self, text: String) {
+        self.value.push_str(&format!("<![CDATA[{}]]>", text))
+    }
+
+    fn handle_markup(&mut self, markup: String) {
+        self.value.push_str(&markup)
+    }
+
+    /// Unlike strings, empty items are built too since dropping them would
+    /// shift the indices of the items after them
+    fn built_array_item(&self) -> Option<String> {
+        Some(self.value.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reader::xml_reader::event_handler::EventHandler;
+
+    use super::ArrayItemEventHandler;
+
+    #[test]
+    fn builds_item_with_character_and_cdata_events() {
+        let mut handler = ArrayItemEventHandler::new();
+        handler.handle_cdata_event(String::from("cdata event"));
+        handler.handle_characters_event(String::from(" character event"));
+        assert_eq!(
+            handler.built_array_item().unwrap(),
+            "<![CDATA[cdata event]]> character event"
+        )
+    }
+
+    #[test]
+    fn builds_empty_item() {
+        assert_eq!(ArrayItemEventHandler::new().built_array_item().unwrap(), "")
+    }
+}
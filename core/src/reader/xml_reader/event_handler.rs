@@ -1,9 +1,13 @@
 use xml::attribute::OwnedAttribute;
 
+use crate::android_plural::AndroidPlural;
+use crate::android_plural::PluralItem;
 use crate::android_string::AndroidString;
+use crate::android_string_array::AndroidStringArray;
 use crate::error::InnerError;
 
-/// One instance of `EventHandler` is only expected to ever build one `AndroidString`
+/// One instance of `EventHandler` is only expected to ever build one `AndroidString`,
+/// `AndroidPlural`, `AndroidStringArray` or an item of those
 pub trait EventHandler {
     fn build_handler(
         &self,
@@ -19,6 +23,21 @@ pub trait EventHandler {
         // No op
     }
 
+    /// Called with the item built by a child handler once the child is done
+    fn handle_plural_item(&mut self, _item: PluralItem) {
+        // No op
+    }
+
+    /// Called with the item built by a child handler once the child is done
+    fn handle_array_item(&mut self, _item: String) {
+        // No op
+    }
+
+    /// Called with the markup built by a child handler once the child is done
+    fn handle_markup(&mut self, _markup: String) {
+        // No op
+    }
+
     // It would be great if a way can be found to make this consume self instead of
     // just take in a reference. Compiler complains if this is made a consumer as
     // `EventHandler` is used as a trait object & boxed, un-sized objects can't be
@@ -26,4 +45,24 @@ pub trait EventHandler {
     fn built_string(&self) -> Option<AndroidString> {
         None
     }
+
+    fn built_plural(&self) -> Option<AndroidPlural> {
+        None
+    }
+
+    fn built_plural_item(&self) -> Option<PluralItem> {
+        None
+    }
+
+    fn built_string_array(&self) -> Option<AndroidStringArray> {
+        None
+    }
+
+    fn built_array_item(&self) -> Option<String> {
+        None
+    }
+
+    fn built_markup(&self) -> Option<String> {
+        None
+    }
 }
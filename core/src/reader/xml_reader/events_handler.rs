@@ -2,21 +2,37 @@ use xml::attribute::OwnedAttribute;
 
 use android_localization_utilities::DevExpt;
 
+use crate::android_plural::AndroidPlural;
+use crate::android_resources::AndroidResources;
 use crate::android_string::AndroidString;
+use crate::android_string_array::AndroidStringArray;
 use crate::error::InnerError;
 use crate::reader::xml_reader::event_handler::EventHandler;
 use crate::reader::xml_reader::root_event_handler::RootEventHandler;
 
 pub struct EventsHandler {
     android_strings: Vec<AndroidString>,
+    android_plurals: Vec<AndroidPlural>,
+    android_string_arrays: Vec<AndroidStringArray>,
     event_handlers: Vec<Box<dyn EventHandler>>,
+    /// Comment that was last seen right inside `resources`
+    pending_comment: Option<String>,
+    /// Comment of the resource currently being built
+    current_comment: Option<String>,
 }
 
+/// Root & resources handlers
+const RESOURCES_DEPTH: usize = 2;
+
 impl EventsHandler {
     pub fn new() -> EventsHandler {
         EventsHandler {
             android_strings: vec![],
+            android_plurals: vec![],
+            android_string_arrays: vec![],
             event_handlers: vec![Box::new(RootEventHandler::new())],
+            pending_comment: None,
+            current_comment: None,
         }
     }
 
@@ -25,6 +41,11 @@ impl EventsHandler {
         tag_name: String,
         attributes: Vec<OwnedAttribute>,
     ) -> Result<(), InnerError> {
+        // A comment only belongs to the resource that immediately follows it
+        if self.event_handlers.len() == RESOURCES_DEPTH {
+            self.current_comment = self.pending_comment.take();
+        }
+
         let event_handler = self
             .event_handlers
             .last_mut()
@@ -48,16 +69,51 @@ impl EventsHandler {
             .handle_cdata_event(text);
     }
 
+    pub fn handle_comment_event(&mut self, text: String) {
+        if self.event_handlers.len() == RESOURCES_DEPTH {
+            self.pending_comment = Some(String::from(text.trim()));
+        }
+    }
+
     pub fn handle_end_element_event(&mut self) {
         if let Some(event_handler) = self.event_handlers.pop() {
             if let Some(android_string) = event_handler.built_string() {
-                self.android_strings.push(android_string);
+                self.android_strings
+                    .push(android_string.with_comment(self.current_comment.take()));
+            }
+
+            if let Some(android_plural) = event_handler.built_plural() {
+                self.android_plurals
+                    .push(android_plural.with_comment(self.current_comment.take()));
+            }
+
+            if let Some(android_string_array) = event_handler.built_string_array() {
+                self.android_string_arrays
+                    .push(android_string_array.with_comment(self.current_comment.take()));
+            }
+
+            if let Some(parent_event_handler) = self.event_handlers.last_mut() {
+                if let Some(plural_item) = event_handler.built_plural_item() {
+                    parent_event_handler.handle_plural_item(plural_item);
+                }
+
+                if let Some(array_item) = event_handler.built_array_item() {
+                    parent_event_handler.handle_array_item(array_item);
+                }
+
+                if let Some(markup) = event_handler.built_markup() {
+                    parent_event_handler.handle_markup(markup);
+                }
             }
         }
     }
 
-    pub fn strings(mut self) -> Vec<AndroidString> {
+    pub fn resources(mut self) -> AndroidResources {
         self.event_handlers.clear();
-        self.android_strings
+        AndroidResources::new(
+            self.android_strings,
+            self.android_plurals,
+            self.android_string_arrays,
+        )
     }
 }
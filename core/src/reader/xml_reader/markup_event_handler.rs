@@ -0,0 +1,110 @@
+use xml::attribute::OwnedAttribute;
+
+use crate::error::InnerError;
+use crate::reader::xml_reader::event_handler::EventHandler;
+
+/// Serializes elements inside of values (like `<b>`, `<annotation>` or `<xliff:g>`)
+/// as is, so that they make it into the value verbatim
+pub struct MarkupEventHandler {
+    tag_name: String,
+    start_tag_content: String,
+    content: String,
+}
+
+impl MarkupEventHandler {
+    pub fn new(tag_name: String, attributes: Vec<OwnedAttribute>) -> MarkupEventHandler {
+        let mut start_tag_content = tag_name.clone();
+        for attribute in attributes {
+            start_tag_content.push_str(&format!(
+                " {}=\"{}\"",
+                attribute.name.borrow().to_repr(),
+                escape_attribute_value(&attribute.value)
+            ));
+        }
+
+        MarkupEventHandler {
+            tag_name,
+            start_tag_content,
+            content: String::new(),
+        }
+    }
+}
+
+impl EventHandler for MarkupEventHandler {
+    fn build_handler(
+        &self,
+        tag_name: String,
+        attributes: Vec<OwnedAttribute>,
+    ) -> Result<Box<dyn EventHandler>, InnerError> {
+        Ok(Box::new(MarkupEventHandler::new(tag_name, attributes)))
+    }
+
+    fn handle_characters_event(&mut self, text: String) {
+        self.content.push_str(&escape_text(&text))
+    }
+
+    fn handle_cdata_event(&mut self, text: String) {
+        self.content.push_str(&format!("<![CDATA[{}]]>", text))
+    }
+
+    fn handle_markup(&mut self, markup: String) {
+        self.content.push_str(&markup)
+    }
+
+    fn built_markup(&self) -> Option<String> {
+        if self.content.is_empty() {
+            Some(format!("<{}/>", self.start_tag_content))
+        } else {
+            Some(format!(
+                "<{}>{}</{}>",
+                self.start_tag_content, self.content, self.tag_name
+            ))
+        }
+    }
+}
+
+/// Values are kept as markup. Only the characters that would otherwise make the
+/// markup malformed are escaped
+pub fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;")
+}
+
+fn escape_attribute_value(value: &str) -> String {
+    escape_text(value).replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use xml::attribute::OwnedAttribute;
+    use xml::name::OwnedName;
+
+    use crate::reader::xml_reader::event_handler::EventHandler;
+
+    use super::MarkupEventHandler;
+
+    #[test]
+    fn builds_markup_with_attributes_and_nested_markup() {
+        let mut handler = MarkupEventHandler::new(
+            String::from("xliff:g"),
+            vec![
+                OwnedAttribute::new(OwnedName::local("id"), "count"),
+                OwnedAttribute::new(OwnedName::local("example"), "\"5\" & more"),
+            ],
+        );
+
+        handler.handle_characters_event(String::from("%1$d < "));
+        handler.handle_markup(String::from("<b>x</b>"));
+        handler.handle_cdata_event(String::from("<i>"));
+
+        assert_eq!(
+            handler.built_markup().unwrap(),
+            r##"<xliff:g id="count" example="&quot;5&quot; &amp; more">%1$d &lt; <b>x</b><![CDATA[<i>]]></xliff:g>"##
+        )
+    }
+
+    #[test]
+    fn builds_empty_markup() {
+        let handler = MarkupEventHandler::new(String::from("br"), vec![]);
+        assert_eq!(handler.built_markup().unwrap(), "<br/>")
+    }
+}
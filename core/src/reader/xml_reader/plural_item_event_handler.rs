@@ -0,0 +1,112 @@
+use xml::attribute::OwnedAttribute;
+
+use crate::android_plural::PluralItem;
+use crate::android_plural::Quantity;
+use crate::constants;
+use crate::error::InnerError;
+use crate::reader::xml_reader::event_handler::EventHandler;
+use crate::reader::xml_reader::markup_event_handler;
+use crate::reader::xml_reader::markup_event_handler::MarkupEventHandler;
+
+pub struct PluralItemEventHandler {
+    quantity: Quantity,
+    value: Option<String>,
+}
+
+impl PluralItemEventHandler {
+    pub fn build(attributes: Vec<OwnedAttribute>) -> Result<PluralItemEventHandler, InnerError> {
+        let quantity = attributes
+            .into_iter()
+            .find(|attribute| attribute.name.local_name == constants::attributes::QUANTITY)
+            .map(|attribute| attribute.value);
+
+        match quantity {
+            None => Err("item element is missing required quantity attribute".into()),
+            Some(quantity) => match Quantity::parse(&quantity) {
+                None => Err(format!("item element has unknown quantity: {}", quantity).into()),
+                Some(quantity) => Ok(PluralItemEventHandler {
+                    quantity,
+                    value: None,
+                }),
+            },
+        }
+    }
+
+    fn append_or_create_value(&mut self, text: String) {
+        self.value = match self.value.take() {
+            None => Some(text),
+            Some(value) => Some(format!("{}{}", value, text)),
+        }
+    }
+}
+
+impl EventHandler for PluralItemEventHandler {
+    fn build_handler(
+        &self,
+        tag_name: String,
+        attributes: Vec<OwnedAttribute>,
+    ) -> Result<Box<dyn EventHandler>, InnerError> {
+        Ok(Box::new(MarkupEventHandler::new(tag_name, attributes)))
+    }
+
+    fn handle_characters_event(&mut self, text: String) {
+        self.append_or_create_value(markup_event_handler::escape_text(&text))
+    }
+
+    fn handle_cdata_event(&mut self, text: String) {
+        self.append_or_create_value(format!("<![CDATA[{}]]>", text))
+    }
+
+    fn handle_markup(&mut self, markup: String) {
+        self.append_or_create_value(markup)
+    }
+
+    fn built_plural_item(&self) -> Option<PluralItem> {
+        self.value
+            .clone()
+            .map(|value| PluralItem::new(self.quantity, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use xml::attribute::OwnedAttribute;
+    use xml::name::OwnedName;
+
+    use crate::android_plural::Quantity;
+    use crate::reader::xml_reader::event_handler::EventHandler;
+
+    use super::PluralItemEventHandler;
+
+    #[test]
+    fn builds_item_with_character_and_cdata_events() {
+        let mut handler = PluralItemEventHandler::build(vec![build_quantity("few")]).unwrap();
+        handler.handle_characters_event(String::from("character event "));
+        handler.handle_cdata_event(String::from("cdata event"));
+
+        let item = handler.built_plural_item().unwrap();
+        assert_eq!(item.quantity(), Quantity::Few);
+        assert_eq!(item.value(), "character event <![CDATA[cdata event]]>");
+    }
+
+    #[test]
+    fn errors_for_missing_quantity() {
+        let error = PluralItemEventHandler::build(vec![]).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "item element is missing required quantity attribute"
+        )
+    }
+
+    #[test]
+    fn errors_for_unknown_quantity() {
+        let error = PluralItemEventHandler::build(vec![build_quantity("some")])
+            .err()
+            .unwrap();
+        assert_eq!(error.to_string(), "item element has unknown quantity: some")
+    }
+
+    fn build_quantity(value: &str) -> OwnedAttribute {
+        OwnedAttribute::new(OwnedName::local("quantity"), value)
+    }
+}
@@ -0,0 +1,97 @@
+use xml::attribute::OwnedAttribute;
+
+use crate::android_plural::AndroidPlural;
+use crate::android_plural::PluralItem;
+use crate::constants;
+use crate::error::InnerError;
+use crate::reader::xml_reader::event_handler::EventHandler;
+use crate::reader::xml_reader::plural_item_event_handler::PluralItemEventHandler;
+use crate::reader::xml_reader::sinking_event_handler::SinkingEventHandler;
+
+pub struct PluralsEventHandler {
+    name: String,
+    is_localizable: bool,
+    items: Vec<PluralItem>,
+}
+
+impl PluralsEventHandler {
+    pub fn build(attributes: Vec<OwnedAttribute>) -> Result<PluralsEventHandler, InnerError> {
+        let mut plural_name = None;
+        let mut is_localizable = true;
+        for attribute in attributes {
+            match attribute.name.local_name.as_str() {
+                constants::attributes::NAME => plural_name = Some(attribute.value),
+                constants::attributes::LOCALIZABLE => {
+                    if let constants::flags::FALSE = attribute.value.as_str() {
+                        is_localizable = false
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        match plural_name {
+            None => Err("plurals element is missing required name attribute".into()),
+            Some(name) => Ok(PluralsEventHandler {
+                name,
+                is_localizable,
+                items: vec![],
+            }),
+        }
+    }
+}
+
+impl EventHandler for PluralsEventHandler {
+    fn build_handler(
+        &self,
+        tag_name: String,
+        attributes: Vec<OwnedAttribute>,
+    ) -> Result<Box<dyn EventHandler>, InnerError> {
+        match tag_name.as_str() {
+            constants::elements::ITEM => Ok(Box::new(PluralItemEventHandler::build(attributes)?)),
+            _ => Ok(Box::new(SinkingEventHandler::new())),
+        }
+    }
+
+    fn handle_plural_item(&mut self, item: PluralItem) {
+        self.items.push(item)
+    }
+
+    fn built_plural(&self) -> Option<AndroidPlural> {
+        Some(AndroidPlural::new(
+            self.name.clone(),
+            self.items.clone(),
+            self.is_localizable,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::android_plural::AndroidPlural;
+    use crate::android_plural::PluralItem;
+    use crate::android_plural::Quantity;
+    use crate::reader::xml_reader::event_handler::EventHandler;
+
+    use super::PluralsEventHandler;
+
+    #[test]
+    fn builds_plural_with_items_in_order() {
+        let mut handler = PluralsEventHandler {
+            name: String::from("test_plural"),
+            is_localizable: true,
+            items: vec![],
+        };
+
+        handler.handle_plural_item(PluralItem::new(Quantity::Other, String::from("other")));
+        handler.handle_plural_item(PluralItem::new(Quantity::One, String::from("one")));
+
+        assert_eq!(
+            handler.built_plural().unwrap(),
+            AndroidPlural::localizable(
+                "test_plural",
+                vec![(Quantity::Other, "other"), (Quantity::One, "one")]
+            )
+        )
+    }
+}
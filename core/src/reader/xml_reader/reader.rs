@@ -4,13 +4,15 @@ use std::io::Read;
 use xml::reader::XmlEvent;
 use xml::ParserConfig;
 
-use crate::android_string::AndroidString;
+use crate::android_resources::AndroidResources;
 use crate::error::InnerError;
 use crate::reader::xml_reader::events_handler::EventsHandler;
 
-pub fn read<S: Read>(source: S) -> Result<Vec<AndroidString>, InnerError> {
+pub fn read<S: Read>(source: S) -> Result<AndroidResources, InnerError> {
     let mut events_handler = EventsHandler::new();
-    let reader = ParserConfig::new().create_reader(BufReader::new(source));
+    let reader = ParserConfig::new()
+        .ignore_comments(false)
+        .create_reader(BufReader::new(source));
 
     for element_or_error in reader {
         match element_or_error {
@@ -18,16 +20,20 @@ pub fn read<S: Read>(source: S) -> Result<Vec<AndroidString>, InnerError> {
             Ok(element) => match element {
                 XmlEvent::StartElement {
                     name, attributes, ..
-                } => events_handler.handle_start_element_event(name.local_name, attributes)?,
-                XmlEvent::Characters(text) => events_handler.handle_characters_event(text),
+                } => events_handler
+                    .handle_start_element_event(name.borrow().to_repr(), attributes)?,
+                XmlEvent::Characters(text) | XmlEvent::Whitespace(text) => {
+                    events_handler.handle_characters_event(text)
+                }
                 XmlEvent::CData(text) => events_handler.handle_cdata_event(text),
+                XmlEvent::Comment(text) => events_handler.handle_comment_event(text),
                 XmlEvent::EndElement { .. } => events_handler.handle_end_element_event(),
                 _ => {} // No op for other events
             },
         }
     }
 
-    Ok(events_handler.strings())
+    Ok(events_handler.resources())
 }
 
 #[cfg(test)]
@@ -37,11 +43,16 @@ mod tests {
 
     use test_utilities;
 
+    use crate::android_plural::AndroidPlural;
+    use crate::android_plural::Quantity;
+    use crate::android_resources::AndroidResources;
     use crate::android_string::AndroidString;
+    use crate::android_string_array::AndroidStringArray;
+    use crate::warnings;
 
     #[test]
     fn reads_strings_from_valid_clean_file() {
-        let strings = write_to_file_and_read_strings_out(
+        let strings = write_to_file_and_read_resources_out(
             r##"
 			<?xml version="1.0" encoding="utf-8"?>
 			<resources>
@@ -50,7 +61,8 @@ mod tests {
 				<string name="non_localizable_string" translatable="false">non localizable string value</string>
 			</resources>
 		"##,
-        );
+        )
+        .into_strings();
 
         test_utilities::list::assert_strict_list_eq(
             strings,
@@ -67,7 +79,7 @@ mod tests {
 
     #[test]
     fn reads_strings_from_valid_dirty_file() {
-        let strings = write_to_file_and_read_strings_out(
+        let strings = write_to_file_and_read_resources_out(
             r##"
 			<?xml version="1.0" encoding="utf-8"?>
 			<string name="dont_care_string_1">value</string>
@@ -86,7 +98,8 @@ mod tests {
 				<string name="dont_care_string_6" translatable="false">value</string>
 			</outside_container>
 		"##,
-        );
+        )
+        .into_strings();
 
         test_utilities::list::assert_strict_list_eq(
             strings,
@@ -103,14 +116,15 @@ mod tests {
 
     #[test]
     fn reads_cdata_correctly() {
-        let strings = write_to_file_and_read_strings_out(
+        let strings = write_to_file_and_read_resources_out(
             r##"
             <?xml version="1.0" encoding="utf-8"?>
             <resources>
                 <string name="s1">Hi there. <![CDATA[<a href=\"https://www.mozilla.com\">Mozilla</a>]]> is awesome</string>
             </resources>
         "##,
-        );
+        )
+        .into_strings();
 
         test_utilities::list::assert_strict_list_eq(
             strings,
@@ -123,14 +137,15 @@ mod tests {
 
     #[test]
     fn reads_string_with_whitespace_between_cdata() {
-        let strings = write_to_file_and_read_strings_out(
+        let strings = write_to_file_and_read_resources_out(
             r##"
             <?xml version="1.0" encoding="utf-8"?>
             <resources>
                 <string name="s1"><![CDATA[<a href=\"https://www.mozilla.com\">Mozilla</a>]]> <![CDATA[<a href=\"https://www.firefox.com\">Firefox</a>]]></string>
             </resources>
         "##,
-        );
+        )
+        .into_strings();
 
         test_utilities::list::assert_strict_list_eq(
             strings,
@@ -141,7 +156,216 @@ mod tests {
         );
     }
 
-    fn write_to_file_and_read_strings_out(file_content: &str) -> Vec<AndroidString> {
+    #[test]
+    fn reads_plurals() {
+        let resources = write_to_file_and_read_resources_out(
+            r##"<?xml version="1.0" encoding="utf-8"?>
+            <resources>
+                <string name="s1">value</string>
+                <plurals name="p1">
+                    <item quantity="one">%d <![CDATA[<b>item</b>]]></item>
+                    <item quantity="other">%d items</item>
+                    <unknown>value</unknown>
+                </plurals>
+                <plurals name="p2" translatable="false">
+                    <item quantity="other">value</item>
+                </plurals>
+            </resources>
+        "##,
+        );
+
+        assert_eq!(
+            resources,
+            AndroidResources::new(
+                vec![AndroidString::localizable("s1", "value")],
+                vec![
+                    AndroidPlural::localizable(
+                        "p1",
+                        vec![
+                            (Quantity::One, "%d <![CDATA[<b>item</b>]]>"),
+                            (Quantity::Other, "%d items")
+                        ]
+                    ),
+                    AndroidPlural::unlocalizable("p2", vec![(Quantity::Other, "value")]),
+                ],
+                vec![]
+            )
+        )
+    }
+
+    #[test]
+    fn reads_string_arrays() {
+        let resources = write_to_file_and_read_resources_out(
+            r##"<?xml version="1.0" encoding="utf-8"?>
+            <resources>
+                <string name="s1">value</string>
+                <string-array name="a1">
+                    <item>first</item>
+                    <item></item>
+                    <unknown>value</unknown>
+                    <item><![CDATA[<b>third</b>]]></item>
+                </string-array>
+                <string-array name="a2" translatable="false">
+                    <item>value</item>
+                </string-array>
+            </resources>
+        "##,
+        );
+
+        assert_eq!(
+            resources,
+            AndroidResources::new(
+                vec![AndroidString::localizable("s1", "value")],
+                vec![],
+                vec![
+                    AndroidStringArray::localizable(
+                        "a1",
+                        vec!["first", "", "<![CDATA[<b>third</b>]]>"]
+                    ),
+                    AndroidStringArray::unlocalizable("a2", vec!["value"]),
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn reads_inline_markup_verbatim() {
+        let resources = write_to_file_and_read_resources_out(
+            r##"<?xml version="1.0" encoding="utf-8"?>
+            <resources xmlns:xliff="urn:oasis:names:tc:xliff:document:1.2">
+                <string name="s1">Hi <b>you</b> <i>&amp;</i> <annotation font="a &lt; b">them</annotation></string>
+                <string name="s2"><xliff:g id="count" example="5">%1$d</xliff:g> &lt; items<br/></string>
+                <plurals name="p1">
+                    <item quantity="other"><u>%d</u> items</item>
+                </plurals>
+                <string-array name="a1">
+                    <item><b>first</b></item>
+                </string-array>
+            </resources>
+        "##,
+        );
+
+        assert_eq!(
+            resources,
+            AndroidResources::new(
+                vec![
+                    AndroidString::localizable(
+                        "s1",
+                        r##"Hi <b>you</b> <i>&amp;</i> <annotation font="a &lt; b">them</annotation>"##
+                    ),
+                    AndroidString::localizable(
+                        "s2",
+                        r##"<xliff:g id="count" example="5">%1$d</xliff:g> &lt; items<br/>"##
+                    ),
+                ],
+                vec![AndroidPlural::localizable(
+                    "p1",
+                    vec![(Quantity::Other, "<u>%d</u> items")]
+                )],
+                vec![AndroidStringArray::localizable("a1", vec!["<b>first</b>"])]
+            )
+        )
+    }
+
+    #[test]
+    fn reads_comments_right_before_resources() {
+        let resources = write_to_file_and_read_resources_out(
+            r##"<?xml version="1.0" encoding="utf-8"?>
+            <!-- Not of any resource -->
+            <resources>
+                <!-- Greeting on the home screen -->
+                <string name="s1">value <!-- Not a comment of a resource -->value</string>
+                <string name="s2">value</string>
+                <!-- Of something other than a resource -->
+                <dimen name="d1">1dp</dimen>
+                <string name="s3">value</string>
+                <!-- Count of items -->
+                <plurals name="p1">
+                    <item quantity="other">%d items</item>
+                </plurals>
+                <!-- Days of a week -->
+                <string-array name="a1">
+                    <item>first</item>
+                </string-array>
+            </resources>
+        "##,
+        );
+
+        assert_eq!(
+            resources,
+            AndroidResources::new(
+                vec![
+                    AndroidString::localizable("s1", "value value")
+                        .with_comment(Some(String::from("Greeting on the home screen"))),
+                    AndroidString::localizable("s2", "value"),
+                    AndroidString::localizable("s3", "value"),
+                ],
+                vec![
+                    AndroidPlural::localizable("p1", vec![(Quantity::Other, "%d items")])
+                        .with_comment(Some(String::from("Count of items")))
+                ],
+                vec![AndroidStringArray::localizable("a1", vec!["first"])
+                    .with_comment(Some(String::from("Days of a week")))]
+            )
+        )
+    }
+
+    #[test]
+    fn reads_max_lengths_of_strings() {
+        let resources = write_to_file_and_read_resources_out(
+            r##"<?xml version="1.0" encoding="utf-8"?>
+            <resources xmlns:tools="http://schemas.android.com/tools">
+                <!-- Used on the checkout button -->
+                <string name="s1" tools:maxLength="20">value</string>
+                <string name="s2" maxLength="30">value</string>
+            </resources>
+        "##,
+        );
+
+        assert_eq!(
+            resources,
+            AndroidResources::new(
+                vec![
+                    AndroidString::localizable("s1", "value")
+                        .with_comment(Some(String::from("Used on the checkout button")))
+                        .with_max_length(Some(20)),
+                    AndroidString::localizable("s2", "value"),
+                ],
+                vec![],
+                vec![]
+            )
+        )
+    }
+
+    #[test]
+    fn ignores_invalid_max_lengths_with_a_warning() {
+        let (resources, warnings) = warnings::collect(|| {
+            write_to_file_and_read_resources_out(
+                r##"<resources xmlns:tools="http://schemas.android.com/tools">
+                <string name="s1" tools:maxLength="short">value</string>
+            </resources>
+        "##,
+            )
+        });
+
+        assert_eq!(
+            resources,
+            AndroidResources::new(
+                vec![AndroidString::localizable("s1", "value")],
+                vec![],
+                vec![]
+            )
+        );
+        assert_eq!(
+            warnings
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>(),
+            vec!["s1: short isn't a valid max length & is ignored"]
+        )
+    }
+
+    fn write_to_file_and_read_resources_out(file_content: &str) -> AndroidResources {
         // Write content to file
         let mut tmpfile: File = tempfile::tempfile().unwrap();
         tmpfile.write_all(file_content.as_bytes()).unwrap();
@@ -149,7 +373,7 @@ mod tests {
         // Seek to start
         tmpfile.seek(SeekFrom::Start(0)).unwrap();
 
-        // Read strings from file
+        // Read resources from file
         super::read(tmpfile.try_clone().unwrap()).unwrap()
     }
 }
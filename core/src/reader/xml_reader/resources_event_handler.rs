@@ -3,7 +3,9 @@ use xml::attribute::OwnedAttribute;
 use crate::constants;
 use crate::error::InnerError;
 use crate::reader::xml_reader::event_handler::EventHandler;
+use crate::reader::xml_reader::plurals_event_handler::PluralsEventHandler;
 use crate::reader::xml_reader::sinking_event_handler::SinkingEventHandler;
+use crate::reader::xml_reader::string_array_event_handler::StringArrayEventHandler;
 use crate::reader::xml_reader::string_event_handler::StringEventHandler;
 
 pub struct ResourcesEventHandler {}
@@ -22,6 +24,10 @@ impl EventHandler for ResourcesEventHandler {
     ) -> Result<Box<dyn EventHandler>, InnerError> {
         match tag_name.as_str() {
             constants::elements::STRING => Ok(Box::new(StringEventHandler::build(attributes)?)),
+            constants::elements::PLURALS => Ok(Box::new(PluralsEventHandler::build(attributes)?)),
+            constants::elements::STRING_ARRAY => {
+                Ok(Box::new(StringArrayEventHandler::build(attributes)?))
+            }
             _ => Ok(Box::new(SinkingEventHandler::new())),
         }
     }
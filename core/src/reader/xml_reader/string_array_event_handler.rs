@@ -0,0 +1,91 @@
+use xml::attribute::OwnedAttribute;
+
+use crate::android_string_array::AndroidStringArray;
+use crate::constants;
+use crate::error::InnerError;
+use crate::reader::xml_reader::array_item_event_handler::ArrayItemEventHandler;
+use crate::reader::xml_reader::event_handler::EventHandler;
+use crate::reader::xml_reader::sinking_event_handler::SinkingEventHandler;
+
+pub struct StringArrayEventHandler {
+    name: String,
+    is_localizable: bool,
+    items: Vec<String>,
+}
+
+impl StringArrayEventHandler {
+    pub fn build(attributes: Vec<OwnedAttribute>) -> Result<StringArrayEventHandler, InnerError> {
+        let mut array_name = None;
+        let mut is_localizable = true;
+        for attribute in attributes {
+            match attribute.name.local_name.as_str() {
+                constants::attributes::NAME => array_name = Some(attribute.value),
+                constants::attributes::LOCALIZABLE => {
+                    if let constants::flags::FALSE = attribute.value.as_str() {
+                        is_localizable = false
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        match array_name {
+            None => Err("string-array element is missing required name attribute".into()),
+            Some(name) => Ok(StringArrayEventHandler {
+                name,
+                is_localizable,
+                items: vec![],
+            }),
+        }
+    }
+}
+
+impl EventHandler for StringArrayEventHandler {
+    fn build_handler(
+        &self,
+        tag_name: String,
+        _attributes: Vec<OwnedAttribute>,
+    ) -> Result<Box<dyn EventHandler>, InnerError> {
+        match tag_name.as_str() {
+            constants::elements::ITEM => Ok(Box::new(ArrayItemEventHandler::new())),
+            _ => Ok(Box::new(SinkingEventHandler::new())),
+        }
+    }
+
+    fn handle_array_item(&mut self, item: String) {
+        self.items.push(item)
+    }
+
+    fn built_string_array(&self) -> Option<AndroidStringArray> {
+        Some(AndroidStringArray::new(
+            self.name.clone(),
+            self.items.clone(),
+            self.is_localizable,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::android_string_array::AndroidStringArray;
+    use crate::reader::xml_reader::event_handler::EventHandler;
+
+    use super::StringArrayEventHandler;
+
+    #[test]
+    fn builds_string_array_with_items_in_order() {
+        let mut handler = StringArrayEventHandler {
+            name: String::from("test_array"),
+            is_localizable: false,
+            items: vec![],
+        };
+
+        handler.handle_array_item(String::from("second"));
+        handler.handle_array_item(String::from("first"));
+
+        assert_eq!(
+            handler.built_string_array().unwrap(),
+            AndroidStringArray::unlocalizable("test_array", vec!["second", "first"])
+        )
+    }
+}
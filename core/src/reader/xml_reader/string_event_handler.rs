@@ -4,11 +4,14 @@ use crate::android_string::AndroidString;
 use crate::constants;
 use crate::error::InnerError;
 use crate::reader::xml_reader::event_handler::EventHandler;
-use crate::reader::xml_reader::sinking_event_handler::SinkingEventHandler;
+use crate::reader::xml_reader::markup_event_handler;
+use crate::reader::xml_reader::markup_event_handler::MarkupEventHandler;
+use crate::warnings;
 
 pub struct StringEventHandler {
     name: String,
     is_localizable: bool,
+    max_length: Option<usize>,
     built_android_string: Option<AndroidString>,
 }
 
@@ -16,8 +19,19 @@ impl StringEventHandler {
     pub fn build(attributes: Vec<OwnedAttribute>) -> Result<StringEventHandler, InnerError> {
         let mut string_name = None;
         let mut is_localizable = true;
+        let mut max_length = None;
+        let mut invalid_max_length = None;
         for attribute in attributes {
+            let is_tools = attribute.name.namespace.as_deref()
+                == Some(constants::namespaces::TOOLS)
+                || attribute.name.prefix.as_deref() == Some(constants::namespaces::TOOLS_PREFIX);
             match attribute.name.local_name.as_str() {
+                constants::attributes::MAX_LENGTH if is_tools => {
+                    match attribute.value.trim().parse() {
+                        Ok(length) => max_length = Some(length),
+                        Err(_) => invalid_max_length = Some(attribute.value),
+                    }
+                }
                 constants::attributes::NAME => string_name = Some(attribute.value),
                 constants::attributes::LOCALIZABLE => {
                     if let constants::flags::FALSE = attribute.value.as_str() {
@@ -30,11 +44,22 @@ impl StringEventHandler {
 
         match string_name {
             None => Err("string element is missing required name attribute".into()),
-            Some(name) => Ok(StringEventHandler {
-                name,
-                is_localizable,
-                built_android_string: None,
-            }),
+            Some(name) => {
+                // Max lengths are only hints. A string mustn't be lost over one
+                if let Some(value) = invalid_max_length {
+                    warnings::warn(
+                        &name,
+                        format!("{} isn't a valid max length & is ignored", value),
+                    );
+                }
+
+                Ok(StringEventHandler {
+                    name,
+                    is_localizable,
+                    max_length,
+                    built_android_string: None,
+                })
+            }
         }
     }
 
@@ -44,31 +69,34 @@ impl StringEventHandler {
             Some(s) => format!("{}{}", s.value(), text),
         };
 
-        self.built_android_string = Some(AndroidString::new(
-            self.name.clone(),
-            text,
-            self.is_localizable,
-        ));
+        self.built_android_string = Some(
+            AndroidString::new(self.name.clone(), text, self.is_localizable)
+                .with_max_length(self.max_length),
+        );
     }
 }
 
 impl EventHandler for StringEventHandler {
     fn build_handler(
         &self,
-        _tag_name: String,
-        _attributes: Vec<OwnedAttribute>,
+        tag_name: String,
+        attributes: Vec<OwnedAttribute>,
     ) -> Result<Box<dyn EventHandler>, InnerError> {
-        Ok(Box::new(SinkingEventHandler::new()))
+        Ok(Box::new(MarkupEventHandler::new(tag_name, attributes)))
     }
 
     fn handle_characters_event(&mut self, text: String) {
-        self.append_or_create_string(text)
+        self.append_or_create_string(markup_event_handler::escape_text(&text))
     }
 
     fn handle_cdata_event(&mut self, text: String) {
         self.append_or_create_string(format!("<![CDATA[{}]]>", text))
     }
 
+    fn handle_markup(&mut self, markup: String) {
+        self.append_or_create_string(markup)
+    }
+
     fn built_string(&self) -> Option<AndroidString> {
         self.built_android_string.clone()
     }
@@ -127,6 +155,7 @@ mod tests {
         StringEventHandler {
             name: String::from("test_string"),
             is_localizable: true,
+            max_length: None,
             built_android_string: None,
         }
     }
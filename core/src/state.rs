@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::error::InnerError;
+use crate::ops::keys;
+
+/// Hashes of the default values the translations were made against, by locale
+/// ID & key (look @ `keys::build`). Written out by `localized` & read by
+/// `localize` to find the translations whose default value has changed since.
+/// Plurals & string arrays are tracked by their flattened names (Eg., `p1[one]`
+/// & `a1[0]`)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct State {
+    hashes: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl State {
+    /// A missing file is read as an empty state
+    pub fn read(path: &Path) -> Result<State, InnerError> {
+        if !path.exists() {
+            return Ok(State::default());
+        }
+
+        let mut content = String::new();
+        File::open(path)?.read_to_string(&mut content)?;
+        let document: Value = serde_json::from_str(&content)?;
+        let invalid = || InnerError::from("State file isn't laid out as expected");
+
+        let mut hashes = BTreeMap::new();
+        for (locale_id, locale_hashes) in document.as_object().ok_or_else(invalid)? {
+            let mut locale_hashes_map = BTreeMap::new();
+            for (key, hash) in locale_hashes.as_object().ok_or_else(invalid)? {
+                locale_hashes_map.insert(
+                    key.clone(),
+                    String::from(hash.as_str().ok_or_else(invalid)?),
+                );
+            }
+
+            hashes.insert(locale_id.clone(), locale_hashes_map);
+        }
+
+        Ok(State { hashes })
+    }
+
+    /// Locales & keys are sorted so that the file can be committed & diffed
+    pub fn write(&self, path: &Path) -> Result<(), InnerError> {
+        let mut document = Map::new();
+        for (locale_id, locale_hashes) in &self.hashes {
+            document.insert(
+                locale_id.clone(),
+                Value::Object(
+                    locale_hashes
+                        .iter()
+                        .map(|(key, hash)| (key.clone(), Value::from(hash.as_str())))
+                        .collect(),
+                ),
+            );
+        }
+
+        let mut file = File::create(path)?;
+        serde_json::to_writer_pretty(&mut file, &Value::Object(document))?;
+        file.write_all(b"\n")?;
+        Ok(())
+    }
+
+    pub fn record(
+        &mut self,
+        locale_id: &str,
+        module: Option<&str>,
+        name: &str,
+        default_value: &str,
+    ) {
+        self.hashes
+            .entry(String::from(locale_id))
+            .or_default()
+            .insert(keys::build(name, module), hash(default_value));
+    }
+
+    /// Strings that were never recorded aren't considered stale since there is
+    /// no telling what they were localized against
+    pub fn is_stale(
+        &self,
+        locale_id: &str,
+        module: Option<&str>,
+        name: &str,
+        default_value: &str,
+    ) -> bool {
+        self.hashes
+            .get(locale_id)
+            .and_then(|locale_hashes| locale_hashes.get(&keys::build(name, module)))
+            .is_some_and(|recorded_hash| *recorded_hash != hash(default_value))
+    }
+}
+
+/// 64 bit FNV-1a as a hex string. Unlike `DefaultHasher`, it is guaranteed to
+/// stay the same across builds
+fn hash(value: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in value.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::State;
+
+    #[test]
+    fn hashes_are_stable() {
+        assert_eq!(super::hash(""), "cbf29ce484222325");
+        assert_eq!(super::hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn finds_stale_strings() {
+        let mut state = State::default();
+        state.record("fr", None, "s1", "english 1");
+        state.record("fr", Some("lib/src/main/res"), "s1", "english 1");
+
+        assert!(!state.is_stale("fr", None, "s1", "english 1"));
+        assert!(state.is_stale("fr", None, "s1", "new english 1"));
+        assert!(state.is_stale("fr", Some("lib/src/main/res"), "s1", "new english 1"));
+        assert!(!state.is_stale("fr", Some("app/src/main/res"), "s1", "new english 1"));
+        assert!(!state.is_stale("es", None, "s1", "new english 1"));
+        assert!(!state.is_stale("fr", None, "s2", "new english 2"));
+    }
+
+    #[test]
+    fn writes_and_reads_state() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("state.json");
+        assert_eq!(State::read(&path).unwrap(), State::default());
+
+        let mut state = State::default();
+        state.record("fr", None, "s2", "english 2");
+        state.record("fr", None, "s1", "english 1");
+        state.record("de", None, "s1", "english 1");
+        state.write(&path).unwrap();
+
+        assert_eq!(State::read(&path).unwrap(), state);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            format!(
+                "{{\n  \"de\": {{\n    \"s1\": \"{h1}\"\n  }},\n  \"fr\": {{\n    \"s1\": \"{h1}\",\n    \"s2\": \"{h2}\"\n  }}\n}}\n",
+                h1 = super::hash("english 1"),
+                h2 = super::hash("english 2")
+            )
+        );
+    }
+
+    #[test]
+    fn errors_for_invalid_state_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("state.json");
+        fs::write(&path, r#"{"fr": ["s1"]}"#).unwrap();
+        assert_eq!(
+            State::read(&path).unwrap_err().to_string(),
+            "State file isn't laid out as expected"
+        );
+    }
+}
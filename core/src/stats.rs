@@ -0,0 +1,347 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::localizable_strings::LocalizableStrings;
+use crate::ops::text;
+use crate::ops::tokens;
+use crate::ops::tokens::Token;
+
+/// How much there is to be localized for a locale. Format specifiers & markup
+/// aren't counted (look @ `to_countable_text`). A string whose text is the same
+/// as that of a string before it (for the same locale) is a repeated segment
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LocaleStats {
+    pub locale: String,
+    pub strings: usize,
+    pub words: usize,
+    /// Whitespace isn't counted
+    pub characters: usize,
+    pub repeated_segments: usize,
+    pub repeated_words: usize,
+}
+
+impl LocaleStats {
+    /// Words in repeated segments aren't charged for
+    pub fn cost(&self, rate_per_word: f64) -> f64 {
+        (self.words - self.repeated_words) as f64 * rate_per_word
+    }
+
+    fn add(&mut self, other: &LocaleStats) {
+        self.strings += other.strings;
+        self.words += other.words;
+        self.characters += other.characters;
+        self.repeated_segments += other.repeated_segments;
+        self.repeated_words += other.repeated_words;
+    }
+}
+
+/// Stats of every locale in the same order
+pub fn compute(localizable_strings_list: &[LocalizableStrings]) -> Vec<LocaleStats> {
+    localizable_strings_list
+        .iter()
+        .map(|localizable_strings| {
+            let mut stats = LocaleStats {
+                locale: String::from(localizable_strings.to_locale()),
+                ..Default::default()
+            };
+
+            let mut seen_texts = HashSet::new();
+            for string in localizable_strings.default_locale_strings() {
+                let text = to_countable_text(string.value());
+                let words = count_words(&text);
+                stats.strings += 1;
+                stats.words += words;
+                stats.characters += text.chars().filter(|c| !c.is_whitespace()).count();
+                if !seen_texts.insert(text) {
+                    stats.repeated_segments += 1;
+                    stats.repeated_words += words;
+                }
+            }
+
+            stats
+        })
+        .collect()
+}
+
+/// A table with a row per locale & the totals. With rates (per word, by
+/// locale), a cost column is written out too. Locales without a rate don't
+/// have a cost
+pub fn format_to_string(stats_list: &[LocaleStats], rates: &HashMap<String, f64>) -> String {
+    let mut rows = vec![vec![
+        String::from("locale"),
+        String::from("strings"),
+        String::from("words"),
+        String::from("characters"),
+        String::from("repeated segments"),
+        String::from("repeated words"),
+    ]];
+
+    let mut add_row = |locale: &str, stats: &LocaleStats, cost: Option<f64>| {
+        let mut row = vec![
+            String::from(locale),
+            stats.strings.to_string(),
+            stats.words.to_string(),
+            stats.characters.to_string(),
+            stats.repeated_segments.to_string(),
+            stats.repeated_words.to_string(),
+        ];
+
+        if !rates.is_empty() {
+            row.push(cost.map_or(String::new(), |cost| format!("{:.2}", cost)));
+        }
+
+        rows.push(row);
+    };
+
+    for stats in stats_list {
+        add_row(&stats.locale, stats, cost_of(stats, rates));
+    }
+
+    add_row(
+        "total",
+        &total_of(stats_list),
+        total_cost_of(stats_list, rates),
+    );
+    if !rates.is_empty() {
+        rows[0].push(String::from("cost"));
+    }
+
+    // Locales are left aligned & numbers are right aligned
+    let column_count = rows[0].len();
+    let widths = (0..column_count)
+        .map(|index| {
+            rows.iter()
+                .map(|row| row[index].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<usize>>();
+
+    let mut output = String::new();
+    for row in rows {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(index, (cell, width))| {
+                if index == 0 {
+                    format!("{:<width$}", cell, width = width)
+                } else {
+                    format!("{:>width$}", cell, width = width)
+                }
+            })
+            .collect::<Vec<String>>();
+        output.push_str(cells.join("  ").trim_end());
+        output.push('\n');
+    }
+
+    output
+}
+
+/// An object with the stats of every locale (in a `locales` array) & the
+/// totals. With rates (per word, by locale), the rate & the cost are written
+/// out for the locales with a rate
+pub fn format_to_json(stats_list: &[LocaleStats], rates: &HashMap<String, f64>) -> String {
+    let to_object = |stats: &LocaleStats, rate: Option<f64>, cost: Option<f64>| {
+        let mut object = Map::new();
+        object.insert(String::from("strings"), Value::from(stats.strings));
+        object.insert(String::from("words"), Value::from(stats.words));
+        object.insert(String::from("characters"), Value::from(stats.characters));
+        object.insert(
+            String::from("repeated_segments"),
+            Value::from(stats.repeated_segments),
+        );
+        object.insert(
+            String::from("repeated_words"),
+            Value::from(stats.repeated_words),
+        );
+        if let Some(rate) = rate {
+            object.insert(String::from("rate"), Value::from(rate));
+        }
+
+        if let Some(cost) = cost {
+            object.insert(String::from("cost"), Value::from(cost));
+        }
+
+        object
+    };
+
+    let locales = stats_list
+        .iter()
+        .map(|stats| {
+            let mut object = Map::new();
+            object.insert(String::from("locale"), Value::from(stats.locale.as_str()));
+            object.extend(to_object(
+                stats,
+                rates.get(&stats.locale).copied(),
+                cost_of(stats, rates),
+            ));
+            Value::Object(object)
+        })
+        .collect::<Vec<Value>>();
+
+    let mut document = Map::new();
+    document.insert(String::from("locales"), Value::Array(locales));
+    document.insert(
+        String::from("total"),
+        Value::Object(to_object(
+            &total_of(stats_list),
+            None,
+            total_cost_of(stats_list, rates),
+        )),
+    );
+
+    Value::Object(document).to_string()
+}
+
+fn cost_of(stats: &LocaleStats, rates: &HashMap<String, f64>) -> Option<f64> {
+    rates.get(&stats.locale).map(|rate| stats.cost(*rate))
+}
+
+fn total_of(stats_list: &[LocaleStats]) -> LocaleStats {
+    let mut total = LocaleStats::default();
+    for stats in stats_list {
+        total.add(stats);
+    }
+
+    total
+}
+
+/// Only the locales with a rate are added up
+fn total_cost_of(stats_list: &[LocaleStats], rates: &HashMap<String, f64>) -> Option<f64> {
+    if rates.is_empty() {
+        None
+    } else {
+        Some(
+            stats_list
+                .iter()
+                .filter_map(|stats| cost_of(stats, rates))
+                .sum(),
+        )
+    }
+}
+
+/// Text as it would be shown to the user (look @ `text::to_plain_text`) with
+/// format specifiers & markup (look @ `Token`) swapped for spaces
+fn to_countable_text(android_value: &str) -> String {
+    let mut value = String::with_capacity(android_value.len());
+    for token in tokens::tokenize(android_value) {
+        match token {
+            Token::Text(c) => value.push(c),
+
+            // Escapes & entities are resolved into text
+            Token::Verbatim(verbatim)
+                if verbatim.starts_with('\\') || verbatim.starts_with('&') =>
+            {
+                value.push_str(verbatim)
+            }
+
+            Token::Verbatim(_) => value.push(' '),
+        }
+    }
+
+    text::to_plain_text(&value)
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Only the parts with a letter or a digit in them are words
+fn count_words(text: &str) -> usize {
+    text.split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use test_utilities;
+
+    use super::LocaleStats;
+    use crate::android_string::AndroidString;
+    use crate::localizable_strings::LocalizableStrings;
+
+    fn build_stats_list() -> Vec<LocaleStats> {
+        super::compute(&[
+            LocalizableStrings::new(
+                String::from("french"),
+                vec![
+                    AndroidString::localizable("s1", "Hi <b>%1$s</b>, you\\'ve got mail!"),
+                    AndroidString::localizable("s2", "Cancel"),
+                    AndroidString::localizable("s3", "<i>Cancel</i>"),
+                    AndroidString::localizable("s4", "%1$d / %2$d"),
+                ],
+            ),
+            LocalizableStrings::new(
+                String::from("spanish"),
+                vec![AndroidString::localizable("s2", "Cancel")],
+            ),
+        ])
+    }
+
+    #[test]
+    fn computes() {
+        test_utilities::list::assert_strict_list_eq(
+            build_stats_list(),
+            vec![
+                LocaleStats {
+                    locale: String::from("french"),
+                    strings: 4,
+                    words: 6,
+                    characters: 30,
+                    repeated_segments: 1,
+                    repeated_words: 1,
+                },
+                LocaleStats {
+                    locale: String::from("spanish"),
+                    strings: 1,
+                    words: 1,
+                    characters: 6,
+                    repeated_segments: 0,
+                    repeated_words: 0,
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn formats_to_string() {
+        assert_eq!(
+            super::format_to_string(&build_stats_list(), &HashMap::new()),
+            r#"locale   strings  words  characters  repeated segments  repeated words
+french         4      6          30                  1               1
+spanish        1      1           6                  0               0
+total          5      7          36                  1               1
+"#
+        );
+    }
+
+    #[test]
+    fn formats_to_string_with_rates() {
+        let mut rates = HashMap::new();
+        rates.insert(String::from("french"), 0.1);
+        assert_eq!(
+            super::format_to_string(&build_stats_list(), &rates),
+            r#"locale   strings  words  characters  repeated segments  repeated words  cost
+french         4      6          30                  1               1  0.50
+spanish        1      1           6                  0               0
+total          5      7          36                  1               1  0.50
+"#
+        );
+    }
+
+    #[test]
+    fn formats_to_json_with_rates() {
+        let mut rates = HashMap::new();
+        rates.insert(String::from("spanish"), 0.25);
+        assert_eq!(
+            super::format_to_json(&build_stats_list(), &rates),
+            r#"{"locales":[{"locale":"french","strings":4,"words":6,"characters":30,"repeated_segments":1,"repeated_words":1},{"locale":"spanish","strings":1,"words":1,"characters":6,"repeated_segments":0,"repeated_words":0,"rate":0.25,"cost":0.25}],"total":{"strings":5,"words":7,"characters":36,"repeated_segments":1,"repeated_words":1,"cost":0.25}}"#
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use std::fmt;
+
+use crate::constants;
+
+/// Used when the document doesn't give away how it is indented
+const DEFAULT_INDENT: &str = "    ";
+
+/// (Prefix, URI) of the namespaces values could refer to. Look @
+/// `StringsDocument::declare_namespaces_used_by`
+const NAMESPACES: [(&str, &str); 2] = [
+    (
+        constants::namespaces::XLIFF_PREFIX,
+        constants::namespaces::XLIFF,
+    ),
+    (
+        constants::namespaces::TOOLS_PREFIX,
+        constants::namespaces::TOOLS,
+    ),
+];
+
+/// An ordered model of a strings file which holds on to everything in it as is
+/// (declarations, comments, whitespace, unknown elements, attributes this utility
+/// doesn't care about etc.). Only the elements that are upserted are rewritten
+/// & the rest of the file round-trips untouched
+#[derive(Clone, Debug, PartialEq)]
+pub struct StringsDocument {
+    prolog: String,
+    nodes: Vec<Node>,
+    epilog: String,
+}
+
+/// A direct child of the `resources` element
+#[derive(Clone, Debug, PartialEq)]
+pub enum Node {
+    /// Text, comments, processing instructions etc. between elements
+    Raw(String),
+    Element {
+        tag_name: String,
+        name: Option<String>,
+        markup: String,
+    },
+}
+
+impl StringsDocument {
+    /// `prolog` is everything up to & including the start tag of the `resources`
+    /// element & `epilog` is everything from its end tag
+    pub fn new(prolog: String, nodes: Vec<Node>, epilog: String) -> StringsDocument {
+        StringsDocument {
+            prolog,
+            nodes,
+            epilog,
+        }
+    }
+
+    /// A document for a new strings file without any elements
+    pub fn empty() -> StringsDocument {
+        StringsDocument::new(
+            format!(
+                "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<{}>",
+                constants::elements::RESOURCES
+            ),
+            vec![],
+            format!("</{}>\n", constants::elements::RESOURCES),
+        )
+    }
+
+    /// The indentation of the elements in this document
+    pub fn indent(&self) -> String {
+        let mut preceding_raw_text = None;
+        for node in &self.nodes {
+            match node {
+                Node::Raw(text) => preceding_raw_text = Some(text),
+                Node::Element { .. } => break,
+            }
+        }
+
+        preceding_raw_text
+            .and_then(|text| text.rfind('\n').map(|index| &text[index + 1..]))
+            .filter(|indent| !indent.is_empty() && indent.trim().is_empty())
+            .map_or_else(|| String::from(DEFAULT_INDENT), String::from)
+    }
+
+    pub fn line_ending(&self) -> &'static str {
+        let has_crlf = self.prolog.contains("\r\n")
+            || self.nodes.iter().any(|node| match node {
+                Node::Raw(text) => text.contains("\r\n"),
+                Node::Element { markup, .. } => markup.contains("\r\n"),
+            });
+
+        if has_crlf {
+            "\r\n"
+        } else {
+            "\n"
+        }
+    }
+
+    /// Replaces the markup of the first element with the same tag & name. If
+    /// there is no such element, the markup is added as the last element
+    pub fn upsert(&mut self, tag_name: &str, name: &str, markup: String) {
+        self.declare_namespaces_used_by(&markup);
+        let existing_element = self.nodes.iter_mut().find(|node| match node {
+            Node::Raw(_) => false,
+            Node::Element {
+                tag_name: existing_tag_name,
+                name: existing_name,
+                ..
+            } => existing_tag_name == tag_name && existing_name.as_deref() == Some(name),
+        });
+
+        if let Some(Node::Element {
+            markup: existing_markup,
+            ..
+        }) = existing_element
+        {
+            *existing_markup = markup;
+            return;
+        }
+
+        self.push(tag_name, name, markup)
+    }
+
+    /// Adds the markup as the last element
+    pub fn push(&mut self, tag_name: &str, name: &str, markup: String) {
+        self.declare_namespaces_used_by(&markup);
+
+        // Whatever whitespace is before the end tag of `resources` must stay there
+        let line_ending = self.line_ending();
+        let indent = self.indent();
+        let mut trailing_whitespace = String::from(line_ending);
+        if let Some(Node::Raw(text)) = self.nodes.last_mut() {
+            let content_length = text.trim_end().len();
+            if content_length < text.len() {
+                trailing_whitespace = text.split_off(content_length);
+            }
+
+            if text.is_empty() {
+                self.nodes.pop();
+            }
+        }
+
+        self.nodes
+            .push(Node::Raw(format!("{}{}", line_ending, indent)));
+        self.nodes.push(Node::Element {
+            tag_name: String::from(tag_name),
+            name: Some(String::from(name)),
+            markup,
+        });
+        self.nodes.push(Node::Raw(trailing_whitespace));
+    }
+
+    /// Markup with `xliff:g` elements or `tools:...` attributes is malformed
+    /// unless the `resources` element declares their namespace. Existing files
+    /// often don't (they didn't need it till now), so it is added to the start
+    /// tag of `resources` (the end of the prolog)
+    fn declare_namespaces_used_by(&mut self, markup: &str) {
+        for (prefix, uri) in &NAMESPACES {
+            let is_used = markup.contains(&format!("<{}:", prefix))
+                || markup.contains(&format!(" {}:", prefix));
+            if is_used && !self.declares_namespace(prefix) {
+                let start_tag_end_index = self.prolog.len() - 1;
+                self.prolog.insert_str(
+                    start_tag_end_index,
+                    &format!(" xmlns:{}=\"{}\"", prefix, uri),
+                );
+            }
+        }
+    }
+
+    fn declares_namespace(&self, prefix: &str) -> bool {
+        let start_tag = &self.prolog[self.prolog.rfind('<').unwrap_or(0)..];
+        let declaration = format!("xmlns:{}", prefix);
+        start_tag.match_indices(&declaration).any(|(index, _)| {
+            start_tag[index + declaration.len()..]
+                .trim_start()
+                .starts_with('=')
+        })
+    }
+}
+
+impl fmt::Display for StringsDocument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.prolog)?;
+        for node in &self.nodes {
+            match node {
+                Node::Raw(text) => f.write_str(text)?,
+                Node::Element { markup, .. } => f.write_str(markup)?,
+            }
+        }
+
+        f.write_str(&self.epilog)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Node;
+    use super::StringsDocument;
+
+    #[test]
+    fn upsert_replaces_existing_element() {
+        let mut document = build_document(vec![
+            raw("\n  "),
+            element("string", "s1", "<string name=\"s1\">old</string>"),
+            raw("\n  <!-- comment -->\n  "),
+            element("plurals", "s1", "<plurals name=\"s1\"/>"),
+            raw("\n"),
+        ]);
+
+        document.upsert(
+            "string",
+            "s1",
+            String::from("<string name=\"s1\">new</string>"),
+        );
+        assert_eq!(
+            document.to_string(),
+            "<resources>\n  <string name=\"s1\">new</string>\n  <!-- comment -->\n  <plurals name=\"s1\"/>\n</resources>\n"
+        )
+    }
+
+    #[test]
+    fn upsert_inserts_missing_element_with_document_indent() {
+        let mut document = build_document(vec![
+            raw("\n\t"),
+            element("string", "s1", "<string name=\"s1\">value</string>"),
+            raw("\n"),
+        ]);
+
+        document.upsert(
+            "string",
+            "s2",
+            String::from("<string name=\"s2\">value</string>"),
+        );
+        assert_eq!(
+            document.to_string(),
+            "<resources>\n\t<string name=\"s1\">value</string>\n\t<string name=\"s2\">value</string>\n</resources>\n"
+        )
+    }
+
+    #[test]
+    fn upsert_inserts_into_empty_document() {
+        let mut document = build_document(vec![]);
+        document.upsert(
+            "string",
+            "s1",
+            String::from("<string name=\"s1\">value</string>"),
+        );
+        assert_eq!(
+            document.to_string(),
+            "<resources>\n    <string name=\"s1\">value</string>\n</resources>\n"
+        )
+    }
+
+    #[test]
+    fn upsert_keeps_crlf_line_endings() {
+        let mut document = build_document(vec![raw("\r\n  <!-- comment -->\r\n")]);
+        document.upsert(
+            "string",
+            "s1",
+            String::from("<string name=\"s1\">value</string>"),
+        );
+        assert_eq!(
+            document.to_string(),
+            "<resources>\r\n  <!-- comment -->\r\n    <string name=\"s1\">value</string>\r\n</resources>\n"
+        )
+    }
+
+    #[test]
+    fn upsert_declares_namespaces_used_by_markup() {
+        let mut document = StringsDocument::new(
+            String::from("<resources xmlns:tools=\"http://schemas.android.com/tools\">"),
+            vec![],
+            String::from("</resources>\n"),
+        );
+
+        document.upsert(
+            "string",
+            "s1",
+            String::from("<string name=\"s1\" tools:ignore=\"x\">a <xliff:g id=\"n\">%1$d</xliff:g></string>"),
+        );
+        document.upsert(
+            "string",
+            "s2",
+            String::from("<string name=\"s2\">b <xliff:g>%2$d</xliff:g></string>"),
+        );
+        assert_eq!(
+            document.to_string(),
+            "<resources xmlns:tools=\"http://schemas.android.com/tools\" xmlns:xliff=\"urn:oasis:names:tc:xliff:document:1.2\">\n    <string name=\"s1\" tools:ignore=\"x\">a <xliff:g id=\"n\">%1$d</xliff:g></string>\n    <string name=\"s2\">b <xliff:g>%2$d</xliff:g></string>\n</resources>\n"
+        )
+    }
+
+    fn build_document(nodes: Vec<Node>) -> StringsDocument {
+        StringsDocument::new(
+            String::from("<resources>"),
+            nodes,
+            String::from("</resources>\n"),
+        )
+    }
+
+    fn raw(text: &str) -> Node {
+        Node::Raw(String::from(text))
+    }
+
+    fn element(tag_name: &str, name: &str, markup: &str) -> Node {
+        Node::Element {
+            tag_name: String::from(tag_name),
+            name: Some(String::from(name)),
+            markup: String::from(markup),
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use crate::android_string::AndroidString;
+use crate::constants;
+
+/// Translations already in the foreign strings files of a locale, by the
+/// default values they were made for. Used to suggest translations for the
+/// strings that are yet to be localized. Eg., if `cancel_button` (`Cancel`) is
+/// already localized, `string_2` (`Cancel`) needn't be localized again
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TranslationMemory {
+    translations: BTreeMap<String, String>,
+    /// Default values of `translations` by their length (in chars), so that
+    /// only the ones that could be similar enough are compared against
+    values_by_length: BTreeMap<usize, Vec<String>>,
+}
+
+impl TranslationMemory {
+    /// Default & foreign strings are paired by name. Plurals & string arrays
+    /// can be passed in flattened. If a default value has been localized more
+    /// than once, the translation of the string that comes first is kept
+    pub fn build(
+        default_strings: &[AndroidString],
+        foreign_strings: &[AndroidString],
+    ) -> TranslationMemory {
+        let mut foreign_values: HashMap<&str, &str> = HashMap::new();
+        for foreign_string in foreign_strings {
+            foreign_values
+                .entry(foreign_string.name())
+                .or_insert(foreign_string.value());
+        }
+
+        let mut translations = BTreeMap::new();
+        for default_string in default_strings {
+            if default_string.value().is_empty() {
+                continue;
+            }
+
+            if let Some(foreign_value) = foreign_values.get(default_string.name()) {
+                if !foreign_value.is_empty() {
+                    translations
+                        .entry(String::from(default_string.value()))
+                        .or_insert_with(|| String::from(*foreign_value));
+                }
+            }
+        }
+
+        let mut values_by_length: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+        for value in translations.keys() {
+            values_by_length
+                .entry(value.chars().count())
+                .or_default()
+                .push(value.clone());
+        }
+
+        TranslationMemory {
+            translations,
+            values_by_length,
+        }
+    }
+
+    pub fn exact_match(&self, default_value: &str) -> Option<&str> {
+        self.translations.get(default_value).map(String::as_str)
+    }
+
+    /// The translation of the same default value or of the most similar one
+    /// (look @ `constants::translation_memory::MIN_SIMILARITY_PERCENT`). Ties
+    /// go to the default value that sorts first
+    pub fn suggest(&self, default_value: &str) -> Option<&str> {
+        if let Some(translation) = self.exact_match(default_value) {
+            return Some(translation);
+        }
+
+        // Values whose lengths differ by too much can't be similar enough (look
+        // @ `similarity_percent`)
+        let min_similarity = constants::translation_memory::MIN_SIMILARITY_PERCENT;
+        let length = default_value.chars().count();
+        let min_length = length * min_similarity / 100;
+        let max_length = (length * 100).div_ceil(min_similarity);
+
+        let mut best_match: Option<(usize, &str)> = None;
+        for values in self
+            .values_by_length
+            .range(min_length..=max_length)
+            .map(|(_, v)| v)
+        {
+            for value in values {
+                let similarity = similarity_percent(default_value, value);
+                let is_better = best_match.is_none_or(|(best_similarity, best_value)| {
+                    similarity > best_similarity
+                        || (similarity == best_similarity && value.as_str() < best_value)
+                });
+                if similarity >= min_similarity && is_better {
+                    best_match = Some((similarity, value));
+                }
+            }
+        }
+
+        best_match.map(|(_, value)| self.translations[value].as_str())
+    }
+}
+
+/// 100 for equal values & 0 for values with nothing in common
+fn similarity_percent(value1: &str, value2: &str) -> usize {
+    let length1 = value1.chars().count();
+    let length2 = value2.chars().count();
+    let max_length = length1.max(length2);
+    if max_length == 0 {
+        return 100;
+    }
+
+    // The edit distance is at least the difference in lengths. No point in
+    // computing it if that is already too much
+    let max_distance =
+        max_length * (100 - constants::translation_memory::MIN_SIMILARITY_PERCENT) / 100;
+    if length1.abs_diff(length2) > max_distance {
+        return 0;
+    }
+
+    100 - edit_distance(value1, value2) * 100 / max_length
+}
+
+/// Levenshtein distance (in chars)
+fn edit_distance(value1: &str, value2: &str) -> usize {
+    let chars2: Vec<char> = value2.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=chars2.len()).collect();
+    let mut current_row = vec![0; chars2.len() + 1];
+    for (index1, char1) in value1.chars().enumerate() {
+        current_row[0] = index1 + 1;
+        for (index2, char2) in chars2.iter().enumerate() {
+            let substitution_cost = usize::from(char1 != *char2);
+            current_row[index2 + 1] = (previous_row[index2] + substitution_cost)
+                .min(previous_row[index2 + 1] + 1)
+                .min(current_row[index2] + 1);
+        }
+
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+
+    previous_row[chars2.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::android_string::AndroidString;
+
+    use super::TranslationMemory;
+
+    fn build_memory() -> TranslationMemory {
+        TranslationMemory::build(
+            &[
+                AndroidString::localizable("cancel_button", "Cancel"),
+                AndroidString::localizable("dismiss", "Cancel"),
+                AndroidString::localizable("save_note", "Save the note"),
+                AndroidString::localizable("untranslated", "Untranslated"),
+                AndroidString::localizable("empty", ""),
+            ],
+            &[
+                AndroidString::localizable("save_note", "Enregistrer la note"),
+                AndroidString::localizable("dismiss", "Fermer"),
+                AndroidString::localizable("cancel_button", "Annuler"),
+                AndroidString::localizable("empty", "vide"),
+            ],
+        )
+    }
+
+    #[test]
+    fn finds_exact_matches() {
+        let memory = build_memory();
+        assert_eq!(memory.exact_match("Cancel"), Some("Annuler"));
+        assert_eq!(
+            memory.exact_match("Save the note"),
+            Some("Enregistrer la note")
+        );
+        assert_eq!(memory.exact_match("cancel"), None);
+        assert_eq!(memory.exact_match("Untranslated"), None);
+        assert_eq!(memory.exact_match(""), None);
+    }
+
+    #[test]
+    fn suggests_translations_of_similar_values() {
+        let memory = build_memory();
+        assert_eq!(memory.suggest("Cancel"), Some("Annuler"));
+        assert_eq!(
+            memory.suggest("Save the notes"),
+            Some("Enregistrer la note")
+        );
+        assert_eq!(memory.suggest("Delete the note"), None);
+        assert_eq!(memory.suggest("Delete"), None);
+    }
+
+    #[test]
+    fn suggests_translation_of_the_value_that_sorts_first_on_ties() {
+        let memory = TranslationMemory::build(
+            &[
+                AndroidString::localizable("s1", "Cancel all"),
+                AndroidString::localizable("s2", "Cancel"),
+                AndroidString::localizable("s3", "Cancels"),
+                AndroidString::localizable("s4", "Cancelled"),
+            ],
+            &[
+                AndroidString::localizable("s1", "Annuler tout"),
+                AndroidString::localizable("s2", "Annuler"),
+                AndroidString::localizable("s3", "Annule"),
+                AndroidString::localizable("s4", "Annulé"),
+            ],
+        );
+
+        // "Cancele" is as similar to "Cancel" as it is to "Cancels"
+        assert_eq!(memory.suggest("Cancele"), Some("Annuler"));
+        assert_eq!(memory.suggest("Cancelled!"), Some("Annulé"));
+    }
+
+    #[test]
+    fn pairs_default_strings_with_the_first_foreign_string_of_the_same_name() {
+        let memory = TranslationMemory::build(
+            &[AndroidString::localizable("s1", "Cancel")],
+            &[
+                AndroidString::localizable("s1", "Annuler"),
+                AndroidString::localizable("s1", "Fermer"),
+            ],
+        );
+
+        assert_eq!(memory.exact_match("Cancel"), Some("Annuler"));
+    }
+
+    #[test]
+    fn computes_edit_distance() {
+        assert_eq!(super::edit_distance("", ""), 0);
+        assert_eq!(super::edit_distance("abc", ""), 3);
+        assert_eq!(super::edit_distance("kitten", "sitting"), 3);
+        assert_eq!(super::edit_distance("café", "cafe"), 1);
+    }
+}
@@ -0,0 +1,319 @@
+use std::time::Duration;
+
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::constants;
+use crate::error::{Error, InnerError, ResultExt};
+use crate::localizable_strings::LocalizableStrings;
+use crate::localized_string::LocalizedString;
+use crate::localized_strings::LocalizedStrings;
+use crate::ops::mask;
+
+/// Machine translates the strings to be localized. Translations are only ever
+/// written out as drafts for translators to review (look @
+/// `csv_writer::Writer::write`) & never straight into the foreign strings files
+pub trait TranslationProvider {
+    /// Returns the translations of every locale's strings. Strings that couldn't
+    /// be translated are left out
+    fn translate(
+        &self,
+        strings_list: &[LocalizableStrings],
+    ) -> Result<Vec<LocalizedStrings>, Error>;
+}
+
+/// Posts the strings of a locale as a JSON object to the endpoint, like
+/// `{"source_language": "en", "target_language": "fr", "texts": [...]}` &
+/// expects back `{"translations": [...]}` with a translation per text (in the
+/// same order). The target language is the BCP 47 tag of the locale (like `fr`
+/// or `pt-BR`; look @ `LocalizableStrings::to_language_tag`) & not the name it
+/// is mapped to. Texts are sent as plain text with format
+/// specifiers & markup masked (look @ `mask`) so that the service can't mangle
+/// them. Translations that come back without
+/// every placeholder or with an empty value are left out
+pub struct HttpTranslationProvider {
+    endpoint: String,
+    source_language: String,
+    agent: ureq::Agent,
+}
+
+impl HttpTranslationProvider {
+    pub fn new(endpoint: String) -> HttpTranslationProvider {
+        HttpTranslationProvider {
+            endpoint,
+            source_language: String::from(constants::translation_provider::DEFAULT_SOURCE_LANGUAGE),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(
+                    constants::translation_provider::TIMEOUT_SECS,
+                ))
+                .build(),
+        }
+    }
+
+    pub fn with_source_language(mut self, source_language: String) -> HttpTranslationProvider {
+        self.source_language = source_language;
+        self
+    }
+
+    fn request(&self, target_language: &str, texts: &[&str]) -> Result<Vec<String>, InnerError> {
+        let mut document = Map::new();
+        document.insert(
+            String::from(constants::translation_provider::SOURCE_LANGUAGE_FIELD),
+            Value::from(self.source_language.as_str()),
+        );
+        document.insert(
+            String::from(constants::translation_provider::TARGET_LANGUAGE_FIELD),
+            Value::from(target_language),
+        );
+        document.insert(
+            String::from(constants::translation_provider::TEXTS_FIELD),
+            Value::from(texts.to_vec()),
+        );
+
+        let response = self
+            .agent
+            .post(&self.endpoint)
+            .set("Content-Type", "application/json")
+            .send_string(&Value::Object(document).to_string())?;
+        let document: Value = serde_json::from_reader(response.into_reader())?;
+        match document.get(constants::translation_provider::TRANSLATIONS_FIELD) {
+            Some(Value::Array(translations)) if translations.len() == texts.len() => translations
+                .iter()
+                .map(|translation| match translation {
+                    Value::String(translation) => Ok(translation.clone()),
+                    _ => Err(InnerError::from("Translations must be strings")),
+                })
+                .collect(),
+
+            _ => Err(InnerError::from(
+                "Translation service response isn't laid out as expected",
+            )),
+        }
+    }
+}
+
+impl TranslationProvider for HttpTranslationProvider {
+    fn translate(
+        &self,
+        strings_list: &[LocalizableStrings],
+    ) -> Result<Vec<LocalizedStrings>, Error> {
+        let mut localized_strings_list = vec![];
+        for localizable_strings in strings_list {
+            let strings = localizable_strings.default_locale_strings();
+            if strings.is_empty() {
+                continue;
+            }
+
+            let masked_values = strings
+                .iter()
+                .map(|string| mask::mask(string.value()))
+                .collect::<Vec<_>>();
+            let texts = masked_values
+                .iter()
+                .map(|(masked_value, _)| masked_value.as_str())
+                .collect::<Vec<_>>();
+            let translations = self
+                .request(&localizable_strings.to_language_tag(), &texts)
+                .with_context(self.endpoint.clone())?;
+
+            let localized_strings = strings
+                .iter()
+                .zip(masked_values.iter())
+                .zip(translations)
+                .filter(|(_, translation)| !translation.is_empty())
+                .filter_map(|((string, (_, masked_parts)), translation)| {
+                    mask::unmask(&translation, masked_parts).map(|localized| {
+                        LocalizedString::new(
+                            String::from(string.name()),
+                            String::from(string.value()),
+                            localized,
+                        )
+                        .with_module(string.module().map(String::from))
+                    })
+                })
+                .collect();
+
+            localized_strings_list.push(LocalizedStrings::new(
+                String::from(localizable_strings.to_locale()),
+                localized_strings,
+            ));
+        }
+
+        Ok(localized_strings_list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufRead;
+    use std::io::BufReader;
+    use std::io::Read;
+    use std::io::Write;
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    use serde_json::Value;
+
+    use super::HttpTranslationProvider;
+    use super::TranslationProvider;
+    use crate::android_string::AndroidString;
+    use crate::localizable_strings::LocalizableStrings;
+    use crate::localized_string::LocalizedString;
+    use crate::localized_strings::LocalizedStrings;
+
+    /// Answers a request per response (in order) & sends back the bodies of
+    /// the requests it received. Returns the endpoint it is listening at
+    fn serve(responses: Vec<&'static str>) -> (String, mpsc::Receiver<Value>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}/translate", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for response in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+
+                    let (header, value) = line.split_once(':').unwrap_or((line, ""));
+                    if header.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                sender.send(serde_json::from_slice(&body).unwrap()).unwrap();
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    response.len(),
+                    response
+                )
+                .unwrap();
+            }
+        });
+
+        (endpoint, receiver)
+    }
+
+    #[test]
+    fn translates_strings_with_their_placeholders_masked() {
+        let (endpoint, requests) = serve(vec![
+            r#"{"translations": ["Bonjour ⟦0⟧ ! C'est \"là\"", "Supprimer", ""]}"#,
+            r#"{"translations": ["Hola ⟦0⟧ ⟦0⟧", "Eliminar", "Guardar"]}"#,
+        ]);
+
+        let strings = vec![
+            AndroidString::localizable("string_1", r"Hi <b>%1$s</b>! It\'s &amp;"),
+            AndroidString::localizable("string_2", "Delete").with_module(Some(String::from("lib"))),
+            AndroidString::localizable("string_3", "Save"),
+        ];
+
+        let localized_strings_list = HttpTranslationProvider::new(endpoint)
+            .with_source_language(String::from("en-US"))
+            .translate(&[
+                LocalizableStrings::new(String::from("french"), strings.clone())
+                    .with_to_locale_id(String::from("fr")),
+                LocalizableStrings::new(String::from("es"), strings),
+                LocalizableStrings::new(String::from("de"), vec![]),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            requests.recv().unwrap(),
+            serde_json::json!({
+                "source_language": "en-US",
+                "target_language": "fr",
+                "texts": ["Hi \u{27e6}0\u{27e7}! It's &", "Delete", "Save"]
+            })
+        );
+        assert_eq!(
+            requests.recv().unwrap()["target_language"],
+            Value::from("es")
+        );
+
+        test_utilities::list::assert_strict_list_eq(
+            localized_strings_list,
+            vec![
+                LocalizedStrings::build(
+                    "french",
+                    vec![
+                        LocalizedString::build(
+                            "string_1",
+                            r"Hi <b>%1$s</b>! It\'s &amp;",
+                            r#"Bonjour <b>%1$s</b> ! C\'est \"là\""#,
+                        ),
+                        LocalizedString::build("string_2", "Delete", "Supprimer")
+                            .with_module(Some(String::from("lib"))),
+                    ],
+                ),
+                LocalizedStrings::build(
+                    "es",
+                    vec![
+                        LocalizedString::build("string_2", "Delete", "Eliminar")
+                            .with_module(Some(String::from("lib"))),
+                        LocalizedString::build("string_3", "Save", "Guardar"),
+                    ],
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn sends_language_tags_of_locales() {
+        let (endpoint, requests) = serve(vec![
+            r#"{"translations": ["Cancelar"]}"#,
+            r#"{"translations": ["Otkaži"]}"#,
+        ]);
+
+        let strings = vec![AndroidString::localizable("string_1", "Cancel")];
+        HttpTranslationProvider::new(endpoint)
+            .translate(&[
+                LocalizableStrings::new(String::from("portuguese"), strings.clone())
+                    .with_to_locale_id(String::from("pt-rBR")),
+                LocalizableStrings::new(String::from("serbian"), strings)
+                    .with_to_locale_id(String::from("b+sr+Latn")),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            requests.recv().unwrap()["target_language"],
+            Value::from("pt-BR")
+        );
+        assert_eq!(
+            requests.recv().unwrap()["target_language"],
+            Value::from("sr-Latn")
+        );
+    }
+
+    #[test]
+    fn errors_if_response_is_not_laid_out_as_expected() {
+        let (endpoint, _requests) = serve(vec![r#"{"translations": ["Annuler"]}"#]);
+        let error = HttpTranslationProvider::new(endpoint.clone())
+            .translate(&[LocalizableStrings::new(
+                String::from("fr"),
+                vec![
+                    AndroidString::localizable("string_1", "Cancel"),
+                    AndroidString::localizable("string_2", "Save"),
+                ],
+            )])
+            .unwrap_err();
+
+        assert_eq!(error.context(), endpoint);
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "{}: Translation service response isn't laid out as expected",
+                endpoint
+            )
+        );
+    }
+}
@@ -1,3 +1,6 @@
+pub mod bundle_finder;
 pub mod foreign_locale_ids_finder;
+pub mod res_dirs_finder;
+pub mod source_sets;
 pub mod two_pointer_traversal;
 pub mod xml_utilities;
@@ -0,0 +1,86 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::error::Error;
+
+/// A file of a bundle (like `messages_fr.properties` or `app_fr.arb`) along
+/// with the base file of the bundle (`messages.properties` or `app.arb`) it is
+/// localized from
+#[derive(Debug, PartialEq)]
+pub struct BundleFile {
+    base_path: PathBuf,
+    locale: String,
+}
+
+impl BundleFile {
+    pub fn base_path(&self) -> &Path {
+        &self.base_path
+    }
+
+    /// Whatever follows the name of the base file. Eg., `fr` or `pt_BR`
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+}
+
+/// The base file is the one in the same dir with the same extension & the
+/// longest name that the name of the file starts with (followed by a `_`)
+pub fn find(file_path: &Path) -> Result<BundleFile, Error> {
+    let context = file_path.to_string_lossy();
+    let stem = file_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .ok_or_else(|| Error::new(context.clone(), "File doesn't have a name"))?;
+
+    let extension = file_path
+        .extension()
+        .map(|extension| extension.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    stem.rmatch_indices('_')
+        .map(|(index, _)| {
+            let mut base_path = file_path.with_file_name(&stem[..index]);
+            base_path.set_extension(&extension);
+            BundleFile {
+                base_path,
+                locale: String::from(&stem[index + 1..]),
+            }
+        })
+        .find(|bundle_file| !bundle_file.locale.is_empty() && bundle_file.base_path.is_file())
+        .ok_or_else(|| {
+            Error::new(
+                context,
+                "Base file (like messages.properties for messages_fr.properties) can't be found",
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    #[test]
+    fn finds_the_longest_base_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        for file_name in &["to.arb", "to_localize.arb", "to_localize_pt_BR.arb"] {
+            fs::write(temp_dir.path().join(file_name), "").unwrap();
+        }
+
+        let bundle_file = super::find(&temp_dir.path().join("to_localize_pt_BR.arb")).unwrap();
+        assert_eq!(
+            bundle_file.base_path(),
+            temp_dir.path().join("to_localize.arb")
+        );
+        assert_eq!(bundle_file.locale(), "pt_BR");
+    }
+
+    #[test]
+    fn errors_if_there_is_no_base_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("messages_fr.properties");
+        fs::write(&file_path, "").unwrap();
+
+        let error = super::find(&file_path).unwrap_err();
+        assert!(error.to_string().ends_with("can't be found"));
+    }
+}
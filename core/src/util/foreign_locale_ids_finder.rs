@@ -2,22 +2,19 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
-use regex::Regex;
-
-use android_localization_utilities::DevExpt;
-
-use crate::constants;
 use crate::error::Error;
 use crate::error::ResultExt;
-
-lazy_static::lazy_static! {
-    static ref LOCALE_ID_REGEX: Regex = Regex::new("-([a-zA-z]+)$").expt("Invalid regex!");
-}
-
-/// Finds language IDs from the folder names. Only folders whose name are of the
-/// format `values-...` that have a file with the name `strings.xml` are considered.
-/// What is after the last `-` in the folder name is returned as the lang id
-pub fn find(res_dir_path: &str) -> Result<Vec<String>, Error> {
+use crate::qualifiers::Locale;
+use crate::qualifiers::Qualifiers;
+use crate::util::xml_utilities;
+
+/// Finds locales from the folder names. Only folders whose name are of the
+/// format `values-...` that have at least one `*.xml` file in them are considered.
+/// The qualifiers in the folder name must be made up of only a locale (`fr`,
+/// `pt-rBR`, `b+sr+Latn` etc.). Folders with other configs (`values-night`,
+/// `values-fr-land` etc.) & those of pseudolocales (`values-en-rXA` &
+/// `values-ar-rXB`; look @ `pseudolocalize`) are skipped
+pub fn find(res_dir_path: &str) -> Result<Vec<Locale>, Error> {
     if !Path::new(res_dir_path).is_dir() {
         return Err(Error::new(
             res_dir_path,
@@ -25,37 +22,36 @@ pub fn find(res_dir_path: &str) -> Result<Vec<String>, Error> {
         ));
     }
 
-    let locale_ids = fs::read_dir(res_dir_path)
+    let locales = fs::read_dir(res_dir_path)
         .with_context(String::from(res_dir_path))?
         .filter_map(|dir_entry| match dir_entry {
             Err(_) => None,
             Ok(dir_entry) => match dir_entry.file_type() {
                 Err(_) => None,
                 Ok(file_type) => {
-                    if !file_type.is_dir() {
+                    if !file_type.is_dir() || !has_resource_files(&dir_entry.path()) {
                         None
                     } else {
-                        let mut strings_file_path = dir_entry.path();
-                        strings_file_path.push(constants::fs::STRING_FILE_NAME);
-                        if !strings_file_path.is_file() {
-                            None
-                        } else {
-                            dir_entry.file_name().to_str().map(String::from)
-                        }
+                        dir_entry.file_name().to_str().map(String::from)
                     }
                 }
             },
         })
-        .filter_map(|file_name| match LOCALE_ID_REGEX.captures(&file_name) {
-            None => None,
-            Some(capture) => match capture.get(1) {
-                None => None,
-                Some(m) => Some(String::from(m.as_str())),
-            },
-        })
+        .filter_map(|file_name| Qualifiers::from_values_dir_name(&file_name))
+        .filter(Qualifiers::is_locale_only)
+        .filter_map(Qualifiers::into_locale)
+        .filter(|locale| !locale.is_pseudo())
         .collect();
 
-    Ok(locale_ids)
+    Ok(locales)
+}
+
+fn has_resource_files(dir_path: &Path) -> bool {
+    fs::read_dir(dir_path).is_ok_and(|dir_entries| {
+        dir_entries
+            .filter_map(Result::ok)
+            .any(|dir_entry| xml_utilities::is_resource_file(&dir_entry.path()))
+    })
 }
 
 /// Look @ `find`'s doc to figure out how the lang IDs are figured out
@@ -64,8 +60,8 @@ pub fn build_map_if_empty_or_return<S: ::std::hash::BuildHasher>(
     res_dir_path: &str,
 ) -> Result<HashMap<String, String, S>, Error> {
     if map.is_empty() {
-        for locale_id in find(res_dir_path)? {
-            map.insert(locale_id.clone(), locale_id);
+        for locale in find(res_dir_path)? {
+            map.insert(locale.to_string(), locale.to_string());
         }
     }
 
@@ -107,7 +103,8 @@ mod tests {
 
         let mut locale_ids = super::find(res_dir_path.to_str().unwrap())
             .unwrap()
-            .into_iter();
+            .into_iter()
+            .map(|locale| locale.to_string());
 
         let locale_id_1 = locale_ids.next().unwrap();
         let locale_id_2 = locale_ids.next().unwrap();
@@ -116,6 +113,56 @@ mod tests {
         test_utilities::eq::assert_eq_to_either_or(locale_id_2.as_str(), "fr", "it");
     }
 
+    #[test]
+    fn find_finds_locales_without_strings_file() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut res_dir_path = tempdir.path().to_path_buf();
+        res_dir_path.push("res");
+
+        test_utilities::res::setup_empty_resource_file_for_locale(
+            res_dir_path.clone(),
+            "de",
+            "strings_settings.xml",
+        );
+        test_utilities::res::setup_empty_resource_file_for_locale(
+            res_dir_path.clone(),
+            "es",
+            "notes.txt",
+        );
+
+        let locale_ids: Vec<String> = super::find(res_dir_path.to_str().unwrap())
+            .unwrap()
+            .into_iter()
+            .map(|locale| locale.to_string())
+            .collect();
+
+        assert_eq!(locale_ids, vec!["de"]);
+    }
+
+    #[test]
+    fn find_parses_qualifiers_of_folder_names() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut res_dir_path = tempdir.path().to_path_buf();
+        res_dir_path.push("res");
+
+        test_utilities::res::setup_empty_strings_for_locale(res_dir_path.clone(), "pt-rBR");
+        test_utilities::res::setup_empty_strings_for_locale(res_dir_path.clone(), "b+sr+Latn");
+        test_utilities::res::setup_empty_strings_for_locale(res_dir_path.clone(), "night");
+        test_utilities::res::setup_empty_strings_for_locale(res_dir_path.clone(), "fr-land");
+        test_utilities::res::setup_empty_strings_for_locale(res_dir_path.clone(), "car");
+        test_utilities::res::setup_empty_strings_for_locale(res_dir_path.clone(), "en-rXA");
+        test_utilities::res::setup_empty_strings_for_locale(res_dir_path.clone(), "ar-rXB");
+
+        let mut locale_ids: Vec<String> = super::find(res_dir_path.to_str().unwrap())
+            .unwrap()
+            .into_iter()
+            .map(|locale| locale.to_string())
+            .collect();
+        locale_ids.sort();
+
+        assert_eq!(locale_ids, vec!["b+sr+Latn", "pt-rBR"]);
+    }
+
     #[test]
     fn build_map_if_empty_or_return_returns_as_is() {
         let mut map = HashMap::new();
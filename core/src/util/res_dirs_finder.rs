@@ -0,0 +1,161 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::constants;
+use crate::error::Error;
+use crate::error::ResultExt;
+
+/// A `res` dir of a Gradle project
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResDir {
+    module: String,
+    path: PathBuf,
+}
+
+impl ResDir {
+    /// Path of the res dir relative to the project dir with `/` as the separator.
+    /// Eg., `app/src/main/res`. This is what ties the exported strings back to
+    /// the res dir they are from
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Finds every `src/*/res` dir under the project dir (in the order of their
+/// paths). Hidden dirs & `build` dirs are skipped since they only carry tooling
+/// & generated files
+pub fn find(project_dir_path: &str) -> Result<Vec<ResDir>, Error> {
+    let project_dir_path = Path::new(project_dir_path);
+    if !project_dir_path.is_dir() {
+        return Err(Error::new(
+            project_dir_path.to_string_lossy(),
+            "Project dir path doesn't exist or it is not a directory",
+        ));
+    }
+
+    let mut res_dirs = vec![];
+    find_in(project_dir_path, project_dir_path, &mut res_dirs)?;
+    res_dirs.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(res_dirs)
+}
+
+fn find_in(
+    project_dir_path: &Path,
+    dir_path: &Path,
+    res_dirs: &mut Vec<ResDir>,
+) -> Result<(), Error> {
+    for sub_dir_path in list_sub_dirs(dir_path)? {
+        let sub_dir_name = sub_dir_path
+            .file_name()
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+        if sub_dir_name.starts_with('.') || sub_dir_name == constants::fs::BUILD_DIR_NAME {
+            continue;
+        }
+
+        if sub_dir_name != constants::fs::SRC_DIR_NAME {
+            find_in(project_dir_path, &sub_dir_path, res_dirs)?;
+            continue;
+        }
+
+        // Every source set could have its own res dir
+        for source_set_dir_path in list_sub_dirs(&sub_dir_path)? {
+            let res_dir_path = source_set_dir_path.join(constants::fs::RES_DIR_NAME);
+            if res_dir_path.is_dir() {
+                res_dirs.push(ResDir {
+                    module: build_module(project_dir_path, &res_dir_path),
+                    path: res_dir_path,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn list_sub_dirs(dir_path: &Path) -> Result<Vec<PathBuf>, Error> {
+    let context = dir_path.to_string_lossy();
+    let mut sub_dir_paths = vec![];
+    for dir_entry in fs::read_dir(dir_path).with_context(context.clone())? {
+        let sub_dir_path = dir_entry.with_context(context.clone())?.path();
+        if sub_dir_path.is_dir() {
+            sub_dir_paths.push(sub_dir_path);
+        }
+    }
+
+    Ok(sub_dir_paths)
+}
+
+fn build_module(project_dir_path: &Path, res_dir_path: &Path) -> String {
+    res_dir_path
+        .strip_prefix(project_dir_path)
+        .unwrap_or(res_dir_path)
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    #[test]
+    fn find_errors_if_project_dir_does_not_exist() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut project_dir_path = tempdir.path().to_path_buf();
+        project_dir_path.push("project");
+
+        let error = super::find(project_dir_path.to_str().unwrap()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "{}: Project dir path doesn't exist or it is not a directory",
+                project_dir_path.to_str().unwrap()
+            )
+        )
+    }
+
+    #[test]
+    fn find_finds_res_dirs_of_every_module() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let project_dir_path = tempdir.path();
+        for dir_path in &[
+            "app/src/main/res/values",
+            "app/src/debug/res",
+            "app/src/test/java",
+            "app/build/intermediates/src/main/res",
+            "features/settings/src/main/res",
+            ".gradle/src/main/res",
+            "libs/empty",
+        ] {
+            fs::create_dir_all(project_dir_path.join(dir_path)).unwrap();
+        }
+
+        let res_dirs = super::find(project_dir_path.to_str().unwrap()).unwrap();
+        test_utilities::list::assert_strict_list_eq(
+            res_dirs
+                .iter()
+                .map(|res_dir| String::from(res_dir.module()))
+                .collect::<Vec<String>>(),
+            vec![
+                String::from("app/src/debug/res"),
+                String::from("app/src/main/res"),
+                String::from("features/settings/src/main/res"),
+            ],
+        );
+
+        assert_eq!(
+            res_dirs[1].path(),
+            project_dir_path
+                .join("app")
+                .join("src")
+                .join("main")
+                .join("res")
+        );
+    }
+}
@@ -0,0 +1,402 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use android_localization_utilities::DevExpt;
+
+use crate::android_resources::AndroidResources;
+use crate::constants;
+use crate::error::Error;
+use crate::qualifiers::Locale;
+use crate::strings_document::StringsDocument;
+use crate::util::foreign_locale_ids_finder;
+use crate::util::xml_utilities;
+use crate::util::xml_utilities::StringsWithPath;
+
+/// The res dirs Android merges together to build a variant (Eg., `src/main/res`,
+/// `src/free/res` & `src/debug/res`). The first one is the main res dir & the
+/// rest are overlays in the increasing order of precedence (the same way AGP
+/// lets a build type override a flavor which in turn overrides main). A resource
+/// in an overlay overrides the resource with the same name in the res dirs
+/// before it
+pub struct SourceSets {
+    source_sets: Vec<SourceSet>,
+}
+
+pub struct SourceSet {
+    name: String,
+    res_dir_path: PathBuf,
+}
+
+impl SourceSet {
+    fn new(res_dir_path: &str) -> SourceSet {
+        let res_dir_path = PathBuf::from(res_dir_path);
+        let name = res_dir_path.parent().and_then(Path::file_name).map_or_else(
+            || String::from(res_dir_path.to_string_lossy()),
+            |name| String::from(name.to_string_lossy()),
+        );
+
+        SourceSet { name, res_dir_path }
+    }
+
+    /// Name of the dir the res dir is in. Eg., `free` for `app/src/free/res`
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn res_dir_path(&self) -> &Path {
+        &self.res_dir_path
+    }
+}
+
+impl SourceSets {
+    pub fn new(res_dir_path: &str, overlay_res_dir_paths: &[&str]) -> SourceSets {
+        let mut source_sets = vec![SourceSet::new(res_dir_path)];
+        source_sets.extend(
+            overlay_res_dir_paths
+                .iter()
+                .map(|path| SourceSet::new(path)),
+        );
+        SourceSets { source_sets }
+    }
+
+    pub fn has_overlays(&self) -> bool {
+        self.source_sets.len() > 1
+    }
+
+    pub fn get(&self, index: usize) -> &SourceSet {
+        &self.source_sets[index]
+    }
+
+    /// Foreign locales of all the res dirs (in the order they are first found)
+    pub fn find_locales(&self) -> Result<Vec<Locale>, Error> {
+        let mut locales: Vec<Locale> = vec![];
+        for source_set in &self.source_sets {
+            for locale in
+                foreign_locale_ids_finder::find(&source_set.res_dir_path.to_string_lossy())?
+            {
+                if !locales.contains(&locale) {
+                    locales.push(locale);
+                }
+            }
+        }
+
+        Ok(locales)
+    }
+
+    /// Look @ `foreign_locale_ids_finder::build_map_if_empty_or_return`
+    pub fn build_map_if_empty_or_return<S: ::std::hash::BuildHasher>(
+        &self,
+        mut map: HashMap<String, String, S>,
+    ) -> Result<HashMap<String, String, S>, Error> {
+        if map.is_empty() {
+            for locale in self.find_locales()? {
+                map.insert(locale.to_string(), locale.to_string());
+            }
+        }
+
+        Ok(map)
+    }
+
+    /// The default values dir of the main res dir must exist. Overlays without
+    /// one are skipped
+    pub fn read_default_strings(&self) -> Result<MergedStrings, Error> {
+        let mut strings_with_paths = vec![];
+        for (index, source_set) in self.source_sets.iter().enumerate() {
+            if index == 0 || has_values_dir(source_set, constants::fs::BASE_VALUES_DIR_NAME) {
+                for strings_with_path in
+                    xml_utilities::read_default_strings(&source_set.res_dir_path)?
+                {
+                    strings_with_paths.push((index, strings_with_path));
+                }
+            }
+        }
+
+        Ok(merge(strings_with_paths, |_| 0))
+    }
+
+    /// Translations from a res dir that comes before the one the default resource
+    /// is from are dropped since they were done for the overridden value
+    pub fn read_foreign_strings(
+        &self,
+        locale: &Locale,
+        default_strings: &MergedStrings,
+    ) -> Result<MergedStrings, Error> {
+        let mut strings_with_paths = vec![];
+        for (index, source_set) in self.source_sets.iter().enumerate() {
+            if index == 0 || has_values_dir(source_set, &locale.values_dir_name()) {
+                for strings_with_path in
+                    xml_utilities::read_foreign_strings(&source_set.res_dir_path, locale)?
+                {
+                    strings_with_paths.push((index, strings_with_path));
+                }
+            }
+        }
+
+        Ok(merge(strings_with_paths, |key| {
+            default_strings.index_of(key).unwrap_or(0)
+        }))
+    }
+
+    /// Along with the merged strings, returns the document of every foreign file
+    /// (including the ones whose resources have all been overridden)
+    pub fn read_foreign_strings_and_documents(
+        &self,
+        locale: &Locale,
+        default_strings: &MergedStrings,
+    ) -> Result<(MergedStrings, Vec<(PathBuf, StringsDocument)>), Error> {
+        let mut strings_with_paths = vec![];
+        let mut documents = vec![];
+        for (index, source_set) in self.source_sets.iter().enumerate() {
+            if index == 0 || has_values_dir(source_set, &locale.values_dir_name()) {
+                for (strings_with_path, document) in
+                    xml_utilities::read_foreign_strings_and_documents(
+                        &source_set.res_dir_path,
+                        locale,
+                    )?
+                {
+                    documents.push((PathBuf::from(strings_with_path.path()), document));
+                    strings_with_paths.push((index, strings_with_path));
+                }
+            }
+        }
+
+        let merged_strings = merge(strings_with_paths, |key| {
+            default_strings.index_of(key).unwrap_or(0)
+        });
+
+        Ok((merged_strings, documents))
+    }
+}
+
+fn has_values_dir(source_set: &SourceSet, values_dir_name: &str) -> bool {
+    source_set.res_dir_path.join(values_dir_name).is_dir()
+}
+
+/// Strings, plurals & string arrays can share names
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ResourceKey {
+    String(String),
+    Plural(String),
+    StringArray(String),
+}
+
+impl ResourceKey {
+    /// Plurals & string arrays are flattened to `name[quantity]` & `name[index]`.
+    /// Since both look the same once flattened, plurals are looked for first
+    fn candidates_for_flattened(name: &str) -> Vec<ResourceKey> {
+        match name.find('[') {
+            None => vec![ResourceKey::String(String::from(name))],
+            Some(index) => vec![
+                ResourceKey::Plural(String::from(&name[..index])),
+                ResourceKey::StringArray(String::from(&name[..index])),
+            ],
+        }
+    }
+}
+
+/// The resources from all the res dirs with the overridden ones dropped. The
+/// res dir each resource is from is tracked
+#[derive(Default)]
+pub struct MergedStrings {
+    strings_with_paths: Vec<StringsWithPath>,
+    source_set_indices: HashMap<ResourceKey, usize>,
+    file_paths: HashMap<ResourceKey, String>,
+}
+
+impl MergedStrings {
+    pub fn strings_with_paths(&self) -> &[StringsWithPath] {
+        &self.strings_with_paths
+    }
+
+    pub fn combine(&self) -> AndroidResources {
+        xml_utilities::combine_resources(&self.strings_with_paths)
+    }
+
+    /// Index of the res dir in `SourceSets` the resource is from
+    pub fn index_of(&self, key: &ResourceKey) -> Option<usize> {
+        self.source_set_indices.get(key).copied()
+    }
+
+    /// Path of the file the resource is in
+    pub fn file_path_of(&self, key: &ResourceKey) -> Option<&str> {
+        self.file_paths.get(key).map(String::as_str)
+    }
+
+    /// Same as `index_of` but for the name of a flattened string. Look @
+    /// `AndroidPlural::flatten` & `AndroidStringArray::flatten`
+    pub fn index_of_flattened(&self, name: &str) -> Option<usize> {
+        ResourceKey::candidates_for_flattened(name)
+            .iter()
+            .find_map(|key| self.index_of(key))
+    }
+
+    /// Same as `file_path_of` but for the name of a flattened string
+    pub fn file_path_of_flattened(&self, name: &str) -> Option<&str> {
+        ResourceKey::candidates_for_flattened(name)
+            .iter()
+            .find_map(|key| self.file_path_of(key))
+    }
+}
+
+/// Resources from the res dirs of higher precedence are picked over the ones
+/// with the same names from the rest. Resources from res dirs before the one
+/// returned by `min_index_of` are dropped
+fn merge<F>(strings_with_paths: Vec<(usize, StringsWithPath)>, min_index_of: F) -> MergedStrings
+where
+    F: Fn(&ResourceKey) -> usize,
+{
+    let mut source_set_indices = HashMap::new();
+    let mut file_paths = HashMap::new();
+    let mut merged = vec![];
+    let max_index = strings_with_paths
+        .iter()
+        .map(|(i, _)| *i)
+        .max()
+        .unwrap_or(0);
+    let mut strings_with_paths = strings_with_paths.into_iter().map(Some).collect::<Vec<_>>();
+    for index in (0..=max_index).rev() {
+        for slot in strings_with_paths.iter_mut() {
+            let is_from_index = slot.as_ref().is_some_and(|(i, _)| *i == index);
+            if !is_from_index {
+                continue;
+            }
+
+            let (_, strings_with_path) = slot.take().expt("Just checked that it is present");
+            let path = String::from(strings_with_path.path());
+            let mut keep = |key: ResourceKey| {
+                if source_set_indices.contains_key(&key) || index < min_index_of(&key) {
+                    false
+                } else {
+                    source_set_indices.insert(key.clone(), index);
+                    file_paths.insert(key, path.clone());
+                    true
+                }
+            };
+
+            let (strings, plurals, string_arrays) = strings_with_path.into_resources().into_parts();
+            let resources = AndroidResources::new(
+                strings
+                    .into_iter()
+                    .filter(|s| keep(ResourceKey::String(String::from(s.name()))))
+                    .collect(),
+                plurals
+                    .into_iter()
+                    .filter(|p| keep(ResourceKey::Plural(String::from(p.name()))))
+                    .collect(),
+                string_arrays
+                    .into_iter()
+                    .filter(|a| keep(ResourceKey::StringArray(String::from(a.name()))))
+                    .collect(),
+            );
+
+            merged.push((index, StringsWithPath::new(path, resources)));
+        }
+    }
+
+    // Main res dir first
+    merged.sort_by_key(|(index, _)| *index);
+    MergedStrings {
+        strings_with_paths: merged.into_iter().map(|(_, s)| s).collect(),
+        source_set_indices,
+        file_paths,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::android_string::AndroidString;
+
+    use super::ResourceKey;
+    use super::SourceSets;
+
+    #[test]
+    fn names_source_sets_after_their_dirs() {
+        let source_sets = SourceSets::new("app/src/main/res", &["app/src/free/res", "res"]);
+        assert_eq!(source_sets.get(0).name(), "main");
+        assert_eq!(source_sets.get(1).name(), "free");
+        assert_eq!(source_sets.get(2).name(), "res");
+        assert!(source_sets.has_overlays());
+    }
+
+    #[test]
+    fn overlays_override_resources_with_the_same_name() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let main_res_path = temp_dir.path().join("main").join("res");
+        let free_res_path = temp_dir.path().join("free").join("res");
+        let debug_res_path = temp_dir.path().join("debug").join("res");
+        write_strings(&main_res_path, "values", &[("s1", "main"), ("s2", "main")]);
+        write_strings(&free_res_path, "values", &[("s2", "free"), ("s3", "free")]);
+        write_strings(&debug_res_path, "values", &[("s3", "debug")]);
+
+        let source_sets = build_source_sets(&main_res_path, &[&free_res_path, &debug_res_path]);
+        let default_strings = source_sets.read_default_strings().unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            without_source_files(default_strings.combine().strings()),
+            vec![
+                AndroidString::localizable("s1", "main"),
+                AndroidString::localizable("s2", "free"),
+                AndroidString::localizable("s3", "debug"),
+            ],
+        );
+
+        assert_eq!(
+            default_strings.index_of(&ResourceKey::String(String::from("s2"))),
+            Some(1)
+        );
+        assert_eq!(default_strings.index_of_flattened("s3"), Some(2));
+    }
+
+    #[test]
+    fn drops_translations_of_overridden_default_values() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let main_res_path = temp_dir.path().join("main").join("res");
+        let free_res_path = temp_dir.path().join("free").join("res");
+        write_strings(&main_res_path, "values", &[("s1", "main"), ("s2", "main")]);
+        write_strings(&main_res_path, "values-fr", &[("s1", "fr"), ("s2", "fr")]);
+        write_strings(&free_res_path, "values", &[("s2", "free")]);
+
+        let source_sets = build_source_sets(&main_res_path, &[&free_res_path]);
+        let default_strings = source_sets.read_default_strings().unwrap();
+        let foreign_strings = source_sets
+            .read_foreign_strings(&"fr".parse().unwrap(), &default_strings)
+            .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            without_source_files(foreign_strings.combine().strings()),
+            vec![AndroidString::localizable("s1", "fr")],
+        );
+    }
+
+    fn build_source_sets(main_res_path: &Path, overlay_res_paths: &[&Path]) -> SourceSets {
+        let overlay_res_paths = overlay_res_paths
+            .iter()
+            .map(|path| path.to_str().unwrap())
+            .collect::<Vec<&str>>();
+        SourceSets::new(main_res_path.to_str().unwrap(), &overlay_res_paths)
+    }
+
+    fn write_strings(res_path: &Path, values_dir_name: &str, strings: &[(&str, &str)]) {
+        let mut content = String::from("<resources>");
+        for (name, value) in strings {
+            content.push_str(&format!("<string name=\"{}\">{}</string>", name, value));
+        }
+
+        content.push_str("</resources>");
+        std::fs::create_dir_all(res_path.join(values_dir_name)).unwrap();
+        test_utilities::file::write_content(
+            res_path.join(values_dir_name).join("strings.xml"),
+            content,
+        );
+    }
+
+    fn without_source_files(strings: &[AndroidString]) -> Vec<AndroidString> {
+        strings
+            .iter()
+            .map(|s| s.clone().with_source_file(None))
+            .collect()
+    }
+}
@@ -195,8 +195,8 @@ mod tests {
         );
 
         assert_eq!(common, vec![(1, 1.0), (2, 2.0), (3, 3.0)]);
-        assert_eq!(only_in_list1, vec![]);
-        assert_eq!(only_in_list2, vec![])
+        assert!(only_in_list1.is_empty());
+        assert!(only_in_list2.is_empty())
     }
 
     #[test]
@@ -1,132 +1,285 @@
+use std::fs;
 use std::fs::File;
-use std::ops::Add;
+use std::io::Read;
 use std::path::Path;
+use std::path::PathBuf;
 
-use crate::android_string::AndroidString;
+use crate::android_resources::AndroidResources;
 use crate::constants;
 use crate::error::Error;
 use crate::error::ResultExt;
+use crate::qualifiers::Locale;
+use crate::reader::document_reader;
 use crate::reader::xml_reader;
+use crate::strings_document::StringsDocument;
 
-type FileWithPath = (File, String);
-
-pub fn read_default_strings(res_dir_path: &Path) -> Result<StringsWithPath, Error> {
-    read_strings(open_default_strings_file(res_dir_path)?)
+/// Reads every resource file in the default values dir. Look @ `read_values_dir`
+pub fn read_default_strings(res_dir_path: &Path) -> Result<Vec<StringsWithPath>, Error> {
+    read_values_dir(res_dir_path, constants::fs::BASE_VALUES_DIR_NAME)
 }
 
+/// Reads every resource file in the values dir of the locale. Look @ `read_values_dir`
 pub fn read_foreign_strings(
     res_dir_path: &Path,
-    locale_id: &str,
-) -> Result<StringsWithPath, Error> {
-    read_strings(open_foreign_strings_file(res_dir_path, locale_id)?)
+    locale: &Locale,
+) -> Result<Vec<StringsWithPath>, Error> {
+    read_values_dir(res_dir_path, &locale.values_dir_name())
+}
+
+/// Along with the strings, returns a document per file which holds on to
+/// everything in the file as is. Look @ `StringsDocument`
+pub fn read_foreign_strings_and_documents(
+    res_dir_path: &Path,
+    locale: &Locale,
+) -> Result<Vec<(StringsWithPath, StringsDocument)>, Error> {
+    let mut strings_and_documents = vec![];
+    for file_path in find_resource_files(&values_dir_path(res_dir_path, &locale.values_dir_name()))?
+    {
+        let path = String::from(file_path.to_string_lossy());
+        let mut content = String::new();
+        File::open(&file_path)
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .with_context(path.clone())?;
+
+        let strings_with_path = read_strings(content.as_bytes(), &file_path)?;
+        let document = document_reader::read(content.as_bytes()).with_context(path)?;
+        strings_and_documents.push((strings_with_path, document));
+    }
+
+    Ok(strings_and_documents)
+}
+
+/// Combines the resources of all the files into one
+pub fn combine_resources(strings_with_paths: &[StringsWithPath]) -> AndroidResources {
+    let mut resources = AndroidResources::default();
+    for strings_with_path in strings_with_paths {
+        resources.extend(strings_with_path.resources.clone());
+    }
+
+    resources
 }
 
-fn read_strings(file_with_path: FileWithPath) -> Result<StringsWithPath, Error> {
-    let (file, path) = file_with_path;
-    xml_reader::read(file)
-        .with_context(path.clone())
-        .map(|strings| StringsWithPath { path, strings })
+/// Path of the file with the passed in name in the values dir of the locale
+pub fn foreign_file_path(res_dir_path: &Path, locale: &Locale, file_name: &str) -> PathBuf {
+    let mut file_path = values_dir_path(res_dir_path, &locale.values_dir_name());
+    file_path.push(file_name);
+    file_path
 }
 
-fn open_default_strings_file(res_dir_path: &Path) -> Result<FileWithPath, Error> {
-    open_strings_file(res_dir_path, constants::fs::BASE_VALUES_DIR_NAME)
+/// Whether the path points to an XML file (which could be holding resources)
+pub fn is_resource_file(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .is_some_and(|extension| extension == constants::extn::XML)
 }
 
-fn open_foreign_strings_file(res_dir_path: &Path, locale_id: &str) -> Result<FileWithPath, Error> {
-    let values_dir_name = String::from(constants::fs::BASE_VALUES_DIR_NAME);
-    let values_dir_name = values_dir_name.add(&format!("-{}", locale_id));
-    open_strings_file(res_dir_path, &values_dir_name)
+/// Every `*.xml` file in the values dir is read (in the order of their names).
+/// Each read resource is tagged with the name of its file. Resources from files
+/// whose names start with `donottranslate` are considered non-localizable (the
+/// same way Android lint does)
+fn read_values_dir(
+    res_dir_path: &Path,
+    values_dir_name: &str,
+) -> Result<Vec<StringsWithPath>, Error> {
+    find_resource_files(&values_dir_path(res_dir_path, values_dir_name))?
+        .into_iter()
+        .map(|file_path| {
+            let file = File::open(&file_path).with_context(file_path.to_string_lossy())?;
+            read_strings(file, &file_path)
+        })
+        .collect()
 }
 
-fn open_strings_file(res_dir_path: &Path, values_dir_name: &str) -> Result<FileWithPath, Error> {
-    let mut strings_file_path = res_dir_path.to_path_buf();
-    strings_file_path.push(values_dir_name);
-    strings_file_path.push(constants::fs::STRING_FILE_NAME);
+fn find_resource_files(values_dir_path: &Path) -> Result<Vec<PathBuf>, Error> {
+    let context = String::from(values_dir_path.to_string_lossy());
+    let mut file_paths = vec![];
+    for dir_entry in fs::read_dir(values_dir_path).with_context(context.clone())? {
+        let file_path = dir_entry.with_context(context.clone())?.path();
+        if is_resource_file(&file_path) {
+            file_paths.push(file_path);
+        }
+    }
+
+    file_paths.sort();
+    Ok(file_paths)
+}
 
-    let path = String::from(strings_file_path.to_string_lossy());
-    File::open(strings_file_path)
-        .with_context(path.clone())
-        .map(|file| (file, path))
+fn values_dir_path(res_dir_path: &Path, values_dir_name: &str) -> PathBuf {
+    let mut values_dir_path = res_dir_path.to_path_buf();
+    values_dir_path.push(values_dir_name);
+    values_dir_path
+}
+
+fn read_strings<S: Read>(source: S, file_path: &Path) -> Result<StringsWithPath, Error> {
+    let path = String::from(file_path.to_string_lossy());
+    let file_name = file_path
+        .file_name()
+        .map_or_else(|| path.clone(), |name| String::from(name.to_string_lossy()));
+
+    let resources = xml_reader::read(source)
+        .with_context(path.clone())?
+        .with_source_file(&file_name);
+    let resources = if file_name.starts_with(constants::fs::DO_NOT_TRANSLATE_FILE_NAME_PREFIX) {
+        resources.into_unlocalizable()
+    } else {
+        resources
+    };
+
+    Ok(StringsWithPath { path, resources })
 }
 
 pub struct StringsWithPath {
     path: String,
-    strings: Vec<AndroidString>,
+    resources: AndroidResources,
 }
 
 impl StringsWithPath {
+    pub fn new(path: String, resources: AndroidResources) -> StringsWithPath {
+        StringsWithPath { path, resources }
+    }
+
     pub fn path(&self) -> &str {
         &self.path
     }
 
-    pub fn strings(&self) -> &[AndroidString] {
-        &self.strings
+    pub fn resources(&self) -> &AndroidResources {
+        &self.resources
     }
 
-    pub fn into_strings(self) -> Vec<AndroidString> {
-        self.strings
+    pub fn into_resources(self) -> AndroidResources {
+        self.resources
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::io::Read;
+    use std::path::Path;
 
     use test_utilities;
 
+    use crate::android_string::AndroidString;
     use crate::error;
 
     #[test]
-    fn open_strings_file_errors_if_values_dir_is_missing() {
+    fn read_default_strings_errors_if_values_dir_is_missing() {
         let res_dir = tempfile::tempdir().unwrap();
-        let error = super::open_strings_file(res_dir.path(), "values");
-        match error.unwrap_err().kind {
+        let error = super::read_default_strings(res_dir.path()).err().unwrap();
+        match error.kind {
             error::ErrorKind::Io(_) => {}
             error_kind => panic!("Expected IO error. Received: {:?}", error_kind),
         }
     }
 
     #[test]
-    fn open_strings_file_errors_if_strings_file_is_missing() {
+    fn read_default_strings_reads_every_resource_file() {
         let res_dir = tempfile::tempdir().unwrap();
-        test_utilities::res::setup_values_dir_for_default_locale(res_dir.path());
+        let strings_file =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_dir.path());
+        let settings_file = test_utilities::res::setup_empty_resource_file_for_default_locale(
+            res_dir.path(),
+            "strings_settings.xml",
+        );
+        let do_not_translate_file =
+            test_utilities::res::setup_empty_resource_file_for_default_locale(
+                res_dir.path(),
+                "donottranslate.xml",
+            );
+        let not_a_resource_file = test_utilities::res::setup_empty_resource_file_for_default_locale(
+            res_dir.path(),
+            "notes.txt",
+        );
 
-        let error = super::open_strings_file(res_dir.path(), "values");
-        match error.unwrap_err().kind {
-            error::ErrorKind::Io(_) => {}
-            error_kind => panic!("Expected IO error. Received: {:?}", error_kind),
-        }
+        test_utilities::file::write_content(
+            strings_file.path.clone(),
+            r#"<resources><string name="s1">v1</string></resources>"#,
+        );
+        test_utilities::file::write_content(
+            settings_file.path.clone(),
+            r#"<resources><string name="s2">v2</string></resources>"#,
+        );
+        test_utilities::file::write_content(
+            do_not_translate_file.path.clone(),
+            r#"<resources><string name="s3">v3</string></resources>"#,
+        );
+        test_utilities::file::write_content(not_a_resource_file.path, "not xml");
+
+        let strings_with_paths = super::read_default_strings(res_dir.path()).unwrap();
+        test_utilities::list::assert_strict_list_eq(
+            strings_with_paths
+                .iter()
+                .map(|s| String::from(s.path()))
+                .collect::<Vec<String>>(),
+            vec![
+                do_not_translate_file.path,
+                strings_file.path,
+                settings_file.path,
+            ],
+        );
+
+        test_utilities::list::assert_strict_list_eq(
+            super::combine_resources(&strings_with_paths).into_strings(),
+            vec![
+                AndroidString::unlocalizable("s3", "v3")
+                    .with_source_file(Some(String::from("donottranslate.xml"))),
+                AndroidString::localizable("s1", "v1")
+                    .with_source_file(Some(String::from("strings.xml"))),
+                AndroidString::localizable("s2", "v2")
+                    .with_source_file(Some(String::from("strings_settings.xml"))),
+            ],
+        );
     }
 
     #[test]
-    fn open_default_strings_file_opens() {
+    fn read_foreign_strings_and_documents_reads_every_resource_file() {
         let res_dir = tempfile::tempdir().unwrap();
+        let strings_file =
+            test_utilities::res::setup_empty_strings_for_locale(res_dir.path(), "fr");
+        let settings_file = test_utilities::res::setup_empty_resource_file_for_locale(
+            res_dir.path(),
+            "fr",
+            "strings_settings.xml",
+        );
 
-        let strings_file_path =
-            test_utilities::res::setup_empty_strings_for_default_locale(res_dir.path()).path;
-        test_utilities::file::write_content(strings_file_path.clone(), "example content");
+        let strings_file_content = "<resources><string name=\"s1\">v1</string></resources>\n";
+        let settings_file_content = "<resources>\n<string name=\"s2\">v2</string>\n</resources>";
+        test_utilities::file::write_content(strings_file.path.clone(), strings_file_content);
+        test_utilities::file::write_content(settings_file.path.clone(), settings_file_content);
 
-        let mut file_contents = String::new();
-        let (mut file, file_path) = super::open_default_strings_file(res_dir.path()).unwrap();
-        file.read_to_string(&mut file_contents).unwrap();
+        let strings_and_documents =
+            super::read_foreign_strings_and_documents(res_dir.path(), &"fr".parse().unwrap())
+                .unwrap();
+        let (paths, documents): (Vec<String>, Vec<String>) = strings_and_documents
+            .into_iter()
+            .map(|(strings_with_path, document)| {
+                (String::from(strings_with_path.path()), document.to_string())
+            })
+            .unzip();
 
-        assert_eq!(file_contents, "example content");
-        assert_eq!(file_path, strings_file_path);
+        test_utilities::list::assert_strict_list_eq(
+            paths,
+            vec![strings_file.path, settings_file.path],
+        );
+        test_utilities::list::assert_strict_list_eq(
+            documents,
+            vec![
+                String::from(strings_file_content),
+                String::from(settings_file_content),
+            ],
+        );
     }
 
     #[test]
-    fn open_foreign_strings_file_opens() {
-        let res_dir = tempfile::tempdir().unwrap();
-
-        let strings_file_path =
-            test_utilities::res::setup_empty_strings_for_locale(res_dir.path(), "fr").path;
-        test_utilities::file::write_content(strings_file_path.clone(), "example content");
-
-        let mut file_contents = String::new();
-        let (mut file, file_path) = super::open_foreign_strings_file(res_dir.path(), "fr").unwrap();
-        file.read_to_string(&mut file_contents).unwrap();
-
-        assert_eq!(file_contents, "example content");
-        assert_eq!(file_path, strings_file_path);
+    fn foreign_file_path_points_into_values_dir_of_locale() {
+        assert_eq!(
+            super::foreign_file_path(
+                Path::new("res"),
+                &"pt-rBR".parse().unwrap(),
+                "strings_settings.xml"
+            ),
+            Path::new("res")
+                .join("values-pt-rBR")
+                .join("strings_settings.xml")
+        )
     }
 }
@@ -1,5 +1,6 @@
 pub mod apostrophe;
 pub mod format_string;
 pub mod formatter;
+pub mod glossary;
 pub mod missing_strings;
 pub mod validator;
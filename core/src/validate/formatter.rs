@@ -113,6 +113,20 @@ fn format_errors_from_one_file(
         }
     }
 
+    if let Some(glossary_error) = invalid_strings_file.glossary_error {
+        for violation in glossary_error.violations {
+            issues_count_in_file += 1;
+            writeln!(
+                &mut file_output,
+                "Error {} (glossary term): Expected [{}] for [{}] in {}",
+                issues_count_in_file,
+                violation.required_translation,
+                violation.term,
+                violation.android_string.value()
+            )?;
+        }
+    }
+
     let pluralized_issue = if issues_count_in_file <= 1 {
         "issue"
     } else {
@@ -134,6 +148,7 @@ mod tests {
     use crate::android_string::AndroidString;
     use crate::validate::apostrophe;
     use crate::validate::format_string;
+    use crate::validate::glossary;
     use crate::validate::missing_strings;
     use crate::validate::validator::InvalidStringsFile;
 
@@ -155,6 +170,7 @@ mod tests {
                 }),
                 format_string_error: None,
                 missing_strings_error: None,
+                glossary_error: None,
             },
             InvalidStringsFile {
                 file_path: String::from("french"),
@@ -167,11 +183,18 @@ mod tests {
                             sorted_format_strings: vec![],
                         },
                         foreign_parsed_data: format_string::ParsedData {
-                            android_string: french_s1,
+                            android_string: french_s1.clone(),
                             sorted_format_strings: vec![String::from("asdf"), String::from("qwer")],
                         },
                     }],
                 }),
+                glossary_error: Some(glossary::Violations {
+                    violations: vec![glossary::Violation {
+                        android_string: french_s1,
+                        term: String::from("Wallet"),
+                        required_translation: String::from("Portefeuille"),
+                    }],
+                }),
             },
             InvalidStringsFile {
                 file_path: String::from("spanish"),
@@ -209,6 +232,7 @@ mod tests {
                     extra_in_default_locale: vec![default_s1, default_s2],
                     extra_in_foreign_locale: vec![spanish_s1, spanish_s2],
                 }),
+                glossary_error: None,
             },
         ];
 
@@ -218,9 +242,10 @@ mod tests {
                 r#"Path: default (1 issue)
 Error 1 (unescaped apostrophe): default_value1
 
-Path: french (1 issue)
+Path: french (2 issues)
 Error 1 (mismatched format string(s)): Found [asdf, qwer] in french_value1
                                        Found [] in default_value1
+Error 2 (glossary term): Expected [Portefeuille] for [Wallet] in french_value1
 
 Path: spanish (8 issues)
 Error 1 (unescaped apostrophe): spanish_value1
@@ -234,7 +259,7 @@ Error 6 (unlocalized string): default_value2
 Error 7 (string not in defaut locale): spanish_value1
 Error 8 (string not in defaut locale): spanish_value2
 
-Found 10 issues across 3 files!"#
+Found 11 issues across 3 files!"#
             )
         );
     }
@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+
+use csv::ReaderBuilder;
+
+use android_localization_utilities::DevExpt;
+
+use crate::android_plural::AndroidPlural;
+use crate::android_plural::Quantity;
+use crate::android_string::AndroidString;
+use crate::constants;
+use crate::error::InnerError;
+
+/// Terms (like brand & product names) that have to be translated the same way
+/// everywhere or not at all. Read off of a CSV file with a `term` column, an
+/// optional `do_not_translate` column (`true` or `false`) & a column per locale
+/// ID (the same way they appear in the values dir names) with the required
+/// translation of the term. Eg.,
+///
+/// term,do_not_translate,fr,es
+/// Wallet,,Portefeuille,Cartera
+/// Acme,true,,
+#[derive(Debug)]
+pub struct Glossary {
+    terms: Vec<Term>,
+}
+
+#[derive(Debug)]
+struct Term {
+    term: String,
+    do_not_translate: bool,
+    /// By locale ID. Locales without a translation aren't checked
+    translations: HashMap<String, String>,
+}
+
+impl Glossary {
+    pub fn read(path: &Path) -> Result<Glossary, InnerError> {
+        let mut reader = ReaderBuilder::new()
+            .has_headers(true)
+            .flexible(false)
+            .trim(csv::Trim::All)
+            .from_reader(File::open(path)?);
+
+        let headers = reader.headers()?.clone();
+        let index_of = |header| headers.iter().position(|h| h == header);
+        let term_index = index_of(constants::glossary::TERM_HEADER).ok_or_else(|| {
+            InnerError::from(format!(
+                "Glossary doesn't have a {} column",
+                constants::glossary::TERM_HEADER
+            ))
+        })?;
+        let do_not_translate_index = index_of(constants::glossary::DO_NOT_TRANSLATE_HEADER);
+
+        let mut terms = vec![];
+        for record in reader.records() {
+            let record = record?;
+            let term = record.get(term_index).expt("Record without a term!");
+            if term.is_empty() {
+                return Err(InnerError::from("Glossary has an empty term"));
+            }
+
+            let do_not_translate = match do_not_translate_index.map(|index| &record[index]) {
+                None | Some("") | Some("false") => false,
+                Some("true") => true,
+                Some(flag) => {
+                    return Err(InnerError::from(format!(
+                        "Glossary has an invalid {} flag ({}) for {}",
+                        constants::glossary::DO_NOT_TRANSLATE_HEADER,
+                        flag,
+                        term
+                    )))
+                }
+            };
+
+            let translations = headers
+                .iter()
+                .zip(record.iter())
+                .enumerate()
+                .filter(|(index, (_, translation))| {
+                    *index != term_index
+                        && Some(*index) != do_not_translate_index
+                        && !translation.is_empty()
+                })
+                .map(|(_, (locale_id, translation))| {
+                    (String::from(locale_id), String::from(translation))
+                })
+                .collect();
+
+            terms.push(Term {
+                term: String::from(term),
+                do_not_translate,
+                translations,
+            })
+        }
+
+        Ok(Glossary { terms })
+    }
+}
+
+/// Foreign strings whose default values have a term in them (as a whole word)
+/// must have the required translation of the term (or the term itself if it
+/// isn't to be translated) in them. Case is ignored. Plurals & string arrays
+/// are expected to be flattened. Quantities the default plural doesn't have
+/// are checked against its `other` quantity
+pub fn validate(
+    glossary: &Glossary,
+    locale_id: &str,
+    default_strings: &[AndroidString],
+    foreign_strings: &[AndroidString],
+) -> Result<(), Violations> {
+    let default_strings_by_name = default_strings
+        .iter()
+        .map(|s| (s.name(), s))
+        .collect::<HashMap<_, _>>();
+
+    let mut violations = vec![];
+    for foreign_string in foreign_strings {
+        let default_string =
+            match default_strings_by_name
+                .get(foreign_string.name())
+                .or_else(|| {
+                    let (plural_name, _) = AndroidPlural::unflatten_name(foreign_string.name())?;
+                    default_strings_by_name
+                        .get(AndroidPlural::flattened_name(plural_name, Quantity::Other).as_str())
+                }) {
+                Some(default_string) => default_string,
+                None => continue,
+            };
+
+        let foreign_value = foreign_string.value().to_lowercase();
+        for term in &glossary.terms {
+            let required_translation = if term.do_not_translate {
+                &term.term
+            } else {
+                match term.translations.get(locale_id) {
+                    Some(translation) => translation,
+                    None => continue,
+                }
+            };
+
+            if has_term(default_string.value(), &term.term)
+                && !foreign_value.contains(&required_translation.to_lowercase())
+            {
+                violations.push(Violation {
+                    android_string: foreign_string.clone(),
+                    term: term.term.clone(),
+                    required_translation: required_translation.clone(),
+                });
+            }
+        }
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(Violations { violations })
+    }
+}
+
+/// Case insensitive & only as a whole word, i.e., the chars around the term
+/// can't be letters, digits or `_`. Unlike `\b` in a regex, this works for
+/// terms that start or end with punctuation (like `C++` or `Wi-Fi+`)
+fn has_term(value: &str, term: &str) -> bool {
+    let value = value.to_lowercase();
+    let term = term.to_lowercase();
+    let is_word_char = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    value.match_indices(&term).any(|(index, _)| {
+        !is_word_char(value[..index].chars().next_back())
+            && !is_word_char(value[index + term.len()..].chars().next())
+    })
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Violation {
+    pub android_string: AndroidString,
+    pub term: String,
+    pub required_translation: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Violations {
+    pub violations: Vec<Violation>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use test_utilities;
+
+    use super::Glossary;
+    use super::Violation;
+    use crate::android_string::AndroidString;
+
+    fn read_glossary(content: &str) -> Glossary {
+        let tempdir = tempfile::tempdir().unwrap();
+        let path = tempdir.path().join("glossary.csv");
+        fs::write(&path, content).unwrap();
+        Glossary::read(&path).unwrap()
+    }
+
+    #[test]
+    fn passes_if_terms_are_translated_as_required() {
+        let glossary = read_glossary("term,do_not_translate,fr\nWallet,,Portefeuille\nAcme,true,");
+        assert!(super::validate(
+            &glossary,
+            "fr",
+            &[
+                AndroidString::localizable("s1", "Open your wallet"),
+                AndroidString::localizable("s2", "Welcome to Acme"),
+                AndroidString::localizable("s3", "Wallets"),
+            ],
+            &[
+                AndroidString::localizable("s1", "Ouvrez votre portefeuille"),
+                AndroidString::localizable("s2", "Bienvenue chez Acme"),
+                AndroidString::localizable("s3", "Portemonnaies"),
+            ],
+        )
+        .is_ok())
+    }
+
+    #[test]
+    fn skips_locales_without_required_translations() {
+        let glossary = read_glossary("term,fr\nWallet,Portefeuille");
+        assert!(super::validate(
+            &glossary,
+            "es",
+            &[AndroidString::localizable("s1", "Wallet")],
+            &[AndroidString::localizable("s1", "Cartera")],
+        )
+        .is_ok())
+    }
+
+    #[test]
+    fn errors_if_terms_are_not_translated_as_required() {
+        let glossary = read_glossary(
+            "term, do_not_translate, fr, es\nWallet, , Portefeuille, Cartera\nAcme, true, , ",
+        );
+
+        let violations = super::validate(
+            &glossary,
+            "fr",
+            &[
+                AndroidString::localizable("s1", "Open your Acme wallet"),
+                AndroidString::localizable("s2", "Wallet"),
+                AndroidString::localizable("s3", "Cancel"),
+            ],
+            &[
+                AndroidString::localizable("s1", "Ouvrez votre porte-monnaie Akme"),
+                AndroidString::localizable("s2", "Portefeuille"),
+                AndroidString::localizable("s3", "Annuler"),
+                AndroidString::localizable("s4", "Wallet"),
+            ],
+        )
+        .unwrap_err();
+
+        test_utilities::list::assert_strict_list_eq(
+            violations.violations,
+            vec![
+                Violation {
+                    android_string: AndroidString::localizable(
+                        "s1",
+                        "Ouvrez votre porte-monnaie Akme",
+                    ),
+                    term: String::from("Wallet"),
+                    required_translation: String::from("Portefeuille"),
+                },
+                Violation {
+                    android_string: AndroidString::localizable(
+                        "s1",
+                        "Ouvrez votre porte-monnaie Akme",
+                    ),
+                    term: String::from("Acme"),
+                    required_translation: String::from("Acme"),
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn matches_terms_with_punctuation_as_whole_words() {
+        let glossary = read_glossary("term,do_not_translate\nC++,true\nWi-Fi+,true");
+        let violations = super::validate(
+            &glossary,
+            "fr",
+            &[
+                AndroidString::localizable("s1", "Learn C++."),
+                AndroidString::localizable("s2", "(wi-fi+) on"),
+                AndroidString::localizable("s3", "ABC++ & Wi-Fi+s"),
+            ],
+            &[
+                AndroidString::localizable("s1", "Apprendre le C"),
+                AndroidString::localizable("s2", "Wi-Fi activé"),
+                AndroidString::localizable("s3", "ABC & Wi-Fi"),
+            ],
+        )
+        .unwrap_err();
+
+        test_utilities::list::assert_strict_list_eq(
+            violations.violations,
+            vec![
+                Violation {
+                    android_string: AndroidString::localizable("s1", "Apprendre le C"),
+                    term: String::from("C++"),
+                    required_translation: String::from("C++"),
+                },
+                Violation {
+                    android_string: AndroidString::localizable("s2", "Wi-Fi activé"),
+                    term: String::from("Wi-Fi+"),
+                    required_translation: String::from("Wi-Fi+"),
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn read_errors_for_invalid_do_not_translate_flag() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let path = tempdir.path().join("glossary.csv");
+        fs::write(&path, "term,do_not_translate\nAcme,yes").unwrap();
+        assert_eq!(
+            Glossary::read(&path).unwrap_err().to_string(),
+            "Glossary has an invalid do_not_translate flag (yes) for Acme"
+        )
+    }
+}
@@ -1,7 +1,41 @@
+use std::collections::HashSet;
+
+use crate::android_plural::AndroidPlural;
+use crate::android_plural::Quantity;
+use crate::android_resources::AndroidResources;
 use crate::android_string::AndroidString;
+use crate::android_string_array::AndroidStringArray;
+use crate::ops::filter;
 use crate::ops::sort;
 use crate::util::two_pointer_traversal;
 
+/// Validates strings, plurals & string arrays & combines the results. Plurals
+/// must have the `plural_quantities` of the foreign locale (look @
+/// `validate_plurals`)
+pub fn validate_resources(
+    default_resources: &AndroidResources,
+    foreign_resources: &AndroidResources,
+    plural_quantities: &[Quantity],
+) -> Result<(), MissingStrings> {
+    MissingStrings::combine(
+        MissingStrings::combine(
+            validate(
+                &mut default_resources.strings().to_vec(),
+                &mut foreign_resources.strings().to_vec(),
+            ),
+            validate_plurals(
+                default_resources.plurals(),
+                foreign_resources.plurals(),
+                plural_quantities,
+            ),
+        ),
+        validate_string_arrays(
+            default_resources.string_arrays(),
+            foreign_resources.string_arrays(),
+        ),
+    )
+}
+
 pub fn validate(
     default_strings: &mut [AndroidString],
     foreign_strings: &mut [AndroidString],
@@ -36,15 +70,127 @@ pub fn validate(
     }
 }
 
+/// Foreign plurals must have every quantity of `plural_quantities` since
+/// different locales need different quantities (look @ `Quantity::required_by`).
+/// Missing quantities are reported as the flattened strings of the default
+/// plurals (look @ `filter::find_missing_plurals`)
+pub fn validate_plurals(
+    default_plurals: &[AndroidPlural],
+    foreign_plurals: &[AndroidPlural],
+    plural_quantities: &[Quantity],
+) -> Result<(), MissingStrings> {
+    let default_names: HashSet<&str> = default_plurals.iter().map(|p| p.name()).collect();
+
+    // It is ok for non-translatable plurals to be present in default locale but
+    // not the other way around
+    let extra_in_default_locale: Vec<AndroidString> = filter::find_missing_plurals(
+        foreign_plurals,
+        &filter::find_localizable_plurals(default_plurals.to_vec()),
+        plural_quantities,
+    )
+    .iter()
+    .flat_map(AndroidPlural::flatten)
+    .collect();
+
+    let extra_in_foreign_locale: Vec<AndroidString> = foreign_plurals
+        .iter()
+        .filter(|p| !default_names.contains(p.name()))
+        .flat_map(AndroidPlural::flatten)
+        .collect();
+
+    if extra_in_default_locale.is_empty() && extra_in_foreign_locale.is_empty() {
+        Ok(())
+    } else {
+        Err(MissingStrings {
+            extra_in_default_locale,
+            extra_in_foreign_locale,
+        })
+    }
+}
+
+/// String arrays are compared by their names & the number of items they have.
+/// Missing items are reported as their flattened strings
+pub fn validate_string_arrays(
+    default_string_arrays: &[AndroidStringArray],
+    foreign_string_arrays: &[AndroidStringArray],
+) -> Result<(), MissingStrings> {
+    let mut extra_in_default_locale = vec![];
+    let mut extra_in_foreign_locale = vec![];
+    for default_string_array in default_string_arrays {
+        let foreign_items_count = foreign_string_arrays
+            .iter()
+            .find(|a| a.name() == default_string_array.name())
+            .map_or(0, |a| a.items().len());
+
+        // It is ok for non-translatable string arrays to be present in default
+        // locale but not the other way around
+        if default_string_array.is_localizable() {
+            extra_in_default_locale.extend(
+                default_string_array
+                    .flatten()
+                    .into_iter()
+                    .skip(foreign_items_count),
+            );
+        }
+    }
+
+    for foreign_string_array in foreign_string_arrays {
+        let default_items_count = default_string_arrays
+            .iter()
+            .find(|a| a.name() == foreign_string_array.name())
+            .map_or(0, |a| a.items().len());
+
+        extra_in_foreign_locale.extend(
+            foreign_string_array
+                .flatten()
+                .into_iter()
+                .skip(default_items_count),
+        );
+    }
+
+    if extra_in_default_locale.is_empty() && extra_in_foreign_locale.is_empty() {
+        Ok(())
+    } else {
+        Err(MissingStrings {
+            extra_in_default_locale,
+            extra_in_foreign_locale,
+        })
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct MissingStrings {
     pub extra_in_default_locale: Vec<AndroidString>,
     pub extra_in_foreign_locale: Vec<AndroidString>,
 }
 
+impl MissingStrings {
+    pub fn combine(
+        result1: Result<(), MissingStrings>,
+        result2: Result<(), MissingStrings>,
+    ) -> Result<(), MissingStrings> {
+        match (result1, result2) {
+            (Ok(()), Ok(())) => Ok(()),
+            (Err(missing), Ok(())) | (Ok(()), Err(missing)) => Err(missing),
+            (Err(mut missing1), Err(missing2)) => {
+                missing1
+                    .extra_in_default_locale
+                    .extend(missing2.extra_in_default_locale);
+                missing1
+                    .extra_in_foreign_locale
+                    .extend(missing2.extra_in_foreign_locale);
+                Err(missing1)
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::android_plural::AndroidPlural;
+    use crate::android_plural::Quantity;
     use crate::android_string::AndroidString;
+    use crate::android_string_array::AndroidStringArray;
 
     use super::MissingStrings;
 
@@ -94,4 +240,95 @@ mod tests {
             }
         )
     }
+
+    #[test]
+    fn validate_plurals_errors_in_presence_of_missing_plurals() {
+        let default_plurals = vec![
+            AndroidPlural::localizable("p1", vec![(Quantity::Other, "d1")]),
+            AndroidPlural::localizable("p2", vec![(Quantity::Other, "d2")]),
+            AndroidPlural::unlocalizable("p3", vec![(Quantity::Other, "d3")]),
+        ];
+
+        let foreign_plurals = vec![
+            AndroidPlural::localizable("p1", vec![(Quantity::Other, "f1")]),
+            AndroidPlural::localizable("p4", vec![(Quantity::Many, "f4")]),
+        ];
+
+        assert_eq!(
+            super::validate_plurals(&default_plurals, &foreign_plurals, &[Quantity::Other])
+                .unwrap_err(),
+            MissingStrings {
+                extra_in_default_locale: vec![AndroidString::localizable("p2[other]", "d2")],
+                extra_in_foreign_locale: vec![AndroidString::localizable("p4[many]", "f4")],
+            }
+        )
+    }
+
+    #[test]
+    fn validate_plurals_errors_for_missing_quantities() {
+        let default_plurals = vec![
+            AndroidPlural::localizable("p1", vec![(Quantity::One, "d1"), (Quantity::Other, "d1s")]),
+            AndroidPlural::localizable("p2", vec![(Quantity::One, "d2"), (Quantity::Other, "d2s")]),
+        ];
+
+        let foreign_plurals = vec![
+            AndroidPlural::localizable(
+                "p1",
+                vec![
+                    (Quantity::One, "f1"),
+                    (Quantity::Few, "f1f"),
+                    (Quantity::Many, "f1m"),
+                    (Quantity::Other, "f1s"),
+                ],
+            ),
+            AndroidPlural::localizable("p2", vec![(Quantity::One, "f2")]),
+        ];
+
+        assert_eq!(
+            super::validate_plurals(
+                &default_plurals,
+                &foreign_plurals,
+                &[
+                    Quantity::One,
+                    Quantity::Few,
+                    Quantity::Many,
+                    Quantity::Other
+                ]
+            )
+            .unwrap_err(),
+            MissingStrings {
+                extra_in_default_locale: vec![
+                    AndroidString::localizable("p2[few]", "d2s"),
+                    AndroidString::localizable("p2[many]", "d2s"),
+                    AndroidString::localizable("p2[other]", "d2s"),
+                ],
+                extra_in_foreign_locale: vec![],
+            }
+        )
+    }
+
+    #[test]
+    fn validate_string_arrays_fails_for_missing_items() {
+        let default_string_arrays = vec![
+            AndroidStringArray::localizable("a1", vec!["d1", "d2"]),
+            AndroidStringArray::localizable("a2", vec!["d1"]),
+            AndroidStringArray::unlocalizable("a3", vec!["d1"]),
+        ];
+
+        let foreign_string_arrays = vec![
+            AndroidStringArray::localizable("a1", vec!["f1"]),
+            AndroidStringArray::localizable("a4", vec!["f1"]),
+        ];
+
+        assert_eq!(
+            super::validate_string_arrays(&default_string_arrays, &foreign_string_arrays),
+            Err(MissingStrings {
+                extra_in_default_locale: vec![
+                    AndroidString::localizable("a1[1]", "d2"),
+                    AndroidString::localizable("a2[0]", "d1"),
+                ],
+                extra_in_foreign_locale: vec![AndroidString::localizable("a4[0]", "f1")],
+            })
+        )
+    }
 }
@@ -1,42 +1,80 @@
+use std::collections::BTreeMap;
 use std::path::Path;
 
+use crate::android_resources::AndroidResources;
 use crate::android_string::AndroidString;
-use crate::error::Error;
-use crate::util::foreign_locale_ids_finder;
+use crate::constants;
+use crate::error::{Error, ResultExt};
+use crate::qualifiers::Locale;
+use crate::util::source_sets::MergedStrings;
+use crate::util::source_sets::SourceSets;
 use crate::util::xml_utilities;
 use crate::util::xml_utilities::StringsWithPath;
 use crate::validate::apostrophe;
 use crate::validate::format_string;
 use crate::validate::format_string::ParsedData;
+use crate::validate::glossary;
+use crate::validate::glossary::Glossary;
 use crate::validate::missing_strings;
+use crate::validate::missing_strings::MissingStrings;
 
 /// Runs all validations for default & all foreign strings & returns a collection
-/// of file names on which the validations were run
+/// of file names on which the validations were run. With a glossary file (look
+/// @ `Glossary`), foreign strings are also checked for the required translations
+/// of the terms in their default values
 pub fn validate(
     res_dir_path: &str,
     fail_on_unlocalized: bool,
+    glossary_file_path: Option<&str>,
 ) -> Result<Result<Vec<String>, Vec<InvalidStringsFile>>, Error> {
+    validate_with_overlays(res_dir_path, &[], fail_on_unlocalized, glossary_file_path)
+}
+
+/// Same as `validate` but for the merged view of the res dir & its overlays
+/// (look @ `SourceSets`). Overridden resources aren't validated & translations
+/// of overridden default values are considered missing
+pub fn validate_with_overlays(
+    res_dir_path: &str,
+    overlay_res_dir_paths: &[&str],
+    fail_on_unlocalized: bool,
+    glossary_file_path: Option<&str>,
+) -> Result<Result<Vec<String>, Vec<InvalidStringsFile>>, Error> {
+    let glossary = glossary_file_path
+        .map(|path| Glossary::read(Path::new(path)).with_context(path))
+        .transpose()?;
     let mut path_of_validated_files = vec![];
     let mut invalid_strings_files = vec![];
 
-    let default_strings_with_path = xml_utilities::read_default_strings(Path::new(res_dir_path))?;
-    let mut default_parsed_data =
-        format_string::parse_and_build_data(&default_strings_with_path.strings());
+    let source_sets = SourceSets::new(res_dir_path, overlay_res_dir_paths);
+    let merged_default_strings = source_sets.read_default_strings()?;
+    for default_strings_with_path in merged_default_strings.strings_with_paths() {
+        validate_default_strings(
+            default_strings_with_path,
+            &mut path_of_validated_files,
+            &mut invalid_strings_files,
+        );
+    }
 
-    validate_default_strings(
-        &default_strings_with_path,
-        &mut path_of_validated_files,
-        &mut invalid_strings_files,
-    );
+    let default_resources = merged_default_strings.combine();
+    let flattened_default_strings = flatten_resources(&default_resources);
+    let mut default_parsed_data = format_string::parse_and_build_data(&flattened_default_strings);
 
-    let mut default_strings = default_strings_with_path.into_strings();
-    let res_dir_path_string = res_dir_path;
-    let locale_ids = foreign_locale_ids_finder::find(res_dir_path_string)?;
-    for locale_id in locale_ids {
+    for locale in source_sets.find_locales()? {
+        let merged_foreign_strings =
+            source_sets.read_foreign_strings(&locale, &merged_default_strings)?;
         validate_foreign_strings(
-            xml_utilities::read_foreign_strings(Path::new(res_dir_path), &locale_id)?,
-            &mut default_strings,
+            &MissingStringsLocator {
+                source_sets: &source_sets,
+                locale: &locale,
+                merged_default_strings: &merged_default_strings,
+                merged_foreign_strings: &merged_foreign_strings,
+            },
+            &default_resources,
             &mut default_parsed_data,
+            glossary.as_ref().map(|glossary| GlossaryCheck {
+                glossary,
+                default_strings: &flattened_default_strings,
+            }),
             fail_on_unlocalized,
             &mut path_of_validated_files,
             &mut invalid_strings_files,
@@ -56,55 +94,197 @@ fn validate_default_strings(
     invalid_strings_files: &mut Vec<InvalidStringsFile>,
 ) {
     let default_strings_file_path = String::from(strings_with_path.path());
-    let apos_result = apostrophe::validate(strings_with_path.strings());
+    let apos_result = apostrophe::validate(&flatten_resources(strings_with_path.resources()));
     if let Err(apos_error) = apos_result {
         invalid_strings_files.push(InvalidStringsFile {
             file_path: default_strings_file_path,
             apostrophe_error: Some(apos_error),
             format_string_error: None,
             missing_strings_error: None,
+            glossary_error: None,
         })
     } else {
         path_of_validated_files.push(default_strings_file_path)
     }
 }
 
+/// Apostrophes, format strings & glossary terms are validated file by file.
+/// Missing strings are looked for across all the files of the locale but are
+/// reported against the file they are expected to be in (the file with the same
+/// name as the one the default string is in). Look @ `group_by_file`
 fn validate_foreign_strings(
-    strings_with_path: StringsWithPath,
-    mut default_strings: &mut [AndroidString],
-    mut default_parsed_data: &mut [ParsedData],
+    missing_strings_locator: &MissingStringsLocator,
+    default_resources: &AndroidResources,
+    default_parsed_data: &mut [ParsedData],
+    glossary_check: Option<GlossaryCheck>,
     fail_on_unlocalized: bool,
     path_of_validated_files: &mut Vec<String>,
     invalid_strings_files: &mut Vec<InvalidStringsFile>,
 ) {
-    let foreign_strings_file_path = String::from(strings_with_path.path());
-    let mut foreign_strings = strings_with_path.into_strings();
+    let mut missing_strings_by_file = BTreeMap::new();
+    if fail_on_unlocalized {
+        let foreign_resources = missing_strings_locator.merged_foreign_strings.combine();
+        if let Err(ms_error) = missing_strings::validate_resources(
+            default_resources,
+            &foreign_resources,
+            missing_strings_locator.locale.plural_quantities(),
+        ) {
+            missing_strings_by_file = group_by_file(missing_strings_locator, ms_error);
+        }
+    }
 
-    let apos_result = apostrophe::validate(&foreign_strings);
-    let fs_result = format_string::validate(&mut default_parsed_data, &mut foreign_strings);
-    let ms_result = missing_strings::validate(&mut default_strings, &mut foreign_strings);
+    for strings_with_path in missing_strings_locator
+        .merged_foreign_strings
+        .strings_with_paths()
+    {
+        let foreign_strings_file_path = String::from(strings_with_path.path());
+        let mut flattened_foreign_strings = flatten_resources(strings_with_path.resources());
+
+        let apos_result = apostrophe::validate(&flattened_foreign_strings);
+        let fs_result =
+            format_string::validate(default_parsed_data, &mut flattened_foreign_strings);
+        let glossary_result = match glossary_check {
+            None => Ok(()),
+            Some(GlossaryCheck {
+                glossary,
+                default_strings,
+            }) => glossary::validate(
+                glossary,
+                &missing_strings_locator.locale.to_string(),
+                default_strings,
+                &flattened_foreign_strings,
+            ),
+        };
 
-    let mut potential_invalid_file = InvalidStringsFile::new(foreign_strings_file_path.clone());
+        let mut potential_invalid_file = InvalidStringsFile::new(foreign_strings_file_path.clone());
 
-    if let Err(apos_error) = apos_result {
-        potential_invalid_file.apostrophe_error = Some(apos_error);
+        if let Err(apos_error) = apos_result {
+            potential_invalid_file.apostrophe_error = Some(apos_error);
+        }
+
+        if let Err(fs_error) = fs_result {
+            potential_invalid_file.format_string_error = Some(fs_error);
+        }
+
+        if let Err(glossary_error) = glossary_result {
+            potential_invalid_file.glossary_error = Some(glossary_error);
+        }
+
+        potential_invalid_file.missing_strings_error =
+            missing_strings_by_file.remove(&foreign_strings_file_path);
+
+        if potential_invalid_file.has_errors() {
+            invalid_strings_files.push(potential_invalid_file)
+        } else {
+            path_of_validated_files.push(foreign_strings_file_path)
+        }
     }
 
-    if let Err(fs_error) = fs_result {
-        potential_invalid_file.format_string_error = Some(fs_error);
+    // Missing strings which are expected to be in files the locale doesn't have yet
+    for (file_path, ms_error) in missing_strings_by_file {
+        let mut invalid_file = InvalidStringsFile::new(file_path);
+        invalid_file.missing_strings_error = Some(ms_error);
+        invalid_strings_files.push(invalid_file);
     }
+}
 
-    if fail_on_unlocalized {
-        if let Err(ms_error) = ms_result {
-            potential_invalid_file.missing_strings_error = Some(ms_error);
+/// Groups the missing strings by the path of the file they are expected to be
+/// in. Strings from the default locale go into the file with the same name as
+/// the default file (of the source set the default string is from) & strings
+/// from the foreign locale are in their own files
+fn group_by_file(
+    missing_strings_locator: &MissingStringsLocator,
+    missing_strings: MissingStrings,
+) -> BTreeMap<String, MissingStrings> {
+    let mut grouped: BTreeMap<String, MissingStrings> = BTreeMap::new();
+    for string in missing_strings.extra_in_default_locale {
+        group_for_file(
+            &mut grouped,
+            missing_strings_locator.find_for_default_string(&string),
+        )
+        .extra_in_default_locale
+        .push(string);
+    }
+
+    for string in missing_strings.extra_in_foreign_locale {
+        group_for_file(
+            &mut grouped,
+            missing_strings_locator.find_for_foreign_string(&string),
+        )
+        .extra_in_foreign_locale
+        .push(string);
+    }
+
+    grouped
+}
+
+fn group_for_file(
+    grouped: &mut BTreeMap<String, MissingStrings>,
+    file_path: String,
+) -> &mut MissingStrings {
+    grouped.entry(file_path).or_insert_with(|| MissingStrings {
+        extra_in_default_locale: vec![],
+        extra_in_foreign_locale: vec![],
+    })
+}
+
+/// Look @ `Glossary`
+#[derive(Clone, Copy)]
+struct GlossaryCheck<'a> {
+    glossary: &'a Glossary,
+    default_strings: &'a [AndroidString],
+}
+
+struct MissingStringsLocator<'a> {
+    source_sets: &'a SourceSets,
+    locale: &'a Locale,
+    merged_default_strings: &'a MergedStrings,
+    merged_foreign_strings: &'a MergedStrings,
+}
+
+impl<'a> MissingStringsLocator<'a> {
+    fn find_for_default_string(&self, string: &AndroidString) -> String {
+        let source_set = self.source_sets.get(
+            self.merged_default_strings
+                .index_of_flattened(string.name())
+                .unwrap_or(0),
+        );
+
+        xml_utilities::foreign_file_path(
+            source_set.res_dir_path(),
+            self.locale,
+            string
+                .source_file()
+                .unwrap_or(constants::fs::STRING_FILE_NAME),
+        )
+        .to_string_lossy()
+        .into_owned()
+    }
+
+    fn find_for_foreign_string(&self, string: &AndroidString) -> String {
+        match self
+            .merged_foreign_strings
+            .file_path_of_flattened(string.name())
+        {
+            Some(file_path) => String::from(file_path),
+            None => self.find_for_default_string(string),
         }
     }
+}
 
-    if potential_invalid_file.has_errors() {
-        invalid_strings_files.push(potential_invalid_file)
-    } else {
-        path_of_validated_files.push(foreign_strings_file_path)
+/// Plurals are validated as one string per quantity & string arrays as one
+/// string per item
+fn flatten_resources(resources: &AndroidResources) -> Vec<AndroidString> {
+    let mut flattened_strings = resources.strings().to_vec();
+    for plural in resources.plurals() {
+        flattened_strings.extend(plural.flatten());
+    }
+
+    for string_array in resources.string_arrays() {
+        flattened_strings.extend(string_array.flatten());
     }
+
+    flattened_strings
 }
 
 #[derive(Debug, Default, PartialEq)]
@@ -113,6 +293,7 @@ pub struct InvalidStringsFile {
     pub apostrophe_error: Option<apostrophe::InvalidStrings>,
     pub format_string_error: Option<format_string::Mismatches>,
     pub missing_strings_error: Option<missing_strings::MissingStrings>,
+    pub glossary_error: Option<glossary::Violations>,
 }
 
 impl InvalidStringsFile {
@@ -127,6 +308,7 @@ impl InvalidStringsFile {
         self.apostrophe_error.is_some()
             || self.format_string_error.is_some()
             || self.missing_strings_error.is_some()
+            || self.glossary_error.is_some()
     }
 }
 
@@ -134,9 +316,14 @@ impl InvalidStringsFile {
 mod tests {
     use test_utilities;
 
+    use crate::android_plural::AndroidPlural;
+    use crate::android_plural::Quantity;
+    use crate::android_resources::AndroidResources;
     use crate::android_string::AndroidString;
+    use crate::android_string_array::AndroidStringArray;
     use crate::validate::apostrophe;
     use crate::validate::format_string;
+    use crate::validate::glossary;
     use crate::validate::missing_strings;
     use crate::validate::validator::InvalidStringsFile;
     use crate::writer::xml_writer;
@@ -156,23 +343,23 @@ mod tests {
 
         xml_writer::write(
             &mut default_strings.file,
-            vec![AndroidString::localizable("s1", "value")],
+            vec![localizable_in_strings_file("s1", "value")].into(),
         )
         .unwrap();
 
         xml_writer::write(
             &mut french_strings.file,
-            vec![AndroidString::localizable("s1", "value")],
+            vec![localizable_in_strings_file("s1", "value")].into(),
         )
         .unwrap();
 
         xml_writer::write(
             &mut spanish_strings.file,
-            vec![AndroidString::localizable("s1", "value")],
+            vec![localizable_in_strings_file("s1", "value")].into(),
         )
         .unwrap();
 
-        let mut actual_output = super::validate(res_path.to_str().unwrap(), true)
+        let mut actual_output = super::validate(res_path.to_str().unwrap(), true, None)
             .unwrap()
             .unwrap();
 
@@ -189,6 +376,419 @@ mod tests {
         )
     }
 
+    #[test]
+    fn errors_for_string_arrays() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut res_path = tempdir.path().to_path_buf();
+        res_path.push("res");
+
+        let mut default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let mut french_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        xml_writer::write(
+            &mut default_strings.file,
+            AndroidResources::new(
+                vec![],
+                vec![],
+                vec![AndroidStringArray::localizable(
+                    "a1",
+                    vec!["first", "%1$d second"],
+                )],
+            ),
+        )
+        .unwrap();
+
+        xml_writer::write(
+            &mut french_strings.file,
+            AndroidResources::new(
+                vec![],
+                vec![],
+                vec![AndroidStringArray::localizable("a1", vec!["f'irst"])],
+            ),
+        )
+        .unwrap();
+
+        let invalid_strings_files = super::validate(res_path.to_str().unwrap(), true, None)
+            .unwrap()
+            .unwrap_err();
+
+        test_utilities::list::assert_strict_list_eq(
+            invalid_strings_files,
+            vec![InvalidStringsFile {
+                file_path: french_strings.path,
+                apostrophe_error: Some(apostrophe::InvalidStrings {
+                    invalid_strings: vec![localizable_in_strings_file("a1[0]", "f'irst")],
+                }),
+                format_string_error: None,
+                missing_strings_error: Some(missing_strings::MissingStrings {
+                    extra_in_default_locale: vec![localizable_in_strings_file(
+                        "a1[1]",
+                        "%1$d second",
+                    )],
+                    extra_in_foreign_locale: vec![],
+                }),
+                glossary_error: None,
+            }],
+        )
+    }
+
+    #[test]
+    fn errors_for_glossary_terms() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut res_path = tempdir.path().to_path_buf();
+        res_path.push("res");
+        let glossary_path = tempdir.path().join("glossary.csv");
+        test_utilities::file::write_content(
+            glossary_path.clone(),
+            "term,do_not_translate,fr,es\nWallet,,Portefeuille,Cartera\n",
+        );
+
+        let mut default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let mut french_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+        let mut spanish_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "es");
+
+        xml_writer::write(
+            &mut default_strings.file,
+            vec![localizable_in_strings_file("s1", "Open wallet")].into(),
+        )
+        .unwrap();
+
+        xml_writer::write(
+            &mut french_strings.file,
+            vec![localizable_in_strings_file("s1", "Ouvrir le porte-monnaie")].into(),
+        )
+        .unwrap();
+
+        xml_writer::write(
+            &mut spanish_strings.file,
+            vec![localizable_in_strings_file("s1", "Abrir cartera")].into(),
+        )
+        .unwrap();
+
+        let invalid_strings_files = super::validate(
+            res_path.to_str().unwrap(),
+            true,
+            Some(glossary_path.to_str().unwrap()),
+        )
+        .unwrap()
+        .unwrap_err();
+
+        test_utilities::list::assert_strict_list_eq(
+            invalid_strings_files,
+            vec![InvalidStringsFile {
+                file_path: french_strings.path,
+                apostrophe_error: None,
+                format_string_error: None,
+                missing_strings_error: None,
+                glossary_error: Some(glossary::Violations {
+                    violations: vec![glossary::Violation {
+                        android_string: localizable_in_strings_file(
+                            "s1",
+                            "Ouvrir le porte-monnaie",
+                        ),
+                        term: String::from("Wallet"),
+                        required_translation: String::from("Portefeuille"),
+                    }],
+                }),
+            }],
+        )
+    }
+
+    #[test]
+    fn errors_for_glossary_terms_in_plurals_and_string_arrays() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut res_path = tempdir.path().to_path_buf();
+        res_path.push("res");
+        let glossary_path = tempdir.path().join("glossary.csv");
+        test_utilities::file::write_content(
+            glossary_path.clone(),
+            "term,do_not_translate,fr\nWallet,,Portefeuille\nC++,true,\n",
+        );
+
+        let default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let french_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        test_utilities::file::write_content(
+            default_strings.path,
+            r##"<resources>
+    <plurals name="p1">
+        <item quantity="one">%d wallet</item>
+        <item quantity="other">Wallet items: %d</item>
+    </plurals>
+    <string-array name="a1">
+        <item>Learn C++</item>
+    </string-array>
+</resources>"##,
+        );
+
+        // `many` is checked against `other`
+        test_utilities::file::write_content(
+            &french_strings.path,
+            r##"<resources>
+    <plurals name="p1">
+        <item quantity="one">%d portefeuille</item>
+        <item quantity="many">%d porte-monnaie</item>
+        <item quantity="other">%d portefeuilles</item>
+    </plurals>
+    <string-array name="a1">
+        <item>Apprendre le C</item>
+    </string-array>
+</resources>"##,
+        );
+
+        let invalid_strings_files = super::validate(
+            res_path.to_str().unwrap(),
+            false,
+            Some(glossary_path.to_str().unwrap()),
+        )
+        .unwrap()
+        .unwrap_err();
+
+        test_utilities::list::assert_strict_list_eq(
+            invalid_strings_files,
+            vec![InvalidStringsFile {
+                file_path: french_strings.path,
+                apostrophe_error: None,
+                format_string_error: None,
+                missing_strings_error: None,
+                glossary_error: Some(glossary::Violations {
+                    violations: vec![
+                        glossary::Violation {
+                            android_string: localizable_in_strings_file("a1[0]", "Apprendre le C"),
+                            term: String::from("C++"),
+                            required_translation: String::from("C++"),
+                        },
+                        glossary::Violation {
+                            android_string: localizable_in_strings_file(
+                                "p1[many]",
+                                "%d porte-monnaie",
+                            ),
+                            term: String::from("Wallet"),
+                            required_translation: String::from("Portefeuille"),
+                        },
+                    ],
+                }),
+            }],
+        )
+    }
+
+    #[test]
+    fn errors_for_plurals() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut res_path = tempdir.path().to_path_buf();
+        res_path.push("res");
+
+        let mut default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let mut french_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        xml_writer::write(
+            &mut default_strings.file,
+            AndroidResources::new(
+                vec![],
+                vec![
+                    AndroidPlural::localizable(
+                        "p1",
+                        vec![(Quantity::One, "one"), (Quantity::Other, "%1$d others")],
+                    ),
+                    AndroidPlural::localizable("p2", vec![(Quantity::Other, "other")]),
+                ],
+                vec![],
+            ),
+        )
+        .unwrap();
+
+        xml_writer::write(
+            &mut french_strings.file,
+            AndroidResources::new(
+                vec![],
+                vec![AndroidPlural::localizable(
+                    "p1",
+                    vec![(Quantity::Few, "f'ew"), (Quantity::Other, "%1$s others")],
+                )],
+                vec![],
+            ),
+        )
+        .unwrap();
+
+        let invalid_strings_files = super::validate(res_path.to_str().unwrap(), true, None)
+            .unwrap()
+            .unwrap_err();
+
+        test_utilities::list::assert_strict_list_eq(
+            invalid_strings_files,
+            vec![InvalidStringsFile {
+                file_path: french_strings.path,
+                apostrophe_error: Some(apostrophe::InvalidStrings {
+                    invalid_strings: vec![localizable_in_strings_file("p1[few]", "f'ew")],
+                }),
+                format_string_error: Some(format_string::Mismatches {
+                    mismatches: vec![format_string::Mismatch {
+                        default_parsed_data: format_string::ParsedData {
+                            android_string: localizable_in_strings_file("p1[other]", "%1$d others"),
+                            sorted_format_strings: vec![String::from("%1$d")],
+                        },
+                        foreign_parsed_data: format_string::ParsedData {
+                            android_string: localizable_in_strings_file("p1[other]", "%1$s others"),
+                            sorted_format_strings: vec![String::from("%1$s")],
+                        },
+                    }],
+                }),
+                missing_strings_error: Some(missing_strings::MissingStrings {
+                    extra_in_default_locale: vec![
+                        localizable_in_strings_file("p1[one]", "one"),
+                        localizable_in_strings_file("p1[many]", "%1$d others"),
+                        localizable_in_strings_file("p2[one]", "other"),
+                        localizable_in_strings_file("p2[many]", "other"),
+                        localizable_in_strings_file("p2[other]", "other"),
+                    ],
+                    extra_in_foreign_locale: vec![],
+                }),
+                glossary_error: None,
+            }],
+        )
+    }
+
+    #[test]
+    fn errors_for_missing_strings_of_every_file() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut res_path = tempdir.path().to_path_buf();
+        res_path.push("res");
+
+        let mut default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(res_path.clone());
+        let mut default_settings_strings =
+            test_utilities::res::setup_empty_resource_file_for_default_locale(
+                res_path.clone(),
+                "strings_settings.xml",
+            );
+        let mut default_do_not_translate_strings =
+            test_utilities::res::setup_empty_resource_file_for_default_locale(
+                res_path.clone(),
+                "donottranslate.xml",
+            );
+        let mut french_strings =
+            test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "fr");
+
+        xml_writer::write(
+            &mut default_strings.file,
+            vec![AndroidString::localizable("s1", "value")].into(),
+        )
+        .unwrap();
+
+        xml_writer::write(
+            &mut default_settings_strings.file,
+            vec![AndroidString::localizable("s2", "value")].into(),
+        )
+        .unwrap();
+
+        xml_writer::write(
+            &mut default_do_not_translate_strings.file,
+            vec![AndroidString::localizable("s3", "value")].into(),
+        )
+        .unwrap();
+
+        xml_writer::write(
+            &mut french_strings.file,
+            vec![AndroidString::localizable("s1", "value")].into(),
+        )
+        .unwrap();
+
+        let invalid_strings_files = super::validate(res_path.to_str().unwrap(), true, None)
+            .unwrap()
+            .unwrap_err();
+
+        let mut french_settings_strings_path = res_path.clone();
+        french_settings_strings_path.push("values-fr");
+        french_settings_strings_path.push("strings_settings.xml");
+        test_utilities::list::assert_strict_list_eq(
+            invalid_strings_files,
+            vec![InvalidStringsFile {
+                file_path: String::from(french_settings_strings_path.to_str().unwrap()),
+                apostrophe_error: None,
+                format_string_error: None,
+                missing_strings_error: Some(missing_strings::MissingStrings {
+                    extra_in_default_locale: vec![AndroidString::localizable("s2", "value")
+                        .with_source_file(Some(String::from("strings_settings.xml")))],
+                    extra_in_foreign_locale: vec![],
+                }),
+                glossary_error: None,
+            }],
+        )
+    }
+
+    #[test]
+    fn errors_for_translations_of_overridden_default_strings() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let main_res_path = tempdir.path().join("main").join("res");
+        let free_res_path = tempdir.path().join("free").join("res");
+
+        let mut default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(main_res_path.clone());
+        let mut free_default_strings =
+            test_utilities::res::setup_empty_strings_for_default_locale(free_res_path.clone());
+        let mut french_strings =
+            test_utilities::res::setup_empty_strings_for_locale(main_res_path.clone(), "fr");
+
+        xml_writer::write(
+            &mut default_strings.file,
+            vec![
+                AndroidString::localizable("s1", "value"),
+                AndroidString::localizable("s2", "value"),
+            ]
+            .into(),
+        )
+        .unwrap();
+
+        xml_writer::write(
+            &mut free_default_strings.file,
+            vec![AndroidString::localizable("s2", "free value")].into(),
+        )
+        .unwrap();
+
+        xml_writer::write(
+            &mut french_strings.file,
+            vec![
+                AndroidString::localizable("s1", "value"),
+                AndroidString::localizable("s2", "value"),
+            ]
+            .into(),
+        )
+        .unwrap();
+
+        let invalid_strings_files = super::validate_with_overlays(
+            main_res_path.to_str().unwrap(),
+            &[free_res_path.to_str().unwrap()],
+            true,
+            None,
+        )
+        .unwrap()
+        .unwrap_err();
+
+        let free_french_strings_path = free_res_path.join("values-fr").join("strings.xml");
+        test_utilities::list::assert_strict_list_eq(
+            invalid_strings_files,
+            vec![InvalidStringsFile {
+                file_path: String::from(free_french_strings_path.to_str().unwrap()),
+                apostrophe_error: None,
+                format_string_error: None,
+                missing_strings_error: Some(missing_strings::MissingStrings {
+                    extra_in_default_locale: vec![localizable_in_strings_file("s2", "free value")],
+                    extra_in_foreign_locale: vec![],
+                }),
+                glossary_error: None,
+            }],
+        )
+    }
+
     #[test]
     fn errors_without_skipping_missing_errors() {
         test_errors(true)
@@ -211,22 +811,22 @@ mod tests {
         let mut spanish_strings =
             test_utilities::res::setup_empty_strings_for_locale(res_path.clone(), "es");
 
-        let default_s1 = AndroidString::localizable("s1", "value");
-        let default_s2 = AndroidString::localizable("s2", "v'alue");
+        let default_s1 = localizable_in_strings_file("s1", "value");
+        let default_s2 = localizable_in_strings_file("s2", "v'alue");
         xml_writer::write(
             &mut default_strings.file,
-            vec![default_s1.clone(), default_s2.clone()],
+            vec![default_s1.clone(), default_s2.clone()].into(),
         )
         .unwrap();
 
-        let french_s1 = AndroidString::localizable("s1", "v'alue");
-        xml_writer::write(&mut french_strings.file, vec![french_s1.clone()]).unwrap();
+        let french_s1 = localizable_in_strings_file("s1", "v'alue");
+        xml_writer::write(&mut french_strings.file, vec![french_s1.clone()].into()).unwrap();
 
-        let spanish_s2 = AndroidString::localizable("s2", "v'alue %1$d");
-        xml_writer::write(&mut spanish_strings.file, vec![spanish_s2.clone()]).unwrap();
+        let spanish_s2 = localizable_in_strings_file("s2", "v'alue %1$d");
+        xml_writer::write(&mut spanish_strings.file, vec![spanish_s2.clone()].into()).unwrap();
 
         let mut invalid_strings_files =
-            super::validate(res_path.to_str().unwrap(), fail_on_unlocalized)
+            super::validate(res_path.to_str().unwrap(), fail_on_unlocalized, None)
                 .unwrap()
                 .unwrap_err();
 
@@ -270,6 +870,7 @@ mod tests {
                         }],
                     }),
                     missing_strings_error: missing_strings_error_for_es,
+                    glossary_error: None,
                 },
                 InvalidStringsFile {
                     file_path: french_strings.path,
@@ -278,6 +879,7 @@ mod tests {
                     }),
                     format_string_error: None,
                     missing_strings_error: missing_strings_error_for_fr,
+                    glossary_error: None,
                 },
                 InvalidStringsFile {
                     file_path: default_strings.path,
@@ -286,8 +888,14 @@ mod tests {
                     }),
                     format_string_error: None,
                     missing_strings_error: None,
+                    glossary_error: None,
                 },
             ],
         )
     }
+
+    /// Every string read in these tests is from `strings.xml`
+    fn localizable_in_strings_file(name: &str, value: &str) -> AndroidString {
+        AndroidString::localizable(name, value).with_source_file(Some(String::from("strings.xml")))
+    }
 }
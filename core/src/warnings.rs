@@ -0,0 +1,82 @@
+use std::cell::RefCell;
+use std::fmt;
+
+thread_local! {
+    /// Warnings raised by the innermost `collect` running on this thread
+    static COLLECTED_WARNINGS: RefCell<Option<Vec<Warning>>> = const { RefCell::new(None) };
+}
+
+/// A problem that doesn't stop a command (like a skipped translation). Displayed
+/// the same way `Error`s are, with some context
+#[derive(Clone, Debug, PartialEq)]
+pub struct Warning {
+    context: String,
+    message: String,
+}
+
+impl Warning {
+    pub fn context(&self) -> &str {
+        &self.context
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.context, self.message)
+    }
+}
+
+/// Runs `command` & returns its result along with the warnings it raised, for
+/// the caller to show them the way it sees fit
+pub fn collect<T, F: FnOnce() -> T>(command: F) -> (T, Vec<Warning>) {
+    let outer_warnings = COLLECTED_WARNINGS.with(|warnings| warnings.replace(Some(vec![])));
+    let result = command();
+    let warnings = COLLECTED_WARNINGS.with(|warnings| warnings.replace(outer_warnings));
+    (result, warnings.unwrap_or_default())
+}
+
+/// Warnings raised outside of `collect` are dropped
+pub(crate) fn warn<C: AsRef<str>, M: AsRef<str>>(context: C, message: M) {
+    COLLECTED_WARNINGS.with(|warnings| {
+        if let Some(warnings) = warnings.borrow_mut().as_mut() {
+            warnings.push(Warning {
+                context: String::from(context.as_ref()),
+                message: String::from(message.as_ref()),
+            })
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn collects_warnings_of_command() {
+        let (result, warnings) = super::collect(|| {
+            super::warn("s1", "one");
+            let (_, inner_warnings) = super::collect(|| super::warn("s2", "two"));
+            assert_eq!(inner_warnings.len(), 1);
+            super::warn("s3", "three");
+            5
+        });
+
+        assert_eq!(result, 5);
+        assert_eq!(
+            warnings
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>(),
+            vec!["s1: one", "s3: three"]
+        );
+    }
+
+    #[test]
+    fn drops_warnings_outside_of_collect() {
+        super::warn("s1", "one");
+        let ((), warnings) = super::collect(|| {});
+        assert!(warnings.is_empty());
+    }
+}
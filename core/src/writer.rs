@@ -1,2 +1,11 @@
+pub mod arb_writer;
 pub mod csv_writer;
+pub mod document_writer;
+pub mod json_writer;
+pub mod po_writer;
+pub mod properties_writer;
+pub mod strings_writer;
+pub mod stringsdict_writer;
+pub mod xliff_writer;
+pub mod xlsx_writer;
 pub mod xml_writer;
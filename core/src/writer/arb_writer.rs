@@ -0,0 +1,131 @@
+use std::io::Write;
+
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::android_string::AndroidString;
+use crate::constants;
+use crate::error::InnerError;
+use crate::ops::arb;
+use crate::ops::keys;
+
+/// Writes out a Flutter ARB file for the locale with a message per string (look
+/// @ `keys::build`). Values are converted into ARB messages (look @
+/// `arb::to_arb_value`). Every message has its metadata (`@key`) with the XML
+/// comment of the string as the description & the placeholders in the message.
+/// Without `include_values`, every message is left empty (to be filled in)
+pub fn write<W: Write>(
+    sink: W,
+    locale: &str,
+    strings: &[AndroidString],
+    include_values: bool,
+) -> Result<(), InnerError> {
+    let mut document = Map::new();
+    document.insert(
+        String::from(constants::arb::LOCALE_KEY),
+        Value::from(locale),
+    );
+
+    for android_string in strings {
+        let key = keys::build(android_string.name(), android_string.module());
+        let (value, placeholders) = arb::to_arb_value(android_string.value());
+        document.insert(
+            key.clone(),
+            Value::from(if include_values { value } else { String::new() }),
+        );
+
+        let mut metadata = Map::new();
+        if let Some(comment) = android_string.comment() {
+            metadata.insert(
+                String::from(constants::arb::DESCRIPTION),
+                Value::from(comment),
+            );
+        }
+
+        if !placeholders.is_empty() {
+            let mut placeholders_object = Map::new();
+            for placeholder in placeholders {
+                let mut placeholder_object = Map::new();
+                placeholder_object.insert(
+                    String::from(constants::arb::TYPE),
+                    Value::from(placeholder.placeholder_type()),
+                );
+                placeholders_object.insert(
+                    String::from(placeholder.name()),
+                    Value::Object(placeholder_object),
+                );
+            }
+
+            metadata.insert(
+                String::from(constants::arb::PLACEHOLDERS),
+                Value::Object(placeholders_object),
+            );
+        }
+
+        if !metadata.is_empty() {
+            document.insert(
+                format!("{}{}", constants::arb::METADATA_PREFIX, key),
+                Value::Object(metadata),
+            );
+        }
+    }
+
+    serde_json::to_writer_pretty(sink, &Value::Object(document))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::android_string::AndroidString;
+
+    #[test]
+    fn writes_messages_with_metadata() {
+        let mut sink = vec![];
+        super::write(
+            &mut sink,
+            "fr",
+            &[
+                AndroidString::localizable("s1", r#"Don\'t %1$s"#)
+                    .with_comment(Some(String::from("Greeting"))),
+                AndroidString::localizable("s2", "two")
+                    .with_module(Some(String::from("app/src/main/res"))),
+            ],
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(sink).unwrap(),
+            r#"{
+  "@@locale": "fr",
+  "s1": "Don't {arg1}",
+  "@s1": {
+    "description": "Greeting",
+    "placeholders": {
+      "arg1": {
+        "type": "String"
+      }
+    }
+  },
+  "app/src/main/res/s2": "two"
+}"#
+        );
+    }
+
+    #[test]
+    fn writes_empty_messages() {
+        let mut sink = vec![];
+        super::write(
+            &mut sink,
+            "fr",
+            &[AndroidString::localizable("s1", "one")],
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(sink).unwrap(),
+            "{\n  \"@@locale\": \"fr\",\n  \"s1\": \"\"\n}"
+        );
+    }
+}
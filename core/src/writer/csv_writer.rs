@@ -1,77 +1,327 @@
-use std::collections::hash_map::DefaultHasher;
+use std::borrow::Cow;
 use std::collections::HashMap;
-use std::hash::Hash;
-use std::hash::Hasher;
 use std::io::Write;
 
 use csv;
 
 use android_localization_utilities::DevExpt;
 
+use crate::constants;
+use crate::csv_dialect::CsvDialect;
+use crate::csv_dialect::Encoding;
+use crate::csv_dialect::QuoteStyle;
 use crate::error::{Error, InnerError};
+use crate::grouping::Grouping;
+use crate::localizable_strings;
 use crate::localizable_strings::LocalizableStrings;
+use crate::localized_strings::LocalizedStrings;
+use crate::ops::formula;
 
 pub fn write(
     strings_list: Vec<LocalizableStrings>,
+    dialect: CsvDialect,
+    grouping: Grouping,
     sink_provider: &mut dyn SinkProvider,
 ) -> Result<(), Error> {
-    // Split strings into groups requiring localization for the same strings
-    let mut grouped_strings_list: HashMap<u64, Vec<LocalizableStrings>> = HashMap::new();
-    for strings in strings_list {
-        grouped_strings_list
-            .entry(find_grouping_hash_of(&strings))
-            .or_default()
-            .push(strings)
-    }
-
     // We may need multiple sinks to write locale requiring
     // different strings to be localized
-    for (_, some_strings_list) in grouped_strings_list.into_iter() {
+    for some_strings_list in group(strings_list, grouping) {
         sink_provider.execute_with_new_sink(Writer {
             strings_list: some_strings_list,
+            dialect,
+            existing_values: HashMap::new(),
         })?;
     }
 
     Ok(())
 }
 
-fn find_grouping_hash_of(strings: &LocalizableStrings) -> u64 {
-    let mut hasher = DefaultHasher::new();
-    strings.default_locale_strings().hash(&mut hasher);
-    hasher.finish()
+/// Splits strings into groups (look @ `Grouping`). Every locale in a group
+/// requires the same strings to be localized. Groups (& the locales in them)
+/// are in the order they first appear in
+pub fn group(
+    strings_list: Vec<LocalizableStrings>,
+    grouping: Grouping,
+) -> Vec<Vec<LocalizableStrings>> {
+    match grouping {
+        Grouping::ByStrings => group_by_strings(strings_list),
+        Grouping::PerLocale => strings_list.into_iter().map(|s| vec![s]).collect(),
+        Grouping::Single if strings_list.is_empty() => vec![],
+        Grouping::Single => {
+            let strings = localizable_strings::union(&strings_list);
+            vec![strings_list
+                .into_iter()
+                .map(|s| {
+                    LocalizableStrings::new(String::from(s.to_locale()), strings.clone())
+                        .with_old_values(s.old_values().clone())
+                        .with_suggestions(s.suggestions().clone())
+                        .with_drafts(s.drafts().clone())
+                })
+                .collect()]
+        }
+    }
+}
+
+fn group_by_strings(strings_list: Vec<LocalizableStrings>) -> Vec<Vec<LocalizableStrings>> {
+    let mut grouped_strings_list: Vec<Vec<LocalizableStrings>> = vec![];
+    for strings in strings_list {
+        // Strings that are new for a locale & changed for another can't share a
+        // status. Look @ `Writer::write`
+        let group = grouped_strings_list.iter_mut().find(|group| {
+            group[0].default_locale_strings() == strings.default_locale_strings()
+                && group[0].old_values().keys().eq(strings.old_values().keys())
+        });
+
+        match group {
+            Some(group) => group.push(strings),
+            None => grouped_strings_list.push(vec![strings]),
+        }
+    }
+
+    grouped_strings_list
 }
 
+/// Locale, module, name & default value
+type ValueKey = (String, Option<String>, String, String);
+
 pub struct Writer {
     strings_list: Vec<LocalizableStrings>,
+    dialect: CsvDialect,
+    existing_values: HashMap<ValueKey, String>,
 }
 
 impl Writer {
+    /// Locales of the foreign columns (in their order)
+    pub fn locales(&self) -> Vec<&str> {
+        self.strings_list.iter().map(|s| s.to_locale()).collect()
+    }
+
+    /// Values already filled in for a string (by a translator, in a file written
+    /// out earlier) are written out instead of leaving them empty. They are only
+    /// picked up if the default value of the string is still the same
+    pub fn with_existing_values(mut self, localized_strings_list: Vec<LocalizedStrings>) -> Writer {
+        for localized_strings in localized_strings_list {
+            let locale = String::from(localized_strings.locale());
+            for string in localized_strings.into_strings() {
+                self.existing_values.insert(
+                    (
+                        locale.clone(),
+                        string.module().map(String::from),
+                        String::from(string.name()),
+                        String::from(string.default()),
+                    ),
+                    String::from(string.localized()),
+                );
+            }
+        }
+
+        self
+    }
+
+    /// Strings from a project (look @ `ResDir`) get an extra leading `module`
+    /// column so that they can be routed back to their res dirs. If any string
+    /// has a comment or a max length, `description` & `max_length` columns are
+    /// written out right after the default values for translators. If any string
+    /// has changed since it was localized (look @ `State`), a `status` column
+    /// (`new` or `changed`) & a column per locale with the old values (Eg.,
+    /// `french (old)`) are written out too. If there are translations of the
+    /// same or similar default values (look @ `TranslationMemory`), a column per
+    /// locale with them (Eg., `french (suggestion)`) is written out next. Machine
+    /// translations (look @ `TranslationProvider`) go into a column per locale
+    /// (Eg., `french (draft)`) at the end. Records are written out as laid out
+    /// by the dialect (look @ `CsvDialect`). Only the values, old values,
+    /// suggestions, drafts & descriptions are escaped in the formula safe mode
+    /// since everything else is a name or a number
     pub fn write(self, sink: &mut dyn Write) -> Result<(), InnerError> {
+        match self.dialect.encoding() {
+            Encoding::Utf8 => {
+                if self.dialect.bom() {
+                    sink.write_all(constants::csv::UTF8_BOM)?;
+                }
+
+                self.write_records(sink)
+            }
+
+            Encoding::Utf16 => {
+                let mut records = vec![];
+                self.write_records(&mut records)?;
+                let records = String::from_utf8(records).expt("CSV writer wrote invalid UTF-8!");
+
+                let mut bytes = Vec::with_capacity(records.len() * 2 + 2);
+                bytes.extend_from_slice(constants::csv::UTF16_LE_BOM);
+                for unit in records.encode_utf16() {
+                    bytes.extend_from_slice(&unit.to_le_bytes());
+                }
+
+                sink.write_all(&bytes)?;
+                Ok(())
+            }
+        }
+    }
+
+    fn write_records(self, sink: &mut dyn Write) -> Result<(), InnerError> {
         // Sink is automatically buffered
-        let mut csv_writer = csv::Writer::from_writer(sink);
+        let mut csv_writer = csv::WriterBuilder::new()
+            .delimiter(self.dialect.delimiter())
+            .quote_style(match self.dialect.quote_style() {
+                QuoteStyle::Necessary => csv::QuoteStyle::Necessary,
+                QuoteStyle::Always => csv::QuoteStyle::Always,
+            })
+            .from_writer(sink);
         let locale_count = self.strings_list.len();
         let localizable_strings = self.strings_list.first().expt("Empty strings list!");
-        let value_count = localizable_strings.default_locale_strings().len();
+        let has_modules = localizable_strings
+            .default_locale_strings()
+            .iter()
+            .any(|s| s.module().is_some());
+        let has_context = localizable_strings
+            .default_locale_strings()
+            .iter()
+            .any(|s| s.comment().is_some() || s.max_length().is_some());
+        let has_changes = self.strings_list.iter().any(|s| !s.old_values().is_empty());
+        let has_suggestions = self
+            .strings_list
+            .iter()
+            .any(|s| !s.suggestions().is_empty());
+        let has_drafts = self.strings_list.iter().any(|s| !s.drafts().is_empty());
+        let leading_column_count = 2
+            + usize::from(has_modules)
+            + if has_context { 2 } else { 0 }
+            + usize::from(has_changes);
+        let locale_column_count = locale_count
+            * (1 + usize::from(has_changes)
+                + usize::from(has_suggestions)
+                + usize::from(has_drafts));
 
         // Write header record
-        let mut header = Vec::with_capacity(locale_count + 2);
-        header.push("string_name");
-        header.push("default_locale");
-        for i in 0..locale_count {
-            header.push(self.strings_list[i].to_locale());
+        let mut header = Vec::with_capacity(locale_column_count + leading_column_count);
+        if has_modules {
+            header.push(Cow::Borrowed("module"));
+        }
+
+        header.push(Cow::Borrowed("string_name"));
+        header.push(Cow::Borrowed("default_locale"));
+        if has_context {
+            header.push(Cow::Borrowed(constants::csv::DESCRIPTION_HEADER));
+            header.push(Cow::Borrowed(constants::csv::MAX_LENGTH_HEADER));
+        }
+
+        if has_changes {
+            header.push(Cow::Borrowed(constants::csv::STATUS_HEADER));
+        }
+
+        for strings in &self.strings_list {
+            header.push(Cow::Borrowed(strings.to_locale()));
+        }
+
+        if has_changes {
+            for strings in &self.strings_list {
+                header.push(Cow::Owned(format!(
+                    "{}{}",
+                    strings.to_locale(),
+                    constants::csv::OLD_VALUES_HEADER_SUFFIX
+                )));
+            }
+        }
+
+        if has_suggestions {
+            for strings in &self.strings_list {
+                header.push(Cow::Owned(format!(
+                    "{}{}",
+                    strings.to_locale(),
+                    constants::csv::SUGGESTIONS_HEADER_SUFFIX
+                )));
+            }
         }
-        csv_writer.write_record(header)?;
+
+        if has_drafts {
+            for strings in &self.strings_list {
+                header.push(Cow::Owned(format!(
+                    "{}{}",
+                    strings.to_locale(),
+                    constants::csv::DRAFTS_HEADER_SUFFIX
+                )));
+            }
+        }
+
+        csv_writer.write_record(header.iter().map(|field| field.as_bytes()))?;
 
         // Write values
-        let mut record = vec![""; locale_count + 2];
-        for i in 0..value_count {
-            let localizable_string = localizable_strings
-                .default_locale_strings()
-                .get(i)
-                .expt("Already checked the size but it still fails!");
-            record[0] = localizable_string.name();
-            record[1] = localizable_string.value();
-            csv_writer.write_record(&record)?;
+        let formula_safe = self.dialect.formula_safe();
+        for localizable_string in localizable_strings.default_locale_strings() {
+            let mut record = Vec::with_capacity(locale_column_count + leading_column_count);
+            if has_modules {
+                record.push(Cow::Borrowed(localizable_string.module().unwrap_or("")));
+            }
+
+            record.push(Cow::Borrowed(localizable_string.name()));
+            record.push(escape(localizable_string.value(), formula_safe));
+            if has_context {
+                record.push(escape(
+                    localizable_string.comment().unwrap_or(""),
+                    formula_safe,
+                ));
+                record.push(Cow::Owned(
+                    localizable_string
+                        .max_length()
+                        .map_or(String::new(), |l| l.to_string()),
+                ));
+            }
+
+            if has_changes {
+                let is_changed = self
+                    .strings_list
+                    .iter()
+                    .any(|s| s.old_value_of(localizable_string).is_some());
+                record.push(Cow::Borrowed(if is_changed {
+                    constants::csv::STATUS_CHANGED
+                } else {
+                    constants::csv::STATUS_NEW
+                }));
+            }
+
+            for strings in &self.strings_list {
+                let key = (
+                    String::from(strings.to_locale()),
+                    localizable_string.module().map(String::from),
+                    String::from(localizable_string.name()),
+                    String::from(localizable_string.value()),
+                );
+
+                record.push(escape(
+                    self.existing_values.get(&key).map_or("", String::as_str),
+                    formula_safe,
+                ));
+            }
+
+            if has_changes {
+                for strings in &self.strings_list {
+                    record.push(escape(
+                        strings.old_value_of(localizable_string).unwrap_or(""),
+                        formula_safe,
+                    ));
+                }
+            }
+
+            if has_suggestions {
+                for strings in &self.strings_list {
+                    record.push(escape(
+                        strings.suggestion_of(localizable_string).unwrap_or(""),
+                        formula_safe,
+                    ));
+                }
+            }
+
+            if has_drafts {
+                for strings in &self.strings_list {
+                    record.push(escape(
+                        strings.draft_of(localizable_string).unwrap_or(""),
+                        formula_safe,
+                    ));
+                }
+            }
+
+            csv_writer.write_record(record.iter().map(|field| field.as_bytes()))?;
         }
 
         csv_writer.flush()?;
@@ -79,17 +329,33 @@ impl Writer {
     }
 }
 
+fn escape(value: &str, formula_safe: bool) -> Cow<'_, str> {
+    if formula_safe {
+        Cow::Owned(formula::escape(value))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
 pub trait SinkProvider {
     fn execute_with_new_sink(&mut self, writer: Writer) -> Result<(), Error>;
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use test_utilities;
 
     use crate::android_string::AndroidString;
+    use crate::csv_dialect::CsvDialect;
+    use crate::csv_dialect::Encoding;
+    use crate::csv_dialect::QuoteStyle;
     use crate::error::ResultExt;
+    use crate::grouping::Grouping;
     use crate::localizable_strings::LocalizableStrings;
+    use crate::localized_string::LocalizedString;
+    use crate::localized_strings::LocalizedStrings;
 
     use super::Error;
     use super::SinkProvider;
@@ -108,6 +374,19 @@ mod tests {
         }
     }
 
+    struct RawByteSinkProvider {
+        data: Vec<Vec<u8>>,
+    }
+
+    impl SinkProvider for RawByteSinkProvider {
+        fn execute_with_new_sink(&mut self, writer: Writer) -> Result<(), Error> {
+            let mut contents = vec![];
+            let result = writer.write(&mut contents);
+            self.data.push(contents);
+            result.with_context("added context for tests")
+        }
+    }
+
     #[test]
     fn writes_strings_to_files() {
         let strings_list = vec![
@@ -138,12 +417,15 @@ mod tests {
         // Convert all the written bytes into strings
         let mut sink_provider = ByteSinkProvider { data: vec![] };
 
-        super::write(strings_list, &mut sink_provider).unwrap();
-
-        // Since a map is used, sort the contents to be sure of the order
-        sink_provider.data.sort();
+        super::write(
+            strings_list,
+            CsvDialect::default(),
+            Grouping::ByStrings,
+            &mut sink_provider,
+        )
+        .unwrap();
 
-        // Time to assert
+        // Groups are in the order they first appear in
         test_utilities::list::assert_strict_list_eq(
             sink_provider.data,
             vec![
@@ -153,4 +435,372 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn writes_strings_per_locale_or_in_a_single_file() {
+        let strings_list = vec![
+            LocalizableStrings::new(
+                String::from("french"),
+                vec![AndroidString::localizable("string_1", "english 1")],
+            ),
+            LocalizableStrings::new(
+                String::from("german"),
+                vec![AndroidString::localizable("string_1", "english 1")],
+            ),
+            LocalizableStrings::new(
+                String::from("spanish"),
+                vec![
+                    AndroidString::localizable("string_1", "english 1"),
+                    AndroidString::localizable("string_2", "english 2"),
+                ],
+            ),
+        ];
+
+        let mut sink_provider = ByteSinkProvider { data: vec![] };
+        super::write(
+            strings_list.clone(),
+            CsvDialect::default(),
+            Grouping::PerLocale,
+            &mut sink_provider,
+        )
+        .unwrap();
+        test_utilities::list::assert_strict_list_eq(
+            sink_provider.data,
+            vec![
+                String::from("string_name,default_locale,french\nstring_1,english 1,\n"),
+                String::from("string_name,default_locale,german\nstring_1,english 1,\n"),
+                String::from("string_name,default_locale,spanish\nstring_1,english 1,\nstring_2,english 2,\n"),
+            ],
+        );
+
+        let mut sink_provider = ByteSinkProvider { data: vec![] };
+        super::write(
+            strings_list,
+            CsvDialect::default(),
+            Grouping::Single,
+            &mut sink_provider,
+        )
+        .unwrap();
+        test_utilities::list::assert_strict_list_eq(
+            sink_provider.data,
+            vec![String::from(
+                "string_name,default_locale,french,german,spanish\nstring_1,english 1,,,\nstring_2,english 2,,,\n",
+            )],
+        );
+    }
+
+    #[test]
+    fn writes_existing_values() {
+        let writer = Writer {
+            strings_list: vec![
+                LocalizableStrings::new(
+                    String::from("french"),
+                    vec![
+                        AndroidString::localizable("string_1", "english 1"),
+                        AndroidString::localizable("string_2", "english 2"),
+                    ],
+                ),
+                LocalizableStrings::new(
+                    String::from("german"),
+                    vec![
+                        AndroidString::localizable("string_1", "english 1"),
+                        AndroidString::localizable("string_2", "english 2"),
+                    ],
+                ),
+            ],
+            dialect: CsvDialect::default(),
+            existing_values: HashMap::new(),
+        }
+        .with_existing_values(vec![
+            LocalizedStrings::build(
+                "french",
+                vec![
+                    LocalizedString::build("string_1", "english 1", "french 1"),
+                    LocalizedString::build("string_2", "old english 2", "old french 2"),
+                ],
+            ),
+            LocalizedStrings::build(
+                "german",
+                vec![LocalizedString::build("string_2", "english 2", "german 2")],
+            ),
+        ]);
+
+        assert_eq!(writer.locales(), vec!["french", "german"]);
+
+        let mut sink = vec![];
+        writer.write(&mut sink).unwrap();
+        assert_eq!(
+            String::from_utf8(sink).unwrap(),
+            "string_name,default_locale,french,german\nstring_1,english 1,french 1,\nstring_2,english 2,,german 2\n"
+        );
+    }
+
+    #[test]
+    fn writes_context_of_strings() {
+        let strings_list = vec![LocalizableStrings::new(
+            String::from("french"),
+            vec![
+                AndroidString::localizable("string_1", "english 1")
+                    .with_comment(Some(String::from("Used on the checkout button")))
+                    .with_max_length(Some(20)),
+                AndroidString::localizable("string_2", "english 2"),
+                AndroidString::localizable("string_3", "english 3")
+                    .with_comment(Some(String::from("=1+1"))),
+            ],
+        )];
+
+        let mut sink_provider = ByteSinkProvider { data: vec![] };
+        super::write(
+            strings_list,
+            CsvDialect::default().with_formula_safe(true),
+            Grouping::ByStrings,
+            &mut sink_provider,
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            sink_provider.data,
+            vec![String::from(
+                "string_name,default_locale,description,max_length,french\nstring_1,english 1,Used on the checkout button,20,\nstring_2,english 2,,,\nstring_3,english 3,'=1+1,,\n",
+            )],
+        );
+    }
+
+    #[test]
+    fn writes_status_and_old_values_of_changed_strings() {
+        let strings = vec![
+            AndroidString::localizable("string_1", "english 1"),
+            AndroidString::localizable("string_2", "english 2"),
+        ];
+
+        let old_values = |value: &str| {
+            vec![((None, String::from("string_1")), String::from(value))]
+                .into_iter()
+                .collect()
+        };
+
+        let strings_list = vec![
+            LocalizableStrings::new(String::from("french"), strings.clone())
+                .with_old_values(old_values("french 1")),
+            LocalizableStrings::new(String::from("german"), strings.clone()),
+            LocalizableStrings::new(String::from("spanish"), strings)
+                .with_old_values(old_values("=spanish 1")),
+        ];
+
+        let mut sink_provider = ByteSinkProvider { data: vec![] };
+        super::write(
+            strings_list,
+            CsvDialect::default().with_formula_safe(true),
+            Grouping::ByStrings,
+            &mut sink_provider,
+        )
+        .unwrap();
+
+        // Locales with different statuses for the same string aren't grouped
+        test_utilities::list::assert_strict_list_eq(
+            sink_provider.data,
+            vec![
+                String::from("string_name,default_locale,status,french,spanish,french (old),spanish (old)\nstring_1,english 1,changed,,,french 1,'=spanish 1\nstring_2,english 2,new,,,,\n"),
+                String::from("string_name,default_locale,german\nstring_1,english 1,\nstring_2,english 2,\n"),
+            ],
+        );
+    }
+
+    #[test]
+    fn writes_suggestions_of_strings() {
+        let strings = vec![
+            AndroidString::localizable("string_1", "Cancel"),
+            AndroidString::localizable("string_2", "Save the notes"),
+            AndroidString::localizable("string_3", "Delete"),
+        ];
+
+        let strings_list = vec![
+            LocalizableStrings::new(String::from("french"), strings.clone()).with_suggestions(
+                vec![
+                    ((None, String::from("string_1")), String::from("Annuler")),
+                    (
+                        (None, String::from("string_2")),
+                        String::from("Enregistrer la note"),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            LocalizableStrings::new(String::from("spanish"), strings).with_suggestions(
+                vec![((None, String::from("string_1")), String::from("=Cancelar"))]
+                    .into_iter()
+                    .collect(),
+            ),
+        ];
+
+        let mut sink_provider = ByteSinkProvider { data: vec![] };
+        super::write(
+            strings_list,
+            CsvDialect::default().with_formula_safe(true),
+            Grouping::ByStrings,
+            &mut sink_provider,
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            sink_provider.data,
+            vec![String::from(
+                "string_name,default_locale,french,spanish,french (suggestion),spanish (suggestion)\nstring_1,Cancel,,,Annuler,'=Cancelar\nstring_2,Save the notes,,,Enregistrer la note,\nstring_3,Delete,,,,\n",
+            )],
+        );
+    }
+
+    #[test]
+    fn writes_drafts_of_strings() {
+        let strings = vec![
+            AndroidString::localizable("string_1", "Cancel"),
+            AndroidString::localizable("string_2", "Save"),
+        ];
+
+        let strings_list = vec![
+            LocalizableStrings::new(String::from("french"), strings.clone())
+                .with_suggestions(
+                    vec![((None, String::from("string_1")), String::from("Annuler"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .with_drafts(
+                    vec![
+                        ((None, String::from("string_1")), String::from("Annuler")),
+                        (
+                            (None, String::from("string_2")),
+                            String::from("Enregistrer"),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+            LocalizableStrings::new(String::from("spanish"), strings),
+        ];
+
+        let mut sink_provider = ByteSinkProvider { data: vec![] };
+        super::write(
+            strings_list,
+            CsvDialect::default(),
+            Grouping::Single,
+            &mut sink_provider,
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            sink_provider.data,
+            vec![String::from(
+                "string_name,default_locale,french,spanish,french (suggestion),spanish (suggestion),french (draft),spanish (draft)\nstring_1,Cancel,,,Annuler,,Annuler,\nstring_2,Save,,,,,Enregistrer,\n",
+            )],
+        );
+    }
+
+    #[test]
+    fn writes_modules_of_strings() {
+        let strings_list = vec![LocalizableStrings::new(
+            String::from("french"),
+            vec![
+                AndroidString::localizable("string_1", "english 1")
+                    .with_module(Some(String::from("app/src/main/res"))),
+                AndroidString::localizable("string_1", "english 1")
+                    .with_module(Some(String::from("lib/src/main/res"))),
+            ],
+        )];
+
+        let mut sink_provider = ByteSinkProvider { data: vec![] };
+        super::write(
+            strings_list,
+            CsvDialect::default(),
+            Grouping::ByStrings,
+            &mut sink_provider,
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            sink_provider.data,
+            vec![String::from(
+                "module,string_name,default_locale,french\napp/src/main/res,string_1,english 1,\nlib/src/main/res,string_1,english 1,\n",
+            )],
+        );
+    }
+
+    #[test]
+    fn writes_strings_in_dialect() {
+        let strings_list = vec![LocalizableStrings::new(
+            String::from("french"),
+            vec![AndroidString::localizable("string_1", "english; 1")],
+        )];
+
+        let mut sink_provider = ByteSinkProvider { data: vec![] };
+        super::write(
+            strings_list,
+            CsvDialect::default()
+                .with_delimiter(b';')
+                .with_bom(true)
+                .with_quote_style(QuoteStyle::Always),
+            Grouping::ByStrings,
+            &mut sink_provider,
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            sink_provider.data,
+            vec![String::from(
+                "\u{feff}\"string_name\";\"default_locale\";\"french\"\n\"string_1\";\"english; 1\";\"\"\n",
+            )],
+        );
+    }
+
+    #[test]
+    fn writes_strings_in_utf16() {
+        let strings_list = vec![LocalizableStrings::new(
+            String::from("french"),
+            vec![AndroidString::localizable("string_1", "café")],
+        )];
+
+        let mut sink_provider = RawByteSinkProvider { data: vec![] };
+        super::write(
+            strings_list,
+            CsvDialect::default()
+                .with_delimiter(b'\t')
+                .with_encoding(Encoding::Utf16),
+            Grouping::ByStrings,
+            &mut sink_provider,
+        )
+        .unwrap();
+
+        let mut expected = vec![0xFF, 0xFE];
+        for unit in "string_name\tdefault_locale\tfrench\nstring_1\tcafé\t\n".encode_utf16() {
+            expected.extend_from_slice(&unit.to_le_bytes());
+        }
+
+        test_utilities::list::assert_strict_list_eq(sink_provider.data, vec![expected]);
+    }
+
+    #[test]
+    fn writes_formula_safe_strings() {
+        let strings_list = vec![LocalizableStrings::new(
+            String::from("french"),
+            vec![
+                AndroidString::localizable("string_1", "=1+1"),
+                AndroidString::localizable("string_2", "a=1"),
+            ],
+        )];
+
+        let mut sink_provider = ByteSinkProvider { data: vec![] };
+        super::write(
+            strings_list,
+            CsvDialect::default().with_formula_safe(true),
+            Grouping::ByStrings,
+            &mut sink_provider,
+        )
+        .unwrap();
+
+        test_utilities::list::assert_strict_list_eq(
+            sink_provider.data,
+            vec![String::from(
+                "string_name,default_locale,french\nstring_1,'=1+1,\nstring_2,a=1,\n",
+            )],
+        );
+    }
 }
@@ -0,0 +1,9 @@
+use std::io::Write;
+
+use crate::error::InnerError;
+use crate::strings_document::StringsDocument;
+
+pub fn write<S: Write>(sink: &mut S, document: &StringsDocument) -> Result<(), InnerError> {
+    sink.write_all(document.to_string().as_bytes())?;
+    Ok(())
+}
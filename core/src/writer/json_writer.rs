@@ -0,0 +1,107 @@
+use std::io::Write;
+
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::constants;
+use crate::error::InnerError;
+use crate::localizable_strings::LocalizableStrings;
+
+/// Writes out a JSON document (i18next style) with an object per foreign
+/// locale. Every string to be localized is an object with its default value as
+/// the `source` & an empty `target` to be filled in. Strings with modules (look
+/// @ `ResDir` & `SourceSet`) are nested in an object per module. Keys are
+/// sorted
+pub fn write<W: Write>(sink: W, strings_list: Vec<LocalizableStrings>) -> Result<(), InnerError> {
+    let mut document = Map::new();
+    for localizable_strings in strings_list {
+        let mut locale_object = Map::new();
+        for android_string in localizable_strings.default_locale_strings() {
+            let mut unit = Map::new();
+            unit.insert(
+                String::from(constants::json::SOURCE),
+                Value::from(android_string.value()),
+            );
+            unit.insert(String::from(constants::json::TARGET), Value::from(""));
+
+            let parent = match android_string.module() {
+                None => &mut locale_object,
+                Some(module) => match locale_object
+                    .entry(module)
+                    .or_insert_with(|| Value::Object(Map::new()))
+                {
+                    Value::Object(module_object) => module_object,
+                    _ => return Err(format!("{} is both a string & a module", module).into()),
+                },
+            };
+
+            parent.insert(String::from(android_string.name()), Value::Object(unit));
+        }
+
+        for value in locale_object.values_mut() {
+            if let Value::Object(object) = value {
+                object.sort_keys();
+            }
+        }
+
+        locale_object.sort_keys();
+        document.insert(
+            String::from(localizable_strings.to_locale()),
+            Value::Object(locale_object),
+        );
+    }
+
+    document.sort_keys();
+    serde_json::to_writer_pretty(sink, &Value::Object(document))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::android_string::AndroidString;
+    use crate::localizable_strings::LocalizableStrings;
+
+    #[test]
+    fn writes_an_object_per_locale() {
+        let strings_list = vec![
+            LocalizableStrings::new(
+                String::from("french"),
+                vec![
+                    AndroidString::localizable("s2", "two"),
+                    AndroidString::localizable("s1", "Say \"hi\""),
+                ],
+            ),
+            LocalizableStrings::new(
+                String::from("german"),
+                vec![AndroidString::localizable("s1", "one")
+                    .with_module(Some(String::from("app/src/main/res")))],
+            ),
+        ];
+
+        let mut sink = vec![];
+        super::write(&mut sink, strings_list).unwrap();
+        assert_eq!(
+            String::from_utf8(sink).unwrap(),
+            r#"{
+  "french": {
+    "s1": {
+      "source": "Say \"hi\"",
+      "target": ""
+    },
+    "s2": {
+      "source": "two",
+      "target": ""
+    }
+  },
+  "german": {
+    "app/src/main/res": {
+      "s1": {
+        "source": "one",
+        "target": ""
+      }
+    }
+  }
+}"#
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use std::io::Write;
+
+use crate::android_string::AndroidString;
+use crate::constants;
+use crate::constants::po;
+use crate::error::InnerError;
+
+/// Writes out a POT template when there is no locale & a PO file for the locale
+/// (its language tag; look @ `LocalizableStrings::to_language_tag`) otherwise. Every string is an entry with its name as `msgctxt` (prefixed with
+/// its module if it has one), its default value as `msgid` & an empty `msgstr`.
+/// The XML comment of a string is written out as a translator comment
+pub fn write<W: Write>(
+    mut sink: W,
+    locale: Option<&str>,
+    strings: &[AndroidString],
+) -> Result<(), InnerError> {
+    writeln!(sink, "{} \"\"", po::MSGID)?;
+    writeln!(sink, "{} \"\"", po::MSGSTR)?;
+    writeln!(sink, "\"{}\\n\"", po::CONTENT_TYPE_HEADER)?;
+    if let Some(locale) = locale {
+        writeln!(sink, "\"{}: {}\\n\"", po::LANGUAGE_HEADER, escape(locale))?;
+    }
+
+    for android_string in strings {
+        writeln!(sink)?;
+        write_entry(&mut sink, android_string)?;
+    }
+
+    Ok(())
+}
+
+fn write_entry<W: Write>(sink: &mut W, android_string: &AndroidString) -> Result<(), InnerError> {
+    if let Some(comment) = android_string.comment() {
+        for line in comment.lines() {
+            writeln!(sink, "{} {}", po::EXTRACTED_COMMENT, line.trim())?;
+        }
+    }
+
+    if let Some(source_file) = android_string.source_file() {
+        writeln!(sink, "{} {}", po::REFERENCE, source_file)?;
+    }
+
+    let context = match android_string.module() {
+        None => String::from(android_string.name()),
+        Some(module) => format!(
+            "{}{}{}",
+            module,
+            constants::keys::MODULE_SEPARATOR,
+            android_string.name()
+        ),
+    };
+
+    writeln!(sink, "{} \"{}\"", po::MSGCTXT, escape(&context))?;
+    writeln!(sink, "{} \"{}\"", po::MSGID, escape(android_string.value()))?;
+    writeln!(sink, "{} \"\"", po::MSGSTR)?;
+    Ok(())
+}
+
+/// Look @ `po_reader::unescape`
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::android_string::AndroidString;
+
+    #[test]
+    fn writes_template() {
+        let mut sink = vec![];
+        super::write(
+            &mut sink,
+            None,
+            &[
+                AndroidString::localizable("s1", r#"Say "hi" \n <b>now</b>"#)
+                    .with_comment(Some(String::from("Greeting\n    on home")))
+                    .with_source_file(Some(String::from("strings.xml"))),
+                AndroidString::localizable("s2", "two")
+                    .with_module(Some(String::from("app/src/main/res"))),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(sink).unwrap(),
+            r#"msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+
+#. Greeting
+#. on home
+#: strings.xml
+msgctxt "s1"
+msgid "Say \"hi\" \\n <b>now</b>"
+msgstr ""
+
+msgctxt "app/src/main/res/s2"
+msgid "two"
+msgstr ""
+"#
+        );
+    }
+
+    #[test]
+    fn writes_language_header_for_locale() {
+        let mut sink = vec![];
+        super::write(
+            &mut sink,
+            Some("fr"),
+            &[AndroidString::localizable("s1", "one")],
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(sink).unwrap(),
+            r#"msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+"Language: fr\n"
+
+msgctxt "s1"
+msgid "one"
+msgstr ""
+"#
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use std::io::Write;
+
+use crate::android_string::AndroidString;
+use crate::error::InnerError;
+use crate::ops::keys;
+use crate::ops::text;
+
+/// Writes out a Java `.properties` file with a key per string (look @
+/// `keys::build`). Values are converted into plain text (look @
+/// `text::to_plain_text`) & format specifiers are left as is since they mean
+/// the same to `String.format`. Without `include_values`, every value is left
+/// empty (to be filled in). Anything other than printable ASCII is written out
+/// as a unicode escape so that the file can be read both as ISO-8859-1 & UTF-8
+pub fn write<W: Write>(
+    mut sink: W,
+    strings: &[AndroidString],
+    include_values: bool,
+) -> Result<(), InnerError> {
+    for (index, android_string) in strings.iter().enumerate() {
+        if index > 0 {
+            writeln!(sink)?;
+        }
+
+        if let Some(comment) = android_string.comment() {
+            for line in comment.lines() {
+                writeln!(sink, "# {}", escape_unicode(line.trim()))?;
+            }
+        }
+
+        let value = if include_values {
+            text::to_plain_text(android_string.value())
+        } else {
+            String::new()
+        };
+
+        writeln!(
+            sink,
+            "{}={}",
+            escape(
+                &keys::build(android_string.name(), android_string.module()),
+                true
+            ),
+            escape(&value, false)
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Look @ `properties_reader::unescape`
+fn escape(text: &str, is_key: bool) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for (index, c) in text.chars().enumerate() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ' ' if is_key || index == 0 => escaped.push_str("\\ "),
+            '=' | ':' | '#' | '!' if is_key => {
+                escaped.push('\\');
+                escaped.push(c)
+            }
+            _ => escaped.push_str(&escape_unicode(&c.to_string())),
+        }
+    }
+
+    escaped
+}
+
+fn escape_unicode(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii() && !c.is_ascii_control() {
+            escaped.push(c)
+        } else {
+            for unit in c.encode_utf16(&mut [0; 2]) {
+                escaped.push_str(&format!("\\u{:04X}", unit))
+            }
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::android_string::AndroidString;
+
+    #[test]
+    fn writes_properties() {
+        let mut sink = vec![];
+        super::write(
+            &mut sink,
+            &[
+                AndroidString::localizable("s1", r#"Don\'t %1$s\n &amp; caf&#233; 😀"#)
+                    .with_comment(Some(String::from("Greeting\n   on home"))),
+                AndroidString::localizable("s2", r#"" two""#)
+                    .with_module(Some(String::from("app/src/main/res"))),
+            ],
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(sink).unwrap(),
+            r#"# Greeting
+# on home
+s1=Don't %1$s\n & caf\u00E9 \uD83D\uDE00
+
+app/src/main/res/s2=\ two
+"#
+        );
+    }
+
+    #[test]
+    fn writes_empty_values() {
+        let mut sink = vec![];
+        super::write(&mut sink, &[AndroidString::localizable("s1", "one")], false).unwrap();
+        assert_eq!(String::from_utf8(sink).unwrap(), "s1=\n");
+    }
+}
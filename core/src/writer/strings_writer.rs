@@ -0,0 +1,96 @@
+use std::io::Write;
+
+use crate::android_string::AndroidString;
+use crate::error::InnerError;
+use crate::ops::ios;
+use crate::ops::keys;
+
+/// Writes out an iOS `.strings` file with a key per string (the name of the
+/// string prefixed with its module if it has one). Values are converted into
+/// iOS ones (look @ `ios::to_ios_value`). Without `include_values`, every value
+/// is left empty (to be filled in). The XML comment of a string is written out
+/// as a comment
+pub fn write<W: Write>(
+    mut sink: W,
+    strings: &[AndroidString],
+    include_values: bool,
+) -> Result<(), InnerError> {
+    for (index, android_string) in strings.iter().enumerate() {
+        if index > 0 {
+            writeln!(sink)?;
+        }
+
+        if let Some(comment) = android_string.comment() {
+            writeln!(sink, "/* {} */", comment.replace("*/", "* /"))?;
+        }
+
+        let value = if include_values {
+            ios::to_ios_value(android_string.value())
+        } else {
+            String::new()
+        };
+
+        writeln!(
+            sink,
+            "\"{}\" = \"{}\";",
+            escape(&keys::build(android_string.name(), android_string.module())),
+            escape(&value)
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Look @ `strings_reader::unescape`
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::android_string::AndroidString;
+
+    #[test]
+    fn writes_strings() {
+        let mut sink = vec![];
+        super::write(
+            &mut sink,
+            &[
+                AndroidString::localizable("s1", r#"Say \"hi\" to %1$s\n"#)
+                    .with_comment(Some(String::from("Greeting"))),
+                AndroidString::localizable("s2", "two")
+                    .with_module(Some(String::from("app/src/main/res"))),
+            ],
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(sink).unwrap(),
+            r#"/* Greeting */
+"s1" = "Say \"hi\" to %1$@\n";
+
+"app/src/main/res/s2" = "two";
+"#
+        );
+    }
+
+    #[test]
+    fn writes_empty_values() {
+        let mut sink = vec![];
+        super::write(&mut sink, &[AndroidString::localizable("s1", "one")], false).unwrap();
+        assert_eq!(String::from_utf8(sink).unwrap(), "\"s1\" = \"\";\n");
+    }
+}
@@ -0,0 +1,189 @@
+use std::io::Write;
+
+use xml::writer;
+use xml::writer::XmlEvent as WriteXmlEvent;
+use xml::EmitterConfig;
+
+use crate::android_plural::AndroidPlural;
+use crate::android_string::AndroidString;
+use crate::constants;
+use crate::constants::ios::elements;
+use crate::constants::ios::keys as plist_keys;
+use crate::error::InnerError;
+use crate::ops::ios;
+use crate::ops::keys;
+
+/// Writes out an iOS `.stringsdict` file with an entry per plural. `strings`
+/// are expected to be flattened plurals (look @ `AndroidPlural::flatten`) &
+/// anything else is skipped. Keys & values are built the same way as the ones
+/// in `strings_writer`
+pub fn write<W: Write>(
+    mut sink: W,
+    strings: &[AndroidString],
+    include_values: bool,
+) -> Result<(), InnerError> {
+    // xml-rs can't emit a doctype
+    writeln!(sink, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(sink, "{}", constants::ios::PLIST_DOCTYPE)?;
+
+    let mut writer = EmitterConfig::new()
+        .perform_indent(true)
+        .write_document_declaration(false)
+        .create_writer(sink);
+
+    writer.write(
+        WriteXmlEvent::start_element(elements::PLIST)
+            .attr("version", constants::ios::PLIST_VERSION),
+    )?;
+
+    writer.write(WriteXmlEvent::start_element(elements::DICT))?;
+    for (key, items) in group_plurals(strings) {
+        write_plural(&mut writer, &key, &items, include_values)?;
+    }
+
+    writer.write(WriteXmlEvent::end_element())?;
+    writer.write(WriteXmlEvent::end_element())?;
+    Ok(())
+}
+
+/// Keys of the plurals along with their quantities & iOS values, in the order
+/// they are first seen in
+fn group_plurals(strings: &[AndroidString]) -> Vec<(String, Vec<(&'static str, String)>)> {
+    let mut plurals: Vec<(String, Vec<(&'static str, String)>)> = vec![];
+    for android_string in strings {
+        let (name, quantity) = match AndroidPlural::unflatten_name(android_string.name()) {
+            None => continue,
+            Some(unflattened) => unflattened,
+        };
+
+        let key = keys::build(name, android_string.module());
+        let item = (quantity.as_str(), ios::to_ios_value(android_string.value()));
+        match plurals.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, items)) => items.push(item),
+            None => plurals.push((key, vec![item])),
+        }
+    }
+
+    plurals
+}
+
+fn write_plural<W: Write>(
+    writer: &mut writer::EventWriter<W>,
+    key: &str,
+    items: &[(&str, String)],
+    include_values: bool,
+) -> Result<(), InnerError> {
+    let value_type = items
+        .iter()
+        .find_map(|(_, value)| ios::value_type(value))
+        .unwrap_or(constants::ios::DEFAULT_VALUE_TYPE);
+
+    write_text_element(writer, elements::KEY, key)?;
+    writer.write(WriteXmlEvent::start_element(elements::DICT))?;
+    write_key_value(
+        writer,
+        plist_keys::FORMAT_KEY,
+        &format!("%#@{}@", constants::ios::PLURAL_VARIABLE),
+    )?;
+
+    write_text_element(writer, elements::KEY, constants::ios::PLURAL_VARIABLE)?;
+    writer.write(WriteXmlEvent::start_element(elements::DICT))?;
+    write_key_value(
+        writer,
+        plist_keys::SPEC_TYPE_KEY,
+        plist_keys::PLURAL_RULE_TYPE,
+    )?;
+    write_key_value(writer, plist_keys::VALUE_TYPE_KEY, value_type)?;
+    for (quantity, value) in items {
+        write_key_value(writer, quantity, if include_values { value } else { "" })?;
+    }
+
+    writer.write(WriteXmlEvent::end_element())?;
+    writer.write(WriteXmlEvent::end_element())?;
+    Ok(())
+}
+
+fn write_key_value<W: Write>(
+    writer: &mut writer::EventWriter<W>,
+    key: &str,
+    value: &str,
+) -> Result<(), InnerError> {
+    write_text_element(writer, elements::KEY, key)?;
+    write_text_element(writer, elements::STRING, value)
+}
+
+fn write_text_element<W: Write>(
+    writer: &mut writer::EventWriter<W>,
+    name: &str,
+    text: &str,
+) -> Result<(), InnerError> {
+    writer.write(WriteXmlEvent::start_element(name))?;
+    if !text.is_empty() {
+        writer.write(WriteXmlEvent::characters(text))?;
+    }
+
+    writer.write(WriteXmlEvent::end_element())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::android_string::AndroidString;
+
+    #[test]
+    fn writes_plurals() {
+        let mut sink = vec![];
+        super::write(
+            &mut sink,
+            &[
+                AndroidString::localizable("p1[one]", "%1$d file &amp; more"),
+                AndroidString::localizable("s1", "skipped"),
+                AndroidString::localizable("p1[other]", "%1$d files"),
+                AndroidString::localizable("p2[other]", "many")
+                    .with_module(Some(String::from("lib"))),
+            ],
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(sink).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+  <dict>
+    <key>p1</key>
+    <dict>
+      <key>NSStringLocalizedFormatKey</key>
+      <string>%#@value@</string>
+      <key>value</key>
+      <dict>
+        <key>NSStringFormatSpecTypeKey</key>
+        <string>NSStringPluralRuleType</string>
+        <key>NSStringFormatValueTypeKey</key>
+        <string>d</string>
+        <key>one</key>
+        <string>%1$d file &amp; more</string>
+        <key>other</key>
+        <string>%1$d files</string>
+      </dict>
+    </dict>
+    <key>lib/p2</key>
+    <dict>
+      <key>NSStringLocalizedFormatKey</key>
+      <string>%#@value@</string>
+      <key>value</key>
+      <dict>
+        <key>NSStringFormatSpecTypeKey</key>
+        <string>NSStringPluralRuleType</string>
+        <key>NSStringFormatValueTypeKey</key>
+        <string>d</string>
+        <key>other</key>
+        <string>many</string>
+      </dict>
+    </dict>
+  </dict>
+</plist>"#
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use std::io::Write;
+
+use xml::writer;
+use xml::writer::XmlEvent as WriteXmlEvent;
+use xml::EmitterConfig;
+
+use crate::android_string::AndroidString;
+use crate::constants;
+use crate::constants::xliff::attributes;
+use crate::constants::xliff::elements;
+use crate::error::InnerError;
+use crate::localizable_strings::LocalizableStrings;
+use crate::ops::tokens;
+use crate::ops::tokens::Segment;
+
+/// Writes out an XLIFF 1.2 document with a `file` per foreign locale & source
+/// file. Locales are written out as language tags (look @
+/// `LocalizableStrings::to_language_tag`). Values are written out as plain text with the markup & format
+/// specifiers in them as `ph` elements (look @ `tokens::segment`). Strings with
+/// modules (look @ `ResDir` & `SourceSet`) are put in a `group` per module
+pub fn write<W: Write>(
+    sink: W,
+    strings_list: Vec<LocalizableStrings>,
+    source_language: &str,
+) -> Result<(), InnerError> {
+    let mut writer = EmitterConfig::new()
+        .perform_indent(true)
+        .create_writer(sink);
+
+    writer.write(WriteXmlEvent::StartDocument {
+        version: xml::common::XmlVersion::Version10,
+        encoding: Some("UTF-8"),
+        standalone: None,
+    })?;
+
+    writer.write(
+        WriteXmlEvent::start_element(elements::XLIFF)
+            .default_ns(constants::namespaces::XLIFF)
+            .attr(attributes::VERSION, constants::xliff::VERSION),
+    )?;
+
+    for localizable_strings in &strings_list {
+        let mut source_files: Vec<&str> = vec![];
+        for android_string in localizable_strings.default_locale_strings() {
+            let source_file = source_file_of(android_string);
+            if !source_files.contains(&source_file) {
+                source_files.push(source_file);
+            }
+        }
+
+        for source_file in source_files {
+            write_file(
+                &mut writer,
+                localizable_strings,
+                source_file,
+                source_language,
+            )?;
+        }
+    }
+
+    writer.write(WriteXmlEvent::end_element())?;
+    Ok(())
+}
+
+fn write_file<W: Write>(
+    writer: &mut writer::EventWriter<W>,
+    localizable_strings: &LocalizableStrings,
+    source_file: &str,
+    source_language: &str,
+) -> Result<(), InnerError> {
+    let target_language = localizable_strings.to_language_tag();
+    writer.write(
+        WriteXmlEvent::start_element(elements::FILE)
+            .attr(attributes::ORIGINAL, source_file)
+            .attr(attributes::DATATYPE, constants::xliff::values::PLAINTEXT)
+            .attr(attributes::SOURCE_LANGUAGE, source_language)
+            .attr(attributes::TARGET_LANGUAGE, &target_language),
+    )?;
+
+    writer.write(WriteXmlEvent::start_element(elements::BODY))?;
+
+    // Strings of a module are next to each other
+    let mut current_module: Option<&str> = None;
+    for android_string in localizable_strings
+        .default_locale_strings()
+        .iter()
+        .filter(|android_string| source_file_of(android_string) == source_file)
+    {
+        if android_string.module() != current_module {
+            if current_module.is_some() {
+                writer.write(WriteXmlEvent::end_element())?;
+            }
+
+            current_module = android_string.module();
+            if let Some(module) = current_module {
+                writer.write(
+                    WriteXmlEvent::start_element(elements::GROUP).attr(attributes::RESNAME, module),
+                )?;
+            }
+        }
+
+        write_trans_unit(writer, android_string)?;
+    }
+
+    if current_module.is_some() {
+        writer.write(WriteXmlEvent::end_element())?;
+    }
+
+    writer.write(WriteXmlEvent::end_element())?;
+    writer.write(WriteXmlEvent::end_element())?;
+    Ok(())
+}
+
+fn write_trans_unit<W: Write>(
+    writer: &mut writer::EventWriter<W>,
+    android_string: &AndroidString,
+) -> Result<(), InnerError> {
+    // IDs must be unique in a file. Strings from different modules can share names
+    let id = match android_string.module() {
+        None => String::from(android_string.name()),
+        Some(module) => format!("{}/{}", module, android_string.name()),
+    };
+
+    let mut trans_unit_element = WriteXmlEvent::start_element(elements::TRANS_UNIT)
+        .attr(attributes::ID, &id)
+        .attr(attributes::RESNAME, android_string.name());
+    if !android_string.is_localizable() {
+        trans_unit_element =
+            trans_unit_element.attr(attributes::TRANSLATE, constants::xliff::values::NO);
+    }
+
+    writer.write(trans_unit_element)?;
+
+    writer.write(WriteXmlEvent::start_element(elements::SOURCE))?;
+    write_segments(writer, android_string.value())?;
+    writer.write(WriteXmlEvent::end_element())?;
+
+    if android_string.is_localizable() {
+        writer.write(WriteXmlEvent::start_element(elements::TARGET).attr(
+            attributes::STATE,
+            constants::xliff::states::NEEDS_TRANSLATION,
+        ))?;
+        writer.write(WriteXmlEvent::end_element())?;
+    }
+
+    if let Some(comment) = android_string.comment() {
+        writer.write(
+            WriteXmlEvent::start_element(elements::NOTE)
+                .attr(attributes::FROM, constants::xliff::values::DEVELOPER),
+        )?;
+        writer.write(WriteXmlEvent::characters(comment))?;
+        writer.write(WriteXmlEvent::end_element())?;
+    }
+
+    writer.write(WriteXmlEvent::end_element())?;
+    Ok(())
+}
+
+/// Text is written out as is & everything else as `ph` elements (numbered
+/// from 1) so that tools don't let them be translated
+fn write_segments<W: Write>(
+    writer: &mut writer::EventWriter<W>,
+    android_value: &str,
+) -> Result<(), InnerError> {
+    // The writer doesn't indent around elements right after some text. The
+    // content of a `source` is to be kept as is
+    writer.write(WriteXmlEvent::characters(""))?;
+
+    let mut ph_count = 0;
+    for segment in tokens::segment(android_value) {
+        match segment {
+            Segment::Text(text) => writer.write(WriteXmlEvent::characters(&text))?,
+            Segment::Verbatim(verbatim) => {
+                ph_count += 1;
+                writer.write(
+                    WriteXmlEvent::start_element(elements::PH)
+                        .attr(attributes::ID, &ph_count.to_string()),
+                )?;
+                writer.write(WriteXmlEvent::characters(&verbatim))?;
+                writer.write(WriteXmlEvent::end_element())?;
+            }
+        }
+    }
+
+    writer.write(WriteXmlEvent::characters(""))?;
+    Ok(())
+}
+
+/// Strings read from a res dir always have one
+fn source_file_of(android_string: &AndroidString) -> &str {
+    android_string
+        .source_file()
+        .unwrap_or(constants::fs::STRING_FILE_NAME)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::android_string::AndroidString;
+    use crate::localizable_strings::LocalizableStrings;
+
+    #[test]
+    fn writes_a_file_per_locale_and_source_file() {
+        let strings_list = vec![
+            LocalizableStrings::new(
+                String::from("fr"),
+                vec![
+                    AndroidString::localizable("s1", "<b>bold</b> &amp; %1$s")
+                        .with_comment(Some(String::from("Shown on the home screen")))
+                        .with_source_file(Some(String::from("strings.xml"))),
+                    AndroidString::unlocalizable("s2", "brand")
+                        .with_source_file(Some(String::from("donottranslate.xml"))),
+                ],
+            ),
+            LocalizableStrings::new(
+                String::from("spanish"),
+                vec![AndroidString::localizable("s1", r"Don\'t")],
+            )
+            .with_to_locale_id(String::from("b+es+419")),
+        ];
+
+        let mut sink = vec![];
+        super::write(&mut sink, strings_list, "en").unwrap();
+        assert_eq!(
+            String::from_utf8(sink).unwrap(),
+            r##"<?xml version="1.0" encoding="UTF-8"?>
+<xliff xmlns="urn:oasis:names:tc:xliff:document:1.2" version="1.2">
+  <file original="strings.xml" datatype="plaintext" source-language="en" target-language="fr">
+    <body>
+      <trans-unit id="s1" resname="s1">
+        <source><ph id="1">&lt;b></ph>bold<ph id="2">&lt;/b></ph> &amp; <ph id="3">%1$s</ph></source>
+        <target state="needs-translation" />
+        <note from="developer">Shown on the home screen</note>
+      </trans-unit>
+    </body>
+  </file>
+  <file original="donottranslate.xml" datatype="plaintext" source-language="en" target-language="fr">
+    <body>
+      <trans-unit id="s2" resname="s2" translate="no">
+        <source>brand</source>
+      </trans-unit>
+    </body>
+  </file>
+  <file original="strings.xml" datatype="plaintext" source-language="en" target-language="es-419">
+    <body>
+      <trans-unit id="s1" resname="s1">
+        <source>Don't</source>
+        <target state="needs-translation" />
+      </trans-unit>
+    </body>
+  </file>
+</xliff>"##
+        );
+    }
+
+    #[test]
+    fn writes_a_group_per_module() {
+        let strings_list = vec![LocalizableStrings::new(
+            String::from("fr"),
+            vec![
+                AndroidString::localizable("s1", "one")
+                    .with_module(Some(String::from("app/src/main/res"))),
+                AndroidString::localizable("s1", "one")
+                    .with_module(Some(String::from("lib/src/main/res"))),
+            ],
+        )];
+
+        let mut sink = vec![];
+        super::write(&mut sink, strings_list, "en").unwrap();
+        let content = String::from_utf8(sink).unwrap();
+        assert!(content.contains(
+            r##"<group resname="app/src/main/res">
+        <trans-unit id="app/src/main/res/s1" resname="s1">"##
+        ));
+        assert!(content.contains(
+            r##"<group resname="lib/src/main/res">
+        <trans-unit id="lib/src/main/res/s1" resname="s1">"##
+        ));
+    }
+}
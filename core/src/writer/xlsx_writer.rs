@@ -0,0 +1,379 @@
+use std::io::Write;
+
+use rust_xlsxwriter::Format as CellFormat;
+use rust_xlsxwriter::ProtectionOptions;
+use rust_xlsxwriter::Workbook;
+use rust_xlsxwriter::Worksheet;
+
+use android_localization_utilities::DevExpt;
+
+use crate::constants;
+use crate::error::InnerError;
+use crate::grouping::Grouping;
+use crate::localizable_strings::LocalizableStrings;
+use crate::writer::csv_writer;
+
+const NAME_COLUMN_WIDTH: f64 = 30.0;
+const VALUE_COLUMN_WIDTH: f64 = 50.0;
+const NUMBER_COLUMN_WIDTH: f64 = 12.0;
+/// Excel doesn't allow longer sheet names
+const MAX_SHEET_NAME_LENGTH: usize = 31;
+
+/// Writes out a workbook with a sheet per group of locales (look @
+/// `csv_writer::group`). Sheets are laid out the same way as the CSV files
+/// (look @ `csv_writer::Writer::write`) except for drafts, which are only ever
+/// written into CSV files. Every cell is formatted as text (so that nothing
+/// gets mangled into numbers or formulae) & only the columns of the foreign
+/// locales can be edited
+pub fn write<W: Write>(
+    mut sink: W,
+    strings_list: Vec<LocalizableStrings>,
+    grouping: Grouping,
+) -> Result<(), InnerError> {
+    let mut workbook = Workbook::new();
+    let mut sheet_names = vec![];
+    for some_strings_list in csv_writer::group(strings_list, grouping) {
+        let locales: Vec<&str> = some_strings_list.iter().map(|s| s.to_locale()).collect();
+        let sheet_name = build_sheet_name(&locales, &sheet_names);
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(&sheet_name)?;
+        write_sheet(worksheet, &some_strings_list)?;
+        sheet_names.push(sheet_name);
+    }
+
+    sink.write_all(&workbook.save_to_buffer()?)?;
+    Ok(())
+}
+
+/// Sheets are named after the locales in them, the same way CSV files are (Eg.,
+/// `fr_it`; look @ `localize::FileProvider`). Characters Excel doesn't allow
+/// are replaced, long names are cut down to `MAX_SHEET_NAME_LENGTH` & names
+/// clashing with the ones in `taken_names` (Excel ignores case) get a number
+/// at the end
+fn build_sheet_name(locales: &[&str], taken_names: &[String]) -> String {
+    let name: String = locales
+        .join("_")
+        .chars()
+        .map(|c| match c {
+            '[' | ']' | ':' | '*' | '?' | '/' | '\\' => '_',
+            c => c,
+        })
+        .collect();
+    let name = name.trim_matches('\'');
+    let name = if name.is_empty() { "sheet" } else { name };
+
+    let is_taken = |name: &str| {
+        taken_names
+            .iter()
+            .any(|taken_name| taken_name.to_lowercase() == name.to_lowercase())
+    };
+
+    let truncate = |name: &str, length: usize| name.chars().take(length).collect::<String>();
+    let mut sheet_name = truncate(name, MAX_SHEET_NAME_LENGTH);
+    let mut number = 1;
+    while is_taken(&sheet_name) {
+        number += 1;
+        let suffix = format!("_{}", number);
+        sheet_name = truncate(name, MAX_SHEET_NAME_LENGTH - suffix.len()) + &suffix;
+    }
+
+    sheet_name
+}
+
+fn write_sheet(
+    worksheet: &mut Worksheet,
+    strings_list: &[LocalizableStrings],
+) -> Result<(), InnerError> {
+    let header_format = CellFormat::new().set_bold();
+    let locked_format = CellFormat::new().set_text_wrap().set_num_format("@");
+    let unlocked_format = CellFormat::new()
+        .set_text_wrap()
+        .set_num_format("@")
+        .set_unlocked();
+
+    let localizable_strings = strings_list.first().expt("Empty strings list!");
+    let default_locale_strings = localizable_strings.default_locale_strings();
+    let has_modules = default_locale_strings.iter().any(|s| s.module().is_some());
+    let has_context = default_locale_strings
+        .iter()
+        .any(|s| s.comment().is_some() || s.max_length().is_some());
+    let has_changes = strings_list.iter().any(|s| !s.old_values().is_empty());
+    let has_suggestions = strings_list.iter().any(|s| !s.suggestions().is_empty());
+
+    // Columns that can't be edited before the ones of the foreign locales
+    let mut leading_headers = vec![];
+    if has_modules {
+        leading_headers.push((String::from("module"), NAME_COLUMN_WIDTH));
+    }
+
+    leading_headers.push((String::from("string_name"), NAME_COLUMN_WIDTH));
+    leading_headers.push((String::from("default_locale"), VALUE_COLUMN_WIDTH));
+    if has_context {
+        leading_headers.push((
+            String::from(constants::csv::DESCRIPTION_HEADER),
+            VALUE_COLUMN_WIDTH,
+        ));
+        leading_headers.push((
+            String::from(constants::csv::MAX_LENGTH_HEADER),
+            NUMBER_COLUMN_WIDTH,
+        ));
+    }
+
+    if has_changes {
+        leading_headers.push((
+            String::from(constants::csv::STATUS_HEADER),
+            NUMBER_COLUMN_WIDTH,
+        ));
+    }
+
+    // & after them
+    let mut trailing_headers = vec![];
+    for (is_written, suffix) in [
+        (has_changes, constants::csv::OLD_VALUES_HEADER_SUFFIX),
+        (has_suggestions, constants::csv::SUGGESTIONS_HEADER_SUFFIX),
+    ] {
+        if is_written {
+            for strings in strings_list {
+                trailing_headers.push((
+                    format!("{}{}", strings.to_locale(), suffix),
+                    VALUE_COLUMN_WIDTH,
+                ));
+            }
+        }
+    }
+
+    // Columns to be filled in
+    let leading_column_count = leading_headers.len() as u16;
+    let locale_count = strings_list.len() as u16;
+    for (index, strings) in strings_list.iter().enumerate() {
+        let column = leading_column_count + index as u16;
+        worksheet.set_column_format(column, &unlocked_format)?;
+        worksheet.set_column_width(column, VALUE_COLUMN_WIDTH)?;
+        worksheet.write_string_with_format(0, column, strings.to_locale(), &header_format)?;
+    }
+
+    let locked_columns = (0..leading_column_count)
+        .chain(leading_column_count + locale_count..)
+        .zip(leading_headers.iter().chain(trailing_headers.iter()));
+    for (column, (header, width)) in locked_columns {
+        worksheet.write_string_with_format(0, column, header, &header_format)?;
+        worksheet.set_column_width(column, *width)?;
+    }
+
+    // Values
+    for (index, localizable_string) in default_locale_strings.iter().enumerate() {
+        let row = index as u32 + 1;
+        let mut leading_values = vec![];
+        if has_modules {
+            leading_values.push(String::from(localizable_string.module().unwrap_or("")));
+        }
+
+        leading_values.push(String::from(localizable_string.name()));
+        leading_values.push(String::from(localizable_string.value()));
+        if has_context {
+            leading_values.push(String::from(localizable_string.comment().unwrap_or("")));
+            leading_values.push(
+                localizable_string
+                    .max_length()
+                    .map_or(String::new(), |l| l.to_string()),
+            );
+        }
+
+        if has_changes {
+            let is_changed = strings_list
+                .iter()
+                .any(|s| s.old_value_of(localizable_string).is_some());
+            leading_values.push(String::from(if is_changed {
+                constants::csv::STATUS_CHANGED
+            } else {
+                constants::csv::STATUS_NEW
+            }));
+        }
+
+        let mut trailing_values = vec![];
+        if has_changes {
+            for strings in strings_list {
+                trailing_values.push(strings.old_value_of(localizable_string).unwrap_or(""));
+            }
+        }
+
+        if has_suggestions {
+            for strings in strings_list {
+                trailing_values.push(strings.suggestion_of(localizable_string).unwrap_or(""));
+            }
+        }
+
+        for (column, value) in leading_values.iter().enumerate() {
+            worksheet.write_string_with_format(row, column as u16, value, &locked_format)?;
+        }
+
+        for (index, value) in trailing_values.into_iter().enumerate() {
+            let column = leading_column_count + locale_count + index as u16;
+            worksheet.write_string_with_format(row, column, value, &locked_format)?;
+        }
+    }
+
+    worksheet.set_freeze_panes(1, 0)?;
+    worksheet.protect_with_options(&ProtectionOptions {
+        format_columns: true,
+        format_rows: true,
+        ..ProtectionOptions::new()
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::io::Cursor;
+
+    use calamine::{open_workbook_from_rs, Data, Reader, Xlsx};
+
+    use crate::android_string::AndroidString;
+    use crate::grouping::Grouping;
+    use crate::localizable_strings::LocalizableStrings;
+
+    #[test]
+    fn writes_a_sheet_per_group_of_locales() {
+        let strings_list = vec![
+            LocalizableStrings::new(
+                String::from("french"),
+                vec![
+                    AndroidString::localizable("s1", "=SUM(A1:A2)"),
+                    AndroidString::localizable("s2", "007"),
+                ],
+            ),
+            LocalizableStrings::new(
+                String::from("spanish"),
+                vec![
+                    AndroidString::localizable("s1", "=SUM(A1:A2)"),
+                    AndroidString::localizable("s2", "007"),
+                ],
+            ),
+            LocalizableStrings::new(
+                String::from("german"),
+                vec![AndroidString::localizable("s1", "=SUM(A1:A2)")
+                    .with_module(Some(String::from("app/src/main/res")))],
+            ),
+        ];
+
+        let mut sheets = write_and_read_sheets(strings_list, Grouping::ByStrings);
+        sheets.sort();
+
+        assert_eq!(
+            sheets,
+            vec![
+                vec![
+                    vec!["module", "string_name", "default_locale", "german"],
+                    vec!["app/src/main/res", "s1", "=SUM(A1:A2)", ""],
+                ],
+                vec![
+                    vec!["string_name", "default_locale", "french", "spanish"],
+                    vec!["s1", "=SUM(A1:A2)", "", ""],
+                    vec!["s2", "007", "", ""],
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_out_columns_for_translators() {
+        let strings = vec![
+            AndroidString::localizable("s1", "one")
+                .with_comment(Some(String::from("Count")))
+                .with_max_length(Some(10)),
+            AndroidString::localizable("s2", "two"),
+        ];
+
+        let mut old_values = BTreeMap::new();
+        old_values.insert((None, String::from("s1")), String::from("un"));
+        let mut suggestions = BTreeMap::new();
+        suggestions.insert((None, String::from("s2")), String::from("deux"));
+        let strings_list = vec![LocalizableStrings::new(String::from("french"), strings)
+            .with_old_values(old_values)
+            .with_suggestions(suggestions)];
+
+        assert_eq!(
+            write_and_read_sheets(strings_list, Grouping::ByStrings),
+            vec![vec![
+                vec![
+                    "string_name",
+                    "default_locale",
+                    "description",
+                    "max_length",
+                    "status",
+                    "french",
+                    "french (old)",
+                    "french (suggestion)",
+                ],
+                vec!["s1", "one", "Count", "10", "changed", "", "un", ""],
+                vec!["s2", "two", "", "", "new", "", "", "deux"],
+            ]]
+        );
+    }
+
+    #[test]
+    fn names_sheets_after_their_locales() {
+        let strings_list = vec![
+            LocalizableStrings::new(
+                String::from("french"),
+                vec![AndroidString::localizable("s1", "one")],
+            ),
+            LocalizableStrings::new(
+                String::from("spanish"),
+                vec![AndroidString::localizable("s1", "one")],
+            ),
+            LocalizableStrings::new(
+                String::from("german"),
+                vec![AndroidString::localizable("s2", "two")],
+            ),
+        ];
+
+        let mut sink = vec![];
+        super::write(&mut sink, strings_list, Grouping::ByStrings).unwrap();
+        let workbook: Xlsx<_> = open_workbook_from_rs(Cursor::new(sink)).unwrap();
+        assert_eq!(workbook.sheet_names(), vec!["french_spanish", "german"]);
+    }
+
+    #[test]
+    fn builds_sheet_names_excel_allows() {
+        assert_eq!(super::build_sheet_name(&["fr", "it"], &[]), "fr_it");
+        assert_eq!(super::build_sheet_name(&["fr:ca", "it"], &[]), "fr_ca_it");
+        assert_eq!(
+            super::build_sheet_name(&["french", "spanish", "german", "italian"], &[]),
+            "french_spanish_german_italian"
+        );
+        assert_eq!(
+            super::build_sheet_name(&["french", "spanish", "german", "italian", "dutch"], &[]),
+            "french_spanish_german_italian_d"
+        );
+        assert_eq!(
+            super::build_sheet_name(
+                &["french", "spanish", "german", "italian", "dutch"],
+                &[String::from("French_spanish_german_italian_d")]
+            ),
+            "french_spanish_german_italian_2"
+        );
+    }
+
+    fn write_and_read_sheets(
+        strings_list: Vec<LocalizableStrings>,
+        grouping: Grouping,
+    ) -> Vec<Vec<Vec<String>>> {
+        let mut sink = vec![];
+        super::write(&mut sink, strings_list, grouping).unwrap();
+
+        let mut workbook: Xlsx<_> = open_workbook_from_rs(Cursor::new(sink)).unwrap();
+        workbook
+            .worksheets()
+            .into_iter()
+            .map(|(_, range)| {
+                range
+                    .rows()
+                    .map(|row| row.iter().map(Data::to_string).collect::<Vec<String>>())
+                    .collect::<Vec<Vec<String>>>()
+            })
+            .collect()
+    }
+}
@@ -1,85 +1,219 @@
-use std::io::BufWriter;
 use std::io::Write;
 
-use xml::reader::XmlEvent as ReadXmlEvent;
 use xml::writer;
 use xml::writer::XmlEvent as WriteXmlEvent;
 use xml::EmitterConfig;
 use xml::ParserConfig;
 
+use crate::android_plural::AndroidPlural;
+use crate::android_resources::AndroidResources;
 use crate::android_string::AndroidString;
+use crate::android_string_array::AndroidStringArray;
 use crate::constants;
 use crate::error::InnerError;
+use crate::strings_document::StringsDocument;
+use crate::writer::document_writer;
 
-pub fn write<S: Write>(
-    sink: &mut S,
-    android_strings: Vec<AndroidString>,
-) -> Result<(), InnerError> {
+/// Children of plurals & string arrays are indented this much more than their parents
+const CHILD_INDENT: &str = "    ";
+
+/// Writes out a whole new strings file (like the ones of pseudolocales). Look
+/// @ `StringsDocument` to update existing files instead
+pub fn write<S: Write>(sink: &mut S, resources: AndroidResources) -> Result<(), InnerError> {
+    let mut document = StringsDocument::empty();
+    let indent = document.indent();
+    let line_ending = document.line_ending();
+    let (android_strings, android_plurals, android_string_arrays) = resources.into_parts();
+    for android_string in android_strings {
+        document.push(
+            constants::elements::STRING,
+            android_string.name(),
+            build_string_element(&android_string)?,
+        );
+    }
+
+    for android_plural in android_plurals {
+        document.push(
+            constants::elements::PLURALS,
+            android_plural.name(),
+            build_plurals_element(&android_plural, &indent, line_ending)?,
+        );
+    }
+
+    for android_string_array in android_string_arrays {
+        document.push(
+            constants::elements::STRING_ARRAY,
+            android_string_array.name(),
+            build_string_array_element(&android_string_array, &indent, line_ending)?,
+        );
+    }
+
+    document_writer::write(sink, &document)
+}
+
+/// Builds the markup of a lone `string` element (to be placed in an existing document)
+pub fn build_string_element(android_string: &AndroidString) -> Result<String, InnerError> {
+    build_element(|writer| write_android_string(writer, android_string))
+}
+
+/// Builds the markup of a lone `plurals` element (to be placed in an existing
+/// document at `indent`). Its items are indented one level deeper than `indent`
+pub fn build_plurals_element(
+    android_plural: &AndroidPlural,
+    indent: &str,
+    line_ending: &str,
+) -> Result<String, InnerError> {
+    let layout = ChildrenLayout::new(indent, line_ending);
+    build_element(|writer| write_android_plural(writer, android_plural, &layout))
+}
+
+/// Builds the markup of a lone `string-array` element (to be placed in an existing
+/// document at `indent`). Its items are indented one level deeper than `indent`
+pub fn build_string_array_element(
+    android_string_array: &AndroidStringArray,
+    indent: &str,
+    line_ending: &str,
+) -> Result<String, InnerError> {
+    let layout = ChildrenLayout::new(indent, line_ending);
+    build_element(|writer| write_android_string_array(writer, android_string_array, &layout))
+}
+
+fn build_element<F>(write_element: F) -> Result<String, InnerError>
+where
+    F: FnOnce(&mut writer::EventWriter<&mut Vec<u8>>) -> Result<(), InnerError>,
+{
+    let mut sink = vec![];
     let mut writer = EmitterConfig::new()
-        .perform_indent(true)
-        .indent_string("    ") // 4 spaces
-        .write_document_declaration(true)
-        .create_writer(BufWriter::new(sink));
+        .perform_indent(false)
+        .normalize_empty_elements(false)
+        .write_document_declaration(false)
+        .create_writer(&mut sink);
 
-    // Start resources element
-    writer.write(WriteXmlEvent::start_element(constants::elements::RESOURCES))?;
+    write_element(&mut writer)?;
+    String::from_utf8(sink).map_err(|error| InnerError::from(error.to_string()))
+}
 
-    // Write all string elements
-    for android_string in android_strings {
-        // String tag with name attribute
-        let mut string_element = WriteXmlEvent::start_element(constants::elements::STRING)
-            .attr(constants::attributes::NAME, android_string.name());
-
-        // Include `localizable` attribute if required
-        if !android_string.is_localizable() {
-            string_element =
-                string_element.attr(constants::attributes::LOCALIZABLE, constants::flags::FALSE);
+/// Whitespace around the children of plurals & string arrays. Written out by hand
+/// since the elements are placed in documents that have their own indentation
+struct ChildrenLayout {
+    before_child: String,
+    before_end: String,
+}
+
+impl ChildrenLayout {
+    fn new(indent: &str, line_ending: &str) -> ChildrenLayout {
+        ChildrenLayout {
+            before_child: format!("{}{}{}", line_ending, indent, CHILD_INDENT),
+            before_end: format!("{}{}", line_ending, indent),
         }
+    }
+}
+
+fn write_android_string<W: Write>(
+    writer: &mut writer::EventWriter<W>,
+    android_string: &AndroidString,
+) -> Result<(), InnerError> {
+    // String tag with name attribute
+    let mut string_element = WriteXmlEvent::start_element(constants::elements::STRING)
+        .attr(constants::attributes::NAME, android_string.name());
+
+    // Include `localizable` attribute if required
+    if !android_string.is_localizable() {
+        string_element =
+            string_element.attr(constants::attributes::LOCALIZABLE, constants::flags::FALSE);
+    }
+
+    writer.write(string_element)?;
+    write_string(writer, android_string.value())?;
+    writer.write(WriteXmlEvent::end_element())?;
+    Ok(())
+}
+
+fn write_android_plural<W: Write>(
+    writer: &mut writer::EventWriter<W>,
+    android_plural: &AndroidPlural,
+    layout: &ChildrenLayout,
+) -> Result<(), InnerError> {
+    // Plurals tag with name attribute
+    let mut plurals_element = WriteXmlEvent::start_element(constants::elements::PLURALS)
+        .attr(constants::attributes::NAME, android_plural.name());
+
+    // Include `localizable` attribute if required
+    if !android_plural.is_localizable() {
+        plurals_element =
+            plurals_element.attr(constants::attributes::LOCALIZABLE, constants::flags::FALSE);
+    }
 
-        writer.write(string_element)?;
-        write_string(&mut writer, android_string.value())?;
+    writer.write(plurals_element)?;
+    for item in android_plural.items() {
+        writer.write(WriteXmlEvent::characters(&layout.before_child))?;
+        writer.write(
+            WriteXmlEvent::start_element(constants::elements::ITEM)
+                .attr(constants::attributes::QUANTITY, item.quantity().as_str()),
+        )?;
+        write_string(writer, item.value())?;
         writer.write(WriteXmlEvent::end_element())?;
     }
 
-    // Ending resources
+    writer.write(WriteXmlEvent::characters(&layout.before_end))?;
     writer.write(WriteXmlEvent::end_element())?;
+    Ok(())
+}
+
+fn write_android_string_array<W: Write>(
+    writer: &mut writer::EventWriter<W>,
+    android_string_array: &AndroidStringArray,
+    layout: &ChildrenLayout,
+) -> Result<(), InnerError> {
+    // String array tag with name attribute
+    let mut string_array_element = WriteXmlEvent::start_element(constants::elements::STRING_ARRAY)
+        .attr(constants::attributes::NAME, android_string_array.name());
+
+    // Include `localizable` attribute if required
+    if !android_string_array.is_localizable() {
+        string_array_element =
+            string_array_element.attr(constants::attributes::LOCALIZABLE, constants::flags::FALSE);
+    }
+
+    writer.write(string_array_element)?;
+    for item in android_string_array.items() {
+        writer.write(WriteXmlEvent::characters(&layout.before_child))?;
+        writer.write(WriteXmlEvent::start_element(constants::elements::ITEM))?;
+        write_string(writer, item)?;
+        writer.write(WriteXmlEvent::end_element())?;
+    }
 
+    writer.write(WriteXmlEvent::characters(&layout.before_end))?;
+    writer.write(WriteXmlEvent::end_element())?;
     Ok(())
 }
 
+/// Values are markup (text, CDATA sections & elements like `<b>` or `<xliff:g>`)
+/// & are written out verbatim once they are found to be well formed
 fn write_string<W: Write>(
     writer: &mut writer::EventWriter<W>,
     value: &str,
 ) -> Result<(), InnerError> {
-    // Right now, to write CDATA sections in strings properly out to the file,
-    // we are creating a reader & then piping the required read events to the
-    // writer. This feels wasteful! There has got to a better, more efficient
-    // way to do this
-
-    // Artificially inject tags to create valid XML out of the passed in string
-    let value = format!("<a>{}</a>", value);
-    let reader = ParserConfig::new().create_reader(value.as_bytes());
-    for element_or_error in reader {
-        match element_or_error {
-            Err(error) => return Err::<_, InnerError>(From::from(error)),
-            Ok(ref element) => match element {
-                ReadXmlEvent::Characters(_) => {
-                    writer.write(element.as_writer_event().ok_or_else(|| {
-                        InnerError::from(format!("Can't build writer event from {}", &value))
-                    })?)
-                }
-
-                ReadXmlEvent::CData(_) => {
-                    writer.write(element.as_writer_event().ok_or_else(|| {
-                        InnerError::from(format!("Can't build writer event from {}", &value))
-                    })?)
-                }
-
-                _ => Ok(()), // No op for other events
-            },
-        }?;
+    // Artificially inject tags (with the namespaces values usually refer to) to
+    // create valid XML out of the passed in string
+    let wrapped_value = format!(
+        "<a xmlns:{}=\"{}\" xmlns:{}=\"{}\">{}</a>",
+        constants::namespaces::XLIFF_PREFIX,
+        constants::namespaces::XLIFF,
+        constants::namespaces::TOOLS_PREFIX,
+        constants::namespaces::TOOLS,
+        value
+    );
+
+    for element_or_error in ParserConfig::new().create_reader(wrapped_value.as_bytes()) {
+        if let Err(error) = element_or_error {
+            return Err(format!("Value isn't well formed ({}): {}", error, value).into());
+        }
     }
 
+    // Emitter must have been configured to not normalize empty elements, so that
+    // the start tag is already closed
+    writer.inner_mut().write_all(value.as_bytes())?;
     Ok(())
 }
 
@@ -87,7 +221,11 @@ fn write_string<W: Write>(
 mod tests {
     use test_utilities;
 
+    use crate::android_plural::AndroidPlural;
+    use crate::android_plural::Quantity;
+    use crate::android_resources::AndroidResources;
     use crate::android_string::AndroidString;
+    use crate::android_string_array::AndroidStringArray;
 
     #[test]
     fn writes_strings_to_file() {
@@ -98,7 +236,7 @@ mod tests {
 
         // Write strings to a vector & split o/p into lines
         let mut sink: Vec<u8> = vec![];
-        super::write(&mut sink, android_strings).unwrap();
+        super::write(&mut sink, android_strings.into()).unwrap();
         let written_content = String::from_utf8(sink).unwrap();
         let written_lines = written_content.lines();
 
@@ -114,6 +252,178 @@ mod tests {
         )
     }
 
+    #[test]
+    fn writes_plurals_to_file() {
+        let resources = AndroidResources::new(
+            vec![AndroidString::localizable("s1", "value")],
+            vec![
+                AndroidPlural::localizable(
+                    "p1",
+                    vec![
+                        (Quantity::One, "%d <![CDATA[<b>item</b>]]>"),
+                        (Quantity::Other, "%d items"),
+                    ],
+                ),
+                AndroidPlural::unlocalizable("p2", vec![(Quantity::Other, "value")]),
+            ],
+            vec![],
+        );
+
+        // Write resources to a vector & split o/p into lines
+        let mut sink: Vec<u8> = vec![];
+        super::write(&mut sink, resources).unwrap();
+        let written_content = String::from_utf8(sink).unwrap();
+        let written_lines = written_content.lines();
+
+        test_utilities::list::assert_strict_list_eq(
+            written_lines.collect::<Vec<&str>>(),
+            vec![
+                r##"<?xml version="1.0" encoding="utf-8"?>"##,
+                r##"<resources>"##,
+                r##"    <string name="s1">value</string>"##,
+                r##"    <plurals name="p1">"##,
+                r##"        <item quantity="one">%d <![CDATA[<b>item</b>]]></item>"##,
+                r##"        <item quantity="other">%d items</item>"##,
+                r##"    </plurals>"##,
+                r##"    <plurals name="p2" translatable="false">"##,
+                r##"        <item quantity="other">value</item>"##,
+                r##"    </plurals>"##,
+                r##"</resources>"##,
+            ],
+        )
+    }
+
+    #[test]
+    fn writes_string_arrays_to_file() {
+        let resources = AndroidResources::new(
+            vec![AndroidString::localizable("s1", "value")],
+            vec![AndroidPlural::localizable(
+                "p1",
+                vec![(Quantity::Other, "%d items")],
+            )],
+            vec![
+                AndroidStringArray::localizable("a1", vec!["first", "<![CDATA[<b>second</b>]]>"]),
+                AndroidStringArray::unlocalizable("a2", vec!["value"]),
+            ],
+        );
+
+        // Write resources to a vector & split o/p into lines
+        let mut sink: Vec<u8> = vec![];
+        super::write(&mut sink, resources).unwrap();
+        let written_content = String::from_utf8(sink).unwrap();
+        let written_lines = written_content.lines();
+
+        test_utilities::list::assert_strict_list_eq(
+            written_lines.collect::<Vec<&str>>(),
+            vec![
+                r##"<?xml version="1.0" encoding="utf-8"?>"##,
+                r##"<resources>"##,
+                r##"    <string name="s1">value</string>"##,
+                r##"    <plurals name="p1">"##,
+                r##"        <item quantity="other">%d items</item>"##,
+                r##"    </plurals>"##,
+                r##"    <string-array name="a1">"##,
+                r##"        <item>first</item>"##,
+                r##"        <item><![CDATA[<b>second</b>]]></item>"##,
+                r##"    </string-array>"##,
+                r##"    <string-array name="a2" translatable="false">"##,
+                r##"        <item>value</item>"##,
+                r##"    </string-array>"##,
+                r##"</resources>"##,
+            ],
+        )
+    }
+
+    #[test]
+    fn builds_lone_elements() {
+        assert_eq!(
+            super::build_string_element(&AndroidString::unlocalizable("s1", "a <![CDATA[b]]>"))
+                .unwrap(),
+            r##"<string name="s1" translatable="false">a <![CDATA[b]]></string>"##
+        );
+
+        assert_eq!(
+            super::build_plurals_element(
+                &AndroidPlural::localizable(
+                    "p1",
+                    vec![(Quantity::One, "one"), (Quantity::Other, "other")]
+                ),
+                "\t",
+                "\r\n"
+            )
+            .unwrap(),
+            "<plurals name=\"p1\">\r\n\t    <item quantity=\"one\">one</item>\r\n\t    <item quantity=\"other\">other</item>\r\n\t</plurals>"
+        );
+
+        assert_eq!(
+            super::build_string_array_element(
+                &AndroidStringArray::localizable("a1", vec!["first"]),
+                "  ",
+                "\n"
+            )
+            .unwrap(),
+            "<string-array name=\"a1\">\n      <item>first</item>\n  </string-array>"
+        );
+    }
+
+    #[test]
+    fn writes_inline_markup_verbatim() {
+        let value = r##"<xliff:g id="count" example="5">%1$d</xliff:g> &lt; <b>items</b><br/>"##;
+        let mut sink: Vec<u8> = vec![];
+        super::write(
+            &mut sink,
+            vec![AndroidString::localizable("s1", value)].into(),
+        )
+        .unwrap();
+
+        let written_content = String::from_utf8(sink).unwrap();
+        test_utilities::list::assert_strict_list_eq(
+            written_content.lines().collect::<Vec<&str>>(),
+            vec![
+                r##"<?xml version="1.0" encoding="utf-8"?>"##,
+                r##"<resources xmlns:xliff="urn:oasis:names:tc:xliff:document:1.2">"##,
+                &format!("    <string name=\"s1\">{}</string>", value),
+                r##"</resources>"##,
+            ],
+        )
+    }
+
+    #[test]
+    fn declares_xliff_namespace_if_values_refer_to_it() {
+        let mut sink: Vec<u8> = vec![];
+        super::write(
+            &mut sink,
+            vec![AndroidString::localizable(
+                "s1",
+                r#"Hi <xliff:g id="name">%1$s</xliff:g>"#,
+            )]
+            .into(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(sink).unwrap(),
+            r##"<?xml version="1.0" encoding="utf-8"?>
+<resources xmlns:xliff="urn:oasis:names:tc:xliff:document:1.2">
+    <string name="s1">Hi <xliff:g id="name">%1$s</xliff:g></string>
+</resources>
+"##
+        )
+    }
+
+    #[test]
+    fn errors_for_malformed_value() {
+        let mut sink: Vec<u8> = vec![];
+        let error = super::write(
+            &mut sink,
+            vec![AndroidString::localizable("s1", "<b>unclosed")].into(),
+        )
+        .unwrap_err();
+
+        assert!(error.to_string().starts_with("Value isn't well formed"));
+        assert!(error.to_string().ends_with(": <b>unclosed"));
+    }
+
     #[test]
     fn writes_string_with_one_cdata_event() {
         test_cdata_handling("<![CDATA[this is a test]]>")
@@ -137,7 +447,11 @@ mod tests {
     fn test_cdata_handling(value: &str) {
         // Write string to a vector & split o/p into lines
         let mut sink: Vec<u8> = vec![];
-        super::write(&mut sink, vec![AndroidString::localizable("s1", value)]).unwrap();
+        super::write(
+            &mut sink,
+            vec![AndroidString::localizable("s1", value)].into(),
+        )
+        .unwrap();
 
         let written_content = String::from_utf8(sink).unwrap();
         let written_lines = written_content.lines();
@@ -31,6 +31,23 @@ pub fn setup_empty_strings_for_locale<P: AsRef<Path>, L: AsRef<str>>(
     setup_strings_file::<String, L>(values_dir_path, Some(locale_id))
 }
 
+pub fn setup_empty_resource_file_for_default_locale<P: AsRef<Path>>(
+    res_path: P,
+    file_name: &str,
+) -> FileWithPath {
+    let values_dir_path = setup_values_dir_for_default_locale(res_path);
+    setup_resource_file(values_dir_path, file_name)
+}
+
+pub fn setup_empty_resource_file_for_locale<P: AsRef<Path>, L: AsRef<str>>(
+    res_path: P,
+    locale_id: L,
+    file_name: &str,
+) -> FileWithPath {
+    let values_dir_path = setup_values_dir_for_locale(res_path, locale_id);
+    setup_resource_file(values_dir_path, file_name)
+}
+
 fn setup_values_dir<P: AsRef<Path>, L: AsRef<str>>(res_path: P, locale_id: Option<L>) -> String {
     let mut locale_values_dir_path = PathBuf::from(res_path.as_ref());
     match locale_id {
@@ -46,12 +63,16 @@ fn setup_strings_file<P: AsRef<Path>, L: AsRef<str>>(
     locale_values_dir_path: P,
     _locale_id: Option<L>,
 ) -> FileWithPath {
-    let mut strings_file_path = PathBuf::from(locale_values_dir_path.as_ref());
-    strings_file_path.push("strings.xml");
-    let file = File::create(&strings_file_path).unwrap();
+    setup_resource_file(locale_values_dir_path, "strings.xml")
+}
+
+fn setup_resource_file<P: AsRef<Path>>(values_dir_path: P, file_name: &str) -> FileWithPath {
+    let mut file_path = PathBuf::from(values_dir_path.as_ref());
+    file_path.push(file_name);
+    let file = File::create(&file_path).unwrap();
 
     FileWithPath {
         file,
-        path: String::from(strings_file_path.to_str().unwrap()),
+        path: String::from(file_path.to_str().unwrap()),
     }
 }